@@ -6,6 +6,7 @@
 @group(2) @binding(3) var<uniform> trail_color: vec4<f32>;
 @group(2) @binding(4) var<uniform> trail_theta: f32;
 @group(2) @binding(5) var<uniform> clockwise: u32;  
+@group(2) @binding(6) var<uniform> fade_exponent: f32;
 
 const PI = 3.14159265359;
 
@@ -43,10 +44,11 @@ fn fragment(mesh: VertexOutput) -> @location(0) vec4<f32> {
         theta_diff = 2*PI - fragment_theta + origin_theta;
     }
 
-    if theta_diff > trail_theta {
+    if trail_theta <= 0. || theta_diff > trail_theta {
         return background_color;
     }
   
-    let blend = theta_diff / trail_theta;
+    // an exponent of 0 keeps the whole trail opaque, while 1 fades it linearly.
+    let blend = 1. - pow(1. - theta_diff / trail_theta, fade_exponent);
     return mix(trail_color, background_color, vec4(blend, blend, blend, blend));
 }
\ No newline at end of file
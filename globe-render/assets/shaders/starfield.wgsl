@@ -0,0 +1,75 @@
+#import bevy_pbr::mesh_functions::{get_world_from_local, mesh_position_local_to_clip}
+
+@group(2) @binding(0) var<uniform> background_color: vec4<f32>;
+@group(2) @binding(1) var<uniform> brightness: f32;
+@group(2) @binding(2) var<uniform> density: f32;
+@group(2) @binding(3) var<uniform> textured: u32;
+@group(2) @binding(4) var sky_texture: texture_2d<f32>;
+@group(2) @binding(5) var sky_sampler: sampler;
+
+const PI = 3.14159265359;
+// The radius of the stars, relative to the size of the cells they are scattered in.
+const STAR_RADIUS = 0.1;
+// The fraction of cells holding a star.
+const STAR_PROBABILITY = 0.3;
+// The greater, the fewer bright stars there are compared to faint ones.
+const BRIGHTNESS_EXPONENT = 6.;
+
+struct Vertex {
+    @builtin(instance_index) instance_index: u32,
+    @location(0) position: vec3<f32>,
+};
+
+struct VertexOutput {
+    @builtin(position) clip_position: vec4<f32>,
+    @location(0) direction: vec3<f32>,
+};
+
+@vertex
+fn vertex(vertex: Vertex) -> VertexOutput {
+    let world_from_local = get_world_from_local(vertex.instance_index);
+
+    var out: VertexOutput;
+    out.clip_position = mesh_position_local_to_clip(world_from_local, vec4(vertex.position, 1.));
+    // the background lies on the far plane, so it never occludes anything drawn in front of it.
+    out.clip_position.z = 0.;
+    // the direction is rotated into world space, but not translated, so the sky never shifts.
+    out.direction = (world_from_local * vec4(vertex.position, 0.)).xyz;
+    return out;
+}
+
+// Returns a pseudo-random vector, in the range of [0, 1), for the given cell.
+fn hash(cell: vec3<f32>) -> vec3<f32> {
+    var p = fract(cell * vec3(0.1031, 0.1030, 0.0973));
+    p += dot(p, p.yxz + 33.33);
+    return fract((p.xxy + p.yxx) * p.zyx);
+}
+
+// Returns the light of the star, if any, seen in the given direction.
+fn stars(direction: vec3<f32>) -> f32 {
+    let position = direction * density;
+    let cell = floor(position);
+    let random = hash(cell);
+    if random.x > STAR_PROBABILITY {
+        return 0.;
+    }
+
+    let star = cell + 0.5 + (hash(cell + 17.) - 0.5) * (1. - 2. * STAR_RADIUS);
+    let light = 1. - smoothstep(0., STAR_RADIUS, distance(position, star));
+    return light * pow(random.y, BRIGHTNESS_EXPONENT);
+}
+
+@fragment
+fn fragment(in: VertexOutput) -> @location(0) vec4<f32> {
+    let direction = normalize(in.direction);
+
+    if bool(textured) {
+        // the texture is equirectangular, being the z axis the one perpendicular to the system.
+        let uv = vec2(atan2(direction.y, direction.x) / (2. * PI) + 0.5, acos(direction.z) / PI);
+        let sky = textureSample(sky_texture, sky_sampler, uv);
+        return vec4(sky.rgb * brightness, 1.);
+    }
+
+    let light = stars(direction) * brightness;
+    return vec4(background_color.rgb + vec3(light, light, light), 1.);
+}
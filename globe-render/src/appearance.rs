@@ -0,0 +1,181 @@
+use std::{collections::HashMap, path::Path};
+
+use alvidir::name::Name;
+use bevy::{
+    asset::AssetLoadFailedEvent,
+    image::ImageSampler,
+    prelude::*,
+    render::{
+        render_asset::RenderAssetUsages,
+        render_resource::{Extent3d, TextureDimension, TextureFormat},
+    },
+};
+use serde::{de::Error as _, Deserialize, Deserializer};
+
+use crate::{color, orbit::Body};
+
+/// How many squares the default texture of a body has along each of its sides.
+const CHECKER_SQUARES: usize = 8;
+/// The size of each square of the default texture of a body, in texels.
+const CHECKER_SQUARE_SIZE: usize = 8;
+
+/// How a single body looks, keeping the defaults for anything left unspecified.
+#[derive(Debug, Default, Clone, PartialEq, Deserialize)]
+#[serde(default)]
+pub struct BodyAppearance {
+    /// The base color of the body, given as an hexadecimal RGB string (e.g. "#e79356").
+    #[serde(deserialize_with = "deserialize_hex_color")]
+    pub color: Option<Color>,
+    /// How much light the body emits, relative to its base color.
+    pub emissive: Option<f32>,
+    /// The path of the texture wrapping the body, relative to the assets folder.
+    pub texture: Option<String>,
+}
+
+/// The appearance of the bodies in the system, indexed by name.
+///
+/// Bodies with no appearance keep the default one: luminous bodies are orange and glow, while
+/// any other body is khaki.
+#[derive(Resource, Debug, Default, Clone, PartialEq, Deserialize)]
+#[serde(transparent)]
+pub struct Appearance {
+    pub bodies: HashMap<Name<globe_rs::Body>, BodyAppearance>,
+}
+
+impl Plugin for Appearance {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(self.clone())
+            .init_resource::<DefaultTexture>()
+            .add_systems(Update, Self::on_texture_load_failed);
+    }
+}
+
+impl Appearance {
+    /// Returns the base color of the given body.
+    pub fn color(&self, body: &globe_rs::Body) -> Color {
+        self.bodies
+            .get(&body.name)
+            .and_then(|appearance| appearance.color)
+            .unwrap_or(if body.is_luminous() {
+                color::PERSIAN_ORANGE
+            } else {
+                color::KHAKI
+            })
+    }
+
+    /// Returns how much light the given body emits, relative to its base color.
+    pub fn emissive(&self, body: &globe_rs::Body) -> f32 {
+        self.bodies
+            .get(&body.name)
+            .and_then(|appearance| appearance.emissive)
+            .unwrap_or(if body.is_luminous() { 1. } else { 0. })
+    }
+
+    /// Returns the texture wrapping the given body, being the default one if it has none.
+    pub fn texture(
+        &self,
+        body: &globe_rs::Body,
+        asset_server: &AssetServer,
+        default: &DefaultTexture,
+    ) -> Handle<Image> {
+        self.bodies
+            .get(&body.name)
+            .and_then(|appearance| appearance.texture.as_ref())
+            .map(|path| asset_server.load(path.clone()))
+            .unwrap_or_else(|| default.0.clone())
+    }
+
+    /// Wraps the bodies whose texture could not be loaded with the default one, forgetting about
+    /// that texture so bodies spawned later on do not try to load it again.
+    fn on_texture_load_failed(
+        mut texture_load_failed: EventReader<AssetLoadFailedEvent<Image>>,
+        mut materials: ResMut<Assets<StandardMaterial>>,
+        mut appearance: ResMut<Self>,
+        bodies: Query<&MeshMaterial3d<StandardMaterial>, With<Body>>,
+        default: Res<DefaultTexture>,
+    ) {
+        texture_load_failed.read().for_each(|event| {
+            warn!("{}: {}, using the default texture", event.path, event.error);
+
+            appearance
+                .bodies
+                .values_mut()
+                .filter(|appearance| {
+                    appearance
+                        .texture
+                        .as_ref()
+                        .is_some_and(|path| Path::new(path) == event.path.path())
+                })
+                .for_each(|appearance| appearance.texture = None);
+
+            bodies.iter().for_each(|material| {
+                let Some(material) = materials.get_mut(material) else {
+                    return;
+                };
+
+                if material
+                    .base_color_texture
+                    .as_ref()
+                    .is_some_and(|texture| texture.id() == event.id)
+                {
+                    material.base_color_texture = Some(default.0.clone());
+                }
+            });
+        });
+    }
+}
+
+/// The texture wrapping the bodies with no texture of their own, checkered so their rotation is
+/// visible.
+#[derive(Resource)]
+pub struct DefaultTexture(pub Handle<Image>);
+
+impl FromWorld for DefaultTexture {
+    fn from_world(world: &mut World) -> Self {
+        let mut images = world.resource_mut::<Assets<Image>>();
+        Self(images.add(checker_texture()))
+    }
+}
+
+/// Returns a checkered texture, so the rotation of the bodies wrapped with it is visible.
+fn checker_texture() -> Image {
+    let side = CHECKER_SQUARES * CHECKER_SQUARE_SIZE;
+    let data = (0..side * side)
+        .flat_map(|texel| {
+            let (row, column) = (texel / side, texel % side);
+            let light =
+                (row / CHECKER_SQUARE_SIZE + column / CHECKER_SQUARE_SIZE).is_multiple_of(2);
+            let value = if light { u8::MAX } else { 160 };
+            [value, value, value, u8::MAX]
+        })
+        .collect();
+
+    let mut image = Image::new(
+        Extent3d {
+            width: side as u32,
+            height: side as u32,
+            depth_or_array_layers: 1,
+        },
+        TextureDimension::D2,
+        data,
+        TextureFormat::Rgba8UnormSrgb,
+        RenderAssetUsages::RENDER_WORLD,
+    );
+
+    image.sampler = ImageSampler::nearest();
+    image
+}
+
+/// Deserializes an optional color from its hexadecimal RGB representation.
+fn deserialize_hex_color<'de, D>(deserializer: D) -> Result<Option<Color>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    Option::<String>::deserialize(deserializer)?
+        .map(|hex| {
+            Srgba::hex(&hex)
+                .map(Color::from)
+                .map_err(|error| D::Error::custom(format!("{hex}: {error}")))
+        })
+        .transpose()
+}
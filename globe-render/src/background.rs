@@ -0,0 +1,184 @@
+use bevy::{
+    asset::AssetLoadFailedEvent, prelude::*, render::view::RenderLayers, window::PrimaryWindow,
+};
+use serde::Deserialize;
+
+use crate::{
+    camera::MainCamera,
+    color,
+    material::StarfieldMaterial,
+    orbit::{scale::ScaleMode, Body, HabitableZone, Orbit, OrbitalSystem},
+};
+
+/// How many stars fit, at most, along every axis of the sky.
+const DEFAULT_DENSITY: f32 = 200.;
+/// How much the background dims at most when a luminous body fills the window.
+const MAX_DIMMING: f32 = 0.8;
+/// The render layer of the sky, so only the main camera draws it.
+const SKY_LAYER: usize = 1;
+
+/// The sky behind the orbital system, which is either a procedural starfield or the given
+/// texture.
+///
+/// The sky moves along with the camera, so it never shifts as the camera pans, and dims as
+/// luminous bodies get larger on screen, to keep them in contrast.
+#[derive(Resource, Debug, Clone, PartialEq, Deserialize)]
+#[serde(default)]
+pub struct Background {
+    /// The path of an equirectangular texture of the sky, relative to the assets folder, being
+    /// the starfield displayed if none.
+    pub sky: Option<String>,
+    /// How many stars fit, at most, along every axis of the starfield.
+    pub density: f32,
+}
+
+impl Default for Background {
+    fn default() -> Self {
+        Self {
+            sky: None,
+            density: DEFAULT_DENSITY,
+        }
+    }
+}
+
+/// The sphere the sky is drawn on.
+#[derive(Component)]
+struct Sky;
+
+impl Plugin for Background {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(self.clone())
+            .add_plugins(MaterialPlugin::<StarfieldMaterial>::default())
+            .add_systems(Update, Self::spawn_on_camera_added)
+            .add_systems(Update, Self::follow_camera)
+            .add_systems(Update, Self::on_sky_load_failed);
+    }
+}
+
+impl Background {
+    /// Attaches the sky to the main camera as soon as it gets spawned, being the only camera
+    /// drawing it, since it is sized for that one only.
+    fn spawn_on_camera_added(
+        mut commands: Commands,
+        mut meshes: ResMut<Assets<Mesh>>,
+        mut materials: ResMut<Assets<StarfieldMaterial>>,
+        camera: Query<Entity, Added<MainCamera>>,
+        background: Res<Self>,
+        asset_server: Res<AssetServer>,
+    ) {
+        camera.iter().for_each(|camera| {
+            let sky = background.sky.as_ref().map(|path| asset_server.load(path));
+            let material = StarfieldMaterial {
+                background_color: color::NIGHT.to_linear().to_vec4(),
+                brightness: 1.,
+                density: background.density,
+                textured: sky.is_some().then_some(1).unwrap_or_default(),
+                sky,
+            };
+
+            commands
+                .entity(camera)
+                .insert(RenderLayers::default().with(SKY_LAYER))
+                .with_child((
+                    Mesh3d(meshes.add(Sphere::new(1.).mesh().uv(32, 16))),
+                    MeshMaterial3d(materials.add(material)),
+                    Transform::default(),
+                    RenderLayers::layer(SKY_LAYER),
+                    Sky,
+                ));
+        });
+    }
+
+    /// Scales the sky so it covers the whole window, which matters to orthographic projections
+    /// only, and dims it according to the largest luminous body on screen.
+    #[allow(clippy::type_complexity)]
+    fn follow_camera(
+        mut materials: ResMut<Assets<StarfieldMaterial>>,
+        mut sky: Query<(&mut Transform, &MeshMaterial3d<StarfieldMaterial>), With<Sky>>,
+        camera: Query<
+            (&GlobalTransform, &Transform, &Projection),
+            (With<MainCamera>, Without<Sky>),
+        >,
+        bodies: Query<
+            (&Body, &Transform),
+            (
+                Without<Orbit>,
+                Without<HabitableZone>,
+                Without<MainCamera>,
+                Without<Sky>,
+            ),
+        >,
+        window: Query<&Window, With<PrimaryWindow>>,
+        system: Res<OrbitalSystem>,
+        scale: Res<ScaleMode>,
+    ) {
+        let Ok((mut sky_transform, material)) = sky.get_single_mut() else {
+            return;
+        };
+
+        let (camera_global_transform, camera_transform, projection) = camera.single();
+        let window = window.single();
+        let pixel_size = MainCamera::pixel_size(camera_transform, projection, window);
+
+        sky_transform.scale = Vec3::splat(pixel_size * window.size().length());
+
+        // the fraction of the window covered by the largest luminous body.
+        let coverage = bodies
+            .iter()
+            .filter_map(|(body, transform)| {
+                let primary = &system.spec.system(&body.name)?.primary;
+                if !primary.is_luminous() {
+                    return None;
+                }
+
+                let radius = scale.radius(primary.radius) as f32;
+                let distance = match projection {
+                    Projection::Perspective(_) => camera_global_transform
+                        .translation()
+                        .distance(transform.translation),
+                    _ => camera_transform.translation.z.abs(),
+                };
+
+                // the pixel size grows linearly with the distance to the camera.
+                let pixel_size = pixel_size * distance / camera_transform.translation.z.abs();
+                Some((2. * radius / pixel_size / window.height()).min(1.))
+            })
+            .fold(0., f32::max);
+
+        // the material is modified only if needed, since any modification gets it uploaded again.
+        let brightness = 1. - MAX_DIMMING * coverage;
+        if materials
+            .get(material)
+            .is_some_and(|material| material.brightness != brightness)
+        {
+            if let Some(material) = materials.get_mut(material) {
+                material.brightness = brightness;
+            }
+        }
+    }
+
+    /// Falls back to the starfield if the texture of the sky could not be loaded.
+    fn on_sky_load_failed(
+        mut sky_load_failed: EventReader<AssetLoadFailedEvent<Image>>,
+        mut materials: ResMut<Assets<StarfieldMaterial>>,
+        sky: Query<&MeshMaterial3d<StarfieldMaterial>, With<Sky>>,
+    ) {
+        sky_load_failed.read().for_each(|event| {
+            sky.iter().for_each(|material| {
+                let Some(material) = materials.get_mut(material) else {
+                    return;
+                };
+
+                if material
+                    .sky
+                    .as_ref()
+                    .is_some_and(|sky| sky.id() == event.id)
+                {
+                    warn!("{}: {}, using the starfield", event.path, event.error);
+                    material.sky = None;
+                    material.textured = 0;
+                }
+            });
+        });
+    }
+}
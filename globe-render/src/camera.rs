@@ -1,31 +1,80 @@
-use std::f32::consts::FRAC_PI_2;
+use std::{f32::consts::FRAC_PI_2, time::Duration};
 
 use alvidir::name::Name;
-use bevy::prelude::*;
+use bevy::{prelude::*, render::camera::ScalingMode, ui::IsDefaultUiCamera};
 
 use crate::{
     color,
+    config::GlobeRsConfig,
     event::{Clicked, Event, Updated},
+    input::{Action, Controls},
     orbit::{Body, OrbitalSystem, OrbitalSystemState},
+    origin::Origin,
+    ui::jump::TimeInput,
+    vec3,
 };
 
+/// The time it takes for the offset between the camera and the body it starts following to decay
+/// to about a third.
+const FOLLOW_TIME_CONSTANT: Duration = Duration::from_millis(250);
+
+/// How many pixels around the bodies still pick them by default.
+const PICK_RADIUS: f32 = 4.;
+
+/// The field of view the camera starts with, which is also the widest one.
+pub const INITIAL_FOV: f32 = FRAC_PI_2;
+
+/// How far the cameras see by default, relative to the radius of the system.
+pub const FAR_PLANE_MARGIN: f32 = 2.;
+
 /// The main camera.
-#[derive(Component, Default)]
+#[derive(Component)]
 pub struct MainCamera {
     pub follow: Option<Name<globe_rs::Body>>,
+    /// The time constant of the exponential ease of the camera towards the followed body, being
+    /// instantaneous if zero.
+    pub time_constant: Duration,
+    /// How many pixels around the bodies still pick them when clicked.
+    pub pick_radius: f32,
+    /// The distance, in world units, left for the camera to reach the followed body.
+    offset: Vec2,
+}
+
+impl Default for MainCamera {
+    fn default() -> Self {
+        Self {
+            follow: None,
+            time_constant: FOLLOW_TIME_CONSTANT,
+            pick_radius: PICK_RADIUS,
+            offset: Vec2::ZERO,
+        }
+    }
 }
 
 impl Plugin for MainCamera {
     fn build(&self, app: &mut App) {
         app.add_systems(Startup, Self::spawn)
+            .add_systems(
+                Update,
+                Self::on_user_input_event.run_if(TimeInput::is_closed),
+            )
             .add_systems(Update, Self::on_body_clicked)
-            .add_systems(Update, Self::on_body_updated);
+            .add_systems(
+                Update,
+                Self::on_body_updated
+                    .after(Self::on_body_clicked)
+                    .after(OrbitalSystem::interpolate_state),
+            );
     }
 }
 
 impl MainCamera {
     /// Spawns the main camera.
-    fn spawn(mut commands: Commands, /*window: Query<&Window>,*/ system: Res<OrbitalSystem>) {
+    fn spawn(
+        mut commands: Commands,
+        /*window: Query<&Window>,*/ system: Res<OrbitalSystem>,
+        config: Res<GlobeRsConfig>,
+    ) {
         let system_radius = system.spec.radius().as_meters() as f32;
 
         // let window = window.single();
@@ -39,9 +88,9 @@ impl MainCamera {
                 ..default()
             },
             Projection::Perspective(PerspectiveProjection {
-                fov: FRAC_PI_2,
-                near: 1., // near == 0. may arise issues    
-                far: 2. * system_radius,
+                fov: INITIAL_FOV,
+                near: 1., // near == 0. may arise issues
+                far: config.far_plane_margin * system_radius,
                 ..Default::default()
             }),
             // Projection::Orthographic(OrthographicProjection {
@@ -51,49 +100,146 @@ impl MainCamera {
             //     scaling_mode: ScalingMode::WindowSize(1. / initial_scale),
             //     area: Default::default(),
             // }),
-            Transform::from_xyz(0., 0., system_radius).looking_at(Vec3::ZERO, Dir3::Y),
-            MainCamera { follow: None },
+            Self::initial_transform(system_radius),
+            MainCamera::default(),
+            // otherwise the UI would be laid out on the camera drawn last, which is the inset.
+            IsDefaultUiCamera,
         ));
     }
 
+    /// Returns the transform the camera starts with, framing a system of the given radius.
+    pub fn initial_transform(system_radius: f32) -> Transform {
+        Transform::from_xyz(0., 0., system_radius).looking_at(Vec3::ZERO, Dir3::Y)
+    }
+
+    /// Returns the distance, in world units, covered by a single pixel of the window at the plane
+    /// of the orbital system.
+    pub fn pixel_size(transform: &Transform, projection: &Projection, window: &Window) -> f32 {
+        match projection {
+            Projection::Perspective(projection) => {
+                2. * transform.translation.z.abs() * (projection.fov / 2.).tan() / window.height()
+            }
+            Projection::Orthographic(projection) => match projection.scaling_mode {
+                ScalingMode::WindowSize(inv_scale) => projection.scale / inv_scale,
+                _ => projection.area.height() / window.height(),
+            },
+        }
+    }
+
+    /// Follows the clicked body, which becomes the origin of the rendered positions.
+    ///
+    /// The camera keeps its zoom and eases from where it is towards the body, instead of snapping
+    /// to it.
     pub fn on_body_clicked(
         mut body_clicked: EventReader<Event<Body, Clicked, Body>>,
+        mut state_updated: EventWriter<Event<OrbitalSystemState, Updated>>,
         mut camera: Query<(&mut MainCamera, &mut Transform)>,
-        state: Res<OrbitalSystemState>,
+        mut state: ResMut<OrbitalSystemState>,
+        origin: Res<Origin>,
     ) {
-        let Some(state) = body_clicked
+        let Some(name) = body_clicked
             .read()
             .last()
-            .and_then(|event| state.spec.state(&event.data.name))
+            .map(|event| event.data.name.clone())
         else {
             return;
         };
 
+        // where the camera is relative to the body, before the body becomes the origin.
+        let before = state.position_of(&name);
+
+        state.origin = Some(name.clone());
+        let spec = state.spec.clone();
+        state.set_spec(spec);
+        state_updated.send(Event::default());
+
+        let Some(position) = state.position_of(&name) else {
+            return;
+        };
+
         let (mut camera, mut transform) = camera.single_mut();
 
-        camera.follow = Some(state.body.clone());
-        transform.translation.x = state.position.x() as f32;
-        transform.translation.y = state.position.y() as f32;
+        camera.offset = before
+            .map(|before| transform.translation.xy() - vec3(before).xy())
+            .unwrap_or_default();
+
+        camera.follow = Some(name);
+        let position = origin.vec3(position);
+        transform.translation.x = position.x + camera.offset.x;
+        transform.translation.y = position.y + camera.offset.y;
     }
 
+    /// Moves the camera along with the followed body, if any, on every frame, so it keeps up with
+    /// the interpolated position of the body instead of the steps of the simulation.
     pub fn on_body_updated(
-        mut body_updated: EventReader<Event<Body, Updated, Body>>,
-        mut camera: Query<(&MainCamera, &mut Transform)>,
+        mut camera: Query<(&mut MainCamera, &mut Transform)>,
         state: Res<OrbitalSystemState>,
+        origin: Res<Origin>,
+        time: Res<Time>,
     ) {
-        let (camera, mut transform) = camera.single_mut();
-        let Some(subject) = &camera.follow else {
+        let (mut camera, mut transform) = camera.single_mut();
+        let Some(position) = camera
+            .follow
+            .as_ref()
+            .and_then(|subject| state.position_of(subject))
+        else {
             return;
         };
 
-        if let Some(state) = body_updated
-            .read()
-            .filter(|event| &event.data.name == subject)
-            .last()
-            .and_then(|event| state.spec.state(&event.data.name))
-        {
-            transform.translation.x = state.position.x() as f32;
-            transform.translation.y = state.position.y() as f32;
+        // the offset decays exponentially, so the body gets centered no matter how fast it moves.
+        let decay = if camera.time_constant.is_zero() {
+            0.
+        } else {
+            (-time.delta_secs() / camera.time_constant.as_secs_f32()).exp()
+        };
+
+        camera.offset *= decay;
+        let position = origin.vec3(position);
+        transform.translation.x = position.x + camera.offset.x;
+        transform.translation.y = position.y + camera.offset.y;
+    }
+
+    /// Stops following any body on demand, by default when pressing U.
+    ///
+    /// The positions get back to being absolute, so the body no longer stays still on screen, while
+    /// the camera keeps its place relative to it.
+    pub fn on_user_input_event(
+        mut state_updated: EventWriter<Event<OrbitalSystemState, Updated>>,
+        mut camera: Query<(&mut MainCamera, &mut Transform)>,
+        mut state: ResMut<OrbitalSystemState>,
+        controls: Controls,
+    ) {
+        if !controls.just_pressed(Action::Unfollow) {
+            return;
+        }
+
+        let (mut camera, mut transform) = camera.single_mut();
+        if camera.unfollow(&mut transform, &mut state) {
+            state_updated.send(Event::default());
+        }
+    }
+
+    /// Stops following the followed body, if any, making the positions absolute again while the
+    /// camera keeps its place relative to the body. Returns true if, and only if, the state has
+    /// changed.
+    pub fn unfollow(&mut self, transform: &mut Transform, state: &mut OrbitalSystemState) -> bool {
+        let Some(subject) = self.follow.take() else {
+            return false;
         };
+
+        self.offset = Vec2::ZERO;
+
+        let before = state.position_of(&subject);
+        state.origin = None;
+        let spec = state.latest.clone();
+        state.set_spec(spec);
+
+        if let Some((before, after)) = before.zip(state.position_of(&subject)) {
+            let shift = vec3(after).xy() - vec3(before).xy();
+            transform.translation.x += shift.x;
+            transform.translation.y += shift.y;
+        }
+
+        true
     }
 }
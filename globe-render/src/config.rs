@@ -0,0 +1,172 @@
+use std::{fs, path::Path};
+
+use bevy::prelude::*;
+use globe_rs::{ConfigFormat, LoadError};
+use serde::Deserialize;
+
+use crate::{
+    camera::FAR_PLANE_MARGIN,
+    input::KeyBindings,
+    orbit::{scale::ScaleMode, MESH_RESOLUTION, SPHERE_SUBDIVISIONS},
+    ui::clock::Clock,
+    Appearance, Background,
+};
+
+/// How the orbital system gets rendered, and how the simulation starts, keeping the defaults for
+/// anything left unspecified.
+#[derive(Resource, Debug, Clone, PartialEq, Deserialize)]
+#[serde(default)]
+pub struct GlobeRsConfig {
+    /// How many points every orbit and habitable zone gets sampled with.
+    pub mesh_resolution: u32,
+    /// How many times the icosahedron every body is made of gets subdivided.
+    pub sphere_subdivisions: u32,
+    /// How many hours of the simulation pass per second of real time when starting.
+    pub clock_scale: u32,
+    /// Whether the clock starts running, instead of paused.
+    pub autostart: bool,
+    /// How bodies and the distances between them are displayed when starting.
+    pub scale_mode: ScaleMode,
+    /// How far the cameras see, relative to the radius of the system.
+    pub far_plane_margin: f32,
+    /// How the bodies of the system look.
+    pub appearance: Appearance,
+    /// The sky behind the system.
+    pub background: Background,
+    /// The bindings overriding the default ones of the keyboard and mouse.
+    pub bindings: KeyBindings,
+}
+
+impl Default for GlobeRsConfig {
+    fn default() -> Self {
+        let clock = Clock::default();
+        Self {
+            mesh_resolution: MESH_RESOLUTION,
+            sphere_subdivisions: SPHERE_SUBDIVISIONS,
+            clock_scale: clock.scale,
+            autostart: clock.running,
+            scale_mode: ScaleMode::default(),
+            far_plane_margin: FAR_PLANE_MARGIN,
+            appearance: Appearance::default(),
+            background: Background::default(),
+            bindings: KeyBindings::default(),
+        }
+    }
+}
+
+impl GlobeRsConfig {
+    /// Loads the configuration from the TOML file at the given path.
+    pub fn from_path(path: impl AsRef<Path>) -> Result<Self, LoadError> {
+        let source = fs::read(path)?;
+        ConfigFormat::Toml.deserialize(source.as_slice())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{collections::HashMap, str::FromStr};
+
+    use alvidir::name::Name;
+    use bevy::{color::Srgba, prelude::KeyCode};
+    use globe_rs::ConfigFormat;
+
+    use crate::{
+        camera::FAR_PLANE_MARGIN,
+        input::{Binding, KeyBindings},
+        orbit::{scale::ScaleMode, MESH_RESOLUTION, SPHERE_SUBDIVISIONS},
+        ui::clock::Clock,
+        Appearance, Background, BodyAppearance,
+    };
+
+    use super::GlobeRsConfig;
+
+    #[test]
+    fn globe_rs_config_must_default_to_the_constants() {
+        let config = GlobeRsConfig::default();
+        let clock = Clock::from(&config);
+
+        assert_eq!(config.mesh_resolution, MESH_RESOLUTION);
+        assert_eq!(config.sphere_subdivisions, SPHERE_SUBDIVISIONS);
+        assert_eq!(config.far_plane_margin, FAR_PLANE_MARGIN);
+        assert_eq!(config.scale_mode, ScaleMode::default());
+        assert_eq!(clock.scale, Clock::default().scale);
+        assert_eq!(clock.running, Clock::default().running);
+    }
+
+    #[test]
+    fn globe_rs_config_must_deserialize_from_toml() {
+        struct Test {
+            name: &'static str,
+            source: &'static str,
+            output: GlobeRsConfig,
+        }
+
+        vec![
+            Test {
+                name: "empty file",
+                source: "",
+                output: GlobeRsConfig::default(),
+            },
+            Test {
+                name: "some knobs",
+                source: r#"
+                    mesh_resolution = 64
+                    autostart = true
+                    clock_scale = 24
+                    scale_mode = { ExaggeratedBodies = { factor = 10.0 } }
+                "#,
+                output: GlobeRsConfig {
+                    mesh_resolution: 64,
+                    autostart: true,
+                    clock_scale: 24,
+                    scale_mode: ScaleMode::ExaggeratedBodies { factor: 10. },
+                    ..Default::default()
+                },
+            },
+            Test {
+                name: "nested sections",
+                source: r##"
+                    far_plane_margin = 4.0
+
+                    [appearance.Earth]
+                    color = "#0000ff"
+
+                    [background]
+                    density = 50.0
+
+                    [bindings]
+                    TogglePause = [{ key = "KeyP" }]
+                "##,
+                output: GlobeRsConfig {
+                    far_plane_margin: 4.,
+                    appearance: Appearance {
+                        bodies: HashMap::from([(
+                            Name::from_str("Earth").unwrap(),
+                            BodyAppearance {
+                                color: Some(Srgba::rgb_u8(0, 0, 255).into()),
+                                ..Default::default()
+                            },
+                        )]),
+                    },
+                    background: Background {
+                        density: 50.,
+                        ..Default::default()
+                    },
+                    bindings: KeyBindings(HashMap::from([(
+                        "TogglePause".to_string(),
+                        vec![Binding::key(KeyCode::KeyP)],
+                    )])),
+                    ..Default::default()
+                },
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let config: GlobeRsConfig = ConfigFormat::Toml
+                .deserialize(test.source.as_bytes())
+                .unwrap();
+
+            assert_eq!(config, test.output, "{}", test.name);
+        });
+    }
+}
@@ -1,9 +1,23 @@
+use alvidir::name::Name;
 use bevy::{input::mouse::MouseMotion, prelude::*, window::PrimaryWindow};
 
+use crate::{
+    camera::MainCamera,
+    orbit::{apsis::ApsisMarker, landmark::Landmark},
+};
+
 /// The world position of the mouse cursor.
-#[derive(Component, Resource, Default, Clone, Copy)]
+#[derive(Component, Resource, Default, Clone)]
 pub struct Cursor {
     pub position: Vec3,
+    /// The name of the body under the cursor, if any.
+    pub hovered: Option<Name<globe_rs::Body>>,
+    /// The apsis marker under the cursor, if any.
+    pub hovered_apsis: Option<ApsisMarker>,
+    /// The landmark under the cursor, if any.
+    pub hovered_landmark: Option<Landmark>,
+    /// Whether the cursor is over the picture-in-picture inset, which the main view ignores.
+    pub over_inset: bool,
 }
 
 impl Plugin for Cursor {
@@ -19,7 +33,7 @@ impl Cursor {
         mut motion: EventReader<MouseMotion>,
         mut cursor_coords: ResMut<Cursor>,
         window: Query<&Window, With<PrimaryWindow>>,
-        camera: Query<(&Camera, &Projection, &GlobalTransform)>,
+        camera: Query<(&Camera, &Projection, &GlobalTransform), With<MainCamera>>,
     ) {
         let (camera, projection, transform) = camera.single();
         let window = window.single();
@@ -6,6 +6,8 @@ pub struct Created;
 pub struct Updated;
 pub struct Deleted;
 pub struct Clicked;
+pub struct Hovered;
+pub struct Unhovered;
 
 #[derive(Event)]
 pub struct Event<T, K, D = ()> {
@@ -0,0 +1,88 @@
+use std::{
+    fs,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use bevy::{
+    prelude::*,
+    render::view::screenshot::{save_to_disk, Screenshot},
+};
+use globe_rs::Epoch;
+
+use crate::{
+    config::GlobeRsConfig,
+    input::{Action, Controls},
+    orbit::{OrbitalSystem, OrbitalSystemState},
+    ui::jump::TimeInput,
+};
+
+mod svg;
+pub use svg::*;
+
+/// The beginning of the name of every exported file, followed by the moment it was exported.
+const FILE_PREFIX: &str = "globe";
+
+/// Saves what is on screen into the working directory: F12 captures the window as a PNG image,
+/// while Ctrl+F12 exports the layout of the orbits as an SVG document, by default.
+pub struct Export;
+
+impl Plugin for Export {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            Self::on_user_input_event.run_if(TimeInput::is_closed),
+        );
+    }
+}
+
+impl Export {
+    fn on_user_input_event(
+        mut commands: Commands,
+        controls: Controls,
+        system: Res<OrbitalSystem>,
+        state: Res<OrbitalSystemState>,
+        config: Res<GlobeRsConfig>,
+    ) {
+        if controls.just_pressed(Action::Screenshot) {
+            let path = format!("{FILE_PREFIX}-{}.png", timestamp());
+            commands
+                .spawn(Screenshot::primary_window())
+                .observe(save_to_disk(path));
+        }
+
+        if !controls.just_pressed(Action::ExportLayout) {
+            return;
+        }
+
+        // positions are exported as displayed, which is relative to the origin, if any.
+        let path = format!("{FILE_PREFIX}-{}.svg", timestamp());
+        match fs::write(
+            &path,
+            orbit_layout_svg(&system.spec, &state.spec, config.mesh_resolution as usize),
+        ) {
+            Ok(()) => info!("orbit layout exported into {path}"),
+            Err(error) => error!("{path}: {error}"),
+        }
+    }
+}
+
+/// Returns the current date and time in a form that fits into a file name, or the seconds since
+/// the UNIX epoch if the date cannot be computed.
+fn timestamp() -> String {
+    let elapsed = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default();
+
+    let Ok(unix_epoch) = Epoch::from_ymd_hms(1970, 1, 1, 0, 0, 0.) else {
+        return elapsed.as_secs().to_string();
+    };
+
+    let Some((year, month, day, hour, minute, second)) = (unix_epoch + elapsed).as_ymd_hms() else {
+        return elapsed.as_secs().to_string();
+    };
+
+    format!(
+        "{year:04}{month:02}{day:02}-{hour:02}{minute:02}{:02}",
+        second as u32
+    )
+}
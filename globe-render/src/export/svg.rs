@@ -0,0 +1,296 @@
+use std::fmt::Write;
+
+use bevy::color::{Color, Srgba};
+use globe_rs::{
+    cartesian::{
+        shape::{Circle, Sample, Shape},
+        transform::{TransformChain, Translation},
+        Coords,
+    },
+    Body, Distance, HabitableZone, Orbit as _, OrbitKind, OrbitalSystem, OrbitalSystemState,
+};
+
+use crate::color;
+
+/// The room left around the layout, relative to its largest side.
+const MARGIN: f64 = 0.05;
+/// How opaque the habitable zones are, so the orbits crossing them are still visible.
+const HABITABLE_ZONE_OPACITY: f32 = 0.1;
+
+/// Returns the top-down layout of the given system in the given state as an SVG document.
+///
+/// The user units of the document are meters, so every orbit, body and habitable zone keeps its
+/// real-world coordinates, with the y axis pointing upwards as in the simulation. Bodies are
+/// circles as large as their actual radius, hence most of them are only visible when zooming in.
+/// Orbits and habitable zones are sampled with the given amount of points.
+pub fn orbit_layout_svg(
+    system: &OrbitalSystem<OrbitKind>,
+    state: &OrbitalSystemState,
+    resolution: usize,
+) -> String {
+    let mut layout = Layout::default();
+
+    system.iter().for_each(|(body, _, orbit)| {
+        let Some(body_state) = state.state(&body.name) else {
+            return;
+        };
+
+        if let Some(hz) = HabitableZone::try_from(body)
+            .ok()
+            .filter(|hz| hz.outer_edge > body.radius)
+        {
+            layout.add_habitable_zone(body, body_state.position, hz, resolution);
+        }
+
+        if let Some((ruler_state, orbit)) = state.parent_state(&body.name).zip(*orbit) {
+            let placement = TransformChain::new()
+                .then(Translation::default().with_vector(orbit.focus()))
+                .then(Translation::default().with_vector(ruler_state.position));
+
+            let shape = orbit
+                .with_initial_theta(body_state.theta)
+                .sample(resolution)
+                .transform(placement);
+
+            layout.add_orbit(body, shape, orbit.is_closed());
+        }
+
+        layout.add_body(body, body_state.position);
+    });
+
+    layout.into_svg()
+}
+
+/// The elements of an SVG document, grouped by kind so they overlap in the same order as in the
+/// rendered scene.
+#[derive(Default)]
+struct Layout {
+    habitable_zones: String,
+    orbits: String,
+    bodies: String,
+    /// The lower-left and upper-right corners of the box enclosing every element, if any.
+    bounds: Option<(Coords, Coords)>,
+}
+
+impl Layout {
+    fn add_habitable_zone(
+        &mut self,
+        body: &Body,
+        position: Coords,
+        hz: HabitableZone,
+        resolution: usize,
+    ) {
+        let edge = |radius: Distance| {
+            Circle::default()
+                .with_radius(radius)
+                .sample(resolution)
+                .transform(Translation::default().with_vector(position))
+        };
+
+        let (inner, outer) = (edge(hz.inner_edge), edge(hz.outer_edge));
+        self.enclose(&outer);
+
+        let _ = writeln!(
+            self.habitable_zones,
+            r#"    <path d="M {} Z M {} Z" fill="{}" fill-opacity="{HABITABLE_ZONE_OPACITY}" fill-rule="evenodd"><title>{}</title></path>"#,
+            points(&outer),
+            points(&inner),
+            hex(color::SPRING_GREEN),
+            escape(body.name.as_ref()),
+        );
+    }
+
+    fn add_orbit(&mut self, body: &Body, shape: Shape, closed: bool) {
+        self.enclose(&shape);
+
+        let _ = writeln!(
+            self.orbits,
+            r#"    <{} points="{}" fill="none" stroke="{}" stroke-width="1" vector-effect="non-scaling-stroke"><title>{}</title></{0}>"#,
+            if closed { "polygon" } else { "polyline" },
+            points(&shape),
+            hex(color::KHAKI),
+            escape(body.name.as_ref()),
+        );
+    }
+
+    fn add_body(&mut self, body: &Body, position: Coords) {
+        let radius = body.radius.as_meters();
+        self.enclose(&Shape {
+            points: vec![
+                position + Coords::from([-radius, -radius, 0.]),
+                position + Coords::from([radius, radius, 0.]),
+            ],
+        });
+
+        let color = if body.is_luminous() {
+            color::PERSIAN_ORANGE
+        } else {
+            color::KHAKI
+        };
+
+        let _ = writeln!(
+            self.bodies,
+            r#"    <circle cx="{:.0}" cy="{:.0}" r="{radius:.0}" fill="{}"><title>{}</title></circle>"#,
+            position.x(),
+            position.y(),
+            hex(color),
+            escape(body.name.as_ref()),
+        );
+    }
+
+    /// Grows the bounds of the layout so they enclose the given shape.
+    fn enclose(&mut self, shape: &Shape) {
+        if shape.points.is_empty() {
+            return;
+        }
+
+        let (min, max) = shape.aabb();
+        self.bounds = Some(match self.bounds {
+            Some((lower, upper)) => (
+                Coords::from([lower.x().min(min.x()), lower.y().min(min.y()), 0.]),
+                Coords::from([upper.x().max(max.x()), upper.y().max(max.y()), 0.]),
+            ),
+            None => (min, max),
+        });
+    }
+
+    fn into_svg(self) -> String {
+        let (min, max) = self.bounds.unwrap_or_default();
+        let side = (max.x() - min.x()).max(max.y() - min.y());
+        // a layout with no extent at all still needs some room to be displayed.
+        let margin = if side > 0. { MARGIN * side } else { 1. };
+
+        // the y axis gets flipped, hence the view box starts at the top of the layout.
+        let (x, y) = (min.x() - margin, -max.y() - margin);
+        let (width, height) = (
+            max.x() - min.x() + 2. * margin,
+            max.y() - min.y() + 2. * margin,
+        );
+
+        let mut svg = String::new();
+        let _ = writeln!(
+            svg,
+            r#"<svg xmlns="http://www.w3.org/2000/svg" viewBox="{x:.0} {y:.0} {width:.0} {height:.0}">"#
+        );
+        let _ = writeln!(
+            svg,
+            r#"  <rect x="{x:.0}" y="{y:.0}" width="{width:.0}" height="{height:.0}" fill="{}"/>"#,
+            hex(color::NIGHT)
+        );
+        let _ = writeln!(svg, r#"  <g transform="scale(1 -1)">"#);
+        svg.push_str(&self.habitable_zones);
+        svg.push_str(&self.orbits);
+        svg.push_str(&self.bodies);
+        let _ = writeln!(svg, "  </g>");
+        let _ = writeln!(svg, "</svg>");
+        svg
+    }
+}
+
+/// Returns the given points as a list of whitespace-separated pairs of coordinates, rounded to
+/// whole meters.
+fn points(shape: &Shape) -> String {
+    shape
+        .points
+        .iter()
+        .map(|point| format!("{:.0},{:.0}", point.x(), point.y()))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Returns the hexadecimal RGB representation of the given color.
+fn hex(color: Color) -> String {
+    Srgba::from(color).to_hex()
+}
+
+/// Returns the given text with any character having a meaning in XML escaped.
+fn escape(text: &str) -> String {
+    text.chars().fold(String::new(), |mut escaped, character| {
+        match character {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            character => escaped.push(character),
+        };
+
+        escaped
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use globe_rs::{
+        cartesian::shape::{Circle, Ellipse},
+        fixtures::body,
+        Distance, Luminosity, Mass, OrbitKind, OrbitalSystem, OrbitalSystemBuilder, Ratio,
+    };
+
+    use crate::orbit::MESH_RESOLUTION;
+
+    use super::orbit_layout_svg;
+
+    #[test]
+    fn orbit_layout_svg_must_match_golden_files() {
+        struct Test {
+            name: &'static str,
+            system: OrbitalSystem<OrbitKind>,
+            time: Duration,
+            golden: &'static str,
+        }
+
+        vec![
+            Test {
+                name: "a lonely star with its habitable zone",
+                system: OrbitalSystemBuilder::new(
+                    body("Star")
+                        .with_radius(Distance::km(696_340.))
+                        .with_mass(Mass::kg(2e30))
+                        .with_luminosity(Luminosity::SUN),
+                )
+                .build()
+                .unwrap(),
+                time: Duration::ZERO,
+                golden: include_str!("../../tests/golden/lonely_star.svg"),
+            },
+            Test {
+                name: "an eccentric planet with a moon",
+                system: OrbitalSystemBuilder::new(
+                    body("Rock")
+                        .with_radius(Distance::km(6_371.))
+                        .with_mass(Mass::kg(6e24)),
+                )
+                .with_satellite(
+                    body("Pebble & Co")
+                        .with_radius(Distance::km(1_737.))
+                        .with_mass(Mass::kg(7e22)),
+                    Ellipse::default()
+                        .with_semi_major_axis(Distance::km(400_000.))
+                        .with_eccentricity(Ratio::from(0.5))
+                        .into(),
+                    |pebble| {
+                        pebble.with_satellite(
+                            body("Dust")
+                                .with_radius(Distance::km(10.))
+                                .with_mass(Mass::kg(1e15)),
+                            Circle::default().with_radius(Distance::km(20_000.)).into(),
+                            |dust| dust,
+                        )
+                    },
+                )
+                .build()
+                .unwrap(),
+                time: Duration::from_secs(86_400),
+                golden: include_str!("../../tests/golden/eccentric_planet.svg"),
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let state = test.system.state_at(test.time);
+            let svg = orbit_layout_svg(&test.system, &state, MESH_RESOLUTION as usize);
+            assert_eq!(svg, test.golden, "{}", test.name);
+        });
+    }
+}
@@ -0,0 +1,589 @@
+use std::{collections::HashMap, fmt::Display, str::FromStr};
+
+use bevy::{ecs::system::SystemParam, prelude::*};
+use serde::Deserialize;
+
+/// Something the user does through the keyboard or the mouse.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Action {
+    /// Pauses or resumes the clock.
+    TogglePause,
+    /// Reverses the clock, resuming it if paused.
+    ReverseTime,
+    /// Doubles the time scale of the clock.
+    SpeedUp,
+    /// Halves the time scale of the clock.
+    SpeedDown,
+    /// Sets the clock back to the epoch.
+    ResetTime,
+    /// Opens the input setting the clock to the time typed in.
+    OpenTimeInput,
+    /// Sets the clock back to the date stored in the bookmark with the given index, if any.
+    RecallBookmark(usize),
+    /// Stores the date of the clock in the bookmark with the given index.
+    StoreBookmark(usize),
+    /// Makes the mouse wheel zoom instead of scroll while held.
+    ZoomModifier,
+    PanUp,
+    PanDown,
+    PanLeft,
+    PanRight,
+    /// Pans the camera along with the pointer while held.
+    DragPan,
+    /// Gets the camera back to its initial framing.
+    ResetView,
+    /// Frames the body being followed, if any.
+    FrameFollowed,
+    /// Stops following any body.
+    Unfollow,
+    CycleScaleMode,
+    CycleTrailMode,
+    ShortenTrail,
+    LengthenTrail,
+    ToggleOrbits,
+    ToggleHabitableZones,
+    ToggleLabels,
+    ToggleGlow,
+    ToggleApsides,
+    ToggleLagrangePoints,
+    /// Starts or clears a measurement.
+    ToggleMeasurement,
+    /// Closes the info panel and clears the measurement.
+    Dismiss,
+    ToggleInset,
+    ToggleEventLog,
+    /// Captures the window as a PNG image.
+    Screenshot,
+    /// Exports the layout of the orbits as an SVG document.
+    ExportLayout,
+    /// Shows or hides the list of key bindings.
+    ToggleBindings,
+}
+
+impl Display for Action {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::RecallBookmark(index) => write!(f, "RecallBookmark{index}"),
+            Self::StoreBookmark(index) => write!(f, "StoreBookmark{index}"),
+            _ => write!(f, "{self:?}"),
+        }
+    }
+}
+
+impl FromStr for Action {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::ALL
+            .into_iter()
+            .find(|action| action.to_string() == s)
+            .ok_or_else(|| format!("unknown action {s}"))
+    }
+}
+
+impl Action {
+    /// The amount of bookmarks, each of them with its own actions.
+    pub const BOOKMARKS: usize = 10;
+
+    /// All the actions, in the order they are listed.
+    pub const ALL: [Self; 52] = [
+        Self::TogglePause,
+        Self::ReverseTime,
+        Self::SpeedUp,
+        Self::SpeedDown,
+        Self::ResetTime,
+        Self::OpenTimeInput,
+        Self::RecallBookmark(0),
+        Self::RecallBookmark(1),
+        Self::RecallBookmark(2),
+        Self::RecallBookmark(3),
+        Self::RecallBookmark(4),
+        Self::RecallBookmark(5),
+        Self::RecallBookmark(6),
+        Self::RecallBookmark(7),
+        Self::RecallBookmark(8),
+        Self::RecallBookmark(9),
+        Self::StoreBookmark(0),
+        Self::StoreBookmark(1),
+        Self::StoreBookmark(2),
+        Self::StoreBookmark(3),
+        Self::StoreBookmark(4),
+        Self::StoreBookmark(5),
+        Self::StoreBookmark(6),
+        Self::StoreBookmark(7),
+        Self::StoreBookmark(8),
+        Self::StoreBookmark(9),
+        Self::ZoomModifier,
+        Self::PanUp,
+        Self::PanDown,
+        Self::PanLeft,
+        Self::PanRight,
+        Self::DragPan,
+        Self::ResetView,
+        Self::FrameFollowed,
+        Self::Unfollow,
+        Self::CycleScaleMode,
+        Self::CycleTrailMode,
+        Self::ShortenTrail,
+        Self::LengthenTrail,
+        Self::ToggleOrbits,
+        Self::ToggleHabitableZones,
+        Self::ToggleLabels,
+        Self::ToggleGlow,
+        Self::ToggleApsides,
+        Self::ToggleLagrangePoints,
+        Self::ToggleMeasurement,
+        Self::Dismiss,
+        Self::ToggleInset,
+        Self::ToggleEventLog,
+        Self::Screenshot,
+        Self::ExportLayout,
+        Self::ToggleBindings,
+    ];
+}
+
+/// The keys of the bookmarks by default, being the index of each key the one of its bookmark.
+const BOOKMARK_KEYS: [KeyCode; Action::BOOKMARKS] = [
+    KeyCode::Digit0,
+    KeyCode::Digit1,
+    KeyCode::Digit2,
+    KeyCode::Digit3,
+    KeyCode::Digit4,
+    KeyCode::Digit5,
+    KeyCode::Digit6,
+    KeyCode::Digit7,
+    KeyCode::Digit8,
+    KeyCode::Digit9,
+];
+
+/// A key that, held along with the input of a [Binding], changes its meaning.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+pub enum Modifier {
+    Shift,
+    Ctrl,
+    Alt,
+}
+
+impl Modifier {
+    const ALL: [Self; 3] = [Self::Shift, Self::Ctrl, Self::Alt];
+
+    /// Returns the keys of the modifier, at either side of the keyboard.
+    fn keys(&self) -> [KeyCode; 2] {
+        match self {
+            Self::Shift => [KeyCode::ShiftLeft, KeyCode::ShiftRight],
+            Self::Ctrl => [KeyCode::ControlLeft, KeyCode::ControlRight],
+            Self::Alt => [KeyCode::AltLeft, KeyCode::AltRight],
+        }
+    }
+}
+
+/// A key of the keyboard or a button of the mouse.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Input {
+    Key(KeyCode),
+    Mouse(MouseButton),
+}
+
+/// An input triggering an action while holding the given modifiers, e.g. `{ key = "Space",
+/// modifiers = ["Shift"] }` in a key bindings file.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct Binding {
+    #[serde(flatten)]
+    pub input: Input,
+    #[serde(default)]
+    pub modifiers: Vec<Modifier>,
+}
+
+impl Display for Binding {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.modifiers
+            .iter()
+            .try_for_each(|modifier| write!(f, "{modifier:?}+"))?;
+
+        match self.input {
+            Input::Key(key) => {
+                let name = format!("{key:?}");
+                let name = name
+                    .strip_prefix("Key")
+                    .or_else(|| name.strip_prefix("Digit"))
+                    .unwrap_or(&name);
+
+                write!(f, "{name}")
+            }
+            Input::Mouse(button) => write!(f, "{button:?}Click"),
+        }
+    }
+}
+
+impl Binding {
+    pub fn key(key: KeyCode) -> Self {
+        Self {
+            input: Input::Key(key),
+            modifiers: Vec::default(),
+        }
+    }
+
+    pub fn mouse(button: MouseButton) -> Self {
+        Self {
+            input: Input::Mouse(button),
+            modifiers: Vec::default(),
+        }
+    }
+
+    pub fn with_modifier(mut self, modifier: Modifier) -> Self {
+        self.modifiers.push(modifier);
+        self
+    }
+
+    /// Returns true if, and only if, the input of the binding has just been pressed while holding
+    /// its modifiers and no other.
+    pub fn just_pressed(
+        &self,
+        keys: &ButtonInput<KeyCode>,
+        mouse: &ButtonInput<MouseButton>,
+    ) -> bool {
+        self.modifiers_held(keys, true)
+            && match self.input {
+                Input::Key(key) => keys.just_pressed(key),
+                Input::Mouse(button) => mouse.just_pressed(button),
+            }
+    }
+
+    /// Returns true if, and only if, the input of the binding is being pressed while holding its
+    /// modifiers, no matter any other.
+    pub fn pressed(&self, keys: &ButtonInput<KeyCode>, mouse: &ButtonInput<MouseButton>) -> bool {
+        self.modifiers_held(keys, false)
+            && match self.input {
+                Input::Key(key) => keys.pressed(key),
+                Input::Mouse(button) => mouse.pressed(button),
+            }
+    }
+
+    /// Returns true if, and only if, all the modifiers of the binding are held, as well as no other
+    /// if exact.
+    fn modifiers_held(&self, keys: &ButtonInput<KeyCode>, exact: bool) -> bool {
+        Modifier::ALL.into_iter().all(|modifier| {
+            let held = keys.any_pressed(modifier.keys());
+            if self.modifiers.contains(&modifier) {
+                return held;
+            }
+
+            // the input of a binding may be a modifier itself, as the one of the zoom is.
+            let is_input = matches!(self.input, Input::Key(key) if modifier.keys().contains(&key));
+            !exact || is_input || !held
+        })
+    }
+}
+
+/// The bindings of a key bindings file, by the name of their action.
+#[derive(Debug, Default, Clone, PartialEq, Deserialize)]
+#[serde(transparent)]
+pub struct KeyBindings(pub HashMap<String, Vec<Binding>>);
+
+/// The bindings of every [Action].
+#[derive(Resource, Debug, Clone, PartialEq)]
+pub struct InputMap {
+    bindings: HashMap<Action, Vec<Binding>>,
+}
+
+impl Default for InputMap {
+    fn default() -> Self {
+        let key = Binding::key;
+        let shift = |code| key(code).with_modifier(Modifier::Shift);
+        let ctrl = |code| key(code).with_modifier(Modifier::Ctrl);
+
+        let mut bindings = HashMap::from([
+            (Action::TogglePause, vec![key(KeyCode::Space)]),
+            (Action::ReverseTime, vec![shift(KeyCode::Space)]),
+            (Action::SpeedUp, vec![key(KeyCode::ArrowUp)]),
+            (Action::SpeedDown, vec![key(KeyCode::ArrowDown)]),
+            (Action::ResetTime, vec![key(KeyCode::KeyR)]),
+            (Action::OpenTimeInput, vec![key(KeyCode::KeyT)]),
+            (Action::ZoomModifier, vec![key(KeyCode::ControlLeft)]),
+            (Action::PanUp, vec![key(KeyCode::KeyW)]),
+            (Action::PanDown, vec![key(KeyCode::KeyS)]),
+            (
+                Action::PanLeft,
+                vec![key(KeyCode::KeyA), key(KeyCode::ArrowLeft)],
+            ),
+            (
+                Action::PanRight,
+                vec![key(KeyCode::KeyD), key(KeyCode::ArrowRight)],
+            ),
+            (
+                Action::DragPan,
+                vec![
+                    Binding::mouse(MouseButton::Middle),
+                    Binding::mouse(MouseButton::Right),
+                ],
+            ),
+            (Action::ResetView, vec![key(KeyCode::Home)]),
+            (Action::FrameFollowed, vec![key(KeyCode::KeyF)]),
+            (Action::Unfollow, vec![key(KeyCode::KeyU)]),
+            (Action::CycleScaleMode, vec![key(KeyCode::KeyM)]),
+            (Action::CycleTrailMode, vec![key(KeyCode::KeyO)]),
+            (Action::ShortenTrail, vec![key(KeyCode::BracketLeft)]),
+            (Action::LengthenTrail, vec![key(KeyCode::BracketRight)]),
+            (Action::ToggleOrbits, vec![shift(KeyCode::Digit1)]),
+            (Action::ToggleHabitableZones, vec![shift(KeyCode::Digit2)]),
+            (
+                Action::ToggleLabels,
+                vec![shift(KeyCode::Digit3), key(KeyCode::KeyL)],
+            ),
+            (Action::ToggleGlow, vec![shift(KeyCode::Digit4)]),
+            (Action::ToggleApsides, vec![shift(KeyCode::Digit5)]),
+            (Action::ToggleLagrangePoints, vec![shift(KeyCode::Digit6)]),
+            (Action::ToggleMeasurement, vec![key(KeyCode::KeyX)]),
+            (Action::Dismiss, vec![key(KeyCode::Escape)]),
+            (Action::ToggleInset, vec![key(KeyCode::KeyV)]),
+            (Action::ToggleEventLog, vec![key(KeyCode::KeyE)]),
+            (Action::Screenshot, vec![key(KeyCode::F12)]),
+            (Action::ExportLayout, vec![ctrl(KeyCode::F12)]),
+            (Action::ToggleBindings, vec![shift(KeyCode::Slash)]),
+        ]);
+
+        // numbers alone are the bookmarks, since along with Shift they toggle the view filters.
+        BOOKMARK_KEYS
+            .into_iter()
+            .enumerate()
+            .for_each(|(index, code)| {
+                bindings.insert(Action::RecallBookmark(index), vec![key(code)]);
+                bindings.insert(Action::StoreBookmark(index), vec![ctrl(code)]);
+            });
+
+        Self { bindings }
+    }
+}
+
+impl Plugin for InputMap {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(self.clone());
+    }
+}
+
+impl InputMap {
+    /// Replaces the bindings of every action in the given ones, warning about the unknown ones.
+    /// Actions missing from the given bindings keep the ones they had.
+    pub fn with_bindings(mut self, bindings: &KeyBindings) -> Self {
+        bindings.0.iter().for_each(|(name, bindings)| {
+            match Action::from_str(name) {
+                Ok(action) => {
+                    self.bindings.insert(action, bindings.clone());
+                }
+                Err(error) => warn!("key bindings: {error}"),
+            };
+        });
+
+        self
+    }
+
+    /// Returns the bindings of the given action.
+    pub fn bindings(&self, action: Action) -> &[Binding] {
+        self.bindings
+            .get(&action)
+            .map(Vec::as_slice)
+            .unwrap_or_default()
+    }
+
+    /// Returns true if, and only if, any binding of the given action has just been pressed.
+    pub fn just_pressed(
+        &self,
+        action: Action,
+        keys: &ButtonInput<KeyCode>,
+        mouse: &ButtonInput<MouseButton>,
+    ) -> bool {
+        self.bindings(action)
+            .iter()
+            .any(|binding| binding.just_pressed(keys, mouse))
+    }
+
+    /// Returns true if, and only if, any binding of the given action is being pressed.
+    pub fn pressed(
+        &self,
+        action: Action,
+        keys: &ButtonInput<KeyCode>,
+        mouse: &ButtonInput<MouseButton>,
+    ) -> bool {
+        self.bindings(action)
+            .iter()
+            .any(|binding| binding.pressed(keys, mouse))
+    }
+}
+
+/// The keyboard and the mouse, read through the [InputMap].
+#[derive(SystemParam)]
+pub struct Controls<'w> {
+    map: Res<'w, InputMap>,
+    keys: Res<'w, ButtonInput<KeyCode>>,
+    mouse: Res<'w, ButtonInput<MouseButton>>,
+}
+
+impl Controls<'_> {
+    /// Returns true if, and only if, the given action has just been triggered.
+    pub fn just_pressed(&self, action: Action) -> bool {
+        self.map.just_pressed(action, &self.keys, &self.mouse)
+    }
+
+    /// Returns true if, and only if, the given action is being held.
+    pub fn pressed(&self, action: Action) -> bool {
+        self.map.pressed(action, &self.keys, &self.mouse)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::prelude::*;
+    use globe_rs::ConfigFormat;
+
+    use super::{Action, Binding, InputMap, KeyBindings, Modifier};
+
+    #[test]
+    fn binding_must_match_its_modifiers() {
+        struct Test {
+            name: &'static str,
+            binding: Binding,
+            held: Vec<KeyCode>,
+            pressed: KeyCode,
+            just_pressed: bool,
+            is_pressed: bool,
+        }
+
+        vec![
+            Test {
+                name: "key with no modifiers",
+                binding: Binding::key(KeyCode::Space),
+                held: vec![],
+                pressed: KeyCode::Space,
+                just_pressed: true,
+                is_pressed: true,
+            },
+            Test {
+                name: "key along with an unexpected modifier",
+                binding: Binding::key(KeyCode::Space),
+                held: vec![KeyCode::ShiftRight],
+                pressed: KeyCode::Space,
+                just_pressed: false,
+                is_pressed: true,
+            },
+            Test {
+                name: "key along with its modifier",
+                binding: Binding::key(KeyCode::Space).with_modifier(Modifier::Shift),
+                held: vec![KeyCode::ShiftLeft],
+                pressed: KeyCode::Space,
+                just_pressed: true,
+                is_pressed: true,
+            },
+            Test {
+                name: "key without its modifier",
+                binding: Binding::key(KeyCode::F12).with_modifier(Modifier::Ctrl),
+                held: vec![],
+                pressed: KeyCode::F12,
+                just_pressed: false,
+                is_pressed: false,
+            },
+            Test {
+                name: "modifier as the key itself",
+                binding: Binding::key(KeyCode::ControlLeft),
+                held: vec![],
+                pressed: KeyCode::ControlLeft,
+                just_pressed: true,
+                is_pressed: true,
+            },
+            Test {
+                name: "another key",
+                binding: Binding::key(KeyCode::KeyR),
+                held: vec![],
+                pressed: KeyCode::KeyT,
+                just_pressed: false,
+                is_pressed: false,
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let mut keys = ButtonInput::<KeyCode>::default();
+            test.held.iter().for_each(|key| keys.press(*key));
+            keys.clear();
+            keys.press(test.pressed);
+
+            let mouse = ButtonInput::<MouseButton>::default();
+            assert_eq!(
+                test.binding.just_pressed(&keys, &mouse),
+                test.just_pressed,
+                "{}: just pressed",
+                test.name
+            );
+            assert_eq!(
+                test.binding.pressed(&keys, &mouse),
+                test.is_pressed,
+                "{}: pressed",
+                test.name
+            );
+        });
+    }
+
+    #[test]
+    fn input_map_must_fall_back_to_defaults() {
+        let source = r#"
+            TogglePause = [{ key = "KeyP" }]
+            ReverseTime = [{ key = "KeyP", modifiers = ["Shift"] }, { mouse = "Middle" }]
+            StoreBookmark3 = [{ key = "KeyB" }]
+            Teleport = [{ key = "KeyZ" }]
+        "#;
+
+        let bindings: KeyBindings = ConfigFormat::Toml.deserialize(source.as_bytes()).unwrap();
+        let map = InputMap::default().with_bindings(&bindings);
+
+        assert_eq!(
+            map.bindings(Action::TogglePause),
+            [Binding::key(KeyCode::KeyP)]
+        );
+        assert_eq!(
+            map.bindings(Action::ReverseTime),
+            [
+                Binding::key(KeyCode::KeyP).with_modifier(Modifier::Shift),
+                Binding::mouse(MouseButton::Middle)
+            ]
+        );
+        assert_eq!(
+            map.bindings(Action::StoreBookmark(3)),
+            [Binding::key(KeyCode::KeyB)]
+        );
+        assert_eq!(
+            map.bindings(Action::SpeedUp),
+            InputMap::default().bindings(Action::SpeedUp)
+        );
+    }
+
+    #[test]
+    fn default_bindings_must_be_disjoint() {
+        let map = InputMap::default();
+        let bindings: Vec<(Action, &Binding)> = Action::ALL
+            .into_iter()
+            .flat_map(|action| {
+                map.bindings(action)
+                    .iter()
+                    .map(move |binding| (action, binding))
+            })
+            .collect();
+
+        bindings
+            .iter()
+            .enumerate()
+            .for_each(|(index, (action, binding))| {
+                bindings[index + 1..]
+                    .iter()
+                    .filter(|(_, other)| other == binding)
+                    .for_each(|(other, _)| {
+                        panic!("{action} and {other} are both bound to {binding}")
+                    });
+            });
+
+        Action::ALL.into_iter().for_each(|action| {
+            assert!(
+                !map.bindings(action).is_empty(),
+                "{action} has no default bindings"
+            );
+        });
+    }
+}
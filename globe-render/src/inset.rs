@@ -0,0 +1,195 @@
+use bevy::{
+    prelude::*,
+    render::camera::Viewport,
+    window::{PrimaryWindow, WindowResized},
+};
+use globe_rs::cartesian::Coords;
+
+use crate::{
+    camera::{MainCamera, INITIAL_FOV},
+    color,
+    config::GlobeRsConfig,
+    cursor::Cursor,
+    input::{Action, Controls},
+    orbit::{scale::ScaleMode, OrbitalSystem, OrbitalSystemState},
+    origin::Origin,
+    ui::jump::TimeInput,
+};
+
+/// The size of the inset relative to the window.
+const INSET_SIZE: f32 = 0.25;
+/// The distance between the inset and the top-left corner of the window, in logical pixels.
+const INSET_MARGIN: Vec2 = Vec2::new(16., 48.);
+/// How many times the diameter of the followed body fits in the height of the close-up.
+const CLOSE_UP_MARGIN: f32 = 8.;
+
+/// What the picture-in-picture inset in the corner of the window displays, if anything.
+///
+/// The inset is drawn by a camera of its own, hence the zoom, scroll and picking of the main
+/// camera are not aware of it, other than ignoring the cursor while over it.
+#[derive(Resource, Debug, Default, Clone, Copy, PartialEq)]
+pub enum Inset {
+    #[default]
+    Off,
+    /// The whole system, framed as the main camera starts.
+    Overview,
+    /// A close-up of the body followed by the main camera, if any.
+    CloseUp,
+}
+
+/// The camera drawing the inset.
+#[derive(Component)]
+struct InsetCamera;
+
+impl Plugin for Inset {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(*self)
+            .add_systems(Startup, Self::spawn)
+            .add_systems(PreUpdate, Self::track_cursor)
+            .add_systems(
+                Update,
+                Self::on_user_input_event.run_if(TimeInput::is_closed),
+            )
+            .add_systems(Update, Self::on_window_resized)
+            .add_systems(
+                Update,
+                Self::follow_main_camera
+                    .after(MainCamera::on_body_updated)
+                    .after(OrbitalSystem::interpolate_state),
+            );
+    }
+}
+
+impl Inset {
+    /// Returns the inset following self when cycling through all of them.
+    fn next(&self) -> Self {
+        match self {
+            Self::Off => Self::Overview,
+            Self::Overview => Self::CloseUp,
+            Self::CloseUp => Self::Off,
+        }
+    }
+
+    fn spawn(mut commands: Commands, system: Res<OrbitalSystem>, config: Res<GlobeRsConfig>) {
+        let system_radius = system.spec.radius().as_meters() as f32;
+
+        commands.spawn((
+            Camera3d::default(),
+            Camera {
+                // drawn after the main camera, so the inset is on top of it.
+                order: 1,
+                is_active: false,
+                clear_color: ClearColorConfig::Custom(color::EERIE_BLACK),
+                ..default()
+            },
+            Projection::Perspective(PerspectiveProjection {
+                fov: INITIAL_FOV,
+                near: 1., // near == 0. may arise issues
+                far: config.far_plane_margin * system_radius,
+                ..Default::default()
+            }),
+            MainCamera::initial_transform(system_radius),
+            InsetCamera,
+        ));
+    }
+
+    /// Cycles through the insets when pressing V.
+    fn on_user_input_event(mut inset: ResMut<Self>, controls: Controls) {
+        if controls.just_pressed(Action::ToggleInset) {
+            *inset = inset.next();
+        }
+    }
+
+    /// Keeps the inset at the top-left corner of the window, as large as a fraction of it.
+    fn on_window_resized(
+        mut window_resized: EventReader<WindowResized>,
+        mut camera: Query<&mut Camera, With<InsetCamera>>,
+        window: Query<&Window, With<PrimaryWindow>>,
+    ) {
+        let mut camera = camera.single_mut();
+        if window_resized.read().count() == 0 && camera.viewport.is_some() {
+            return;
+        }
+
+        let window = window.single();
+        let window_size = window.physical_size();
+        let position = (INSET_MARGIN * window.scale_factor())
+            .as_uvec2()
+            .min(window_size.saturating_sub(UVec2::ONE));
+
+        // a viewport out of the window, or with no size at all, is not a valid one.
+        let size = (window_size.as_vec2() * INSET_SIZE)
+            .as_uvec2()
+            .min(window_size - position)
+            .max(UVec2::ONE);
+
+        camera.viewport = Some(Viewport {
+            physical_position: position,
+            physical_size: size,
+            ..default()
+        });
+    }
+
+    /// Frames the whole system, or the body followed by the main camera, according to the inset,
+    /// deactivating the camera of the inset if there is nothing to display.
+    fn follow_main_camera(
+        mut inset_camera: Query<(&mut Camera, &mut Transform, &Projection), With<InsetCamera>>,
+        main_camera: Query<&MainCamera>,
+        inset: Res<Self>,
+        system: Res<OrbitalSystem>,
+        state: Res<OrbitalSystemState>,
+        scale: Res<ScaleMode>,
+        origin: Res<Origin>,
+    ) {
+        let (mut camera, mut transform, projection) = inset_camera.single_mut();
+
+        let framing = match *inset {
+            Self::Off => None,
+            Self::Overview => {
+                let system_radius = system.spec.radius().as_meters() as f32;
+                // the center of the system may no longer be the one of the world coordinates.
+                let center = origin.vec3(Coords::default());
+                Some(center.with_z(system_radius))
+            }
+            Self::CloseUp => main_camera.single().follow.as_ref().and_then(|subject| {
+                let radius = scale.radius(system.spec.system(subject)?.primary.radius) as f32;
+                let position = origin.vec3(state.position_of(subject)?);
+
+                let Projection::Perspective(projection) = projection else {
+                    return None;
+                };
+
+                let distance = radius * CLOSE_UP_MARGIN / (projection.fov / 2.).tan();
+                Some(position.with_z(distance))
+            }),
+        };
+
+        let is_active = framing.is_some();
+        if camera.is_active != is_active {
+            camera.is_active = is_active;
+        }
+
+        if let Some(translation) = framing {
+            transform.translation = translation;
+        }
+    }
+
+    /// Tells the cursor whether it is over the inset, so the main view ignores it meanwhile.
+    fn track_cursor(
+        mut cursor: ResMut<Cursor>,
+        camera: Query<&Camera, With<InsetCamera>>,
+        window: Query<&Window, With<PrimaryWindow>>,
+    ) {
+        let camera = camera.single();
+        let over_inset = camera.is_active
+            && window
+                .single()
+                .cursor_position()
+                .zip(camera.logical_viewport_rect())
+                .is_some_and(|(position, viewport)| viewport.contains(position));
+
+        if cursor.over_inset != over_inset {
+            cursor.over_inset = over_inset;
+        }
+    }
+}
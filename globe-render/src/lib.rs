@@ -1,30 +1,106 @@
 use bevy::prelude::*;
 use camera::MainCamera;
 use cursor::Cursor;
-use globe_rs::cartesian::shape::Ellipse;
+use export::Export;
+use globe_rs::OrbitKind;
+use input::InputMap;
+use inset::Inset;
 use orbit::OrbitalSystem;
+use origin::Origin;
 use ui::Ui;
 
+pub use appearance::{Appearance, BodyAppearance};
+pub use background::Background;
+pub use config::GlobeRsConfig;
+pub use input::KeyBindings;
+pub use orbit::scale::ScaleMode;
+
+mod appearance;
+mod background;
 mod camera;
 mod color;
+mod config;
 mod cursor;
 mod event;
+mod export;
+mod input;
+mod inset;
 mod material;
 mod orbit;
+mod origin;
 mod ui;
 
+/// Returns the given coordinates as a [Vec3].
+///
+/// ## Example
+/// ```
+/// use bevy::math::Vec3;
+/// use globe_rs::cartesian::Coords;
+///
+/// assert_eq!(
+///     globe_render::vec3(Coords::from([1., 2., 3.])),
+///     Vec3::new(1., 2., 3.)
+/// );
+/// ```
+pub fn vec3(coords: globe_rs::cartesian::Coords) -> Vec3 {
+    Vec3::from_array(coords.to_f32_array())
+}
+
+/// Returns the given rotation as a [Quat], leaving its pivot aside.
+///
+/// ## Example
+/// ```
+/// use std::f64::consts::FRAC_PI_2;
+///
+/// use bevy::math::Quat;
+/// use globe_rs::cartesian::{transform::Rotation, Coords};
+///
+/// let rotation = Rotation::default()
+///     .with_axis(Coords::from([0., 0., 1.]))
+///     .with_theta(FRAC_PI_2.into());
+///
+/// let want = Quat::from_rotation_z(FRAC_PI_2 as f32);
+/// assert!(globe_render::quat(&rotation).abs_diff_eq(want, 1e-6));
+/// ```
+pub fn quat(rotation: &globe_rs::cartesian::transform::Rotation) -> Quat {
+    Quat::from_array(rotation.to_quaternion().map(|component| component as f32))
+}
+
 #[derive(Component)]
 pub struct GlobeRsPlugin {
-    pub system: globe_rs::OrbitalSystem<Ellipse>,
+    pub system: globe_rs::OrbitalSystem<OrbitKind>,
+    /// How the system gets rendered and how the simulation starts.
+    pub config: GlobeRsConfig,
 }
 
 impl Plugin for GlobeRsPlugin {
     fn build(&self, app: &mut App) {
-        app.insert_resource(OrbitalSystem::from(&self.system))
-            .add_plugins(DefaultPlugins)
+        app.add_plugins(DefaultPlugins);
+
+        if let Err(errors) = self.system.validate() {
+            errors.iter().for_each(|error| error!("{error}"));
+            app.add_systems(Startup, Self::exit_with_error);
+            return;
+        }
+
+        app.insert_resource(self.config.clone())
+            .insert_resource(OrbitalSystem::from(&self.system))
             .add_plugins(OrbitalSystem::from(&self.system))
+            .add_plugins(InputMap::default().with_bindings(&self.config.bindings))
             .add_plugins(MainCamera::default())
             .add_plugins(Cursor::default())
+            .add_plugins(Origin::default())
+            .add_plugins(self.config.appearance.clone())
+            .add_plugins(self.config.background.clone())
+            .add_plugins(Export)
+            .add_plugins(Inset::default())
             .add_plugins(Ui);
     }
 }
+
+impl GlobeRsPlugin {
+    /// Exits the app as soon as it starts, since the orbital system cannot be rendered.
+    fn exit_with_error(mut exit: EventWriter<AppExit>) {
+        exit.send(AppExit::error());
+    }
+}
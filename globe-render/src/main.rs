@@ -1,133 +1,107 @@
-use std::{str::FromStr, time::Duration};
+use std::{fs, process};
 
-use alvidir::name::Name;
 use bevy::prelude::*;
-use globe_render::GlobeRsPlugin;
-use globe_rs::{cartesian::shape::Ellipse, Body, Distance, Luminosity, Mass, Ratio, Rotation};
+use globe_render::{Appearance, Background, GlobeRsConfig, GlobeRsPlugin, KeyBindings};
+use globe_rs::{presets, ConfigFormat, LoadError, OrbitKind, OrbitalSystem};
+use serde::Deserialize;
+
+/// The command line argument holding the path of the orbital system to render.
+const SYSTEM_ARG: &str = "--system";
+/// The command line argument holding the path of the configuration of the renderer.
+const CONFIG_ARG: &str = "--config";
+/// The file in the working directory overriding the default key bindings, if any.
+const KEY_BINDINGS_FILE: &str = "keybindings.toml";
 
 fn main() {
-    let system = globe_rs::OrbitalSystem::<Ellipse> {
-        primary: Body {
-            name: Name::from_str("Sun").unwrap(),
-            radius: Distance::km(696_340.),
-            spin: Rotation {
-                period: Duration::from_secs(27 * 24 * 3600),
-                ..Default::default()
-            },
-            mass: Mass::kg(1.9891e30),
-            luminosity: Luminosity::SUN,
-        },
-        orbit: None,
-        secondary: vec![
-            globe_rs::OrbitalSystem {
-                primary: Body {
-                    name: Name::from_str("Mercury").unwrap(),
-                    radius: Distance::km(2_439.7),
-                    spin: Rotation {
-                        period: Duration::from_secs(59 * 24 * 3600),
-                        ..Default::default()
-                    },
-                    mass: Mass::kg(3.30104e23),
-                    luminosity: Luminosity::ZERO,
-                },
-                orbit: Some(Ellipse {
-                    semi_major_axis: Distance::ASTRONOMICAL_UNIT * 0.38700,
-                    eccentricity: Ratio::from(0.206),
-                    ..Default::default()
-                }),
-                secondary: vec![],
-            },
-            globe_rs::OrbitalSystem {
-                primary: Body {
-                    name: Name::from_str("Venus").unwrap(),
-                    radius: Distance::km(2_439.7),
-                    spin: Rotation {
-                        period: Duration::from_secs(243 * 24 * 3600),
-                        clockwise: true,
-                    },
-                    mass: Mass::kg(4.867e24),
-                    luminosity: Luminosity::ZERO,
-                },
-                orbit: Some(Ellipse {
-                    semi_major_axis: Distance::ASTRONOMICAL_UNIT * 0.72300,
-                    eccentricity: Ratio::from(0.007),
-                    ..Default::default()
-                }),
-                secondary: vec![],
-            },
-            globe_rs::OrbitalSystem {
-                primary: Body {
-                    name: Name::from_str("Earth").unwrap(),
-                    radius: Distance::km(6_371.),
-                    spin: Rotation {
-                        period: Duration::from_secs(23 * 3600 + 56 * 60 + 4),
-                        ..Default::default()
-                    },
-                    mass: Mass::kg(5.97219e24),
-                    luminosity: Luminosity::ZERO,
-                },
-                orbit: Some(Ellipse {
-                    semi_major_axis: Distance::ASTRONOMICAL_UNIT,
-                    eccentricity: Ratio::from(0.017),
-                    ..Default::default()
-                }),
-                secondary: vec![globe_rs::OrbitalSystem {
-                    primary: Body {
-                        name: Name::from_str("Moon").unwrap(),
-                        radius: Distance::km(6_371.) * 20., //Distance::km(1_737.4),
-                        spin: Rotation {
-                            period: Duration::from_secs(27 * 24 * 3600),
-                            ..Default::default()
-                        },
-                        mass: Mass::kg(7.34767309e22),
-                        luminosity: Luminosity::ZERO,
-                    },
-                    orbit: Some(Ellipse {
-                        semi_major_axis: Distance::km(384_748.),
-                        eccentricity: Ratio::from(0.0549006),
-                        ..Default::default()
-                    }),
-                    secondary: Default::default(),
-                }],
-            },
-            globe_rs::OrbitalSystem {
-                primary: Body {
-                    name: Name::from_str("Mars").unwrap(),
-                    radius: Distance::km(3_389.5),
-                    spin: Rotation {
-                        period: Duration::from_secs_f64(24.6 * 3600.),
-                        ..Default::default()
-                    },
-                    mass: Mass::kg(6.39e23),
-                    luminosity: Luminosity::ZERO,
-                },
-                orbit: Some(Ellipse {
-                    semi_major_axis: Distance::ASTRONOMICAL_UNIT * 1.52400,
-                    eccentricity: Ratio::from(0.093),
-                    ..Default::default()
-                }),
-                secondary: vec![],
-            },
-            globe_rs::OrbitalSystem {
-                primary: Body {
-                    name: Name::from_str("Jupiter").unwrap(),
-                    radius: Distance::km(69_911.),
-                    spin: Rotation {
-                        period: Duration::from_secs_f64(9. * 3600. + 55. * 60.),
-                        ..Default::default()
-                    },
-                    mass: Mass::kg(1.898e27),
-                    luminosity: Luminosity::ZERO,
-                },
-                orbit: Some(Ellipse {
-                    semi_major_axis: Distance::ASTRONOMICAL_UNIT * 5.2,
-                    eccentricity: Ratio::from(0.0487),
-                    ..Default::default()
-                }),
-                secondary: vec![],
-            },
-        ],
+    let mut config = arg_value(CONFIG_ARG)
+        .map(|path| load_config(&path))
+        .unwrap_or_default();
+
+    let (system, render) = match arg_value(SYSTEM_ARG) {
+        Some(path) => load_system(&path),
+        None => (presets::solar_system().into(), RenderConfig::default()),
+    };
+
+    // the sections of the system file take precedence over the configuration.
+    if let Some(appearance) = render.appearance {
+        config.appearance = appearance;
+    }
+
+    if let Some(background) = render.background {
+        config.background = background;
+    }
+
+    if let Some(bindings) = key_bindings() {
+        config.bindings = bindings;
+    }
+
+    App::new()
+        .add_plugins(GlobeRsPlugin { system, config })
+        .run();
+}
+
+/// The sections of a system file only concerning its rendering.
+#[derive(Default, Deserialize)]
+#[serde(default)]
+struct RenderConfig {
+    /// How the bodies of the system look, if overriding the configuration.
+    appearance: Option<Appearance>,
+    /// The sky behind the system, if overriding the configuration.
+    background: Option<Background>,
+}
+
+/// Returns the value given to the argument with the given name, if any.
+fn arg_value(name: &str) -> Option<String> {
+    let mut args = std::env::args().skip(1);
+    while let Some(arg) = args.next() {
+        if arg == name {
+            return args.next();
+        }
+    }
+
+    None
+}
+
+/// Loads the configuration of the renderer from the given TOML file, exiting the process on
+/// failure.
+fn load_config(path: &str) -> GlobeRsConfig {
+    GlobeRsConfig::from_path(path).unwrap_or_else(|error| {
+        eprintln!("{path}: {error}");
+        process::exit(1);
+    })
+}
+
+/// Loads the key bindings file, if any, exiting the process if it cannot be parsed.
+fn key_bindings() -> Option<KeyBindings> {
+    let source = fs::read(KEY_BINDINGS_FILE).ok()?;
+
+    let bindings = ConfigFormat::Toml
+        .deserialize(source.as_slice())
+        .unwrap_or_else(|error| {
+            eprintln!("{KEY_BINDINGS_FILE}: {error}");
+            process::exit(1);
+        });
+
+    Some(bindings)
+}
+
+/// Loads the orbital system from the given path, along with the sections concerning its rendering,
+/// exiting the process on failure.
+fn load_system(path: &str) -> (OrbitalSystem<OrbitKind>, RenderConfig) {
+    let Some(format) = ConfigFormat::from_path(path) else {
+        eprintln!("{path}: unsupported format, expected a .json, .toml or .ron file");
+        process::exit(1);
     };
 
-    App::new().add_plugins(GlobeRsPlugin { system }).run();
+    fs::read(path)
+        .map_err(Into::into)
+        .and_then(|source| {
+            let system = OrbitalSystem::from_reader(source.as_slice(), format)?;
+            let config = format.deserialize(source.as_slice())?;
+            Ok((system, config))
+        })
+        .unwrap_or_else(|error: LoadError| {
+            eprintln!("{path}: {error}");
+            process::exit(1);
+        })
 }
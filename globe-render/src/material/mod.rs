@@ -3,3 +3,6 @@ pub use orbit_trail::*;
 
 mod radial_gradient;
 pub use radial_gradient::*;
+
+mod starfield;
+pub use starfield::*;
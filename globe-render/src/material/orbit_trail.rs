@@ -17,6 +17,8 @@ pub struct OrbitTrailMaterial {
     pub trail_theta: f32,
     #[uniform(5)]
     pub clockwise: u32,
+    #[uniform(6)]
+    pub fade_exponent: f32,
 }
 
 impl Material for OrbitTrailMaterial {
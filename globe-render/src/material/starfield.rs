@@ -0,0 +1,57 @@
+use bevy::{
+    pbr::{MaterialPipeline, MaterialPipelineKey},
+    prelude::*,
+    render::{
+        mesh::MeshVertexBufferLayoutRef,
+        render_resource::{
+            AsBindGroup, RenderPipelineDescriptor, ShaderRef, SpecializedMeshPipelineError,
+        },
+    },
+};
+
+const STARFIELD_SHADER: &str = "shaders/starfield.wgsl";
+
+#[derive(Asset, TypePath, AsBindGroup, Debug, Default, Clone)]
+pub struct StarfieldMaterial {
+    #[uniform(0)]
+    pub background_color: Vec4,
+    #[uniform(1)]
+    pub brightness: f32,
+    #[uniform(2)]
+    pub density: f32,
+    #[uniform(3)]
+    pub textured: u32,
+    #[texture(4)]
+    #[sampler(5)]
+    pub sky: Option<Handle<Image>>,
+}
+
+impl Material for StarfieldMaterial {
+    fn vertex_shader() -> ShaderRef {
+        STARFIELD_SHADER.into()
+    }
+
+    fn fragment_shader() -> ShaderRef {
+        STARFIELD_SHADER.into()
+    }
+
+    /// The sky is seen from inside the sphere it is drawn on, and never writes its depth, so
+    /// anything else is drawn over it.
+    fn specialize(
+        _: &MaterialPipeline<Self>,
+        descriptor: &mut RenderPipelineDescriptor,
+        layout: &MeshVertexBufferLayoutRef,
+        _: MaterialPipelineKey<Self>,
+    ) -> Result<(), SpecializedMeshPipelineError> {
+        descriptor.vertex.buffers = vec![layout
+            .0
+            .get_layout(&[Mesh::ATTRIBUTE_POSITION.at_shader_location(0)])?];
+
+        descriptor.primitive.cull_mode = None;
+        if let Some(depth_stencil) = descriptor.depth_stencil.as_mut() {
+            depth_stencil.depth_write_enabled = false;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,137 @@
+use bevy::{
+    prelude::*,
+    window::{CursorLeft, CursorMoved, PrimaryWindow},
+};
+use globe_rs::{Distance, OrbitalEventKind};
+
+use crate::{
+    camera::MainCamera,
+    color,
+    cursor::Cursor,
+    event::{Event, Hovered, Unhovered, Updated},
+    ui::filters::ViewFilters,
+};
+
+use super::OrbitalSystemState;
+
+/// The size of the apsis markers on screen, in pixels.
+const MARKER_SIZE: f32 = 10.;
+
+/// A diamond on the point of an orbit closest to, or farthest from, the body it orbits.
+#[derive(Component, Debug, Clone, Copy, PartialEq)]
+pub struct ApsisMarker {
+    pub kind: OrbitalEventKind,
+    /// The distance from the apsis to the body being orbited.
+    pub distance: Distance,
+}
+
+/// The mesh and material shared by all the apsis markers.
+#[derive(Resource)]
+pub struct ApsisMarkerAssets {
+    pub mesh: Handle<Mesh>,
+    pub material: Handle<StandardMaterial>,
+}
+
+impl FromWorld for ApsisMarkerAssets {
+    fn from_world(world: &mut World) -> Self {
+        let mesh = world
+            .resource_mut::<Assets<Mesh>>()
+            .add(Rhombus::new(1., 1.));
+
+        let material = world
+            .resource_mut::<Assets<StandardMaterial>>()
+            .add(StandardMaterial {
+                base_color: color::KHAKI,
+                unlit: true,
+                ..Default::default()
+            });
+
+        Self { mesh, material }
+    }
+}
+
+/// Keeps the apsis markers the same size on screen no matter the zoom, and tells which one is
+/// under the cursor, if any.
+pub struct Apsides;
+
+impl Plugin for Apsides {
+    fn build(&self, app: &mut App) {
+        app.add_event::<Event<ApsisMarker, Hovered, ApsisMarker>>()
+            .add_event::<Event<ApsisMarker, Unhovered, ApsisMarker>>()
+            .init_resource::<ApsisMarkerAssets>()
+            .add_systems(Update, Self::keep_screen_size)
+            .add_systems(Update, Self::on_cursor_moved);
+    }
+}
+
+impl Apsides {
+    #[allow(clippy::type_complexity)]
+    fn keep_screen_size(
+        mut markers: Query<&mut Transform, With<ApsisMarker>>,
+        camera: Query<(&Transform, &Projection), (With<MainCamera>, Without<ApsisMarker>)>,
+        window: Query<&Window, With<PrimaryWindow>>,
+    ) {
+        let (transform, projection) = camera.single();
+        let size = MARKER_SIZE * MainCamera::pixel_size(transform, projection, window.single());
+
+        markers.iter_mut().for_each(|mut marker| {
+            marker.scale = Vec3::splat(size);
+        });
+    }
+
+    /// Hovers the visible apsis marker under the cursor, if any, unhovering the previous one.
+    #[allow(clippy::too_many_arguments)]
+    fn on_cursor_moved(
+        mut apsis_hovered: EventWriter<Event<ApsisMarker, Hovered, ApsisMarker>>,
+        mut apsis_unhovered: EventWriter<Event<ApsisMarker, Unhovered, ApsisMarker>>,
+        mut cursor_moved: EventReader<CursorMoved>,
+        mut cursor_left: EventReader<CursorLeft>,
+        mut state_updated: EventReader<Event<OrbitalSystemState, Updated>>,
+        mut cursor: ResMut<Cursor>,
+        markers: Query<(&ApsisMarker, &GlobalTransform)>,
+        camera: Query<(&MainCamera, &Camera, &GlobalTransform)>,
+        window: Query<&Window, With<PrimaryWindow>>,
+        filters: Res<ViewFilters>,
+    ) {
+        let cursor_moved = cursor_moved.read().count() > 0;
+        let cursor_left = cursor_left.read().count() > 0;
+        let state_updated = state_updated.read().count() > 0;
+        if !cursor_moved && !cursor_left && !state_updated && !filters.is_changed() {
+            return;
+        }
+
+        let (main_camera, camera, camera_transform) = camera.single();
+        let reach = MARKER_SIZE / 2. + main_camera.pick_radius;
+
+        let hovered = window
+            .single()
+            .cursor_position()
+            .filter(|_| filters.apsides && !cursor.over_inset)
+            .and_then(|pointer| {
+                markers
+                    .iter()
+                    .filter_map(|(marker, transform)| {
+                        let position = camera
+                            .world_to_viewport(camera_transform, transform.translation())
+                            .ok()?;
+
+                        Some((position.distance(pointer), marker))
+                    })
+                    .filter(|(distance, _)| *distance <= reach)
+                    .min_by(|(a, _), (b, _)| a.total_cmp(b))
+                    .map(|(_, marker)| *marker)
+            });
+
+        if hovered == cursor.hovered_apsis {
+            return;
+        }
+
+        if let Some(marker) = std::mem::replace(&mut cursor.hovered_apsis, hovered) {
+            apsis_unhovered.send(marker.into());
+        }
+
+        if let Some(marker) = hovered {
+            apsis_hovered.send(marker.into());
+        }
+    }
+}
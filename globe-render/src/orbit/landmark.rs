@@ -0,0 +1,359 @@
+use std::fmt::Display;
+
+use alvidir::name::Name;
+use bevy::{
+    prelude::*,
+    render::{mesh::PrimitiveTopology, render_asset::RenderAssetUsages},
+    window::{CursorLeft, CursorMoved, PrimaryWindow},
+};
+use globe_rs::{cartesian::Coords, BodyPosition, LagrangePoint, Orbit as _};
+
+use crate::{
+    camera::MainCamera,
+    color,
+    cursor::Cursor,
+    event::{Created, Deleted, Event, Hovered, Unhovered, Updated},
+    origin::Origin,
+    ui::filters::ViewFilters,
+};
+
+use super::{Body, OrbitalSystem, OrbitalSystemState};
+
+/// The size of the landmark markers on screen, in pixels.
+const MARKER_SIZE: f32 = 10.;
+
+/// A point of the system worth marking, even though there is no body on it.
+#[derive(Component, Debug, Clone, PartialEq)]
+pub enum Landmark {
+    /// The given Lagrange point of the secondary body orbiting the primary one.
+    Lagrange {
+        primary: Name<globe_rs::Body>,
+        secondary: Name<globe_rs::Body>,
+        point: LagrangePoint,
+    },
+    /// The center of mass of the whole system.
+    Barycenter,
+}
+
+impl Display for Landmark {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Lagrange {
+                primary,
+                secondary,
+                point,
+            } => write!(f, "{primary}–{secondary} {point}"),
+            Self::Barycenter => write!(f, "barycenter"),
+        }
+    }
+}
+
+impl Landmark {
+    /// Returns true if, and only if, the position of the landmark depends on the body with the
+    /// given name.
+    fn involves(&self, name: &Name<globe_rs::Body>) -> bool {
+        match self {
+            Self::Lagrange {
+                primary, secondary, ..
+            } => primary == name || secondary == name,
+            Self::Barycenter => true,
+        }
+    }
+
+    /// Returns where the landmark is displayed in the current state, if anywhere.
+    ///
+    /// Lagrange points are placed relative to the primary body, as orbits are, while the
+    /// barycenter is placed relative to the root of the system.
+    fn position(&self, system: &OrbitalSystem, state: &OrbitalSystemState) -> Option<Coords> {
+        match self {
+            Self::Lagrange {
+                primary,
+                secondary,
+                point,
+            } => {
+                let primary_system = system.spec.system(primary)?;
+                let secondary_system = system.spec.system(secondary)?;
+                let primary_position = state.spec.position_of(primary)?;
+
+                let (_, position) = globe_rs::lagrange_points(
+                    BodyPosition {
+                        body: &primary_system.primary,
+                        position: primary_position,
+                    },
+                    BodyPosition {
+                        body: &secondary_system.primary,
+                        position: state.spec.position_of(secondary)?,
+                    },
+                    secondary_system.orbit?.is_clockwise(),
+                )
+                .into_iter()
+                .find(|(candidate, _)| candidate == point)?;
+
+                let contact = state.scale.radius(primary_system.primary.radius)
+                    + state.scale.radius(secondary_system.primary.radius);
+
+                Some(state.scale.place(
+                    position,
+                    primary_position,
+                    state.position_of(primary)?,
+                    contact,
+                ))
+            }
+            Self::Barycenter => {
+                let root = &state.spec.body;
+                let barycenter = system.spec.barycenter_of(&state.spec);
+                Some(state.scale.place(
+                    barycenter,
+                    state.spec.position,
+                    state.position_of(root)?,
+                    0.,
+                ))
+            }
+        }
+    }
+}
+
+/// The meshes and materials shared by all the landmark markers.
+#[derive(Resource)]
+pub struct LandmarkAssets {
+    /// A cross marking the Lagrange points.
+    pub cross: Handle<Mesh>,
+    /// A ring marking the barycenter.
+    pub ring: Handle<Mesh>,
+    pub lagrange_material: Handle<StandardMaterial>,
+    pub barycenter_material: Handle<StandardMaterial>,
+}
+
+impl FromWorld for LandmarkAssets {
+    fn from_world(world: &mut World) -> Self {
+        let mut meshes = world.resource_mut::<Assets<Mesh>>();
+        let cross = meshes.add(cross());
+        let ring = meshes.add(Annulus::new(0.35, 0.5));
+
+        let mut materials = world.resource_mut::<Assets<StandardMaterial>>();
+        let mut unlit = |color| {
+            materials.add(StandardMaterial {
+                base_color: color,
+                unlit: true,
+                ..Default::default()
+            })
+        };
+
+        Self {
+            cross,
+            ring,
+            lagrange_material: unlit(color::YELLOW),
+            barycenter_material: unlit(color::CRIMSON),
+        }
+    }
+}
+
+/// Marks the Lagrange points of every body orbiting a luminous one, and the barycenter of the
+/// whole system, keeping the markers the same size on screen no matter the zoom.
+pub struct Landmarks;
+
+impl Plugin for Landmarks {
+    fn build(&self, app: &mut App) {
+        app.add_event::<Event<Landmark, Hovered, Landmark>>()
+            .add_event::<Event<Landmark, Unhovered, Landmark>>()
+            .init_resource::<LandmarkAssets>()
+            .add_systems(Update, Self::spawn_on_body_created)
+            .add_systems(Update, Self::on_body_updated)
+            .add_systems(Update, Self::on_body_deleted)
+            .add_systems(Update, Self::keep_screen_size)
+            .add_systems(Update, Self::on_cursor_moved);
+    }
+}
+
+impl Landmarks {
+    /// Spawns the Lagrange points of every created body orbiting a luminous one, and the
+    /// barycenter along with the root of the system, replacing the previous ones, if any.
+    #[allow(clippy::too_many_arguments)]
+    fn spawn_on_body_created(
+        mut commands: Commands,
+        mut body_created: EventReader<Event<Body, Created, Body>>,
+        landmarks: Query<(Entity, &Landmark)>,
+        system: Res<OrbitalSystem>,
+        state: Res<OrbitalSystemState>,
+        origin: Res<Origin>,
+        assets: Res<LandmarkAssets>,
+    ) {
+        body_created.read().for_each(|event| {
+            let name = &event.data.name;
+
+            let created: Vec<Landmark> = if system.spec.primary.name == *name {
+                vec![Landmark::Barycenter]
+            } else {
+                state
+                    .spec
+                    .parent_state(name)
+                    .and_then(|parent| system.spec.system(&parent.body))
+                    .filter(|parent| parent.primary.is_luminous())
+                    .map(|parent| {
+                        LagrangePoint::ALL
+                            .into_iter()
+                            .map(|point| Landmark::Lagrange {
+                                primary: parent.primary.name.clone(),
+                                secondary: name.clone(),
+                                point,
+                            })
+                            .collect()
+                    })
+                    .unwrap_or_default()
+            };
+
+            landmarks
+                .iter()
+                .filter(|(_, landmark)| created.contains(landmark))
+                .for_each(|(entity, _)| commands.entity(entity).despawn());
+
+            created.into_iter().for_each(|landmark| {
+                let Some(position) = landmark.position(&system, &state) else {
+                    return;
+                };
+
+                let (mesh, material) = match landmark {
+                    Landmark::Lagrange { .. } => (&assets.cross, &assets.lagrange_material),
+                    Landmark::Barycenter => (&assets.ring, &assets.barycenter_material),
+                };
+
+                commands.spawn((
+                    Mesh3d(mesh.clone()),
+                    MeshMaterial3d(material.clone()),
+                    Transform::from_translation(origin.vec3(position)),
+                    landmark,
+                ));
+            });
+        });
+    }
+
+    /// Moves the landmarks depending on the updated bodies.
+    fn on_body_updated(
+        mut body_updated: EventReader<Event<Body, Updated, Body>>,
+        mut landmarks: Query<(&Landmark, &mut Transform)>,
+        system: Res<OrbitalSystem>,
+        state: Res<OrbitalSystemState>,
+        origin: Res<Origin>,
+    ) {
+        let updated: Vec<_> = body_updated.read().map(|event| &event.data.name).collect();
+        if updated.is_empty() {
+            return;
+        }
+
+        landmarks
+            .iter_mut()
+            .filter(|(landmark, _)| updated.iter().any(|name| landmark.involves(name)))
+            .for_each(|(landmark, mut transform)| {
+                if let Some(position) = landmark.position(&system, &state) {
+                    transform.translation = origin.vec3(position);
+                }
+            });
+    }
+
+    /// Despawns the landmarks depending on the deleted bodies.
+    fn on_body_deleted(
+        mut commands: Commands,
+        mut body_deleted: EventReader<Event<Body, Deleted, Body>>,
+        landmarks: Query<(Entity, &Landmark)>,
+        system: Res<OrbitalSystem>,
+    ) {
+        body_deleted.read().for_each(|event| {
+            landmarks
+                .iter()
+                .filter(|(_, landmark)| match landmark {
+                    // the barycenter goes along with the root of the system only.
+                    Landmark::Barycenter => system.spec.primary.name == event.data.name,
+                    landmark => landmark.involves(&event.data.name),
+                })
+                .for_each(|(entity, _)| commands.entity(entity).despawn());
+        });
+    }
+
+    #[allow(clippy::type_complexity)]
+    fn keep_screen_size(
+        mut landmarks: Query<&mut Transform, With<Landmark>>,
+        camera: Query<(&Transform, &Projection), (With<MainCamera>, Without<Landmark>)>,
+        window: Query<&Window, With<PrimaryWindow>>,
+    ) {
+        let (transform, projection) = camera.single();
+        let size = MARKER_SIZE * MainCamera::pixel_size(transform, projection, window.single());
+
+        landmarks.iter_mut().for_each(|mut landmark| {
+            landmark.scale = Vec3::splat(size);
+        });
+    }
+
+    /// Hovers the visible landmark under the cursor, if any, unhovering the previous one.
+    #[allow(clippy::too_many_arguments)]
+    fn on_cursor_moved(
+        mut landmark_hovered: EventWriter<Event<Landmark, Hovered, Landmark>>,
+        mut landmark_unhovered: EventWriter<Event<Landmark, Unhovered, Landmark>>,
+        mut cursor_moved: EventReader<CursorMoved>,
+        mut cursor_left: EventReader<CursorLeft>,
+        mut state_updated: EventReader<Event<OrbitalSystemState, Updated>>,
+        mut cursor: ResMut<Cursor>,
+        landmarks: Query<(&Landmark, &GlobalTransform)>,
+        camera: Query<(&MainCamera, &Camera, &GlobalTransform)>,
+        window: Query<&Window, With<PrimaryWindow>>,
+        filters: Res<ViewFilters>,
+    ) {
+        let cursor_moved = cursor_moved.read().count() > 0;
+        let cursor_left = cursor_left.read().count() > 0;
+        let state_updated = state_updated.read().count() > 0;
+        if !cursor_moved && !cursor_left && !state_updated && !filters.is_changed() {
+            return;
+        }
+
+        let (main_camera, camera, camera_transform) = camera.single();
+        let reach = MARKER_SIZE / 2. + main_camera.pick_radius;
+
+        let hovered = window
+            .single()
+            .cursor_position()
+            .filter(|_| filters.lagrange_points && !cursor.over_inset)
+            .and_then(|pointer| {
+                landmarks
+                    .iter()
+                    .filter_map(|(landmark, transform)| {
+                        let position = camera
+                            .world_to_viewport(camera_transform, transform.translation())
+                            .ok()?;
+
+                        Some((position.distance(pointer), landmark))
+                    })
+                    .filter(|(distance, _)| *distance <= reach)
+                    .min_by(|(a, _), (b, _)| a.total_cmp(b))
+                    .map(|(_, landmark)| landmark.clone())
+            });
+
+        if hovered == cursor.hovered_landmark {
+            return;
+        }
+
+        if let Some(landmark) = std::mem::replace(&mut cursor.hovered_landmark, hovered.clone()) {
+            landmark_unhovered.send(landmark.into());
+        }
+
+        if let Some(landmark) = hovered {
+            landmark_hovered.send(landmark.into());
+        }
+    }
+}
+
+/// Returns a cross made of two lines of unit length meeting at their middle.
+fn cross() -> Mesh {
+    let points: Vec<[f32; 3]> = vec![
+        [-0.5, -0.5, 0.],
+        [0.5, 0.5, 0.],
+        [-0.5, 0.5, 0.],
+        [0.5, -0.5, 0.],
+    ];
+
+    Mesh::new(PrimitiveTopology::LineList, RenderAssetUsages::RENDER_WORLD)
+        .with_inserted_attribute(
+            Mesh::ATTRIBUTE_NORMAL,
+            vec![Vec3::new(0., 0., 1.); points.len()],
+        )
+        .with_inserted_attribute(Mesh::ATTRIBUTE_UV_0, vec![Vec2::new(0., 0.); points.len()])
+        .with_inserted_attribute(Mesh::ATTRIBUTE_POSITION, points)
+}
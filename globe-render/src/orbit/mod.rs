@@ -1,4 +1,8 @@
-use std::{collections::HashMap, f64::consts::FRAC_PI_2, time::Duration};
+use std::{
+    collections::{HashMap, HashSet},
+    f64::consts::FRAC_PI_2,
+    time::Duration,
+};
 
 use alvidir::name::Name;
 use bevy::{
@@ -10,43 +14,140 @@ use bevy::{
         render_asset::RenderAssetUsages,
         storage::ShaderStorageBuffer,
     },
+    window::{CursorLeft, CursorMoved, PrimaryWindow},
 };
 use globe_rs::{
     cartesian::{
-        shape::{Ellipse, Sample},
-        transform::Translation,
+        shape::{Sample, Shape},
+        transform::{Rotation, TransformChain, Translation},
+        Coords,
     },
-    Orbit as _,
+    Distance, Epoch, Orbit as _, OrbitKind, OrbitalEventKind, Radian,
 };
 
+use apsis::{Apsides, ApsisMarker, ApsisMarkerAssets};
+use scale::ScaleMode;
+use trail::TrailSettings;
+
 use crate::{
+    appearance::{Appearance, DefaultTexture},
+    camera::MainCamera,
     color,
+    config::GlobeRsConfig,
     cursor::Cursor,
-    event::{Clicked, Created, Deleted, Event, Updated},
+    event::{Clicked, Created, Deleted, Event, Hovered, Unhovered, Updated},
     material::{OrbitTrailMaterial, RadialGradientMaterial, RadialGradientMaterialBuilder},
-    ui::clock::Clock,
+    origin::Origin,
+    quat,
+    ui::{clock::Clock, filters::ViewFilters},
 };
 
+pub mod apsis;
+pub mod landmark;
+pub mod pan;
+pub mod scale;
 pub mod scroll;
+pub mod trail;
 pub mod zoom;
 
-const SPHERE_SUBDIVISIONS: u32 = 16;
-const MESH_RESOLUTION: u32 = 255;
+/// How many times the icosahedron of every body gets subdivided, by default.
+pub const SPHERE_SUBDIVISIONS: u32 = 16;
+/// How many points every orbit and habitable zone gets sampled with, by default.
+pub const MESH_RESOLUTION: u32 = 255;
+const LIGHT_INTENSITY_SCALE: f64 = 1.;
+/// How much the emissive color of a body gets tinted while hovered.
+const HOVER_TINT: f32 = 0.3;
 
 #[derive(Resource)]
 pub struct OrbitalSystemState {
     pub spec: globe_rs::OrbitalSystemState,
+    /// The orbital periods of the system, so they are not computed on every tick.
+    pub periods: globe_rs::PeriodCache,
+    /// The state as it is currently displayed on screen, if any, with every body located at its
+    /// displayed position.
+    pub rendered: Option<globe_rs::OrbitalSystemState>,
+    /// The body all positions are relative to, if any, so the bodies around it keep their
+    /// precision once converted into single precision floats.
+    pub origin: Option<Name<globe_rs::Body>>,
+    /// The position of every body in the current state, so lookups do not walk the whole tree.
+    pub positions: HashMap<Name<globe_rs::Body>, Coords>,
+    /// The date of the latest state computed by the simulation.
+    pub date: Epoch,
+    /// The latest state computed by the simulation, before making it relative to the origin.
+    pub latest: globe_rs::OrbitalSystemState,
+    /// The state computed by the simulation right before the latest one, if any, so the states in
+    /// between can be displayed.
+    pub previous: Option<globe_rs::OrbitalSystemState>,
+    /// How bodies and distances are displayed.
+    pub scale: ScaleMode,
+    /// The radius of every body, so positions can be displayed according to the scale mode.
+    pub radii: HashMap<Name<globe_rs::Body>, Distance>,
+}
+
+impl OrbitalSystemState {
+    /// Sets the given state as the current one, relative to the origin, if any.
+    pub fn set_spec(&mut self, spec: globe_rs::OrbitalSystemState) {
+        self.spec = match &self.origin {
+            Some(origin) => spec.positions_relative_to(origin),
+            None => spec,
+        };
+
+        self.positions = self
+            .scale
+            .positions(&self.spec, &self.radii, self.origin.as_ref());
+    }
+
+    /// Despawns every entity of the system, so all of them get spawned again on the next update
+    /// of the state.
+    pub fn respawn(&mut self, commands: &mut Commands, entities: &Query<Entity, With<Body>>) {
+        entities.iter().for_each(|entity| {
+            commands.entity(entity).despawn_recursive();
+        });
+
+        self.rendered = None;
+    }
+
+    /// Returns the current position of the body with the given name, if any.
+    pub fn position_of(&self, name: &Name<globe_rs::Body>) -> Option<Coords> {
+        self.positions.get(name).copied()
+    }
+
+    /// Returns the current state with every body located at its displayed position, which differs
+    /// from the actual one unless distances are displayed at scale.
+    pub fn displayed(&self) -> globe_rs::OrbitalSystemState {
+        Self::with_positions(self.spec.clone(), &self.positions)
+    }
+
+    fn with_positions(
+        mut state: globe_rs::OrbitalSystemState,
+        positions: &HashMap<Name<globe_rs::Body>, Coords>,
+    ) -> globe_rs::OrbitalSystemState {
+        if let Some(position) = positions.get(&state.body) {
+            state.position = *position;
+        }
+
+        state.secondary = state
+            .secondary
+            .into_iter()
+            .map(|state| Self::with_positions(state, positions))
+            .collect();
+
+        state
+    }
 }
 
 #[derive(Component, Clone)]
 pub struct Body {
     pub name: Name<globe_rs::Body>,
-    pub ruler: Option<Name<globe_rs::Body>>,
 }
 
 #[derive(Component)]
 pub struct Orbit;
 
+/// The habitable zone around a luminous body.
+#[derive(Component)]
+pub struct HabitableZone;
+
 /// A description of the orbital system.
 #[derive(Resource)]
 pub struct OrbitalSystemStats {
@@ -62,11 +163,11 @@ impl From<globe_rs::SystemStats> for OrbitalSystemStats {
 /// The orbital system.
 #[derive(Resource)]
 pub struct OrbitalSystem {
-    pub spec: globe_rs::OrbitalSystem<Ellipse>,
+    pub spec: globe_rs::OrbitalSystem<OrbitKind>,
 }
 
-impl From<&globe_rs::OrbitalSystem<Ellipse>> for OrbitalSystem {
-    fn from(system: &globe_rs::OrbitalSystem<Ellipse>) -> Self {
+impl From<&globe_rs::OrbitalSystem<OrbitKind>> for OrbitalSystem {
+    fn from(system: &globe_rs::OrbitalSystem<OrbitKind>) -> Self {
         Self {
             spec: system.clone(),
         }
@@ -75,13 +176,24 @@ impl From<&globe_rs::OrbitalSystem<Ellipse>> for OrbitalSystem {
 
 impl Plugin for OrbitalSystem {
     fn build(&self, app: &mut App) {
+        let scale_mode = app
+            .world()
+            .get_resource::<GlobeRsConfig>()
+            .map(|config| config.scale_mode)
+            .unwrap_or_default();
+
         app.add_event::<Event<Body, Created, Body>>()
             .add_event::<Event<Body, Updated, Body>>()
             .add_event::<Event<Body, Deleted, Body>>()
             .add_event::<Event<Body, Clicked, Body>>()
+            .add_event::<Event<Body, Hovered, Body>>()
+            .add_event::<Event<Body, Unhovered, Body>>()
             .add_event::<Event<OrbitalSystemState, Updated>>()
+            .add_event::<Event<globe_rs::OrbitalEvent, Created, globe_rs::OrbitalEvent>>()
             .add_systems(Startup, Self::setup)
-            .add_systems(Update, Self::on_clock_tick_event)
+            .add_systems(FixedUpdate, Self::on_clock_tick_event)
+            .add_systems(Update, Self::interpolate_state)
+            .add_systems(Update, Self::on_orbital_system_update)
             .add_systems(Update, Self::on_orbital_system_state_update)
             .add_systems(Update, Self::spawn_body_on_body_created)
             .add_systems(Update, Self::spawn_habitable_zone_on_body_created)
@@ -89,36 +201,165 @@ impl Plugin for OrbitalSystem {
             .add_systems(Update, Self::on_body_updated)
             .add_systems(Update, Self::on_body_deleted)
             .add_systems(Update, Self::on_mouse_button_event)
+            .add_systems(Update, Self::on_cursor_moved)
+            .add_systems(Update, Self::on_body_hovered_or_unhovered)
             .add_plugins(MaterialPlugin::<OrbitTrailMaterial>::default())
             .add_plugins(MaterialPlugin::<RadialGradientMaterial>::default())
             .add_plugins(zoom::LogarithmicZoom)
-            .add_plugins(scroll::LinearScroll);
+            .add_plugins(scroll::LinearScroll)
+            .add_plugins(pan::KeyboardPan)
+            .add_plugins(pan::DragPan)
+            .add_plugins(scale_mode)
+            .add_plugins(TrailSettings::default())
+            .add_plugins(Apsides)
+            .add_plugins(landmark::Landmarks);
     }
 }
 
 impl OrbitalSystem {
+    /// Computes the state of the system at the date of the clock on every step of the simulation.
     fn on_clock_tick_event(
         mut tick: EventReader<Event<Clock, Updated>>,
         mut state_updated: EventWriter<Event<OrbitalSystemState, Updated>>,
+        mut orbital_event: EventWriter<
+            Event<globe_rs::OrbitalEvent, Created, globe_rs::OrbitalEvent>,
+        >,
         mut state: ResMut<OrbitalSystemState>,
+        mut clock: ResMut<Clock>,
         system: Res<OrbitalSystem>,
-        clock: Res<Clock>,
     ) {
         if tick.read().last().is_none() {
             return;
         };
 
-        state.spec = system.spec.state_at(clock.elapsed_time);
+        match system
+            .spec
+            .try_state_at_epoch_cached(clock.date, &state.periods)
+        {
+            Ok(spec) => {
+                // events are only reported while the clock runs forward after the epoch.
+                state
+                    .date
+                    .duration_since(Epoch::J2000)
+                    .zip(clock.date.duration_since(Epoch::J2000))
+                    .into_iter()
+                    .flat_map(|(from, to)| system.spec.events_between(from, to))
+                    .for_each(|event| {
+                        info!(
+                            "{} passes through its {} at {}",
+                            event.body,
+                            event.kind,
+                            Epoch::J2000 + event.time
+                        );
+
+                        orbital_event.send(event.into());
+                    });
+
+                state.date = clock.date;
+                if clock.running {
+                    state.previous = Some(std::mem::replace(&mut state.latest, spec));
+                    return;
+                }
+
+                // the clock has been set while paused, so there is nothing to interpolate.
+                state.latest = spec.clone();
+                state.previous = None;
+                state.set_spec(spec);
+                state_updated.send(Event::default());
+            }
+            Err(error) => {
+                // keep the latest valid state on screen instead of rendering NaN transforms
+                error!("{error}");
+                clock.running = false;
+            }
+        }
+    }
+
+    /// Displays the state in between the latest two steps of the simulation at which the real
+    /// time is, so bodies move smoothly no matter the frame rate.
+    pub fn interpolate_state(
+        mut state_updated: EventWriter<Event<OrbitalSystemState, Updated>>,
+        mut state: ResMut<OrbitalSystemState>,
+        clock: Res<Clock>,
+        time: Res<Time<Fixed>>,
+    ) {
+        if !clock.running {
+            return;
+        }
+
+        let Some(previous) = &state.previous else {
+            return;
+        };
+
+        let spec = previous.interpolate(&state.latest, time.overstep_fraction_f64());
+        state.set_spec(spec);
         state_updated.send(Event::default());
     }
 
+    /// Recomputes the state and stats of the system whenever its spec is edited at runtime, so
+    /// created, updated and deleted bodies get reflected on screen.
+    fn on_orbital_system_update(
+        mut state_updated: EventWriter<Event<OrbitalSystemState, Updated>>,
+        mut state: ResMut<OrbitalSystemState>,
+        mut stats: ResMut<OrbitalSystemStats>,
+        system: Res<OrbitalSystem>,
+        mut clock: ResMut<Clock>,
+    ) {
+        if !system.is_changed() || system.is_added() {
+            return;
+        }
+
+        state.periods = globe_rs::PeriodCache::from(&system.spec);
+        state.radii = radii(&system.spec);
+        stats.spec = globe_rs::SystemStats::from(&system.spec);
+
+        match system
+            .spec
+            .try_state_at_epoch_cached(clock.date, &state.periods)
+        {
+            Ok(spec) => {
+                state.date = clock.date;
+                state.latest = spec.clone();
+                state.previous = None;
+                state.set_spec(spec);
+                state_updated.send(Event::default());
+            }
+            Err(error) => {
+                // keep the latest valid state on screen instead of rendering NaN transforms
+                error!("{error}");
+                clock.running = false;
+            }
+        }
+    }
+
     pub fn setup(
         mut commands: Commands,
         mut state: EventWriter<Event<OrbitalSystemState, Updated>>,
+        mut exit: EventWriter<AppExit>,
         system: Res<OrbitalSystem>,
+        scale: Res<ScaleMode>,
     ) {
+        let spec = match system.spec.try_state_at(Duration::ZERO) {
+            Ok(spec) => spec,
+            Err(error) => {
+                error!("{error}");
+                exit.send(AppExit::error());
+                return;
+            }
+        };
+
+        let radii = radii(&system.spec);
         commands.insert_resource(OrbitalSystemState {
-            spec: system.spec.state_at(Duration::ZERO),
+            positions: scale.positions(&spec, &radii, None),
+            latest: spec.clone(),
+            previous: None,
+            spec,
+            periods: globe_rs::PeriodCache::from(&system.spec),
+            rendered: None,
+            origin: None,
+            date: Epoch::J2000,
+            scale: *scale,
+            radii,
         });
 
         commands.insert_resource(OrbitalSystemStats::from(globe_rs::SystemStats::from(
@@ -128,85 +369,127 @@ impl OrbitalSystem {
         state.send(Event::default());
     }
 
+    #[allow(clippy::too_many_arguments)]
     fn on_orbital_system_state_update(
         mut state_updated: EventReader<Event<OrbitalSystemState, Updated>>,
         mut body_created: EventWriter<Event<Body, Created, Body>>,
         mut body_updated: EventWriter<Event<Body, Updated, Body>>,
         mut body_deleted: EventWriter<Event<Body, Deleted, Body>>,
-        bodies: Query<&Body>,
-        state: Res<OrbitalSystemState>,
+        mut state: ResMut<OrbitalSystemState>,
+        system: Res<OrbitalSystem>,
+        camera: Query<(&Transform, &Projection), With<MainCamera>>,
+        window: Query<&Window, With<PrimaryWindow>>,
     ) {
         if state_updated.read().last().is_none() {
             return;
         };
 
-        let mut body_by_name: HashMap<Name<globe_rs::Body>, &Body> =
-            HashMap::from_iter(bodies.iter().map(|body| (body.name.clone(), body)));
-
-        fn spawn_or_update_immersion(
-            body_created: &mut EventWriter<Event<Body, Created, Body>>,
-            body_updated: &mut EventWriter<Event<Body, Updated, Body>>,
-            bodies: &mut HashMap<Name<globe_rs::Body>, &Body>,
-            state: &globe_rs::OrbitalSystemState,
-            ruler: Option<&Name<globe_rs::Body>>,
-        ) {
-            if let Some(body) = bodies.remove(&state.body) {
-                body_updated.send(body.clone().into());
-            } else {
+        let Some(rendered) = state.rendered.take() else {
+            state.spec.iter().for_each(|(state, _)| {
                 body_created.send(
                     Body {
                         name: state.body.clone(),
-                        ruler: ruler.cloned(),
                     }
                     .into(),
                 );
-            }
-
-            state.secondary.iter().for_each(|substate| {
-                spawn_or_update_immersion(
-                    body_created,
-                    body_updated,
-                    bodies,
-                    substate,
-                    Some(&state.body),
-                )
             });
-        }
 
-        spawn_or_update_immersion(
-            &mut body_created,
-            &mut body_updated,
-            &mut body_by_name,
-            &state.spec,
-            None,
-        );
+            state.rendered = Some(state.displayed());
+            return;
+        };
+
+        // editing the system may change the orbit of a body without moving it perceptibly.
+        let epsilon = if system.is_changed() {
+            Distance::ZERO
+        } else {
+            let (transform, projection) = camera.single();
+            Distance::meters(MainCamera::pixel_size(transform, projection, window.single()) as f64)
+        };
+
+        // the epsilon is measured on screen, hence so must be the displacements.
+        let displayed = state.displayed();
+        let diff = displayed.diff(&rendered, epsilon);
+        diff.created.iter().for_each(|name| {
+            body_created.send(Body { name: name.clone() }.into());
+        });
+
+        diff.moved.iter().for_each(|(name, _)| {
+            body_updated.send(Body { name: name.clone() }.into());
+        });
 
-        body_by_name.into_values().for_each(|body| {
-            body_deleted.send(body.clone().into());
+        diff.removed.iter().for_each(|name| {
+            body_deleted.send(Body { name: name.clone() }.into());
         });
+
+        let moved: HashSet<&Name<globe_rs::Body>> =
+            diff.moved.iter().map(|(name, _)| name).collect();
+        state.rendered = Some(Self::settle(displayed, &rendered, &moved));
+    }
+
+    /// Returns the given state with the bodies that have not been updated located where they are
+    /// rendered, so small displacements accumulate until they become perceptible.
+    fn settle(
+        mut state: globe_rs::OrbitalSystemState,
+        rendered: &globe_rs::OrbitalSystemState,
+        moved: &HashSet<&Name<globe_rs::Body>>,
+    ) -> globe_rs::OrbitalSystemState {
+        if !moved.contains(&state.body) {
+            if let Some(rendered) = rendered.state(&state.body) {
+                state.position = rendered.position;
+            }
+        }
+
+        state.secondary = state
+            .secondary
+            .into_iter()
+            .map(|state| Self::settle(state, rendered, moved))
+            .collect();
+
+        state
     }
 
+    /// Moves the bodies that changed their position, and spins all of them whenever the state
+    /// gets updated, since bodies keep rotating even when they do not move.
     fn on_body_updated(
         mut body_updated: EventReader<Event<Body, Updated, Body>>,
-        mut bodies: Query<(&mut Transform, &Body), Without<Orbit>>,
+        mut state_updated: EventReader<Event<OrbitalSystemState, Updated>>,
+        mut bodies: Query<(&mut Transform, &Body, Has<HabitableZone>), Without<Orbit>>,
         state: Res<OrbitalSystemState>,
+        origin: Res<Origin>,
     ) {
         body_updated
             .read()
-            .filter_map(|event| state.spec.state(&event.data.name))
-            .for_each(|state| {
+            .filter_map(|event| {
+                state
+                    .position_of(&event.data.name)
+                    .map(|position| (&event.data.name, position))
+            })
+            .for_each(|(name, position)| {
                 bodies
                     .iter_mut()
-                    .filter(|(_, body)| body.name == state.body)
-                    .map(|(transform, _)| transform)
+                    .filter(|(_, body, _)| &body.name == name)
+                    .map(|(transform, _, _)| transform)
                     .for_each(|mut transform| {
-                        *transform = Transform::from_xyz(
-                            state.position.x() as f32,
-                            state.position.y() as f32,
-                            state.position.z() as f32,
-                        );
+                        transform.translation = origin.vec3(position);
                     });
             });
+
+        if state_updated.read().count() == 0 {
+            return;
+        }
+
+        bodies
+            .iter_mut()
+            .filter(|(_, _, is_habitable_zone)| !is_habitable_zone)
+            .filter_map(|(transform, body, _)| {
+                state
+                    .spec
+                    .state(&body.name)
+                    .map(|state| (transform, state.rotation))
+            })
+            .for_each(|(mut transform, rotation)| {
+                transform.rotation = spin(rotation);
+            });
     }
 
     fn on_body_deleted(
@@ -225,6 +508,7 @@ impl OrbitalSystem {
         });
     }
 
+    #[allow(clippy::too_many_arguments)]
     fn spawn_body_on_body_created(
         mut commands: Commands,
         mut meshes: ResMut<Assets<Mesh>>,
@@ -232,6 +516,12 @@ impl OrbitalSystem {
         mut body_created: EventReader<Event<Body, Created, Body>>,
         state: Res<OrbitalSystemState>,
         system: Res<OrbitalSystem>,
+        filters: Res<ViewFilters>,
+        origin: Res<Origin>,
+        appearance: Res<Appearance>,
+        default_texture: Res<DefaultTexture>,
+        asset_server: Res<AssetServer>,
+        config: Res<GlobeRsConfig>,
     ) {
         body_created
             .read()
@@ -239,42 +529,46 @@ impl OrbitalSystem {
                 system
                     .spec
                     .system(&event.data.name)
-                    .zip(state.spec.state(&event.data.name))
-                    .map(|(system, state)| (system, state, event.data.clone()))
+                    .zip(state.position_of(&event.data.name))
+                    .map(|(system, position)| (system, position, event.data.clone()))
             })
-            .for_each(|(system, state, body)| {
-                let radius = system.primary.radius.as_meters() as f32;
+            .for_each(|(system, position, body)| {
+                let radius = state.scale.radius(system.primary.radius) as f32;
                 let mesh = SphereMeshBuilder {
                     sphere: Sphere::new(radius),
                     kind: SphereKind::Ico {
-                        subdivisions: SPHERE_SUBDIVISIONS,
+                        subdivisions: config.sphere_subdivisions,
                     },
                 };
 
+                let base_color = appearance.color(&system.primary);
                 let material = StandardMaterial {
-                    base_color: if system.primary.is_luminous() {
-                        color::PERSIAN_ORANGE
-                    } else {
-                        color::KHAKI
-                    },
+                    base_color,
+                    base_color_texture: Some(appearance.texture(
+                        &system.primary,
+                        &asset_server,
+                        &default_texture,
+                    )),
                     alpha_mode: AlphaMode::Blend,
-                    emissive: if system.primary.is_luminous() {
-                        color::PERSIAN_ORANGE
-                    } else {
-                        Color::BLACK
-                    }
-                    .into(),
+                    emissive: emissive(
+                        base_color,
+                        glow(&appearance, &filters, &system.primary),
+                        false,
+                    ),
                     ..Default::default()
                 };
 
+                let rotation = state
+                    .spec
+                    .state(&body.name)
+                    .map(|state| state.rotation)
+                    .unwrap_or_default();
+
                 let mut entity = commands.spawn((
                     Mesh3d(meshes.add(mesh)),
                     MeshMaterial3d(materials.add(material)),
-                    Transform::from_xyz(
-                        state.position.x() as f32,
-                        state.position.y() as f32,
-                        state.position.z() as f32,
-                    ),
+                    Transform::from_translation(origin.vec3(position))
+                        .with_rotation(spin(rotation)),
                     CascadeShadowConfigBuilder {
                         first_cascade_far_bound: 7.0,
                         maximum_distance: system.radius().as_meters() as f32,
@@ -289,7 +583,10 @@ impl OrbitalSystem {
                     entity.with_child(PointLight {
                         radius,
                         color: Color::WHITE,
-                        intensity: system.primary.luminosity.as_lm() as f32,
+                        intensity: system
+                            .primary
+                            .luminosity
+                            .as_render_intensity(LIGHT_INTENSITY_SCALE),
                         range: system.radius().as_meters() as f32,
                         shadows_enabled: true,
                         // shadow_depth_bias: todo!(),
@@ -309,6 +606,8 @@ impl OrbitalSystem {
         mut body_created: EventReader<Event<Body, Created, Body>>,
         state: Res<OrbitalSystemState>,
         system: Res<OrbitalSystem>,
+        origin: Res<Origin>,
+        config: Res<GlobeRsConfig>,
     ) {
         body_created
             .read()
@@ -316,26 +615,30 @@ impl OrbitalSystem {
                 system
                     .spec
                     .system(&event.data.name)
-                    .zip(state.spec.state(&event.data.name))
-                    .map(|(system, state)| (system, state, event.data.clone()))
+                    .zip(state.position_of(&event.data.name))
+                    .map(|(system, position)| (system, position, event.data.clone()))
             })
-            .for_each(|(system, state, body)| {
-                let hz = globe_rs::HabitableZone::from(&system.primary);
-                if hz.outer_edge <= system.primary.radius {
+            .for_each(|(system, position, body)| {
+                let Some(hz) = globe_rs::HabitableZone::try_from(&system.primary)
+                    .ok()
+                    .filter(|hz| hz.outer_edge > system.primary.radius)
+                else {
                     return;
-                }
+                };
 
-                let transform =
-                    Transform::from_xyz(state.position.y() as f32, -state.position.x() as f32, 0.);
+                let position = origin.vec3(position);
+                let transform = Transform::from_xyz(position.y, -position.x, 0.);
 
-                let inner_radius = hz.inner_edge.as_meters() as f32;
-                let outer_radius = hz.outer_edge.as_meters() as f32;
+                // the edges of the zone are displayed as far from the body as any other body would.
+                let contact = state.scale.radius(system.primary.radius);
+                let inner_radius = state.scale.distance(hz.inner_edge, contact) as f32;
+                let outer_radius = state.scale.distance(hz.outer_edge, contact) as f32;
                 let quarter = (outer_radius - inner_radius) / 4.;
 
                 let transparency = 0.1;
                 let mesh = AnnulusMeshBuilder {
                     annulus: Annulus::new(inner_radius, outer_radius),
-                    resolution: MESH_RESOLUTION,
+                    resolution: config.mesh_resolution,
                 };
 
                 let material = RadialGradientMaterialBuilder::new(&mut buffers)
@@ -357,6 +660,7 @@ impl OrbitalSystem {
                     MeshMaterial3d(materials.add(material)),
                     transform,
                     body,
+                    HabitableZone,
                 ));
             });
     }
@@ -372,6 +676,10 @@ impl OrbitalSystem {
         state: Res<OrbitalSystemState>,
         stats: Res<OrbitalSystemStats>,
         system: Res<OrbitalSystem>,
+        origin: Res<Origin>,
+        trail: Res<TrailSettings>,
+        markers: Res<ApsisMarkerAssets>,
+        config: Res<GlobeRsConfig>,
     ) {
         body_created
             .read()
@@ -394,123 +702,506 @@ impl OrbitalSystem {
                     return;
                 };
 
-                let Some(((ruler_state, ruler_stats), orbit)) = body
-                    .ruler
-                    .as_ref()
-                    .and_then(|ruler| state.spec.state(ruler).zip(stats.spec.stats(ruler)))
-                    .zip(body_system.orbit)
+                let Some((ruler_state, orbit)) =
+                    state.spec.parent_state(&body.name).zip(body_system.orbit)
                 else {
                     return;
                 };
 
-                let mut orbit_points: Vec<[f32; 3]> = orbit
+                let placement = TransformChain::new()
+                    .then(Translation::default().with_vector(orbit.focus()))
+                    .then(Translation::default().with_vector(ruler_state.position));
+
+                let mut orbit_shape = orbit
                     .with_initial_theta(body_state.theta)
-                    .sample(MESH_RESOLUTION as usize)
+                    .sample(config.mesh_resolution as usize)
+                    .transform(placement);
+
+                // the orbit goes through the displayed positions of the body, not the actual ones.
+                let ruler_position = state
+                    .position_of(&ruler_state.body)
+                    .unwrap_or(ruler_state.position);
+
+                let radius = |name| {
+                    system
+                        .spec
+                        .system(name)
+                        .map(|system| state.scale.radius(system.primary.radius))
+                        .unwrap_or_default()
+                };
+
+                let contact = radius(&ruler_state.body) + radius(&body.name);
+                let place = |point| {
+                    state
+                        .scale
+                        .place(point, ruler_state.position, ruler_position, contact)
+                };
+
+                orbit_shape.points = orbit_shape
                     .points
                     .into_iter()
-                    .map(|coord| {
-                        coord
-                            .transform(Translation::default().with_vector(orbit.focus()))
-                            .transform(Translation::default().with_vector(ruler_state.position))
-                    })
-                    .map(|point| [point.x() as f32, point.y() as f32, point.z() as f32])
+                    .map(|point| place(point) - origin.offset)
                     .collect();
 
-                //ensure the mesh is closed.
-                orbit_points.push(orbit_points[0]);
-
-                let mesh = Mesh::new(
-                    PrimitiveTopology::LineStrip,
-                    RenderAssetUsages::RENDER_WORLD,
-                )
-                .with_inserted_attribute(
-                    Mesh::ATTRIBUTE_NORMAL,
-                    vec![Vec3::new(0., 0., 1.); orbit_points.len()],
-                )
-                .with_inserted_attribute(
-                    Mesh::ATTRIBUTE_UV_0,
-                    vec![Vec2::new(0., 0.); orbit_points.len()],
-                )
-                .with_inserted_attribute(Mesh::ATTRIBUTE_POSITION, orbit_points);
-
-                let trail_ratio = ruler_stats
-                    .secondary
-                    .iter()
-                    .fold(None, |fastest, stats| {
-                        let Some(fast) = fastest else {
-                            return Some(stats);
-                        };
-
-                        if fast.max_velocity < stats.max_velocity {
-                            return Some(stats);
-                        }
-
-                        fastest
-                    })
-                    .map(|fastest| {
-                        let radius_inv = 1. / fastest.radius.as_meters();
-                        FRAC_PI_2 / fastest.max_velocity.as_meters_sec() / radius_inv
-                    })
-                    .unwrap_or_default();
+                //ensure the mesh of periodic orbits is closed.
+                if orbit.is_closed() {
+                    orbit_shape.close();
+                }
 
-                let material = OrbitTrailMaterial {
-                    center: Vec3 {
-                        x: (ruler_state.position.x() + orbit.focus().x()) as f32,
-                        y: (ruler_state.position.y() + orbit.focus().y()) as f32,
-                        z: (ruler_state.position.z() + orbit.focus().z()) as f32,
-                    },
-                    origin: Vec3 {
-                        x: body_state.position.x() as f32,
-                        y: body_state.position.y() as f32,
-                        z: body_state.position.z() as f32,
-                    },
+                let mesh = line_strip(orbit_shape);
+
+                let mut material = OrbitTrailMaterial {
+                    center: origin.vec3(place(ruler_state.position + orbit.focus())),
+                    origin: origin
+                        .vec3(state.position_of(&body.name).unwrap_or(body_state.position)),
                     background_color: color::JET.to_linear().to_vec4(),
                     trail_color: color::KHAKI.to_linear().to_vec4(),
-                    trail_theta: (body_state.velocity.as_meters_sec() / orbit.radius().as_meters()
-                        * trail_ratio) as f32,
                     clockwise: orbit.is_clockwise().then_some(1).unwrap_or_default(),
+                    ..Default::default()
                 };
 
+                trail.apply(&mut material);
+
                 commands.spawn((
                     Mesh3d(meshes.add(mesh)),
                     MeshMaterial3d(materials.add(material)),
                     body.clone(),
                     Orbit,
                 ));
+
+                // every point of a circular orbit is as close to the ruler as any other.
+                if orbit.eccentricity().as_f64() == 0. {
+                    return;
+                }
+
+                let Some(body_stats) = stats.spec.stats(&body.name) else {
+                    return;
+                };
+
+                [
+                    (
+                        OrbitalEventKind::Periapsis,
+                        orbit.periapsis(),
+                        body_stats.periapsis,
+                    ),
+                    (
+                        OrbitalEventKind::Apoapsis,
+                        orbit.apoapsis(),
+                        body_stats.apoapsis,
+                    ),
+                ]
+                .into_iter()
+                .filter_map(|(kind, position, distance)| Some((kind, position?, distance?)))
+                .for_each(|(kind, position, distance)| {
+                    let position = place(position + orbit.focus() + ruler_state.position);
+                    commands.spawn((
+                        Mesh3d(markers.mesh.clone()),
+                        MeshMaterial3d(markers.material.clone()),
+                        Transform::from_translation(origin.vec3(position)),
+                        body.clone(),
+                        Orbit,
+                        ApsisMarker { kind, distance },
+                    ));
+                });
             });
     }
 
+    /// Clicks the body under the cursor, if any.
+    #[allow(clippy::too_many_arguments)]
     pub fn on_mouse_button_event(
         mut body_clicked: EventWriter<Event<Body, Clicked, Body>>,
         mut mouse_button: EventReader<MouseButtonInput>,
-        bodies: Query<(&Body, &Transform)>,
+        bodies: Query<(&Body, &Transform), Without<Orbit>>,
+        camera: Query<(
+            &MainCamera,
+            &Camera,
+            &GlobalTransform,
+            &Transform,
+            &Projection,
+        )>,
+        window: Query<&Window, With<PrimaryWindow>>,
         system: Res<OrbitalSystem>,
+        scale: Res<ScaleMode>,
         cursor: Res<Cursor>,
     ) {
         let Some(event) = mouse_button.read().last() else {
             return;
         };
 
-        if event.state.is_pressed() {
+        if event.state.is_pressed() || cursor.over_inset {
+            return;
+        }
+
+        if let Some(body) =
+            Self::body_under_cursor(&bodies, camera.single(), window.single(), &system, &scale)
+        {
+            body_clicked.send(body.clone().into());
+        };
+    }
+
+    /// Hovers the body under the cursor, if any, whenever either the cursor or the bodies move,
+    /// unhovering the previous one. Leaving the window, or entering the inset, unhovers any body.
+    #[allow(clippy::too_many_arguments)]
+    fn on_cursor_moved(
+        mut body_hovered: EventWriter<Event<Body, Hovered, Body>>,
+        mut body_unhovered: EventWriter<Event<Body, Unhovered, Body>>,
+        mut cursor_moved: EventReader<CursorMoved>,
+        mut cursor_left: EventReader<CursorLeft>,
+        mut state_updated: EventReader<Event<OrbitalSystemState, Updated>>,
+        mut cursor: ResMut<Cursor>,
+        bodies: Query<(&Body, &Transform), Without<Orbit>>,
+        camera: Query<(
+            &MainCamera,
+            &Camera,
+            &GlobalTransform,
+            &Transform,
+            &Projection,
+        )>,
+        window: Query<&Window, With<PrimaryWindow>>,
+        system: Res<OrbitalSystem>,
+        scale: Res<ScaleMode>,
+    ) {
+        let cursor_moved = cursor_moved.read().count() > 0;
+        let cursor_left = cursor_left.read().count() > 0;
+        let state_updated = state_updated.read().count() > 0;
+        if !cursor_moved && !cursor_left && !state_updated {
+            return;
+        }
+
+        let hovered = if cursor.over_inset {
+            None
+        } else {
+            Self::body_under_cursor(&bodies, camera.single(), window.single(), &system, &scale)
+                .map(|body| body.name.clone())
+        };
+
+        if hovered == cursor.hovered {
             return;
         }
 
-        if let Some(body) = bodies
+        if let Some(name) = std::mem::replace(&mut cursor.hovered, hovered.clone()) {
+            body_unhovered.send(Body { name }.into());
+        }
+
+        if let Some(name) = hovered {
+            body_hovered.send(Body { name }.into());
+        }
+    }
+
+    /// Returns the nearest body hit by the ray going from the camera through the cursor, if any,
+    /// being every body inflated by the pick radius of the camera so tiny ones remain pickable.
+    fn body_under_cursor<'a>(
+        bodies: &'a Query<(&Body, &Transform), Without<Orbit>>,
+        (main_camera, camera, camera_global_transform, camera_transform, projection): (
+            &MainCamera,
+            &Camera,
+            &GlobalTransform,
+            &Transform,
+            &Projection,
+        ),
+        window: &Window,
+        system: &OrbitalSystem,
+        scale: &ScaleMode,
+    ) -> Option<&'a Body> {
+        let ray = window.cursor_position().and_then(|cursor| {
+            camera
+                .viewport_to_world(camera_global_transform, cursor)
+                .ok()
+        })?;
+
+        let inflation =
+            main_camera.pick_radius * MainCamera::pixel_size(camera_transform, projection, window);
+
+        bodies
             .iter()
             .filter_map(|(body, transform)| {
-                system
-                    .spec
-                    .system(&body.name)
-                    .map(|system| (system, body, transform))
+                let radius = scale.radius(system.spec.system(&body.name)?.primary.radius) as f32;
+                ray_sphere_intersection(ray, transform.translation, radius + inflation)
+                    .map(|distance| (distance, body))
             })
-            .filter(|(system, _, transform)| {
-                transform.translation.xy().distance(cursor.position.xy())
-                    <= system.primary.radius.as_meters() as f32
-            })
-            .map(|(_, body, _)| body)
-            .next()
-        {
-            body_clicked.send(body.clone().into());
+            .min_by(|(a, _), (b, _)| a.total_cmp(b))
+            .map(|(_, body)| body)
+    }
+
+    /// Tints the hovered body, restoring the material of the unhovered one, and turns the glow of
+    /// the luminous bodies on or off according to the view filters.
+    #[allow(clippy::too_many_arguments)]
+    fn on_body_hovered_or_unhovered(
+        mut body_hovered: EventReader<Event<Body, Hovered, Body>>,
+        mut body_unhovered: EventReader<Event<Body, Unhovered, Body>>,
+        mut materials: ResMut<Assets<StandardMaterial>>,
+        bodies: Query<(&Body, &MeshMaterial3d<StandardMaterial>)>,
+        system: Res<OrbitalSystem>,
+        filters: Res<ViewFilters>,
+        cursor: Res<Cursor>,
+        appearance: Res<Appearance>,
+    ) {
+        let hovered = body_hovered.read().count() > 0;
+        let unhovered = body_unhovered.read().count() > 0;
+        if !hovered && !unhovered && !filters.is_changed() {
+            return;
+        }
+
+        bodies.iter().for_each(|(body, material)| {
+            let Some(system) = system.spec.system(&body.name) else {
+                return;
+            };
+
+            // the material is borrowed from the assets, hence it cannot leave the closure.
+            if let Some(material) = materials.get_mut(material) {
+                material.emissive = emissive(
+                    appearance.color(&system.primary),
+                    glow(&appearance, &filters, &system.primary),
+                    cursor.hovered.as_ref() == Some(&body.name),
+                );
+            }
+        });
+    }
+}
+
+/// Returns the radius of every body in the given system, indexed by name.
+fn radii(system: &globe_rs::OrbitalSystem<OrbitKind>) -> HashMap<Name<globe_rs::Body>, Distance> {
+    system
+        .iter()
+        .map(|(body, _, _)| (body.name.clone(), body.radius))
+        .collect()
+}
+
+/// Returns how much light the given body emits, which is none if the glow is filtered out.
+fn glow(appearance: &Appearance, filters: &ViewFilters, body: &globe_rs::Body) -> f32 {
+    if !filters.glow {
+        return 0.;
+    }
+
+    appearance.emissive(body)
+}
+
+/// Returns the emissive color of a body of the given color emitting the given amount of light,
+/// tinted if hovered.
+fn emissive(color: Color, glow: f32, hovered: bool) -> LinearRgba {
+    let emissive = LinearRgba::from(color) * glow;
+    if hovered {
+        return emissive + LinearRgba::from(color::BATTLESHIP_GRAY) * HOVER_TINT;
+    }
+
+    emissive
+}
+
+/// Returns the orientation of a body rotated by the given angle around its spin axis.
+///
+/// Bodies have no obliquity yet, so the axis is the one perpendicular to the plane of the system,
+/// to which the poles of the sphere meshes get aligned first.
+fn spin(rotation: Radian) -> Quat {
+    let spin = Rotation::default()
+        .with_axis(Coords::from([0., 0., 1.]))
+        .with_theta(rotation);
+
+    quat(&spin) * Quat::from_rotation_x(FRAC_PI_2 as f32)
+}
+
+/// Returns the distance along the given ray at which it first hits the sphere of the given center
+/// and radius, if any. A ray starting inside the sphere hits it where it leaves it.
+fn ray_sphere_intersection(ray: Ray3d, center: Vec3, radius: f32) -> Option<f32> {
+    let relative_origin = ray.origin - center;
+    let projection = relative_origin.dot(*ray.direction);
+    let discriminant = projection.powi(2) - (relative_origin.length_squared() - radius.powi(2));
+    if discriminant < 0. {
+        return None;
+    }
+
+    let root = discriminant.sqrt();
+    [-projection - root, -projection + root]
+        .into_iter()
+        .find(|distance| *distance >= 0.)
+}
+
+/// Returns the line strip mesh going through all the points of the given shape, in order, so
+/// orbits, segments and any other sampled shape are drawn the same way.
+pub fn line_strip(shape: Shape) -> Mesh {
+    let points: Vec<[f32; 3]> = shape.points.iter().map(Coords::to_f32_array).collect();
+
+    Mesh::new(
+        PrimitiveTopology::LineStrip,
+        RenderAssetUsages::RENDER_WORLD,
+    )
+    .with_inserted_attribute(
+        Mesh::ATTRIBUTE_NORMAL,
+        vec![Vec3::new(0., 0., 1.); points.len()],
+    )
+    .with_inserted_attribute(Mesh::ATTRIBUTE_UV_0, vec![Vec2::new(0., 0.); points.len()])
+    .with_inserted_attribute(Mesh::ATTRIBUTE_POSITION, points)
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::{prelude::*, render::mesh::PrimitiveTopology};
+    use globe_rs::{
+        cartesian::{
+            shape::{Circle, Sample, Segment, Shape},
+            Coords,
+        },
+        Distance,
+    };
+
+    use super::{line_strip, ray_sphere_intersection};
+
+    #[test]
+    fn ray_sphere_intersection_must_not_fail() {
+        const ABS_ERROR: f32 = 1e-4;
+
+        // a camera ten units above the plane of the system, looking down at its center.
+        let camera = Transform::from_xyz(0., 0., 10.).looking_at(Vec3::ZERO, Dir3::Y);
+        let ray = |target: Vec3| {
+            Ray3d::new(
+                camera.translation,
+                Dir3::new(target - camera.translation).unwrap(),
+            )
         };
+
+        struct Test {
+            name: &'static str,
+            ray: Ray3d,
+            center: Vec3,
+            radius: f32,
+            output: Option<f32>,
+        }
+
+        vec![
+            Test {
+                name: "ray through the center of the sphere",
+                ray: Ray3d::new(camera.translation, camera.forward()),
+                center: Vec3::ZERO,
+                radius: 1.,
+                output: Some(9.),
+            },
+            Test {
+                name: "ray through the center of an off-axis sphere",
+                ray: ray(Vec3::new(1., 0., 0.)),
+                center: Vec3::new(1., 0., 0.),
+                radius: 0.5,
+                output: Some(101_f32.sqrt() - 0.5),
+            },
+            Test {
+                name: "ray missing the sphere",
+                ray: ray(Vec3::new(0., 3., 0.)),
+                center: Vec3::ZERO,
+                radius: 1.,
+                output: None,
+            },
+            Test {
+                name: "sphere behind the camera",
+                ray: Ray3d::new(camera.translation, camera.forward()),
+                center: Vec3::new(0., 0., 20.),
+                radius: 1.,
+                output: None,
+            },
+            Test {
+                name: "camera inside the sphere",
+                ray: Ray3d::new(camera.translation, camera.forward()),
+                center: Vec3::new(0., 0., 10.),
+                radius: 1.,
+                output: Some(1.),
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let distance = ray_sphere_intersection(test.ray, test.center, test.radius);
+            match (distance, test.output) {
+                (Some(got), Some(want)) => assert!(
+                    (got - want).abs() <= ABS_ERROR,
+                    "{}: got distance = {got}, want {want}",
+                    test.name
+                ),
+                (got, want) => assert_eq!(got, want, "{}", test.name),
+            }
+        });
+    }
+
+    #[test]
+    fn line_strip_must_not_fail() {
+        struct Test {
+            name: &'static str,
+            shape: Shape,
+            vertices: usize,
+            closed: bool,
+        }
+
+        let mut circle = Circle::default()
+            .with_radius(Distance::meters(1.))
+            .sample(8);
+        circle.close();
+
+        vec![
+            Test {
+                name: "empty shape",
+                shape: Shape::default(),
+                vertices: 0,
+                closed: false,
+            },
+            Test {
+                name: "open strip",
+                shape: Segment::default()
+                    .with_to(Coords::from([4., 0., 0.]))
+                    .sample(4),
+                vertices: 5,
+                closed: false,
+            },
+            Test {
+                name: "closed strip",
+                shape: circle,
+                vertices: 9,
+                closed: true,
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let want: Vec<[f32; 3]> = test.shape.points.iter().map(Coords::to_f32_array).collect();
+            let mesh = line_strip(test.shape);
+
+            assert_eq!(
+                mesh.primitive_topology(),
+                PrimitiveTopology::LineStrip,
+                "{}",
+                test.name
+            );
+            assert_eq!(
+                mesh.count_vertices(),
+                test.vertices,
+                "{}: got vertices = {}, want {}",
+                test.name,
+                mesh.count_vertices(),
+                test.vertices
+            );
+            // a line strip joins its vertices in order, so it needs no index buffer.
+            assert!(mesh.indices().is_none(), "{}: got indices", test.name);
+
+            let positions = mesh
+                .attribute(Mesh::ATTRIBUTE_POSITION)
+                .and_then(|positions| positions.as_float3())
+                .unwrap_or_default();
+
+            assert_eq!(positions, want.as_slice(), "{}", test.name);
+
+            let closed = positions.len() > 1 && positions.first() == positions.last();
+            assert_eq!(
+                closed, test.closed,
+                "{}: got closed = {closed}, want {}",
+                test.name, test.closed
+            );
+
+            [Mesh::ATTRIBUTE_NORMAL, Mesh::ATTRIBUTE_UV_0]
+                .into_iter()
+                .for_each(|attribute| {
+                    let len = mesh.attribute(attribute).map(|values| values.len());
+                    assert_eq!(
+                        len,
+                        Some(test.vertices),
+                        "{}: got {} = {len:?}, want {}",
+                        test.name,
+                        attribute.name,
+                        test.vertices
+                    );
+                });
+        });
     }
 }
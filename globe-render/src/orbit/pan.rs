@@ -0,0 +1,161 @@
+use bevy::{
+    prelude::*,
+    render::camera::ScalingMode,
+    window::{CursorMoved, PrimaryWindow},
+};
+use globe_rs::cartesian::Coords;
+
+use crate::{
+    camera::{MainCamera, INITIAL_FOV},
+    cursor::Cursor,
+    event::{Event, Updated},
+    input::{Action, Controls},
+    origin::Origin,
+    ui::jump::TimeInput,
+};
+
+use super::{scale::ScaleMode, OrbitalSystem, OrbitalSystemState};
+
+/// The speed at which the camera pans, in pixels per second.
+const PAN_SPEED: f32 = 600.;
+/// How many times the diameter of the followed body fits in the height of the window once framed.
+const FRAME_MARGIN: f32 = 8.;
+
+/// The actions panning the camera, along with the direction each of them pans towards.
+const PAN_ACTIONS: [(Action, Vec2); 4] = [
+    (Action::PanUp, Vec2::Y),
+    (Action::PanLeft, Vec2::NEG_X),
+    (Action::PanDown, Vec2::NEG_Y),
+    (Action::PanRight, Vec2::X),
+];
+
+/// Pans the camera with the keyboard, at a constant speed on screen no matter the zoom.
+///
+/// Resetting the view gets the camera back to its initial framing, while framing the followed body
+/// zooms in on it, if any.
+pub struct KeyboardPan;
+
+impl Plugin for KeyboardPan {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            (Self::on_pan_keys_pressed, Self::on_user_input_event).run_if(TimeInput::is_closed),
+        );
+    }
+}
+
+impl KeyboardPan {
+    fn on_pan_keys_pressed(
+        mut camera: Query<(&mut MainCamera, &mut Transform, &Projection)>,
+        window: Query<&Window, With<PrimaryWindow>>,
+        controls: Controls,
+        time: Res<Time>,
+    ) {
+        let direction: Vec2 = PAN_ACTIONS
+            .iter()
+            .filter(|(action, _)| controls.pressed(*action))
+            .map(|(_, direction)| *direction)
+            .sum();
+
+        if direction == Vec2::ZERO {
+            return;
+        }
+
+        let (mut camera, mut transform, projection) = camera.single_mut();
+        let pixel_size = MainCamera::pixel_size(&transform, projection, window.single());
+        let translation = direction.normalize() * PAN_SPEED * pixel_size * time.delta_secs();
+
+        camera.follow = None;
+        transform.translation.x += translation.x;
+        transform.translation.y += translation.y;
+    }
+
+    /// Resets the view, or frames the followed body, on demand.
+    #[allow(clippy::too_many_arguments)]
+    fn on_user_input_event(
+        mut state_updated: EventWriter<Event<OrbitalSystemState, Updated>>,
+        mut camera: Query<(&mut MainCamera, &mut Transform, &mut Projection)>,
+        mut state: ResMut<OrbitalSystemState>,
+        window: Query<&Window, With<PrimaryWindow>>,
+        controls: Controls,
+        system: Res<OrbitalSystem>,
+        scale: Res<ScaleMode>,
+        origin: Res<Origin>,
+    ) {
+        let (mut camera, mut transform, mut projection) = camera.single_mut();
+        let window = window.single();
+
+        if controls.just_pressed(Action::ResetView) {
+            if camera.unfollow(&mut transform, &mut state) {
+                state_updated.send(Event::default());
+            }
+
+            let system_radius = system.spec.radius().as_meters() as f32;
+            // the center of the system may no longer be the one of the world coordinates.
+            *transform = MainCamera::initial_transform(system_radius);
+            let center = origin.vec3(Coords::default());
+            transform.translation.x = center.x;
+            transform.translation.y = center.y;
+            match &mut *projection {
+                Projection::Perspective(projection) => projection.fov = INITIAL_FOV,
+                Projection::Orthographic(projection) => {
+                    let scale = 2. * system_radius / window.width().min(window.height());
+                    projection.scaling_mode = ScalingMode::WindowSize(1. / scale);
+                }
+            }
+        } else if controls.just_pressed(Action::FrameFollowed) {
+            let Some(radius) = camera
+                .follow
+                .as_ref()
+                .and_then(|subject| system.spec.system(subject))
+                .map(|system| scale.radius(system.primary.radius) as f32)
+            else {
+                return;
+            };
+
+            let height = 2. * radius * FRAME_MARGIN;
+            match &mut *projection {
+                Projection::Perspective(projection) => {
+                    let distance = transform.translation.z.abs();
+                    projection.fov = INITIAL_FOV.min(2. * (height / 2. / distance).atan());
+                }
+                Projection::Orthographic(projection) => {
+                    projection.scaling_mode = ScalingMode::WindowSize(window.height() / height);
+                }
+            }
+        }
+    }
+}
+
+/// Pans the camera by dragging the pointer, by default while holding the middle or right mouse
+/// button, so whatever is under the pointer stays under it.
+pub struct DragPan;
+
+impl Plugin for DragPan {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Update, Self::on_cursor_moved);
+    }
+}
+
+impl DragPan {
+    fn on_cursor_moved(
+        mut cursor_moved: EventReader<CursorMoved>,
+        mut camera: Query<(&mut MainCamera, &mut Transform, &Projection)>,
+        window: Query<&Window, With<PrimaryWindow>>,
+        controls: Controls,
+        cursor: Res<Cursor>,
+    ) {
+        let delta: Vec2 = cursor_moved.read().filter_map(|event| event.delta).sum();
+        if delta == Vec2::ZERO || !controls.pressed(Action::DragPan) || cursor.over_inset {
+            return;
+        }
+
+        let (mut camera, mut transform, projection) = camera.single_mut();
+        let pixel_size = MainCamera::pixel_size(&transform, projection, window.single());
+
+        // the y axis of the window points downwards, while the one of the world points upwards.
+        camera.follow = None;
+        transform.translation.x -= delta.x * pixel_size;
+        transform.translation.y += delta.y * pixel_size;
+    }
+}
@@ -0,0 +1,173 @@
+use std::collections::HashMap;
+
+use alvidir::name::Name;
+use bevy::prelude::*;
+use globe_rs::{cartesian::Coords, Distance};
+use serde::Deserialize;
+
+use crate::{
+    event::{Event, Updated},
+    input::{Action, Controls},
+    ui::jump::TimeInput,
+};
+
+use super::{Body, OrbitalSystemState};
+
+/// The factor by which bodies get enlarged when switching to the exaggerated mode.
+const DEFAULT_FACTOR: f64 = 1000.;
+/// The base of the logarithm compressing distances when switching to the log-compressed mode.
+const DEFAULT_BASE: f64 = std::f64::consts::E;
+/// The distance below which the log-compressed mode barely compresses distances at all.
+const LOG_REFERENCE: Distance = Distance::ASTRONOMICAL_UNIT;
+
+/// How bodies and the distances between them are displayed, without changing the system itself.
+#[derive(Resource, Debug, Default, Clone, Copy, PartialEq, Deserialize)]
+pub enum ScaleMode {
+    /// Bodies and distances are displayed as they are.
+    #[default]
+    Realistic,
+    /// Bodies are enlarged by the given factor, while secondary bodies are pushed out of the
+    /// primary one they would be swallowed by.
+    ExaggeratedBodies { factor: f64 },
+    /// The distance between every body and the one it orbits is compressed logarithmically in the
+    /// given base, while bodies keep their size.
+    LogCompressedDistances { base: f64 },
+}
+
+impl Plugin for ScaleMode {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(*self).add_systems(
+            Update,
+            (
+                Self::on_user_input_event.run_if(TimeInput::is_closed),
+                Self::on_scale_mode_update,
+            )
+                .chain(),
+        );
+    }
+}
+
+impl ScaleMode {
+    /// Returns the mode following self when cycling through all of them.
+    fn next(&self) -> Self {
+        match self {
+            Self::Realistic => Self::ExaggeratedBodies {
+                factor: DEFAULT_FACTOR,
+            },
+            Self::ExaggeratedBodies { .. } => Self::LogCompressedDistances { base: DEFAULT_BASE },
+            Self::LogCompressedDistances { .. } => Self::Realistic,
+        }
+    }
+
+    /// Returns the radius, in meters, at which a body of the given radius is displayed.
+    pub fn radius(&self, radius: Distance) -> f64 {
+        match self {
+            Self::ExaggeratedBodies { factor } => radius.as_meters() * factor,
+            _ => radius.as_meters(),
+        }
+    }
+
+    /// Returns the distance, in meters, at which a body is displayed from the one it orbits given
+    /// the actual distance between both and the displayed distance at which they touch each
+    /// other.
+    pub fn distance(&self, distance: Distance, contact: f64) -> f64 {
+        match self {
+            Self::Realistic => distance.as_meters(),
+            Self::ExaggeratedBodies { .. } => distance.as_meters().max(contact),
+            Self::LogCompressedDistances { base } => {
+                let reference = LOG_REFERENCE.as_meters();
+                let gap = (distance.as_meters() - contact).max(0.);
+                contact + reference * (gap / reference).ln_1p() / base.ln()
+            }
+        }
+    }
+
+    /// Returns where the given point is displayed, being it located relative to a body which is
+    /// actually at the given position, but displayed at the given one, and being contact the
+    /// displayed distance at which the point would touch the body.
+    pub fn place(&self, point: Coords, actual: Coords, displayed: Coords, contact: f64) -> Coords {
+        let relative = point - actual;
+        let distance = relative.magnitude();
+        if distance == 0. {
+            return displayed;
+        }
+
+        displayed + relative.unit() * self.distance(Distance::meters(distance), contact)
+    }
+
+    /// Returns the displayed position of every body in the given state, indexed by name, being
+    /// radii the radius of each body and the origin the body, if any, to be displayed at the
+    /// center of coordinates.
+    pub fn positions(
+        &self,
+        state: &globe_rs::OrbitalSystemState,
+        radii: &HashMap<Name<globe_rs::Body>, Distance>,
+        origin: Option<&Name<globe_rs::Body>>,
+    ) -> HashMap<Name<globe_rs::Body>, Coords> {
+        if *self == Self::Realistic {
+            return state.positions();
+        }
+
+        let mut positions = HashMap::new();
+        self.place_system(state, state.position, radii, &mut positions);
+
+        // the compressed positions are no longer relative to the origin, so they get rebased.
+        if let Some(offset) = origin.and_then(|origin| positions.get(origin)).copied() {
+            positions
+                .values_mut()
+                .for_each(|position| *position = *position - offset);
+        }
+
+        positions
+    }
+
+    /// Places the given system, being its primary body displayed at the given position, into the
+    /// given positions.
+    fn place_system(
+        &self,
+        state: &globe_rs::OrbitalSystemState,
+        displayed: Coords,
+        radii: &HashMap<Name<globe_rs::Body>, Distance>,
+        positions: &mut HashMap<Name<globe_rs::Body>, Coords>,
+    ) {
+        let radius = |name| {
+            radii
+                .get(name)
+                .map(|radius| self.radius(*radius))
+                .unwrap_or_default()
+        };
+
+        positions.insert(state.body.clone(), displayed);
+        state.secondary.iter().for_each(|secondary| {
+            let contact = radius(&state.body) + radius(&secondary.body);
+            let position = self.place(secondary.position, state.position, displayed, contact);
+            self.place_system(secondary, position, radii, positions);
+        });
+    }
+
+    /// Cycles through the scale modes when pressing M.
+    fn on_user_input_event(mut mode: ResMut<Self>, controls: Controls) {
+        if controls.just_pressed(Action::CycleScaleMode) {
+            *mode = mode.next();
+        }
+    }
+
+    /// Rebuilds every entity of the system, so they get displayed according to the current mode.
+    fn on_scale_mode_update(
+        mut commands: Commands,
+        mut state_updated: EventWriter<Event<OrbitalSystemState, Updated>>,
+        mut state: ResMut<OrbitalSystemState>,
+        entities: Query<Entity, With<Body>>,
+        mode: Res<Self>,
+    ) {
+        if !mode.is_changed() || mode.is_added() {
+            return;
+        }
+
+        state.scale = *mode;
+        let spec = state.spec.clone();
+        state.set_spec(spec);
+        state.respawn(&mut commands, &entities);
+        state_updated.send(Event::default());
+    }
+}
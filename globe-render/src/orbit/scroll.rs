@@ -2,7 +2,11 @@ use std::f32::consts::FRAC_PI_2;
 
 use bevy::{input::mouse::MouseWheel, prelude::*, render::camera::ScalingMode};
 
-use crate::camera::MainCamera;
+use crate::{
+    camera::MainCamera,
+    cursor::Cursor,
+    input::{Action, Controls},
+};
 
 use super::OrbitalSystem;
 
@@ -19,11 +23,18 @@ impl LinearScroll {
     pub fn on_mouse_wheel_event(
         mut scroll: EventReader<MouseWheel>,
         mut camera_query: Query<(&mut MainCamera, &mut Transform, &Projection)>,
-        keys: Res<ButtonInput<KeyCode>>,
+        controls: Controls,
         system: Res<OrbitalSystem>,
+        cursor: Res<Cursor>,
     ) {
-        if keys.pressed(KeyCode::ControlLeft) {
-            // left ctrl key is reserved for zooming
+        if controls.pressed(Action::ZoomModifier) {
+            // the modifier of the zoom is reserved for zooming
+            return;
+        }
+
+        if cursor.over_inset {
+            // the inset keeps its own framing.
+            scroll.clear();
             return;
         }
 
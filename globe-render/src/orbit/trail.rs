@@ -0,0 +1,130 @@
+use std::f32::consts::TAU;
+
+use bevy::prelude::*;
+
+use crate::{
+    input::{Action, Controls},
+    material::OrbitTrailMaterial,
+    ui::jump::TimeInput,
+};
+
+use super::Orbit;
+
+/// The fraction of the orbit covered by the tail when switching to the fading-tail mode.
+const DEFAULT_FRACTION: f32 = 0.25;
+/// How much the fraction of the orbit covered by the tail changes on every key stroke.
+const FRACTION_STEP: f32 = 0.05;
+
+/// How much of its orbit the trail of a body covers.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TrailMode {
+    /// The whole orbit is highlighted, with no fading at all.
+    FullOrbit,
+    /// The given fraction of the orbit behind the body is highlighted, fading away from it.
+    FadingTail { fraction: f32 },
+    /// No trail is displayed at all.
+    Off,
+}
+
+impl Default for TrailMode {
+    fn default() -> Self {
+        Self::FadingTail {
+            fraction: DEFAULT_FRACTION,
+        }
+    }
+}
+
+impl TrailMode {
+    /// Returns the mode following self when cycling through all of them.
+    fn next(&self) -> Self {
+        match self {
+            Self::FadingTail { .. } => Self::FullOrbit,
+            Self::FullOrbit => Self::Off,
+            Self::Off => Self::default(),
+        }
+    }
+}
+
+/// How the trails of the bodies along their orbits are displayed.
+#[derive(Resource, Debug, Clone, Copy, PartialEq)]
+pub struct TrailSettings {
+    pub mode: TrailMode,
+    /// How fast the tail fades away from the body: 1 fades it linearly, while greater values
+    /// fade it sooner.
+    pub fade_exponent: f32,
+}
+
+impl Default for TrailSettings {
+    fn default() -> Self {
+        Self {
+            mode: TrailMode::default(),
+            fade_exponent: 1.,
+        }
+    }
+}
+
+impl Plugin for TrailSettings {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(*self).add_systems(
+            Update,
+            (
+                Self::on_user_input_event.run_if(TimeInput::is_closed),
+                Self::on_trail_settings_update,
+            )
+                .chain(),
+        );
+    }
+}
+
+impl TrailSettings {
+    /// Sets the angle covered by the trail, and how it fades, into the given material.
+    pub fn apply(&self, material: &mut OrbitTrailMaterial) {
+        (material.trail_theta, material.fade_exponent) = match self.mode {
+            TrailMode::FullOrbit => (TAU, 0.),
+            TrailMode::FadingTail { fraction } => (TAU * fraction, self.fade_exponent),
+            TrailMode::Off => (0., self.fade_exponent),
+        };
+    }
+
+    /// Cycles through the trail modes when pressing O, and shortens or lengthens the fading tail
+    /// when pressing [ or ] respectively.
+    fn on_user_input_event(mut settings: ResMut<Self>, controls: Controls) {
+        if controls.just_pressed(Action::CycleTrailMode) {
+            settings.mode = settings.mode.next();
+            return;
+        }
+
+        let TrailMode::FadingTail { fraction } = settings.mode else {
+            return;
+        };
+
+        let step = if controls.just_pressed(Action::ShortenTrail) {
+            -FRACTION_STEP
+        } else if controls.just_pressed(Action::LengthenTrail) {
+            FRACTION_STEP
+        } else {
+            return;
+        };
+
+        settings.mode = TrailMode::FadingTail {
+            fraction: (fraction + step).clamp(FRACTION_STEP, 1.),
+        };
+    }
+
+    /// Updates the material of every orbit in place, so no mesh needs to be spawned again.
+    fn on_trail_settings_update(
+        mut materials: ResMut<Assets<OrbitTrailMaterial>>,
+        orbits: Query<&MeshMaterial3d<OrbitTrailMaterial>, With<Orbit>>,
+        settings: Res<Self>,
+    ) {
+        if !settings.is_changed() || settings.is_added() {
+            return;
+        }
+
+        orbits.iter().for_each(|material| {
+            if let Some(material) = materials.get_mut(material) {
+                settings.apply(material);
+            }
+        });
+    }
+}
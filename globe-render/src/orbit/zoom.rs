@@ -1,19 +1,33 @@
-use std::{f32::consts::FRAC_PI_2, ops::DerefMut};
+use std::f32::consts::FRAC_PI_2;
 
 use bevy::{
-    input::mouse::{MouseScrollUnit, MouseWheel},
+    input::{
+        gestures::PinchGesture,
+        mouse::{MouseScrollUnit, MouseWheel},
+    },
     prelude::*,
     render::camera::ScalingMode,
 };
 
-use crate::{camera::MainCamera, cursor::Cursor};
+use crate::{
+    camera::MainCamera,
+    cursor::Cursor,
+    input::{Action, Controls},
+};
+
+/// How much the logarithm of the scale changes per step of the mouse wheel.
+const WHEEL_SENSITIVITY: f32 = 0.1;
+/// How much the logarithm of the scale changes per unit of magnification of a pinch gesture.
+const PINCH_SENSITIVITY: f32 = 1.;
 
-/// Logarithmically zooms towards the pointed object.
+/// Logarithmically zooms towards the pointed object, either with the mouse wheel while holding
+/// the modifier of the zoom or by pinching on a touchpad.
 pub struct LogarithmicZoom;
 
 impl Plugin for LogarithmicZoom {
     fn build(&self, app: &mut App) {
-        app.add_systems(Update, Self::on_mouse_wheel_event);
+        app.add_systems(Update, Self::on_mouse_wheel_event)
+            .add_systems(Update, Self::on_pinch_gesture_event);
     }
 }
 
@@ -21,11 +35,17 @@ impl LogarithmicZoom {
     fn on_mouse_wheel_event(
         mut scroll: EventReader<MouseWheel>,
         mut camera: Query<(&MainCamera, &mut Transform, &mut Projection)>,
-        keys: Res<ButtonInput<KeyCode>>,
+        controls: Controls,
         cursor: Res<Cursor>,
     ) {
-        if !keys.pressed(KeyCode::ControlLeft) {
-            // zoom required the left ctrl key to be pressed
+        if !controls.pressed(Action::ZoomModifier) {
+            // zoom requires its modifier to be held
+            return;
+        }
+
+        if cursor.over_inset {
+            // the inset keeps its own framing.
+            scroll.clear();
             return;
         }
 
@@ -37,46 +57,77 @@ impl LogarithmicZoom {
                 MouseScrollUnit::Pixel => 1., // using fine-grained hardware (e.g. touchpads)
             };
 
-            let scale_ratio = match projection.deref_mut() {
-                Projection::Perspective(projection) => {
-                    Self::on_mouse_wheel_event_for_perspective_projection(
-                        event,
-                        projection,
-                        orientation,
-                    )
-                }
-                Projection::Orthographic(projection) => {
-                    Self::on_mouse_wheel_event_for_orthographic_projection(
-                        event,
-                        projection,
-                        orientation,
-                    )
-                }
-            };
+            Self::zoom(
+                camera,
+                &mut transform,
+                &mut projection,
+                &cursor,
+                WHEEL_SENSITIVITY * event.y * orientation,
+            );
+        });
+    }
 
-            if camera.follow.is_none() {
-                let relative_cursor_before = cursor.position - transform.translation;
-                let relative_cursor_after = relative_cursor_before * scale_ratio;
-                let translation = relative_cursor_after - relative_cursor_before;
+    fn on_pinch_gesture_event(
+        mut pinch: EventReader<PinchGesture>,
+        mut camera: Query<(&MainCamera, &mut Transform, &mut Projection)>,
+        cursor: Res<Cursor>,
+    ) {
+        if cursor.over_inset {
+            // the inset keeps its own framing.
+            pinch.clear();
+            return;
+        }
 
-                transform.translation.x += translation.x;
-                transform.translation.y += translation.y;
-            }
+        let (camera, mut transform, mut projection) = camera.single_mut();
+
+        pinch.read().for_each(|event| {
+            // a positive magnification zooms in, hence it shrinks the scale.
+            Self::zoom(
+                camera,
+                &mut transform,
+                &mut projection,
+                &cursor,
+                -PINCH_SENSITIVITY * event.0,
+            );
         });
     }
 
-    fn on_mouse_wheel_event_for_orthographic_projection(
-        event: &MouseWheel,
-        projection: &mut OrthographicProjection,
-        orientation: f32,
-    ) -> f32 {
+    /// Adds the given amount to the logarithm of the scale of the projection, keeping the pointed
+    /// object under the cursor unless following a body.
+    fn zoom(
+        camera: &MainCamera,
+        transform: &mut Transform,
+        projection: &mut Projection,
+        cursor: &Cursor,
+        amount: f32,
+    ) {
+        let scale_ratio = match projection {
+            Projection::Perspective(projection) => {
+                Self::zoom_perspective_projection(projection, amount)
+            }
+            Projection::Orthographic(projection) => {
+                Self::zoom_orthographic_projection(projection, amount)
+            }
+        };
+
+        if camera.follow.is_none() {
+            let relative_cursor_before = cursor.position - transform.translation;
+            let relative_cursor_after = relative_cursor_before * scale_ratio;
+            let translation = relative_cursor_after - relative_cursor_before;
+
+            transform.translation.x += translation.x;
+            transform.translation.y += translation.y;
+        }
+    }
+
+    fn zoom_orthographic_projection(projection: &mut OrthographicProjection, amount: f32) -> f32 {
         let scale = match projection.scaling_mode {
             ScalingMode::WindowSize(inv_scale) => 1. / inv_scale,
             _ => panic!("scaling mode must be window size"),
         };
 
         let mut new_scale = scale.ln();
-        new_scale += 0.1 * event.y * orientation;
+        new_scale += amount;
         new_scale = new_scale.exp();
 
         let scale_ratio = scale / new_scale;
@@ -85,15 +136,11 @@ impl LogarithmicZoom {
         scale_ratio
     }
 
-    fn on_mouse_wheel_event_for_perspective_projection(
-        event: &MouseWheel,
-        projection: &mut PerspectiveProjection,
-        orientation: f32,
-    ) -> f32 {
+    fn zoom_perspective_projection(projection: &mut PerspectiveProjection, amount: f32) -> f32 {
         let scale = projection.fov / FRAC_PI_2;
 
         let mut new_scale = scale.ln();
-        new_scale += 0.1 * event.y * orientation;
+        new_scale += amount;
         new_scale = new_scale.exp();
 
         let scale_ratio = scale / new_scale;
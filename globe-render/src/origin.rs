@@ -0,0 +1,66 @@
+use bevy::{prelude::*, window::PrimaryWindow};
+use globe_rs::cartesian::Coords;
+
+use crate::{
+    camera::MainCamera,
+    event::{Event, Updated},
+    orbit::{Body, OrbitalSystemState},
+    vec3,
+};
+
+/// The fraction of a pixel the rounding error of the camera position may reach before the origin
+/// gets moved to it.
+const MAX_ROUNDING_ERROR: f32 = 0.1;
+
+/// The point of the system at the center of the world coordinates.
+///
+/// World coordinates are single precision floats, which lose precision the farther they are from
+/// the center, making bodies shake as the camera moves around. Hence, positions are always made
+/// relative to the origin in double precision, and the origin gets moved to the camera whenever it
+/// strays too far from it.
+#[derive(Resource, Default, Clone, Copy)]
+pub struct Origin {
+    /// The position of the origin in the system.
+    pub offset: Coords,
+}
+
+impl Plugin for Origin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(*self)
+            .add_systems(PostUpdate, Self::follow_camera);
+    }
+}
+
+impl Origin {
+    /// Returns the world coordinates of the given position in the system.
+    pub fn vec3(&self, position: Coords) -> Vec3 {
+        vec3(position - self.offset)
+    }
+
+    /// Moves the origin to the camera once the rounding error of its world coordinates becomes
+    /// perceptible, spawning the whole system again around the new origin.
+    fn follow_camera(
+        mut commands: Commands,
+        mut state_updated: EventWriter<Event<OrbitalSystemState, Updated>>,
+        mut camera: Query<(&mut Transform, &Projection), With<MainCamera>>,
+        mut origin: ResMut<Self>,
+        mut state: ResMut<OrbitalSystemState>,
+        window: Query<&Window, With<PrimaryWindow>>,
+        entities: Query<Entity, With<Body>>,
+    ) {
+        let (mut transform, projection) = camera.single_mut();
+        let pixel_size = MainCamera::pixel_size(&transform, projection, window.single());
+
+        let stray = transform.translation.xy();
+        if stray.length() * f32::EPSILON <= pixel_size * MAX_ROUNDING_ERROR {
+            return;
+        }
+
+        origin.offset += Coords::from([stray.x as f64, stray.y as f64, 0.]);
+        transform.translation.x = 0.;
+        transform.translation.y = 0.;
+
+        state.respawn(&mut commands, &entities);
+        state_updated.send(Event::default());
+    }
+}
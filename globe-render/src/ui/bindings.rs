@@ -0,0 +1,107 @@
+use bevy::prelude::*;
+
+use crate::{
+    color,
+    input::{Action, Controls, InputMap},
+};
+
+use super::{jump::TimeInput, REGULAR_BORDER, REGULAR_PADDING, TEXT_FONT};
+
+/// A panel in the middle of the window listing the bindings of every [Action].
+#[derive(Resource, Default, Clone, Copy)]
+pub struct BindingsOverlay {
+    pub visible: bool,
+}
+
+/// The box holding the list of bindings.
+#[derive(Component)]
+struct BindingsOverlayBox;
+
+impl Plugin for BindingsOverlay {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(*self)
+            .add_systems(Startup, Self::spawn)
+            .add_systems(
+                Update,
+                Self::on_user_input_event.run_if(TimeInput::is_closed),
+            )
+            .add_systems(Update, Self::on_bindings_overlay_update);
+    }
+}
+
+impl BindingsOverlay {
+    fn spawn(mut commands: Commands, input: Res<InputMap>, asset_server: Res<AssetServer>) {
+        let style = |color| TextStyle {
+            font: asset_server.load(TEXT_FONT),
+            font_size: 12.,
+            color,
+        };
+
+        let sections = Action::ALL.into_iter().flat_map(|action| {
+            let bindings = input
+                .bindings(action)
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>()
+                .join(", ");
+
+            [
+                TextSection::new(format!("{action}: "), style(color::BATTLESHIP_GRAY)),
+                TextSection::new(format!("{bindings}\n"), style(color::KHAKI)),
+            ]
+        });
+
+        commands
+            .spawn(NodeBundle {
+                style: Style {
+                    position_type: PositionType::Absolute,
+                    width: Val::Percent(100.),
+                    height: Val::Percent(100.),
+                    justify_content: JustifyContent::Center,
+                    align_items: AlignItems::Center,
+                    ..default()
+                },
+                ..default()
+            })
+            .with_children(|parent| {
+                parent
+                    .spawn((
+                        NodeBundle {
+                            style: Style {
+                                border: UiRect::all(REGULAR_BORDER),
+                                padding: UiRect::all(REGULAR_PADDING),
+                                ..default()
+                            },
+                            border_color: color::BATTLESHIP_GRAY.into(),
+                            background_color: color::NIGHT.with_alpha(0.9).into(),
+                            visibility: Visibility::Hidden,
+                            ..default()
+                        },
+                        BindingsOverlayBox,
+                    ))
+                    .with_child(TextBundle::from_sections(sections));
+            });
+    }
+
+    /// Shows or hides the overlay when pressing ? (Shift+/), by default.
+    fn on_user_input_event(mut overlay: ResMut<Self>, controls: Controls) {
+        if controls.just_pressed(Action::ToggleBindings) {
+            overlay.visible = !overlay.visible;
+        }
+    }
+
+    fn on_bindings_overlay_update(
+        mut overlay_box: Query<&mut Visibility, With<BindingsOverlayBox>>,
+        overlay: Res<Self>,
+    ) {
+        if !overlay.is_changed() {
+            return;
+        }
+
+        *overlay_box.single_mut() = if overlay.visible {
+            Visibility::Inherited
+        } else {
+            Visibility::Hidden
+        };
+    }
+}
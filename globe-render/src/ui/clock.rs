@@ -1,57 +1,113 @@
 use std::time::Duration;
 
 use bevy::prelude::*;
+use globe_rs::{format_elapsed, Epoch};
 
 use crate::{
     color,
+    config::GlobeRsConfig,
     event::{Event, Updated},
+    input::{Action, Controls},
 };
 
-use super::{LARGE_PADDING, NUMERIC_FONT, REGULAR_BORDER, REGULAR_PADDING, TEXT_FONT, UI_PADDING};
+use super::{
+    jump::TimeInput, LARGE_PADDING, NUMERIC_FONT, REGULAR_BORDER, REGULAR_PADDING, TEXT_FONT,
+    UI_PADDING,
+};
 
 const SECS_PER_HOUR: u32 = 3600;
 
-fn print_hours(duration: Duration) -> String {
-    let hours = (duration.as_secs_f64() / 3600.).floor();
-    format!("{hours:0>4}")
-}
+/// The default real time between consecutive steps of the simulation.
+const DEFAULT_STEP: Duration = Duration::from_nanos(1_000_000_000 / 60);
 
-fn print_mins_and_secs(duration: Duration) -> String {
-    let mins = (duration.as_secs_f64() % 3600. / 60.).floor();
-    let secs = (duration.as_secs_f64() % 60.).floor();
-    format!(":{mins:0>2}:{secs:0>2}")
-}
+/// The longest frame whose time is fully simulated. Frames taking longer than that (e.g. while the
+/// window is being dragged) are caught up with as many steps as required, so no time is lost.
+const MAX_FRAME_TIME: Duration = Duration::from_secs(60);
 
-/// The world's clock.
+/// The world's clock, which starts at the [Epoch::J2000].
+///
+/// The clock advances in steps of fixed duration, so the simulation does not depend on the frame
+/// rate.
 #[derive(Resource, Component, Clone, Copy)]
 pub struct Clock {
-    pub elapsed_time: Duration,
-    pub started_at: Option<Duration>,
+    pub date: Epoch,
+    pub running: bool,
+    /// How many hours of the simulation pass per second of real time.
     pub scale: u32,
+    /// Whether the clock runs backwards.
+    pub reversed: bool,
+    /// The real time between consecutive steps of the simulation.
+    pub step: Duration,
 }
 
+/// The label displaying the date of the [Clock].
+#[derive(Component)]
+struct ClockDate;
+
 impl Default for Clock {
     fn default() -> Self {
         Self {
-            elapsed_time: Default::default(),
-            started_at: Default::default(),
+            date: Epoch::J2000,
+            running: false,
             scale: 1,
+            reversed: false,
+            step: DEFAULT_STEP,
+        }
+    }
+}
+
+impl From<&GlobeRsConfig> for Clock {
+    fn from(config: &GlobeRsConfig) -> Self {
+        Self {
+            running: config.autostart,
+            scale: config.clock_scale,
+            ..Default::default()
         }
     }
 }
 
 impl Plugin for Clock {
     fn build(&self, app: &mut App) {
-        app.init_resource::<Self>()
+        app.insert_resource(*self)
+            .insert_resource(Time::<Fixed>::from_duration(self.step))
             .add_event::<Event<Clock, Updated>>()
             .add_systems(Startup, Self::spawn)
-            .add_systems(Update, Self::update)
-            .add_systems(Update, Self::on_clock_tick_event)
-            .add_systems(Update, Self::on_user_input_event);
+            .add_systems(Startup, Self::catch_up_long_frames)
+            .add_systems(FixedUpdate, Self::update)
+            .add_systems(Update, Self::on_clock_update)
+            .add_systems(
+                Update,
+                Self::on_user_input_event.run_if(TimeInput::is_closed),
+            );
     }
 }
 
 impl Clock {
+    /// Returns the time elapsed since the epoch, preceded by a minus sign if the date is earlier.
+    fn elapsed(&self) -> String {
+        match self.date.duration_since(Epoch::J2000) {
+            Some(elapsed) => format_elapsed(elapsed),
+            None => format!(
+                "-{}",
+                format_elapsed(Duration::from_secs_f64(-self.date.as_seconds()))
+            ),
+        }
+    }
+
+    /// Returns how many times faster than real time the clock runs.
+    fn multiplier(&self) -> String {
+        format!(" × {}", self.scale.saturating_mul(SECS_PER_HOUR))
+    }
+
+    /// Returns the badge telling whether the clock is paused.
+    fn badge(&self) -> &'static str {
+        if self.running {
+            ""
+        } else {
+            " paused"
+        }
+    }
+
     fn spawn(mut commands: Commands, clock: Res<Self>, asset_server: Res<AssetServer>) {
         // clock box
         commands
@@ -82,13 +138,16 @@ impl Clock {
                         background_color: color::BATTLESHIP_GRAY.into(),
                         ..default()
                     })
-                    .with_child(TextBundle::from_section(
-                        "Hours      Mins      Secs",
-                        TextStyle {
-                            font: asset_server.load(TEXT_FONT),
-                            font_size: 12.,
-                            color: color::NIGHT,
-                        },
+                    .with_child((
+                        TextBundle::from_section(
+                            clock.date.to_string(),
+                            TextStyle {
+                                font: asset_server.load(TEXT_FONT),
+                                font_size: 12.,
+                                color: color::NIGHT,
+                            },
+                        ),
+                        ClockDate,
                     ));
 
                 // actual clock
@@ -108,23 +167,34 @@ impl Clock {
                         ..default()
                     })
                     .with_child((
-                        TextBundle::from_sections(vec![
-                            TextSection {
-                                value: print_hours(clock.elapsed_time),
-                                style: TextStyle {
+                        TextBundle::from_sections([
+                            // elapsed time
+                            TextSection::new(
+                                clock.elapsed(),
+                                TextStyle {
                                     font: asset_server.load(NUMERIC_FONT),
                                     font_size: 32.0,
                                     color: color::BATTLESHIP_GRAY,
                                 },
-                            },
-                            TextSection {
-                                value: print_mins_and_secs(clock.elapsed_time),
-                                style: TextStyle {
+                            ),
+                            // time scale
+                            TextSection::new(
+                                clock.multiplier(),
+                                TextStyle {
                                     font: asset_server.load(NUMERIC_FONT),
-                                    font_size: 24.0,
+                                    font_size: 16.0,
                                     color: color::BATTLESHIP_GRAY,
                                 },
-                            },
+                            ),
+                            // paused badge
+                            TextSection::new(
+                                clock.badge(),
+                                TextStyle {
+                                    font: asset_server.load(TEXT_FONT),
+                                    font_size: 12.0,
+                                    color: color::PERSIAN_ORANGE,
+                                },
+                            ),
                         ])
                         .with_text_justify(JustifyText::Center),
                         *clock,
@@ -132,53 +202,64 @@ impl Clock {
             });
     }
 
-    /// Updates the clock resource.
+    /// Prevents the virtual time from dropping the time of long frames.
+    fn catch_up_long_frames(mut time: ResMut<Time<Virtual>>) {
+        time.set_max_delta(MAX_FRAME_TIME);
+    }
+
+    /// Advances the clock resource by a single step of the simulation.
     fn update(
         mut tick: EventWriter<Event<Clock, Updated>>,
         mut clock: ResMut<Self>,
-        time: Res<Time>,
+        time: Res<Time<Fixed>>,
     ) {
-        if let Some(started_at) = clock.started_at {
-            let elapsed = time.elapsed();
-            let scale = clock.scale.saturating_mul(SECS_PER_HOUR);
-            clock.elapsed_time += elapsed.abs_diff(started_at).saturating_mul(scale);
-            clock.started_at = Some(elapsed);
-
-            tick.send(Event::default());
+        if !clock.running {
+            return;
         }
+
+        let scale = clock.scale.saturating_mul(SECS_PER_HOUR);
+        let delta = time.delta().saturating_mul(scale);
+        clock.date = if clock.reversed {
+            clock.date - delta
+        } else {
+            clock.date + delta
+        };
+
+        tick.send(Event::default());
     }
 
-    /// Displays the latest time in the clock component.
-    fn on_clock_tick_event(
-        mut tick: EventReader<Event<Clock, Updated>>,
-        mut clock_ui: Query<&mut Text, With<Clock>>,
+    /// Displays the latest date, elapsed time, scale and status of the clock.
+    fn on_clock_update(
+        mut clock_ui: Query<&mut Text, (With<Clock>, Without<ClockDate>)>,
+        mut date_ui: Query<&mut Text, With<ClockDate>>,
         clock: Res<Self>,
     ) {
-        if tick.read().last().is_none() {
+        if !clock.is_changed() {
             return;
-        };
+        }
 
         let mut clock_ui = clock_ui.single_mut();
-        clock_ui.sections[0].value = print_hours(clock.elapsed_time);
-        clock_ui.sections[1].value = print_mins_and_secs(clock.elapsed_time);
+        clock_ui.sections[0].value = clock.elapsed();
+        clock_ui.sections[1].value = clock.multiplier();
+        clock_ui.sections[2].value = clock.badge().to_string();
+
+        date_ui.single_mut().sections[0].value = clock.date.to_string();
     }
 
     /// Handles the user input.
-    fn on_user_input_event(
-        mut clock: ResMut<Self>,
-        keys: Res<ButtonInput<KeyCode>>,
-        time: Res<Time>,
-    ) {
-        if keys.just_pressed(KeyCode::Space) {
-            if clock.started_at.take().is_none() {
-                clock.started_at = Some(time.elapsed());
-            }
-        } else if keys.just_pressed(KeyCode::ArrowUp) {
+    fn on_user_input_event(mut clock: ResMut<Self>, controls: Controls) {
+        if controls.just_pressed(Action::ReverseTime) {
+            // reverses the clock, resuming it if paused.
+            clock.reversed = !clock.reversed;
+            clock.running = true;
+        } else if controls.just_pressed(Action::TogglePause) {
+            clock.running = !clock.running;
+        } else if controls.just_pressed(Action::SpeedUp) {
             clock.scale = clock.scale.saturating_mul(2);
-        } else if keys.just_pressed(KeyCode::ArrowDown) {
+        } else if controls.just_pressed(Action::SpeedDown) {
             clock.scale = clock.scale.saturating_div(2).max(1);
-        } else if keys.just_pressed(KeyCode::KeyR) {
-            clock.elapsed_time = Duration::ZERO;
+        } else if controls.just_pressed(Action::ResetTime) {
+            clock.date = Epoch::J2000;
         }
     }
 }
@@ -0,0 +1,248 @@
+use std::collections::VecDeque;
+
+use bevy::prelude::*;
+use globe_rs::{format_elapsed, OrbitalEvent};
+
+use crate::{
+    color,
+    event::{Created, Event},
+    input::{Action, Controls},
+};
+
+use super::{jump::TimeInput, TEXT_FONT};
+
+/// The most entries displayed at once, the oldest ones being dropped first.
+const MAX_ENTRIES: usize = 6;
+/// The real time an entry stays fully opaque, in seconds.
+const FADE_DELAY: f32 = 8.;
+/// The real time an entry takes to fade out once the delay is over, in seconds.
+const FADE_DURATION: f32 = 2.;
+/// The distance between the left edge of the window and the log, so it sits next to the legend of
+/// the view filters.
+const LOG_INDENT: Val = Val::Px(192.);
+
+/// A line of the [EventLog].
+#[derive(Debug, Clone, PartialEq)]
+struct LogEntry {
+    text: String,
+    /// The real time at which the entry got logged, in seconds.
+    logged_at: f32,
+}
+
+/// A scrolling list of the latest events of the simulation, fading out as they get old.
+#[derive(Resource, Debug, Clone)]
+pub struct EventLog {
+    entries: VecDeque<LogEntry>,
+    /// Whether the log is displayed at all, toggled with E.
+    pub visible: bool,
+}
+
+impl Default for EventLog {
+    fn default() -> Self {
+        Self {
+            entries: VecDeque::with_capacity(MAX_ENTRIES),
+            visible: true,
+        }
+    }
+}
+
+/// The text displaying the entries of the [EventLog].
+#[derive(Component)]
+struct EventLogText;
+
+impl Plugin for EventLog {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(self.clone())
+            .add_systems(Startup, Self::spawn)
+            .add_systems(
+                Update,
+                Self::on_user_input_event.run_if(TimeInput::is_closed),
+            )
+            .add_systems(
+                Update,
+                (Self::on_orbital_event, Self::on_event_log_update).chain(),
+            );
+    }
+}
+
+impl EventLog {
+    /// Appends the given text to the log, dropping the oldest entry if full.
+    fn push(&mut self, text: String, logged_at: f32) {
+        if self.entries.len() == MAX_ENTRIES {
+            self.entries.pop_front();
+        }
+
+        self.entries.push_back(LogEntry { text, logged_at });
+    }
+
+    /// Drops every entry that has completely faded out at the given time.
+    fn expire(&mut self, now: f32) {
+        self.entries
+            .retain(|entry| now - entry.logged_at < FADE_DELAY + FADE_DURATION);
+    }
+
+    fn spawn(mut commands: Commands, asset_server: Res<AssetServer>) {
+        commands
+            .spawn(NodeBundle {
+                style: Style {
+                    position_type: PositionType::Absolute,
+                    bottom: Val::Px(0.),
+                    left: Val::Px(0.),
+                    padding: UiRect {
+                        left: LOG_INDENT,
+                        bottom: Val::Px(48.),
+                        ..default()
+                    },
+                    ..default()
+                },
+                ..default()
+            })
+            .with_child((
+                TextBundle::from_sections((0..MAX_ENTRIES).map(|_| {
+                    TextSection::new(
+                        "",
+                        TextStyle {
+                            font: asset_server.load(TEXT_FONT),
+                            font_size: 12.,
+                            color: color::BATTLESHIP_GRAY,
+                        },
+                    )
+                })),
+                EventLogText,
+            ));
+    }
+
+    /// Shows or hides the log when pressing E.
+    fn on_user_input_event(mut log: ResMut<Self>, controls: Controls) {
+        if controls.just_pressed(Action::ToggleEventLog) {
+            log.visible = !log.visible;
+        }
+    }
+
+    /// Logs every event the clock has gone through.
+    fn on_orbital_event(
+        mut orbital_event: EventReader<Event<OrbitalEvent, Created, OrbitalEvent>>,
+        mut log: ResMut<Self>,
+        time: Res<Time<Real>>,
+    ) {
+        orbital_event.read().for_each(|event| {
+            let event = &event.data;
+            log.push(
+                format!(
+                    "t={} — {} {}",
+                    format_elapsed(event.time),
+                    event.body,
+                    event.kind
+                ),
+                time.elapsed_secs(),
+            );
+        });
+    }
+
+    /// Displays the latest entries, the oldest on top, fading them out as they get old.
+    fn on_event_log_update(
+        mut text: Query<(&mut Text, &mut Visibility), With<EventLogText>>,
+        mut log: ResMut<Self>,
+        time: Res<Time<Real>>,
+    ) {
+        let (mut text, mut visibility) = text.single_mut();
+
+        let expected = if log.visible {
+            Visibility::Inherited
+        } else {
+            Visibility::Hidden
+        };
+
+        if *visibility != expected {
+            *visibility = expected;
+        }
+
+        // nothing is fading out unless there are entries.
+        if log.entries.is_empty() && !log.is_changed() {
+            return;
+        }
+
+        let now = time.elapsed_secs();
+        log.expire(now);
+
+        let entries = log.entries.iter().map(Some).chain(std::iter::repeat(None));
+        text.sections
+            .iter_mut()
+            .zip(entries)
+            .for_each(|(section, entry)| {
+                let Some(entry) = entry else {
+                    section.value.clear();
+                    return;
+                };
+
+                let age = now - entry.logged_at;
+                let alpha = 1. - ((age - FADE_DELAY) / FADE_DURATION).clamp(0., 1.);
+
+                section.value = format!("{}\n", entry.text);
+                section.style.color = color::BATTLESHIP_GRAY.with_alpha(alpha);
+            });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{EventLog, FADE_DELAY, FADE_DURATION, MAX_ENTRIES};
+
+    #[test]
+    fn event_log_must_keep_the_latest_entries_only() {
+        struct Test {
+            name: &'static str,
+            pushed: usize,
+            now: f32,
+            want: Vec<&'static str>,
+        }
+
+        let texts = ["a", "b", "c", "d", "e", "f", "g", "h"];
+        assert!(texts.len() > MAX_ENTRIES);
+
+        vec![
+            Test {
+                name: "fewer entries than the limit",
+                pushed: 2,
+                now: 0.,
+                want: vec!["a", "b"],
+            },
+            Test {
+                name: "more entries than the limit",
+                pushed: texts.len(),
+                now: 0.,
+                want: texts[texts.len() - MAX_ENTRIES..].to_vec(),
+            },
+            Test {
+                name: "entries fading out",
+                pushed: 2,
+                now: FADE_DELAY + FADE_DURATION / 2.,
+                want: vec!["a", "b"],
+            },
+            Test {
+                name: "entries faded out",
+                pushed: 2,
+                now: FADE_DELAY + FADE_DURATION,
+                want: vec![],
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let mut log = EventLog::default();
+            texts
+                .iter()
+                .take(test.pushed)
+                .for_each(|text| log.push(text.to_string(), 0.));
+
+            log.expire(test.now);
+
+            let got: Vec<_> = log
+                .entries
+                .iter()
+                .map(|entry| entry.text.as_str())
+                .collect();
+
+            assert_eq!(got, test.want, "{}", test.name);
+        });
+    }
+}
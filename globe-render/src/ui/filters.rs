@@ -0,0 +1,218 @@
+use bevy::prelude::*;
+
+use crate::{
+    color,
+    input::{Action, Controls, InputMap},
+    orbit::{apsis::ApsisMarker, landmark::Landmark, HabitableZone, Orbit},
+};
+
+use super::{jump::TimeInput, TEXT_FONT, UI_PADDING};
+
+/// The actions toggling each of the filters, in the order they are listed in the legend.
+const FILTER_ACTIONS: [Action; 6] = [
+    Action::ToggleOrbits,
+    Action::ToggleHabitableZones,
+    Action::ToggleLabels,
+    Action::ToggleGlow,
+    Action::ToggleApsides,
+    Action::ToggleLagrangePoints,
+];
+
+/// The layers of the view that can be hidden to declutter it.
+#[derive(Resource, Clone, Copy)]
+pub struct ViewFilters {
+    /// Whether the orbits of the bodies are displayed.
+    pub orbits: bool,
+    /// Whether the habitable zones of the luminous bodies are displayed.
+    pub habitable_zones: bool,
+    /// Whether the names of the bodies are displayed.
+    pub labels: bool,
+    /// Whether the glowing bodies, luminous ones by default, emit light.
+    pub glow: bool,
+    /// Whether the closest and farthest points of every orbit are marked.
+    pub apsides: bool,
+    /// Whether the Lagrange points of the bodies orbiting a luminous one, and the barycenter of the
+    /// system, are marked.
+    pub lagrange_points: bool,
+}
+
+impl Default for ViewFilters {
+    fn default() -> Self {
+        Self {
+            orbits: true,
+            habitable_zones: true,
+            labels: true,
+            glow: true,
+            apsides: true,
+            lagrange_points: false,
+        }
+    }
+}
+
+/// The legend of the [ViewFilters].
+#[derive(Component)]
+struct ViewFiltersLegend;
+
+impl Plugin for ViewFilters {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(*self)
+            .add_systems(Startup, Self::spawn)
+            .add_systems(
+                Update,
+                Self::on_user_input_event.run_if(TimeInput::is_closed),
+            )
+            .add_systems(Update, Self::on_view_filters_update);
+    }
+}
+
+impl ViewFilters {
+    /// Returns the name of every filter along with whether it is enabled, in the order of their
+    /// actions.
+    fn entries(&self) -> [(&'static str, bool); FILTER_ACTIONS.len()] {
+        [
+            ("orbits", self.orbits),
+            ("habitable zones", self.habitable_zones),
+            ("labels", self.labels),
+            ("glow", self.glow),
+            ("apsides", self.apsides),
+            ("lagrange points", self.lagrange_points),
+        ]
+    }
+
+    /// Returns the filter toggled by the action with the given index.
+    fn filter_mut(&mut self, index: usize) -> Option<&mut bool> {
+        match index {
+            0 => Some(&mut self.orbits),
+            1 => Some(&mut self.habitable_zones),
+            2 => Some(&mut self.labels),
+            3 => Some(&mut self.glow),
+            4 => Some(&mut self.apsides),
+            5 => Some(&mut self.lagrange_points),
+            _ => None,
+        }
+    }
+
+    fn spawn(
+        mut commands: Commands,
+        filters: Res<Self>,
+        input: Res<InputMap>,
+        asset_server: Res<AssetServer>,
+    ) {
+        let style = |enabled: bool| TextStyle {
+            font: asset_server.load(TEXT_FONT),
+            font_size: 12.,
+            color: legend_color(enabled),
+        };
+
+        commands
+            .spawn(NodeBundle {
+                style: Style {
+                    position_type: PositionType::Absolute,
+                    bottom: Val::Px(0.),
+                    left: Val::Px(0.),
+                    padding: UiRect {
+                        bottom: Val::Px(48.),
+                        ..UI_PADDING
+                    },
+                    ..default()
+                },
+                ..default()
+            })
+            .with_child((
+                TextBundle::from_sections(FILTER_ACTIONS.into_iter().zip(filters.entries()).map(
+                    |(action, (name, enabled))| {
+                        let binding = input
+                            .bindings(action)
+                            .first()
+                            .map(ToString::to_string)
+                            .unwrap_or_default();
+
+                        TextSection::new(format!("{binding} {name}\n"), style(enabled))
+                    },
+                )),
+                ViewFiltersLegend,
+            ));
+    }
+
+    /// Toggles the filters whose action has just been triggered.
+    fn on_user_input_event(mut filters: ResMut<Self>, controls: Controls) {
+        FILTER_ACTIONS
+            .iter()
+            .enumerate()
+            .filter(|(_, action)| controls.just_pressed(**action))
+            .for_each(|(index, _)| {
+                if let Some(filter) = filters.filter_mut(index) {
+                    *filter = !*filter;
+                }
+            });
+    }
+
+    /// Shows or hides the orbits, habitable zones, apsides and landmarks, including the ones
+    /// spawned after the filters changed, and dims the disabled filters in the legend.
+    #[allow(clippy::type_complexity)]
+    fn on_view_filters_update(
+        mut orbits: Query<
+            &mut Visibility,
+            (With<Orbit>, Without<HabitableZone>, Without<ApsisMarker>),
+        >,
+        mut habitable_zones: Query<&mut Visibility, (With<HabitableZone>, Without<Orbit>)>,
+        mut apsides: Query<&mut Visibility, (With<ApsisMarker>, Without<HabitableZone>)>,
+        mut landmarks: Query<
+            &mut Visibility,
+            (
+                With<Landmark>,
+                Without<Orbit>,
+                Without<HabitableZone>,
+                Without<ApsisMarker>,
+            ),
+        >,
+        mut legend: Query<&mut Text, With<ViewFiltersLegend>>,
+        added: Query<(), Or<(Added<Orbit>, Added<HabitableZone>, Added<Landmark>)>>,
+        filters: Res<Self>,
+    ) {
+        if !filters.is_changed() && added.is_empty() {
+            return;
+        }
+
+        let visibility = |visible: bool| {
+            if visible {
+                Visibility::Inherited
+            } else {
+                Visibility::Hidden
+            }
+        };
+
+        orbits
+            .iter_mut()
+            .for_each(|mut orbit| *orbit = visibility(filters.orbits));
+
+        habitable_zones
+            .iter_mut()
+            .for_each(|mut zone| *zone = visibility(filters.habitable_zones));
+
+        apsides
+            .iter_mut()
+            .for_each(|mut apsis| *apsis = visibility(filters.apsides));
+
+        landmarks
+            .iter_mut()
+            .for_each(|mut landmark| *landmark = visibility(filters.lagrange_points));
+
+        if let Ok(mut legend) = legend.get_single_mut() {
+            legend.sections.iter_mut().zip(filters.entries()).for_each(
+                |(section, (_, enabled))| {
+                    section.style.color = legend_color(enabled);
+                },
+            );
+        }
+    }
+}
+
+/// Returns the color of a filter in the legend, which is dimmed if disabled.
+fn legend_color(enabled: bool) -> Color {
+    if enabled {
+        color::BATTLESHIP_GRAY
+    } else {
+        color::JET
+    }
+}
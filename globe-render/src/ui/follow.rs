@@ -0,0 +1,63 @@
+use bevy::prelude::*;
+
+use crate::{camera::MainCamera, color};
+
+use super::{TEXT_FONT, UI_PADDING};
+
+/// A hint telling which body the camera is following, if any.
+#[derive(Component, Default)]
+pub struct FollowHint;
+
+impl Plugin for FollowHint {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Startup, Self::spawn)
+            .add_systems(Update, Self::on_camera_update);
+    }
+}
+
+impl FollowHint {
+    fn spawn(mut commands: Commands, asset_server: Res<AssetServer>) {
+        commands
+            .spawn(NodeBundle {
+                style: Style {
+                    position_type: PositionType::Absolute,
+                    top: Val::Px(0.),
+                    left: Val::Px(0.),
+                    padding: UI_PADDING,
+                    ..default()
+                },
+                ..default()
+            })
+            .with_child((
+                TextBundle::from_section(
+                    "",
+                    TextStyle {
+                        font: asset_server.load(TEXT_FONT),
+                        font_size: 12.,
+                        color: color::BATTLESHIP_GRAY,
+                    },
+                ),
+                FollowHint,
+            ));
+    }
+
+    fn on_camera_update(
+        mut hint: Query<&mut Text, With<FollowHint>>,
+        camera: Query<&MainCamera, Changed<MainCamera>>,
+    ) {
+        let Ok(camera) = camera.get_single() else {
+            return;
+        };
+
+        let value = camera
+            .follow
+            .as_ref()
+            .map(|name| format!("following: {name}"))
+            .unwrap_or_default();
+
+        let mut text = hint.single_mut();
+        if text.sections[0].value != value {
+            text.sections[0].value = value;
+        }
+    }
+}
@@ -0,0 +1,224 @@
+use alvidir::name::Name;
+use bevy::{input::mouse::MouseButtonInput, prelude::*};
+use globe_rs::{Distance, HumanDuration};
+
+use crate::{
+    color,
+    event::{Clicked, Event, Updated},
+    input::{Action, Controls},
+    orbit::{Body, OrbitalSystem, OrbitalSystemState, OrbitalSystemStats},
+};
+
+use super::{jump::TimeInput, REGULAR_BORDER, REGULAR_PADDING, TEXT_FONT, UI_PADDING};
+
+/// The text displayed in place of a value the body does not have.
+const NONE: &str = "-";
+
+/// A panel describing the last clicked body.
+#[derive(Resource, Default)]
+pub struct InfoPanel {
+    /// The name of the body being described, if any.
+    pub body: Option<Name<globe_rs::Body>>,
+}
+
+/// The box of the [InfoPanel].
+#[derive(Component)]
+struct InfoPanelBox;
+
+/// The text of the [InfoPanel].
+#[derive(Component)]
+struct InfoPanelText;
+
+impl Plugin for InfoPanel {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<Self>()
+            .add_systems(Startup, Self::spawn)
+            .add_systems(
+                Update,
+                Self::on_mouse_button_event.after(OrbitalSystem::on_mouse_button_event),
+            )
+            .add_systems(
+                Update,
+                Self::on_user_input_event.run_if(TimeInput::is_closed),
+            )
+            .add_systems(Update, Self::on_info_panel_update);
+    }
+}
+
+impl InfoPanel {
+    /// Returns the lines describing the body with the given name, if any.
+    fn describe(
+        name: &Name<globe_rs::Body>,
+        system: &globe_rs::OrbitalSystem<globe_rs::OrbitKind>,
+        state: &globe_rs::OrbitalSystemState,
+        stats: &globe_rs::SystemStats,
+    ) -> Option<String> {
+        let body = &system.system(name)?.primary;
+        let body_state = state.state(name)?;
+        let body_stats = stats.stats(name)?;
+
+        let optional = |value: Option<String>| value.unwrap_or_else(|| NONE.to_string());
+        let distance = state
+            .parent_state(name)
+            .map(|parent| Distance::meters(body_state.position.distance(&parent.position)));
+
+        Some(
+            [
+                ("radius", format!("{:.3}", body.radius)),
+                ("mass", format!("{:.3}", body.mass)),
+                (
+                    "spin period",
+                    optional(
+                        body.spin
+                            .period()
+                            .map(|period| format!("{:.2}", HumanDuration(period))),
+                    ),
+                ),
+                ("speed", format!("{:.2}", body_state.velocity)),
+                (
+                    "distance",
+                    optional(distance.map(|distance| format!("{distance:.3}"))),
+                ),
+                (
+                    "orbital period",
+                    optional(
+                        body_stats
+                            .orbital_period
+                            .map(|period| format!("{:.2}", HumanDuration(period))),
+                    ),
+                ),
+                ("perimeter", format!("{:.3}", body_stats.perimeter)),
+                (
+                    "min velocity",
+                    optional(
+                        body_stats
+                            .min_velocity
+                            .map(|velocity| format!("{velocity:.2}")),
+                    ),
+                ),
+                (
+                    "max velocity",
+                    optional(
+                        body_stats
+                            .max_velocity
+                            .map(|velocity| format!("{velocity:.2}")),
+                    ),
+                ),
+            ]
+            .into_iter()
+            .map(|(label, value)| format!("\n{label}: {value}"))
+            .collect(),
+        )
+    }
+
+    fn spawn(mut commands: Commands, asset_server: Res<AssetServer>) {
+        commands
+            .spawn(NodeBundle {
+                style: Style {
+                    position_type: PositionType::Absolute,
+                    top: Val::Px(0.),
+                    right: Val::Px(0.),
+                    padding: UI_PADDING,
+                    ..default()
+                },
+                ..default()
+            })
+            .with_children(|parent| {
+                parent
+                    .spawn((
+                        NodeBundle {
+                            style: Style {
+                                border: UiRect::all(REGULAR_BORDER),
+                                padding: UiRect::all(REGULAR_PADDING),
+                                ..default()
+                            },
+                            border_color: color::BATTLESHIP_GRAY.into(),
+                            background_color: color::NIGHT.with_alpha(0.7).into(),
+                            visibility: Visibility::Hidden,
+                            ..default()
+                        },
+                        InfoPanelBox,
+                    ))
+                    .with_child((
+                        TextBundle::from_sections([
+                            // body name
+                            TextSection::new(
+                                "",
+                                TextStyle {
+                                    font: asset_server.load(TEXT_FONT),
+                                    font_size: 16.,
+                                    color: color::PERSIAN_ORANGE,
+                                },
+                            ),
+                            // body details
+                            TextSection::new(
+                                "",
+                                TextStyle {
+                                    font: asset_server.load(TEXT_FONT),
+                                    font_size: 12.,
+                                    color: color::BATTLESHIP_GRAY,
+                                },
+                            ),
+                        ]),
+                        InfoPanelText,
+                    ));
+            });
+    }
+
+    /// Describes the clicked body, or hides the panel when the click hits no body at all.
+    fn on_mouse_button_event(
+        mut mouse_button: EventReader<MouseButtonInput>,
+        mut body_clicked: EventReader<Event<Body, Clicked, Body>>,
+        mut panel: ResMut<Self>,
+    ) {
+        let released = mouse_button
+            .read()
+            .last()
+            .is_some_and(|event| !event.state.is_pressed());
+
+        match body_clicked.read().last() {
+            Some(event) => panel.body = Some(event.data.name.clone()),
+            None if released && panel.body.is_some() => panel.body = None,
+            None => {}
+        }
+    }
+
+    /// Hides the panel when dismissed.
+    fn on_user_input_event(mut panel: ResMut<Self>, controls: Controls) {
+        if controls.just_pressed(Action::Dismiss) && panel.body.is_some() {
+            panel.body = None;
+        }
+    }
+
+    /// Displays the description of the selected body, if any, refreshing it on every change of the
+    /// state of the system.
+    fn on_info_panel_update(
+        mut state_updated: EventReader<Event<OrbitalSystemState, Updated>>,
+        mut panel_box: Query<&mut Visibility, With<InfoPanelBox>>,
+        mut panel_text: Query<&mut Text, With<InfoPanelText>>,
+        panel: Res<Self>,
+        system: Res<OrbitalSystem>,
+        state: Res<OrbitalSystemState>,
+        stats: Res<OrbitalSystemStats>,
+    ) {
+        if state_updated.read().last().is_none() && !panel.is_changed() {
+            return;
+        }
+
+        let description = panel.body.as_ref().and_then(|name| {
+            Self::describe(name, &system.spec, &state.spec, &stats.spec)
+                .map(|details| (name, details))
+        });
+
+        let mut visibility = panel_box.single_mut();
+        let Some((name, details)) = description else {
+            *visibility = Visibility::Hidden;
+            return;
+        };
+
+        *visibility = Visibility::Visible;
+        let mut text = panel_text.single_mut();
+        text.sections[0].value = name.to_string();
+        text.sections[1].value = details;
+    }
+}
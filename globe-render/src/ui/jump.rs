@@ -0,0 +1,203 @@
+use bevy::{
+    input::keyboard::{Key, KeyboardInput},
+    prelude::*,
+};
+use globe_rs::{parse_duration, Epoch};
+
+use crate::{
+    color,
+    event::{Event, Updated},
+    input::{Action, Controls},
+};
+
+use super::{clock::Clock, NUMERIC_FONT, REGULAR_BORDER, REGULAR_PADDING, UI_PADDING};
+
+/// A text input setting the [Clock] to the time typed in, since the epoch (e.g. "365d", "2.5y" or
+/// "-1y" for a year before it).
+#[derive(Resource, Default)]
+pub struct TimeInput {
+    /// The text typed so far, if the input is open.
+    pub text: Option<String>,
+    /// Whether the latest submitted text is not a valid time.
+    pub invalid: bool,
+}
+
+/// The field of the [TimeInput].
+#[derive(Component)]
+struct TimeInputField;
+
+/// The dates stored by the user, so the clock can get back to them at any time.
+#[derive(Resource, Default)]
+pub struct Bookmarks([Option<Epoch>; Action::BOOKMARKS]);
+
+impl Plugin for TimeInput {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<Self>()
+            .init_resource::<Bookmarks>()
+            .add_systems(Startup, Self::spawn)
+            .add_systems(Update, Self::on_keyboard_input_event)
+            .add_systems(Update, Self::on_time_input_update)
+            .add_systems(
+                Update,
+                Bookmarks::on_user_input_event.run_if(Self::is_closed),
+            );
+    }
+}
+
+impl TimeInput {
+    /// Returns true if, and only if, the input is not being typed in.
+    pub fn is_closed(input: Res<Self>) -> bool {
+        input.text.is_none()
+    }
+
+    /// Returns the date of the given time since the epoch, which is before it if negative.
+    fn parse(text: &str) -> Option<Epoch> {
+        match text.trim().strip_prefix('-') {
+            Some(text) => parse_duration(text).ok().map(|time| Epoch::J2000 - time),
+            None => parse_duration(text).ok().map(|time| Epoch::J2000 + time),
+        }
+    }
+
+    fn spawn(mut commands: Commands, asset_server: Res<AssetServer>) {
+        commands
+            .spawn(NodeBundle {
+                style: Style {
+                    position_type: PositionType::Absolute,
+                    bottom: Val::Px(0.),
+                    left: Val::Px(0.),
+                    padding: UI_PADDING,
+                    ..default()
+                },
+                ..default()
+            })
+            .with_children(|parent| {
+                parent
+                    .spawn(NodeBundle {
+                        style: Style {
+                            border: UiRect::all(REGULAR_BORDER),
+                            padding: UiRect::all(REGULAR_PADDING),
+                            ..default()
+                        },
+                        border_color: color::BATTLESHIP_GRAY.into(),
+                        background_color: color::NIGHT.with_alpha(0.7).into(),
+                        visibility: Visibility::Hidden,
+                        ..default()
+                    })
+                    .insert(TimeInputField)
+                    .with_child(TextBundle::from_section(
+                        "",
+                        TextStyle {
+                            font: asset_server.load(NUMERIC_FONT),
+                            font_size: 24.,
+                            color: color::BATTLESHIP_GRAY,
+                        },
+                    ));
+            });
+    }
+
+    /// Opens the input on demand, by default when pressing T, and types in it until either Enter
+    /// or Escape is pressed.
+    fn on_keyboard_input_event(
+        mut keyboard: EventReader<KeyboardInput>,
+        mut tick: EventWriter<Event<Clock, Updated>>,
+        mut input: ResMut<Self>,
+        mut clock: ResMut<Clock>,
+        controls: Controls,
+    ) {
+        if input.text.is_none() {
+            // the keys pressed while closed, including the one opening it, are not typed in.
+            keyboard.clear();
+            if controls.just_pressed(Action::OpenTimeInput) {
+                input.text = Some(String::new());
+                input.invalid = false;
+            }
+
+            return;
+        }
+
+        keyboard
+            .read()
+            .filter(|event| event.state.is_pressed())
+            .for_each(|event| {
+                let Some(text) = &mut input.text else {
+                    return;
+                };
+
+                match &event.logical_key {
+                    Key::Character(chars) => text.push_str(chars),
+                    Key::Space => text.push(' '),
+                    Key::Backspace => {
+                        text.pop();
+                    }
+                    Key::Escape => input.text = None,
+                    Key::Enter => match Self::parse(text) {
+                        Some(date) => {
+                            clock.date = date;
+                            tick.send(Event::default());
+                            input.text = None;
+                        }
+                        // the clock keeps its time, so the text can be fixed.
+                        None => input.invalid = true,
+                    },
+                    _ => {}
+                }
+            });
+    }
+
+    /// Displays the input, if open, with the color of its border telling whether the latest
+    /// submitted text was valid.
+    fn on_time_input_update(
+        mut fields: Query<(&mut Visibility, &mut BorderColor, &Children), With<TimeInputField>>,
+        mut texts: Query<&mut Text>,
+        input: Res<Self>,
+    ) {
+        if !input.is_changed() {
+            return;
+        }
+
+        fields
+            .iter_mut()
+            .for_each(|(mut visibility, mut border, children)| {
+                let Some(text) = &input.text else {
+                    *visibility = Visibility::Hidden;
+                    return;
+                };
+
+                *visibility = Visibility::Visible;
+                *border = if input.invalid {
+                    color::CRIMSON
+                } else {
+                    color::BATTLESHIP_GRAY
+                }
+                .into();
+
+                children.iter().for_each(|&child| {
+                    if let Ok(mut ui) = texts.get_mut(child) {
+                        ui.sections[0].value = format!("> {text}");
+                    }
+                });
+            });
+    }
+}
+
+impl Bookmarks {
+    /// Stores the date of the clock in a bookmark, and sets the clock back to it, on demand. By
+    /// default, when pressing Ctrl and a number, or the number alone, respectively.
+    fn on_user_input_event(
+        mut tick: EventWriter<Event<Clock, Updated>>,
+        mut bookmarks: ResMut<Self>,
+        mut clock: ResMut<Clock>,
+        controls: Controls,
+    ) {
+        (0..Action::BOOKMARKS).for_each(|index| {
+            if controls.just_pressed(Action::StoreBookmark(index)) {
+                bookmarks.0[index] = Some(clock.date);
+            } else if controls.just_pressed(Action::RecallBookmark(index)) {
+                if let Some(date) = bookmarks.0[index] {
+                    clock.date = date;
+                    tick.send(Event::default());
+                }
+            }
+        });
+    }
+}
@@ -0,0 +1,170 @@
+use alvidir::name::Name;
+use bevy::{prelude::*, window::PrimaryWindow};
+
+use crate::{
+    camera::MainCamera,
+    color,
+    event::{Created, Deleted, Event},
+    orbit::{scale::ScaleMode, Body, OrbitalSystem},
+};
+
+use super::{filters::ViewFilters, TEXT_FONT};
+
+/// The size of the font of the labels.
+const FONT_SIZE: f32 = 12.;
+/// The approximated width of a character in the labels, relative to the font size.
+const CHAR_WIDTH_RATIO: f32 = 0.8;
+/// The distance between a body and its label, in pixels.
+const LABEL_OFFSET: f32 = 6.;
+/// The projected radius, in pixels, below which the label of a body starts fading out.
+const FADE_START_RADIUS: f32 = 2.;
+/// The projected radius, in pixels, below which the label of a body is hidden.
+const FADE_END_RADIUS: f32 = 0.5;
+
+/// The name of a body, displayed next to it.
+#[derive(Component)]
+struct BodyLabel {
+    name: Name<globe_rs::Body>,
+}
+
+/// The labels naming the bodies on screen.
+///
+/// Labels fade out as their body gets smaller on screen, and hide when overlapping the label of a
+/// more prominent body: luminous bodies go first, and then the larger ones.
+#[derive(Default)]
+pub struct Labels;
+
+impl Plugin for Labels {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Update, Self::spawn_label_on_body_created)
+            .add_systems(Update, Self::on_body_deleted)
+            .add_systems(Update, Self::follow_bodies);
+    }
+}
+
+impl Labels {
+    fn spawn_label_on_body_created(
+        mut commands: Commands,
+        mut body_created: EventReader<Event<Body, Created, Body>>,
+        labels: Query<&BodyLabel>,
+        asset_server: Res<AssetServer>,
+    ) {
+        body_created
+            .read()
+            // bodies re-created while their label is still alive keep it.
+            .filter(|event| labels.iter().all(|label| label.name != event.data.name))
+            .for_each(|event| {
+                commands.spawn((
+                    TextBundle::from_section(
+                        event.data.name.to_string(),
+                        TextStyle {
+                            font: asset_server.load(TEXT_FONT),
+                            font_size: FONT_SIZE,
+                            color: color::BATTLESHIP_GRAY,
+                        },
+                    )
+                    .with_style(Style {
+                        position_type: PositionType::Absolute,
+                        ..default()
+                    }),
+                    BodyLabel {
+                        name: event.data.name.clone(),
+                    },
+                ));
+            });
+    }
+
+    fn on_body_deleted(
+        mut commands: Commands,
+        mut body_deleted: EventReader<Event<Body, Deleted, Body>>,
+        labels: Query<(Entity, &BodyLabel)>,
+    ) {
+        body_deleted.read().for_each(|event| {
+            labels
+                .iter()
+                .filter(|(_, label)| label.name == event.data.name)
+                .for_each(|(entity, _)| commands.entity(entity).despawn_recursive());
+        });
+    }
+
+    /// Places every label next to its body on screen, with an opacity depending on how large the
+    /// body looks and whether the label would overlap a more prominent one.
+    fn follow_bodies(
+        mut labels: Query<(&mut Style, &mut Text, &BodyLabel)>,
+        bodies: Query<(&Body, &GlobalTransform)>,
+        camera: Query<(&Camera, &GlobalTransform, &Transform, &Projection), With<MainCamera>>,
+        window: Query<&Window, With<PrimaryWindow>>,
+        system: Res<OrbitalSystem>,
+        filters: Res<ViewFilters>,
+        scale: Res<ScaleMode>,
+    ) {
+        let (camera, camera_global_transform, camera_transform, projection) = camera.single();
+        let pixel_size = MainCamera::pixel_size(camera_transform, projection, window.single());
+
+        // the screen position, projected radius and prominence of each body on screen.
+        let mut bodies: Vec<_> = bodies
+            .iter()
+            .filter_map(|(body, transform)| {
+                let system = system.spec.system(&body.name)?;
+                let position = camera
+                    .world_to_viewport(camera_global_transform, transform.translation())
+                    .ok()?;
+
+                let radius = scale.radius(system.primary.radius) as f32 / pixel_size;
+                Some((&body.name, position, radius, system.primary.is_luminous()))
+            })
+            .collect();
+
+        bodies.sort_by(
+            |(_, _, a_radius, a_luminous), (_, _, b_radius, b_luminous)| {
+                b_luminous
+                    .cmp(a_luminous)
+                    .then(b_radius.total_cmp(a_radius))
+            },
+        );
+
+        // the most prominent bodies place their labels first, so they are never the hidden ones.
+        let mut occupied: Vec<Rect> = Vec::new();
+        let placements: Vec<_> = bodies
+            .into_iter()
+            .map(|(name, position, radius, _)| {
+                let min = position + Vec2::new(radius + LABEL_OFFSET, -FONT_SIZE / 2.);
+                let size = Vec2::new(
+                    name.as_ref().chars().count() as f32 * FONT_SIZE * CHAR_WIDTH_RATIO,
+                    FONT_SIZE,
+                );
+
+                let rect = Rect::from_corners(min, min + size);
+                let overlaps = occupied
+                    .iter()
+                    .any(|other| !other.intersect(rect).is_empty());
+
+                let alpha = if !filters.labels || overlaps {
+                    0.
+                } else {
+                    ((radius - FADE_END_RADIUS) / (FADE_START_RADIUS - FADE_END_RADIUS))
+                        .clamp(0., 1.)
+                };
+
+                if alpha > 0. {
+                    occupied.push(rect);
+                }
+
+                (name, min, alpha)
+            })
+            .collect();
+
+        labels.iter_mut().for_each(|(mut style, mut text, label)| {
+            let Some(&(_, min, alpha)) =
+                placements.iter().find(|(name, _, _)| *name == &label.name)
+            else {
+                text.sections[0].style.color = Color::NONE;
+                return;
+            };
+
+            style.left = Val::Px(min.x);
+            style.top = Val::Px(min.y);
+            text.sections[0].style.color = color::BATTLESHIP_GRAY.with_alpha(alpha);
+        });
+    }
+}
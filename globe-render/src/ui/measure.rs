@@ -0,0 +1,217 @@
+use std::time::Duration;
+
+use alvidir::name::Name;
+use bevy::{prelude::*, render::view::NoFrustumCulling};
+use globe_rs::{
+    cartesian::shape::{Sample, Segment},
+    Distance, HumanDuration,
+};
+
+use crate::{
+    camera::MainCamera,
+    color,
+    event::{Clicked, Event, Updated},
+    input::{Action, Controls},
+    orbit::{line_strip, Body, OrbitalSystemState},
+    origin::Origin,
+    vec3,
+};
+
+use super::{jump::TimeInput, TEXT_FONT};
+
+/// The distance between the middle of the measured line and its readout, in pixels.
+const READOUT_OFFSET: Vec2 = Vec2::new(8., 8.);
+
+/// The distance between two bodies, picked by clicking on them after pressing X.
+#[derive(Resource, Debug, Default, Clone, PartialEq)]
+pub enum Measurement {
+    /// Nothing is being measured.
+    #[default]
+    Off,
+    /// The next click picks an end of the measurement, being the given one, if any, the other.
+    Picking(Option<Name<globe_rs::Body>>),
+    /// The distance between both bodies is being measured.
+    Measuring(Name<globe_rs::Body>, Name<globe_rs::Body>),
+}
+
+/// The line between both ends of the [Measurement].
+#[derive(Component, Default)]
+struct MeasurementLine {
+    /// The world coordinates of the middle of the line.
+    middle: Vec3,
+}
+
+/// The text telling the length of the [Measurement].
+#[derive(Component)]
+struct MeasurementReadout;
+
+impl Plugin for Measurement {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(self.clone())
+            .add_systems(Startup, Self::spawn)
+            .add_systems(
+                Update,
+                Self::on_user_input_event.run_if(TimeInput::is_closed),
+            )
+            .add_systems(Update, Self::on_body_clicked)
+            .add_systems(
+                Update,
+                (Self::on_measurement_update, Self::follow_line).chain(),
+            );
+    }
+}
+
+impl Measurement {
+    /// Returns both ends of the measurement, if measuring.
+    fn ends(&self) -> Option<(&Name<globe_rs::Body>, &Name<globe_rs::Body>)> {
+        match self {
+            Self::Measuring(from, to) => Some((from, to)),
+            _ => None,
+        }
+    }
+
+    fn spawn(
+        mut commands: Commands,
+        mut meshes: ResMut<Assets<Mesh>>,
+        mut materials: ResMut<Assets<StandardMaterial>>,
+        asset_server: Res<AssetServer>,
+    ) {
+        commands.spawn((
+            Mesh3d(meshes.add(line_strip(Segment::default().sample(1)))),
+            MeshMaterial3d(materials.add(StandardMaterial {
+                base_color: color::SPRING_GREEN,
+                unlit: true,
+                ..Default::default()
+            })),
+            Visibility::Hidden,
+            // the line gets reshaped on every update, while its bounding box would not.
+            NoFrustumCulling,
+            MeasurementLine::default(),
+        ));
+
+        commands.spawn((
+            TextBundle {
+                visibility: Visibility::Hidden,
+                ..TextBundle::from_section(
+                    "",
+                    TextStyle {
+                        font: asset_server.load(TEXT_FONT),
+                        font_size: 12.,
+                        color: color::SPRING_GREEN,
+                    },
+                )
+                .with_style(Style {
+                    position_type: PositionType::Absolute,
+                    ..default()
+                })
+                .with_background_color(color::NIGHT.with_alpha(0.7))
+            },
+            MeasurementReadout,
+        ));
+    }
+
+    /// Starts picking the ends of a new measurement when pressing X, and clears it when pressing
+    /// X again or Esc, by default.
+    fn on_user_input_event(mut measurement: ResMut<Self>, controls: Controls) {
+        if controls.just_pressed(Action::Dismiss) {
+            *measurement = Self::Off;
+        } else if controls.just_pressed(Action::ToggleMeasurement) {
+            *measurement = match *measurement {
+                Self::Off => Self::Picking(None),
+                _ => Self::Off,
+            };
+        }
+    }
+
+    /// Picks the clicked body as an end of the measurement, clearing it if both were picked
+    /// already.
+    fn on_body_clicked(
+        mut body_clicked: EventReader<Event<Body, Clicked, Body>>,
+        mut measurement: ResMut<Self>,
+    ) {
+        body_clicked.read().for_each(|event| {
+            let name = event.data.name.clone();
+            *measurement = match std::mem::take(&mut *measurement) {
+                Self::Off => Self::Off,
+                Self::Picking(None) => Self::Picking(Some(name)),
+                // picking the same body twice would measure nothing.
+                Self::Picking(Some(from)) if from == name => Self::Picking(Some(from)),
+                Self::Picking(Some(from)) => Self::Measuring(from, name),
+                Self::Measuring(..) => Self::Off,
+            };
+        });
+    }
+
+    /// Draws the line between both ends of the measurement, and tells its length and the time
+    /// light takes to go through it, on every update of the state.
+    #[allow(clippy::type_complexity)]
+    fn on_measurement_update(
+        mut state_updated: EventReader<Event<OrbitalSystemState, Updated>>,
+        mut meshes: ResMut<Assets<Mesh>>,
+        mut line: Query<(&mut MeasurementLine, &Mesh3d, &mut Visibility)>,
+        mut readout: Query<
+            (&mut Text, &mut Visibility),
+            (With<MeasurementReadout>, Without<MeasurementLine>),
+        >,
+        measurement: Res<Self>,
+        state: Res<OrbitalSystemState>,
+        origin: Res<Origin>,
+    ) {
+        if state_updated.read().count() == 0 && !measurement.is_changed() {
+            return;
+        }
+
+        let (mut line, mesh, mut line_visibility) = line.single_mut();
+        let (mut text, mut readout_visibility) = readout.single_mut();
+
+        let Some((from, to)) = measurement.ends() else {
+            *line_visibility = Visibility::Hidden;
+            *readout_visibility = Visibility::Hidden;
+            return;
+        };
+
+        // the line joins the displayed positions, while the readout tells the actual distance.
+        let (Some(displayed), Some(actual)) = (
+            state.position_of(from).zip(state.position_of(to)),
+            state.spec.position_of(from).zip(state.spec.position_of(to)),
+        ) else {
+            *line_visibility = Visibility::Hidden;
+            *readout_visibility = Visibility::Hidden;
+            return;
+        };
+
+        let segment = Segment::default()
+            .with_from(displayed.0 - origin.offset)
+            .with_to(displayed.1 - origin.offset);
+
+        line.middle = vec3(segment.point_at(0.5));
+        meshes.insert(&mesh.0, line_strip(segment.sample(1)));
+        *line_visibility = Visibility::Visible;
+
+        let distance = Distance::meters(actual.0.distance(&actual.1));
+        let light_time = Duration::from_secs_f64(distance.as_light_seconds());
+        text.sections[0].value = format!("{distance:.3}\nlight: {:.2}", HumanDuration(light_time));
+        *readout_visibility = Visibility::Visible;
+    }
+
+    /// Places the readout next to the middle of the line, wherever it is on screen.
+    fn follow_line(
+        mut readout: Query<&mut Style, With<MeasurementReadout>>,
+        line: Query<&MeasurementLine>,
+        camera: Query<(&Camera, &GlobalTransform), With<MainCamera>>,
+        measurement: Res<Self>,
+    ) {
+        if measurement.ends().is_none() {
+            return;
+        }
+
+        let (camera, camera_transform) = camera.single();
+        let Ok(position) = camera.world_to_viewport(camera_transform, line.single().middle) else {
+            return;
+        };
+
+        let mut style = readout.single_mut();
+        style.left = Val::Px(position.x + READOUT_OFFSET.x);
+        style.top = Val::Px(position.y + READOUT_OFFSET.y);
+    }
+}
@@ -1,9 +1,29 @@
 use bevy::prelude::*;
+use bindings::BindingsOverlay;
 use clock::Clock;
+use event_log::EventLog;
+use filters::ViewFilters;
+use follow::FollowHint;
+use info::InfoPanel;
+use jump::TimeInput;
+use labels::Labels;
+use measure::Measurement;
+use scrubber::Scrubber;
+use tooltip::Tooltip;
 
-use crate::color;
+use crate::{color, config::GlobeRsConfig};
 
+pub mod bindings;
 pub mod clock;
+pub mod event_log;
+pub mod filters;
+pub mod follow;
+pub mod info;
+pub mod jump;
+pub mod labels;
+pub mod measure;
+pub mod scrubber;
+pub mod tooltip;
 
 const NUMERIC_FONT: &str = "fonts/major_mono_display/MajorMonoDisplay-Regular.ttf";
 const TEXT_FONT: &str = "fonts/orbitron/static/Orbitron-Bold.ttf";
@@ -20,7 +40,23 @@ pub struct Ui;
 
 impl Plugin for Ui {
     fn build(&self, app: &mut App) {
-        app.add_plugins(Clock::default())
+        let config = app
+            .world()
+            .get_resource::<GlobeRsConfig>()
+            .cloned()
+            .unwrap_or_default();
+
+        app.add_plugins(Clock::from(&config))
+            .add_plugins(TimeInput::default())
+            .add_plugins(Scrubber::default())
+            .add_plugins(ViewFilters::default())
+            .add_plugins(Labels)
+            .add_plugins(InfoPanel::default())
+            .add_plugins(FollowHint)
+            .add_plugins(Tooltip)
+            .add_plugins(Measurement::default())
+            .add_plugins(EventLog::default())
+            .add_plugins(BindingsOverlay::default())
             .add_systems(Startup, Self::spawn);
     }
 }
@@ -0,0 +1,183 @@
+use std::time::Duration;
+
+use bevy::{prelude::*, window::PrimaryWindow};
+use globe_rs::{Epoch, SystemStats};
+
+use crate::{
+    color,
+    event::{Event, Updated},
+    orbit::OrbitalSystemStats,
+};
+
+use super::clock::Clock;
+
+/// The height of the scrubber bar.
+const SCRUBBER_HEIGHT: Val = Val::Px(8.);
+/// The width of the handle marking the date of the clock along the scrubber.
+const HANDLE_WIDTH: Val = Val::Px(4.);
+/// The window of time of a system with no orbital period at all.
+const DEFAULT_WINDOW: Duration = Duration::from_secs(365 * 24 * 3600);
+
+/// A horizontal bar at the bottom of the screen mapping a window of time to its width, so dragging
+/// along it sets the date of the [Clock].
+///
+/// The window the bar maps is the one, out of all the consecutive windows since the epoch,
+/// including the date of the clock.
+#[derive(Resource, Default)]
+pub struct Scrubber {
+    /// The duration of the window, if any. Otherwise, the longest orbital period of the system.
+    pub window: Option<Duration>,
+    /// Whether the clock was running before dragging, and the first date of the window being
+    /// dragged along, if dragging.
+    dragging: Option<(bool, Epoch)>,
+}
+
+/// The bar of the [Scrubber].
+#[derive(Component)]
+struct ScrubberBar;
+
+/// The handle of the [Scrubber].
+#[derive(Component)]
+struct ScrubberHandle;
+
+impl Plugin for Scrubber {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(Self {
+            window: self.window,
+            dragging: None,
+        })
+        .add_systems(Startup, Self::spawn)
+        .add_systems(Update, Self::on_drag)
+        .add_systems(Update, Self::on_clock_update);
+    }
+}
+
+impl Scrubber {
+    /// Returns the duration of the window mapped by the scrubber.
+    fn window(&self, stats: &SystemStats) -> Duration {
+        self.window
+            .or_else(|| longest_period(stats))
+            .filter(|window| !window.is_zero())
+            .unwrap_or(DEFAULT_WINDOW)
+    }
+
+    /// Returns the first date of the window including the given one, along with the fraction of
+    /// the window elapsed at it.
+    fn locate(window: Duration, date: Epoch) -> (Epoch, f64) {
+        let window = window.as_secs_f64();
+        let start = (date.as_seconds() / window).floor() * window;
+        let fraction = (date.as_seconds() - start) / window;
+
+        (Epoch::from_seconds(start), fraction)
+    }
+
+    fn spawn(mut commands: Commands) {
+        commands
+            .spawn((
+                NodeBundle {
+                    style: Style {
+                        position_type: PositionType::Absolute,
+                        bottom: Val::Px(0.),
+                        left: Val::Px(0.),
+                        right: Val::Px(0.),
+                        height: SCRUBBER_HEIGHT,
+                        ..default()
+                    },
+                    background_color: color::JET.with_alpha(0.7).into(),
+                    ..default()
+                },
+                Interaction::default(),
+                ScrubberBar,
+            ))
+            .with_child((
+                NodeBundle {
+                    style: Style {
+                        position_type: PositionType::Absolute,
+                        width: HANDLE_WIDTH,
+                        height: Val::Percent(100.),
+                        ..default()
+                    },
+                    background_color: color::PERSIAN_ORANGE.into(),
+                    ..default()
+                },
+                ScrubberHandle,
+            ));
+    }
+
+    /// Sets the date of the clock to the one under the cursor while the bar is being dragged,
+    /// pausing the clock until it gets released.
+    fn on_drag(
+        mut tick: EventWriter<Event<Clock, Updated>>,
+        mut scrubber: ResMut<Self>,
+        mut clock: ResMut<Clock>,
+        bar: Query<(&Interaction, &Node, &GlobalTransform), With<ScrubberBar>>,
+        primary_window: Query<&Window, With<PrimaryWindow>>,
+        stats: Res<OrbitalSystemStats>,
+    ) {
+        let (interaction, node, transform) = bar.single();
+        if *interaction != Interaction::Pressed {
+            // releasing the bar resumes whatever play state existed before dragging.
+            if let Some((running, _)) = scrubber.dragging.take() {
+                clock.running = running;
+            }
+
+            return;
+        }
+
+        let window = scrubber.window(&stats.spec);
+        // the window is fixed once dragging starts, so reaching its end does not move to the next.
+        let (_, start) = *scrubber.dragging.get_or_insert_with(|| {
+            let (start, _) = Self::locate(window, clock.date);
+            (clock.running, start)
+        });
+
+        if clock.running {
+            clock.running = false;
+        }
+
+        let Some(cursor) = primary_window.single().cursor_position() else {
+            return;
+        };
+
+        let left = transform.translation().x - node.size().x / 2.;
+        let fraction = ((cursor.x - left) / node.size().x).clamp(0., 1.) as f64;
+
+        let date = start + window.mul_f64(fraction);
+        if date != clock.date {
+            clock.date = date;
+            tick.send(Event::default());
+        }
+    }
+
+    /// Moves the handle to the date of the clock.
+    fn on_clock_update(
+        mut handle: Query<&mut Style, With<ScrubberHandle>>,
+        scrubber: Res<Self>,
+        clock: Res<Clock>,
+        stats: Res<OrbitalSystemStats>,
+    ) {
+        if !clock.is_changed() && !stats.is_changed() {
+            return;
+        }
+
+        let window = scrubber.window(&stats.spec);
+        let fraction = match scrubber.dragging {
+            Some((_, start)) => {
+                (clock.date.as_seconds() - start.as_seconds()) / window.as_secs_f64()
+            }
+            None => Self::locate(window, clock.date).1,
+        };
+
+        handle.single_mut().left = Val::Percent(fraction.clamp(0., 1.) as f32 * 100.);
+    }
+}
+
+/// Returns the longest orbital period in the given system, if any.
+fn longest_period(stats: &SystemStats) -> Option<Duration> {
+    stats
+        .secondary
+        .iter()
+        .filter_map(longest_period)
+        .chain(stats.orbital_period)
+        .max()
+}
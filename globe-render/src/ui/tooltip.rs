@@ -0,0 +1,110 @@
+use bevy::{prelude::*, window::PrimaryWindow};
+
+use crate::{
+    color,
+    cursor::Cursor,
+    event::{Event, Hovered, Unhovered},
+    orbit::{apsis::ApsisMarker, landmark::Landmark, Body},
+};
+
+use super::TEXT_FONT;
+
+/// The distance between the pointer and the tooltip, in pixels.
+const TOOLTIP_OFFSET: Vec2 = Vec2::new(12., 12.);
+
+/// A small text next to the pointer naming the hovered body, if any, or telling the distance of the
+/// hovered apsis or naming the hovered landmark otherwise.
+#[derive(Component, Default)]
+pub struct Tooltip;
+
+impl Plugin for Tooltip {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Startup, Self::spawn)
+            .add_systems(Update, Self::on_hovered_or_unhovered)
+            .add_systems(Update, Self::follow_pointer);
+    }
+}
+
+impl Tooltip {
+    fn spawn(mut commands: Commands, asset_server: Res<AssetServer>) {
+        commands.spawn((
+            TextBundle {
+                visibility: Visibility::Hidden,
+                ..TextBundle::from_section(
+                    "",
+                    TextStyle {
+                        font: asset_server.load(TEXT_FONT),
+                        font_size: 12.,
+                        color: color::BATTLESHIP_GRAY,
+                    },
+                )
+                .with_style(Style {
+                    position_type: PositionType::Absolute,
+                    ..default()
+                })
+                .with_background_color(color::NIGHT.with_alpha(0.7))
+            },
+            Tooltip,
+        ));
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn on_hovered_or_unhovered(
+        mut body_hovered: EventReader<Event<Body, Hovered, Body>>,
+        mut body_unhovered: EventReader<Event<Body, Unhovered, Body>>,
+        mut apsis_hovered: EventReader<Event<ApsisMarker, Hovered, ApsisMarker>>,
+        mut apsis_unhovered: EventReader<Event<ApsisMarker, Unhovered, ApsisMarker>>,
+        mut landmark_hovered: EventReader<Event<Landmark, Hovered, Landmark>>,
+        mut landmark_unhovered: EventReader<Event<Landmark, Unhovered, Landmark>>,
+        mut tooltip: Query<(&mut Text, &mut Visibility), With<Tooltip>>,
+        cursor: Res<Cursor>,
+    ) {
+        let body_changed = body_hovered.read().count() + body_unhovered.read().count() > 0;
+        let apsis_changed = apsis_hovered.read().count() + apsis_unhovered.read().count() > 0;
+        let landmark_changed =
+            landmark_hovered.read().count() + landmark_unhovered.read().count() > 0;
+        if !body_changed && !apsis_changed && !landmark_changed {
+            return;
+        }
+
+        let (mut text, mut visibility) = tooltip.single_mut();
+        let value = match (
+            &cursor.hovered,
+            &cursor.hovered_apsis,
+            &cursor.hovered_landmark,
+        ) {
+            (Some(name), _, _) => name.to_string(),
+            (None, Some(apsis), _) => format!("{}: {:.3}", apsis.kind, apsis.distance),
+            (None, None, Some(landmark)) => landmark.to_string(),
+            (None, None, None) => {
+                *visibility = Visibility::Hidden;
+                return;
+            }
+        };
+
+        *visibility = Visibility::Visible;
+        text.sections[0].value = value;
+    }
+
+    /// Places the tooltip next to the pointer.
+    fn follow_pointer(
+        mut tooltip: Query<&mut Style, With<Tooltip>>,
+        window: Query<&Window, With<PrimaryWindow>>,
+        cursor: Res<Cursor>,
+    ) {
+        if cursor.hovered.is_none()
+            && cursor.hovered_apsis.is_none()
+            && cursor.hovered_landmark.is_none()
+        {
+            return;
+        }
+
+        let Some(position) = window.single().cursor_position() else {
+            return;
+        };
+
+        let mut style = tooltip.single_mut();
+        style.left = Val::Px(position.x + TOOLTIP_OFFSET.x);
+        style.top = Val::Px(position.y + TOOLTIP_OFFSET.y);
+    }
+}
@@ -0,0 +1,95 @@
+use std::{str::FromStr, time::Duration};
+
+use alvidir::name::Name;
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use globe_rs::{
+    cartesian::shape::Ellipse, Body, Distance, Mass, Orbit, OrbitalSystem, PeriodCache, Ratio,
+    Rotation,
+};
+
+/// The amount of bodies orbiting the primary one in the synthetic system.
+const PLANETS: usize = 7;
+/// The amount of bodies orbiting each planet in the synthetic system.
+const MOONS: usize = 6;
+
+fn body(name: String, radius: Distance, mass: Mass) -> Body {
+    Body::new(Name::from_str(&name).unwrap())
+        .with_radius(radius)
+        .with_mass(mass)
+        .with_spin(Rotation {
+            period: Duration::from_secs(24 * 3600),
+            ..Default::default()
+        })
+}
+
+fn orbit(semi_major_axis: Distance, eccentricity: f64) -> Option<Ellipse> {
+    Some(
+        Ellipse::default()
+            .with_semi_major_axis(semi_major_axis)
+            .with_eccentricity(Ratio::from(eccentricity)),
+    )
+}
+
+/// Returns a system of 50 bodies: a star, its planets and their moons.
+fn synthetic_system() -> OrbitalSystem<Ellipse> {
+    OrbitalSystem {
+        primary: body("star".into(), Distance::km(696_340.), Mass::kg(1.9891e30)),
+        orbit: None,
+        secondary: (1..=PLANETS)
+            .map(|planet| OrbitalSystem {
+                primary: body(
+                    format!("planet-{planet}"),
+                    Distance::km(6_371.),
+                    Mass::kg(5.97219e24),
+                ),
+                orbit: orbit(Distance::ASTRONOMICAL_UNIT * planet as f64, 0.1),
+                secondary: (1..=MOONS)
+                    .map(|moon| OrbitalSystem {
+                        primary: body(
+                            format!("moon-{planet}-{moon}"),
+                            Distance::km(1_737.4),
+                            Mass::kg(7.342e22),
+                        ),
+                        orbit: orbit(Distance::km(100_000. * moon as f64), 0.05),
+                        secondary: Vec::default(),
+                    })
+                    .collect(),
+            })
+            .collect(),
+    }
+}
+
+/// Computes the theta, position and velocity of every body independently from each other, as the
+/// state of the system used to be computed.
+fn per_method(system: &OrbitalSystem<Ellipse>, time: Duration) -> usize {
+    system
+        .secondary
+        .iter()
+        .filter_map(|subsystem| subsystem.orbit.map(|orbit| (subsystem, orbit)))
+        .map(|(subsystem, orbit)| {
+            black_box(orbit.theta_at(time, &system.primary));
+            black_box(orbit.position_at(time, &system.primary));
+            black_box(orbit.velocity_at(time, &system.primary));
+            1 + per_method(subsystem, time)
+        })
+        .sum()
+}
+
+fn state_at(c: &mut Criterion) {
+    let system = synthetic_system();
+    let periods = PeriodCache::from(&system);
+    let time = Duration::from_secs(365 * 24 * 3600);
+
+    let mut group = c.benchmark_group("state_at");
+    group.bench_function("per_method", |b| {
+        b.iter(|| per_method(&system, black_box(time)))
+    });
+    group.bench_function("uncached", |b| b.iter(|| system.state_at(black_box(time))));
+    group.bench_function("cached", |b| {
+        b.iter(|| system.state_at_cached(black_box(time), &periods))
+    });
+    group.finish();
+}
+
+criterion_group!(benches, state_at);
+criterion_main!(benches);
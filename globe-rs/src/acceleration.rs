@@ -0,0 +1,229 @@
+use std::{
+    fmt::Debug,
+    ops::{Add, Div, Mul},
+    time::Duration,
+};
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::{
+    quantity::{self, Quantity},
+    PositiveFloat, Velocity,
+};
+
+/// The standard acceleration of gravity, as m/s².
+const STANDARD_GRAVITY: f64 = 9.80665;
+
+/// The symbols of the units an acceleration may be deserialized from, and how many meters per
+/// second squared each of them represents.
+const UNITS: &[(&str, f64)] = &[("m/s²", 1.), ("m/s2", 1.), ("g", STANDARD_GRAVITY)];
+
+/// The rate at which the velocity of an arbitrary object changes, which is always a positive
+/// number.
+#[derive(Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Acceleration(PositiveFloat);
+
+impl Add for Acceleration {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self(self.0 + rhs.0)
+    }
+}
+
+impl Mul<f64> for Acceleration {
+    type Output = Self;
+
+    fn mul(self, rhs: f64) -> Self::Output {
+        Self(PositiveFloat::from_abs(self.0 .0 * rhs))
+    }
+}
+
+impl Mul<Duration> for Acceleration {
+    type Output = Velocity;
+
+    /// Returns the velocity gained at this acceleration during the given time.
+    fn mul(self, rhs: Duration) -> Self::Output {
+        Velocity::meters_sec(self.as_meters_sec2() * rhs.as_secs_f64())
+    }
+}
+
+impl Div<Duration> for Velocity {
+    type Output = Acceleration;
+
+    /// Returns the acceleration required to gain the velocity in the given time.
+    fn div(self, rhs: Duration) -> Self::Output {
+        Acceleration::meters_sec2(self.as_meters_sec() / rhs.as_secs_f64())
+    }
+}
+
+impl Debug for Acceleration {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("Acceleration")
+            .field(&format!("{} m/s²", self.0))
+            .finish()
+    }
+}
+
+impl Serialize for Acceleration {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&quantity::format(self.as_meters_sec2(), "m/s²"))
+    }
+}
+
+impl<'de> Deserialize<'de> for Acceleration {
+    /// Deserializes an acceleration from either a number of meters per second squared or a number
+    /// followed by its unit.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Quantity::deserialize(deserializer)?
+            .positive_value(UNITS)
+            .map(Self::meters_sec2)
+    }
+}
+
+impl Acceleration {
+    /// The standard acceleration of gravity on the surface of the Earth.
+    pub const STANDARD_GRAVITY: Self = Self(PositiveFloat(STANDARD_GRAVITY));
+
+    /// Returns a new acceleration of a meters per second squared, saturating at zero.
+    pub fn meters_sec2(a: f64) -> Self {
+        Self(PositiveFloat::new_saturating(a))
+    }
+
+    /// Returns a [f64] representing the acceleration in meters per second squared.
+    pub fn as_meters_sec2(&self) -> f64 {
+        self.0 .0
+    }
+
+    /// Returns a [f64] representing the acceleration relative to the standard gravity.
+    pub fn as_g(&self) -> f64 {
+        self.as_meters_sec2() / STANDARD_GRAVITY
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{str::FromStr, time::Duration};
+
+    use alvidir::name::Name;
+
+    use crate::{tests::approx_eq, Acceleration, Body, Distance, Mass, Velocity};
+
+    #[test]
+    fn conversions_must_not_fail() {
+        struct Test {
+            name: &'static str,
+            output: f64,
+            want: f64,
+        }
+
+        vec![
+            Test {
+                name: "standard gravity in g",
+                output: Acceleration::STANDARD_GRAVITY.as_g(),
+                want: 1.,
+            },
+            Test {
+                name: "meters per second squared in g",
+                output: Acceleration::meters_sec2(19.6133).as_g(),
+                want: 2.,
+            },
+            Test {
+                name: "velocity over duration",
+                output: (Velocity::meters_sec(100.) / Duration::from_secs(4)).as_meters_sec2(),
+                want: 25.,
+            },
+            Test {
+                name: "acceleration times duration",
+                output: (Acceleration::meters_sec2(25.) * Duration::from_secs(4)).as_meters_sec(),
+                want: 100.,
+            },
+            Test {
+                name: "acceleration times scalar",
+                output: (Acceleration::meters_sec2(2.) * 3.).as_meters_sec2(),
+                want: 6.,
+            },
+            Test {
+                name: "acceleration plus acceleration",
+                output: (Acceleration::meters_sec2(2.) + Acceleration::meters_sec2(3.))
+                    .as_meters_sec2(),
+                want: 5.,
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            assert!(
+                approx_eq(test.output, test.want, 1e-9),
+                "{}: got {}, want {}",
+                test.name,
+                test.output,
+                test.want
+            );
+        });
+    }
+
+    #[test]
+    fn earth_surface_gravity_must_be_one_g() {
+        let earth = Body::new(Name::from_str("Earth").unwrap())
+            .with_radius(Distance::km(6_371.))
+            .with_mass(Mass::EARTH);
+
+        let gravity = earth.surface_gravity();
+        assert!(
+            approx_eq(gravity.as_g(), 1., 0.005),
+            "got surface gravity = {gravity:?}, want 1 g"
+        );
+    }
+
+    #[test]
+    fn deserialize_must_not_fail() {
+        struct Test {
+            name: &'static str,
+            input: &'static str,
+            output: Option<Acceleration>,
+        }
+
+        vec![
+            Test {
+                name: "bare number in meters per second squared",
+                input: "9.8",
+                output: Some(Acceleration::meters_sec2(9.8)),
+            },
+            Test {
+                name: "meters per second squared",
+                input: r#""9.8 m/s²""#,
+                output: Some(Acceleration::meters_sec2(9.8)),
+            },
+            Test {
+                name: "meters per second squared in ascii",
+                input: r#""9.8 m/s2""#,
+                output: Some(Acceleration::meters_sec2(9.8)),
+            },
+            Test {
+                name: "standard gravities",
+                input: r#""1 g""#,
+                output: Some(Acceleration::STANDARD_GRAVITY),
+            },
+            Test {
+                name: "invalid suffix",
+                input: r#""1 ft/s2""#,
+                output: None,
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let output = serde_json::from_str::<Acceleration>(test.input).ok();
+            assert_eq!(
+                output, test.output,
+                "{}: got output = {:?}, want {:?}",
+                test.name, output, test.output
+            );
+        });
+    }
+
+    #[test]
+    fn serialize_must_not_fail() {
+        let output = serde_json::to_value(Acceleration::meters_sec2(9.8)).unwrap();
+        assert_eq!(output, "9.8 m/s²");
+    }
+}
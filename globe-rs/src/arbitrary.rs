@@ -0,0 +1,173 @@
+//! Implementations of [Arbitrary] for the unit and coordinate types of the crate, so they can be
+//! generated by property-based tests.
+
+use std::{
+    f64::consts::{FRAC_PI_2, PI},
+    ops::{Range, RangeInclusive},
+};
+
+use proptest::{
+    arbitrary::Arbitrary,
+    strategy::{Map, Strategy},
+};
+
+use crate::{cartesian, geographic, Distance, Luminosity, Mass, Radian, Ratio, Velocity};
+
+/// The greatest distance generated, as kilometers, which is about a light-year.
+const MAX_DISTANCE_KM: f64 = 1e13;
+/// The greatest magnitude of any cartesian component generated.
+const MAX_COORD: f64 = 1e12;
+/// The greatest mass generated, as kilograms, which is about a hundred solar masses.
+const MAX_MASS_KG: f64 = 1e32;
+/// The greatest velocity generated, as meters per second, which is about a tenth of the speed of
+/// light.
+const MAX_VELOCITY_MS: f64 = 3e7;
+/// The greatest luminosity generated, as watts, which is about a million suns.
+const MAX_LUMINOSITY_W: f64 = 1e33;
+
+/// Implements [Arbitrary] for the given type by mapping the values of the given range through
+/// the given constructor.
+macro_rules! arbitrary_from_range {
+    ($type:ty, $strategy:ty, $range:expr, $constructor:expr) => {
+        impl Arbitrary for $type {
+            type Parameters = ();
+            type Strategy = Map<$strategy, fn(f64) -> Self>;
+
+            fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+                ($range).prop_map($constructor)
+            }
+        }
+    };
+}
+
+arbitrary_from_range!(Distance, Range<f64>, 0.0..MAX_DISTANCE_KM, Distance::km);
+arbitrary_from_range!(Mass, Range<f64>, 0.0..MAX_MASS_KG, Mass::kg);
+arbitrary_from_range!(
+    Velocity,
+    Range<f64>,
+    0.0..MAX_VELOCITY_MS,
+    Velocity::meters_sec
+);
+arbitrary_from_range!(
+    Luminosity,
+    Range<f64>,
+    0.0..MAX_LUMINOSITY_W,
+    Luminosity::watts
+);
+arbitrary_from_range!(Radian, Range<f64>, 0.0..2. * PI, Radian::from);
+// closed orbits only, hence eccentricities lower than 1
+arbitrary_from_range!(Ratio, Range<f64>, 0.0..1., Ratio::from);
+arbitrary_from_range!(
+    geographic::Longitude,
+    Range<f64>,
+    -PI..PI,
+    geographic::Longitude::from
+);
+arbitrary_from_range!(
+    geographic::Latitude,
+    RangeInclusive<f64>,
+    -FRAC_PI_2..=FRAC_PI_2,
+    geographic::Latitude::from
+);
+arbitrary_from_range!(
+    geographic::Altitude,
+    Range<f64>,
+    0.0..MAX_COORD,
+    geographic::Altitude::from
+);
+
+impl Arbitrary for cartesian::Coords {
+    type Parameters = ();
+    type Strategy = Map<[RangeInclusive<f64>; 3], fn([f64; 3]) -> Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        let range = -MAX_COORD..=MAX_COORD;
+        [range.clone(), range.clone(), range].prop_map(Self::from)
+    }
+}
+
+impl Arbitrary for geographic::Coords {
+    type Parameters = ();
+    type Strategy = Map<
+        (
+            <geographic::Longitude as Arbitrary>::Strategy,
+            <geographic::Latitude as Arbitrary>::Strategy,
+            <geographic::Altitude as Arbitrary>::Strategy,
+        ),
+        fn(
+            (
+                geographic::Longitude,
+                geographic::Latitude,
+                geographic::Altitude,
+            ),
+        ) -> Self,
+    >;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        (
+            geographic::Longitude::arbitrary(),
+            geographic::Latitude::arbitrary(),
+            geographic::Altitude::arbitrary(),
+        )
+            .prop_map(|(longitude, latitude, altitude)| {
+                Self::default()
+                    .with_longitude(longitude)
+                    .with_latitude(latitude)
+                    .with_altitude(altitude)
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::{prop_assert, prop_assume, proptest};
+
+    use crate::{
+        cartesian::{
+            self,
+            transform::{Rotation, Transform},
+        },
+        geographic, Radian, Ratio,
+    };
+
+    proptest! {
+        #[test]
+        fn cartesian_must_round_trip_through_geographic(point: cartesian::Coords) {
+            let norm = point.distance(&cartesian::Coords::default());
+            prop_assume!(norm > 1.);
+
+            let geographic = geographic::Coords::from(point);
+            let got = cartesian::Coords::from(geographic);
+            prop_assert!(
+                got.distance(&point) <= norm * 1e-9,
+                "got {got:?}, want {point:?}"
+            );
+        }
+
+        #[test]
+        fn rotation_between_must_align_vectors(from: cartesian::Coords, to: cartesian::Coords) {
+            prop_assume!(from.magnitude() > 1. && to.magnitude() > 1.);
+
+            let got = Rotation::between(from, to).transform(from.unit());
+            prop_assert!(
+                got.distance(&to.unit()) <= 1e-9,
+                "got {got:?}, want {:?}", to.unit()
+            );
+        }
+
+        #[test]
+        fn radian_wrap_must_be_idempotent(radian: Radian, turns in -8_i32..8) {
+            let wrapped = Radian::from(radian.as_f64() + f64::from(turns) * Radian::TWO_PI.as_f64());
+            prop_assert!(
+                wrapped.signed_diff(radian).abs() <= 1e-9,
+                "got {wrapped:?}, want {radian:?}"
+            );
+            prop_assert!(Radian::from(wrapped.as_f64()) == wrapped);
+        }
+
+        #[test]
+        fn eccentricity_must_be_closed(eccentricity: Ratio) {
+            prop_assert!(Ratio::try_new(eccentricity.as_f64()).is_ok());
+        }
+    }
+}
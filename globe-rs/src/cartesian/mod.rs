@@ -1,12 +1,13 @@
 use std::{
     f64::consts::{FRAC_PI_2, PI},
-    ops::{Add, AddAssign, Div, Neg},
+    ops::{Add, AddAssign, Div, Mul, Neg, Sub},
 };
 
 use nalgebra::{iter::MatrixIter, ArrayStorage, Const, Vector3};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use transform::Transform;
 
-use crate::geographic;
+use crate::{geographic, Radian};
 
 pub mod shape;
 pub mod transform;
@@ -24,6 +25,39 @@ where
     }
 }
 
+impl From<Coords> for [f64; 3] {
+    fn from(coords: Coords) -> Self {
+        coords.to_array()
+    }
+}
+
+/// A [Coords] as found in a serialized document: either an array with its x, y and z components,
+/// in that order, or a map with a key per axis.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum SerializedCoords {
+    Array([f64; 3]),
+    Map { x: f64, y: f64, z: f64 },
+}
+
+impl Serialize for Coords {
+    /// Serializes the coordinates as an array of its x, y and z components.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.to_array().serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for Coords {
+    /// Deserializes the coordinates from either an array of its x, y and z components or a map
+    /// with the x, y and z keys.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Ok(match SerializedCoords::deserialize(deserializer)? {
+            SerializedCoords::Array(components) => Self::from(components),
+            SerializedCoords::Map { x, y, z } => Self::from([x, y, z]),
+        })
+    }
+}
+
 impl<'a> IntoIterator for &'a Coords {
     type Item = &'a f64;
 
@@ -57,6 +91,22 @@ impl AddAssign for Coords {
     }
 }
 
+impl Sub for Coords {
+    type Output = Coords;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self(self.0 - rhs.0)
+    }
+}
+
+impl Mul<f64> for Coords {
+    type Output = Coords;
+
+    fn mul(self, rhs: f64) -> Self::Output {
+        Self(self.0 * rhs)
+    }
+}
+
 impl Div<f64> for Coords {
     type Output = Coords;
 
@@ -128,6 +178,51 @@ impl Coords {
         self.0[2]
     }
 
+    /// Returns the [nalgebra] vector backing self.
+    ///
+    /// Coords cannot be converted into a [Vector3] through [From], since any type convertible
+    /// into a [Vector3] is already convertible into Coords.
+    ///
+    /// ## Example
+    /// ```
+    /// use globe_rs::cartesian::Coords;
+    /// use nalgebra::Vector3;
+    ///
+    /// let point = Coords::from([1., 2., 3.]);
+    /// assert_eq!(point.as_vector(), &Vector3::new(1., 2., 3.));
+    /// ```
+    pub fn as_vector(&self) -> &Vector3<f64> {
+        &self.0
+    }
+
+    /// Returns the components of self as an array of `[x, y, z]`.
+    ///
+    /// ## Example
+    /// ```
+    /// use globe_rs::cartesian::Coords;
+    ///
+    /// let point = Coords::from([1., 2., 3.]);
+    /// assert_eq!(point.to_array(), [1., 2., 3.]);
+    /// assert_eq!(Coords::from(point.to_array()), point);
+    /// ```
+    pub fn to_array(&self) -> [f64; 3] {
+        [self.x(), self.y(), self.z()]
+    }
+
+    /// Returns the components of self as an array of `[x, y, z]` in single precision, as most
+    /// rendering engines expect them.
+    ///
+    /// ## Example
+    /// ```
+    /// use globe_rs::cartesian::Coords;
+    ///
+    /// let point = Coords::from([1., 2., 3.]);
+    /// assert_eq!(point.to_f32_array(), [1_f32, 2., 3.]);
+    /// ```
+    pub fn to_f32_array(&self) -> [f32; 3] {
+        self.0.cast::<f32>().into()
+    }
+
     /// Returns the [Cartesian] representing the unitary vector of self.
     pub fn unit(&self) -> Self {
         self.0.normalize().into()
@@ -143,6 +238,33 @@ impl Coords {
         self.0.cross(&other.0).into()
     }
 
+    /// Performs the dot product between self and the given point.
+    pub fn dot(&self, rhs: &Coords) -> f64 {
+        self.0.dot(&rhs.0)
+    }
+
+    /// Returns the smallest angle between self and the given point, both as vectors from the
+    /// origin of coordinates, which is zero if any of them is the origin itself.
+    pub fn angle_between(&self, rhs: &Coords) -> Radian {
+        self.0.angle(&rhs.0).into()
+    }
+
+    /// Returns the projection of self onto the given point, as a vector from the origin of
+    /// coordinates, or the origin itself if so is the given point.
+    pub fn project_onto(&self, rhs: &Coords) -> Self {
+        let norm_squared = rhs.0.norm_squared();
+        if norm_squared == 0. {
+            return Self::default();
+        }
+
+        Self(rhs.0 * (self.dot(rhs) / norm_squared))
+    }
+
+    /// Returns the point at the given fraction of the straight line from self to the given point.
+    pub fn lerp(self, rhs: Coords, t: f64) -> Self {
+        self.0.lerp(&rhs.0, t).into()
+    }
+
     /// Returns the distance of the point relative to the origin of coordinates.
     pub fn magnitude(&self) -> f64 {
         self.0.magnitude()
@@ -161,8 +283,67 @@ mod tests {
     use crate::{
         cartesian::Coords,
         geographic::{self, Latitude, Longitude},
+        tests::approx_eq,
     };
 
+    #[test]
+    fn deserialize_must_not_fail() {
+        struct Test {
+            name: &'static str,
+            input: &'static str,
+            output: Option<Coords>,
+        }
+
+        vec![
+            Test {
+                name: "array of components",
+                input: "[1, -2.5, 3e8]",
+                output: Some(Coords::from([1., -2.5, 3e8])),
+            },
+            Test {
+                name: "map of components",
+                input: r#"{"z": 3, "x": 1, "y": -2.5}"#,
+                output: Some(Coords::from([1., -2.5, 3.])),
+            },
+            Test {
+                name: "too few components",
+                input: "[1, 2]",
+                output: None,
+            },
+            Test {
+                name: "too many components",
+                input: "[1, 2, 3, 4]",
+                output: None,
+            },
+            Test {
+                name: "map missing an axis",
+                input: r#"{"x": 1, "y": 2}"#,
+                output: None,
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let output = serde_json::from_str::<Coords>(test.input).ok();
+            assert_eq!(
+                output, test.output,
+                "{}: got coords = {output:?}, want {:?}",
+                test.name, test.output
+            );
+        });
+    }
+
+    #[test]
+    fn serde_must_round_trip() {
+        let coords = Coords::from([0.1, -2e-300, 6.02214076e23]);
+
+        let json = serde_json::to_string(&coords).unwrap();
+        assert_eq!(json, "[0.1,-2e-300,6.02214076e+23]");
+        assert_eq!(serde_json::from_str::<Coords>(&json).unwrap(), coords);
+
+        let ron = ron::to_string(&coords).unwrap();
+        assert_eq!(ron::from_str::<Coords>(&ron).unwrap(), coords);
+    }
+
     #[test]
     fn cartesian_from_geographic_must_not_fail() {
         struct Test {
@@ -255,4 +436,177 @@ mod tests {
             );
         })
     }
+
+    #[test]
+    fn dot_must_not_fail() {
+        struct Test {
+            name: &'static str,
+            lhs: Coords,
+            rhs: Coords,
+            output: f64,
+        }
+
+        vec![
+            Test {
+                name: "orthogonal vectors",
+                lhs: Coords::from([1., 0., 0.]),
+                rhs: Coords::from([0., 2., 0.]),
+                output: 0.,
+            },
+            Test {
+                name: "parallel vectors",
+                lhs: Coords::from([1., 2., 3.]),
+                rhs: Coords::from([2., 4., 6.]),
+                output: 28.,
+            },
+            Test {
+                name: "opposite vectors",
+                lhs: Coords::from([0., 0., 3.]),
+                rhs: Coords::from([0., 0., -2.]),
+                output: -6.,
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let dot = test.lhs.dot(&test.rhs);
+            assert_eq!(
+                dot, test.output,
+                "{}: got dot = {}, want {}",
+                test.name, dot, test.output
+            );
+        })
+    }
+
+    #[test]
+    fn angle_between_must_not_fail() {
+        struct Test {
+            name: &'static str,
+            lhs: Coords,
+            rhs: Coords,
+            output: f64,
+        }
+
+        vec![
+            Test {
+                name: "same direction",
+                lhs: Coords::from([1., 1., 0.]),
+                rhs: Coords::from([3., 3., 0.]),
+                output: 0.,
+            },
+            Test {
+                name: "orthogonal vectors",
+                lhs: Coords::from([1., 0., 0.]),
+                rhs: Coords::from([0., 0., 5.]),
+                output: FRAC_PI_2,
+            },
+            Test {
+                name: "opposite vectors",
+                lhs: Coords::from([0., 1., 0.]),
+                rhs: Coords::from([0., -1., 0.]),
+                output: PI,
+            },
+            Test {
+                name: "origin of coordinates",
+                lhs: Coords::default(),
+                rhs: Coords::from([0., -1., 0.]),
+                output: 0.,
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let angle = test.lhs.angle_between(&test.rhs).as_f64();
+            assert!(
+                approx_eq(angle, test.output, 1e-9),
+                "{}: got angle = {}, want {}",
+                test.name,
+                angle,
+                test.output
+            );
+        })
+    }
+
+    #[test]
+    fn project_onto_must_not_fail() {
+        struct Test {
+            name: &'static str,
+            lhs: Coords,
+            rhs: Coords,
+            output: Coords,
+        }
+
+        vec![
+            Test {
+                name: "onto an axis",
+                lhs: Coords::from([3., 4., 5.]),
+                rhs: Coords::from([0., 2., 0.]),
+                output: Coords::from([0., 4., 0.]),
+            },
+            Test {
+                name: "onto an opposite vector",
+                lhs: Coords::from([2., 0., 0.]),
+                rhs: Coords::from([-1., 0., 0.]),
+                output: Coords::from([2., 0., 0.]),
+            },
+            Test {
+                name: "onto an orthogonal vector",
+                lhs: Coords::from([0., 0., 1.]),
+                rhs: Coords::from([1., 0., 0.]),
+                output: Coords::default(),
+            },
+            Test {
+                name: "onto the origin of coordinates",
+                lhs: Coords::from([1., 2., 3.]),
+                rhs: Coords::default(),
+                output: Coords::default(),
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let projection = test.lhs.project_onto(&test.rhs);
+            assert_eq!(
+                projection, test.output,
+                "{}: got projection = {:?}, want {:?}",
+                test.name, projection, test.output
+            );
+        })
+    }
+
+    #[test]
+    fn lerp_must_not_fail() {
+        struct Test {
+            name: &'static str,
+            t: f64,
+            output: Coords,
+        }
+
+        let from = Coords::from([0., 2., -4.]);
+        let to = Coords::from([4., 2., 0.]);
+
+        vec![
+            Test {
+                name: "start of the line",
+                t: 0.,
+                output: from,
+            },
+            Test {
+                name: "middle of the line",
+                t: 0.5,
+                output: Coords::from([2., 2., -2.]),
+            },
+            Test {
+                name: "end of the line",
+                t: 1.,
+                output: to,
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let point = from.lerp(to, test.t);
+            assert_eq!(
+                point, test.output,
+                "{}: got point = {:?}, want {:?}",
+                test.name, point, test.output
+            );
+        })
+    }
 }
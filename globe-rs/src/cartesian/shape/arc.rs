@@ -5,13 +5,14 @@ use serde::{Deserialize, Serialize};
 use crate::{
     cartesian::{transform::Rotation, Coords},
     orbit::{Orbit, GRAVITATIONAL_CONSTANT},
-    Body, Distance, Radian, Velocity,
+    Body, Distance, Radian, Ratio, Velocity,
 };
 
 use super::{Sample, Shape};
 
 /// A circumference.
 #[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[serde(default)]
 pub struct Circle {
     /// The radius of the circle.
     pub radius: Distance,
@@ -78,12 +79,11 @@ impl Orbit for Circle {
         self.min_velocity(orbitee)
     }
 
-    fn velocity_at(&self, _: Duration, orbitee: &Body) -> Velocity {
+    fn velocity_at_theta(&self, _: Radian, orbitee: &Body) -> Velocity {
         self.min_velocity(orbitee)
     }
 
-    fn position_at(&self, time: Duration, orbitee: &Body) -> Coords {
-        let theta = self.theta_at(time, orbitee);
+    fn position_at_theta(&self, theta: Radian) -> Coords {
         let rotation = Rotation::default()
             .with_axis(Coords::default().with_z(1.))
             .with_theta(theta);
@@ -93,8 +93,9 @@ impl Orbit for Circle {
             .transform(rotation)
     }
 
-    fn theta_at(&self, mut time: Duration, orbitee: &Body) -> Radian {
-        let period = self.period(orbitee);
+    /// Being θ the radiants travelled in the given time, returns 2π - θ (mod 2π) if the circle
+    /// is clockwise.
+    fn theta_in_period(&self, mut time: Duration, period: Duration) -> Radian {
         time = Duration::from_secs_f64(time.as_secs_f64() % period.as_secs_f64());
 
         let theta = Radian::TWO_PI / period.as_secs_f64() * time.as_secs_f64();
@@ -128,6 +129,10 @@ impl Orbit for Circle {
     fn is_clockwise(&self) -> bool {
         self.clockwise
     }
+
+    fn eccentricity(&self) -> Ratio {
+        Ratio::default()
+    }
 }
 
 impl Circle {
@@ -136,8 +141,478 @@ impl Circle {
         self
     }
 
+    pub fn with_clockwise(mut self, clockwise: bool) -> Self {
+        self.clockwise = clockwise;
+        self
+    }
+
+    /// Returns the radiant, relative to the initial radiant and following the direction of the
+    /// circle, and the position (in meters), relative to the center of the circle, of the point of
+    /// the circle closest to the given one.
+    pub fn closest_point(&self, to: Coords) -> (Radian, Coords) {
+        let absolute = Radian::atan2(to.y(), to.x());
+        let rotation = Rotation::default()
+            .with_axis(Coords::default().with_z(1.))
+            .with_theta(absolute);
+
+        let theta = if self.clockwise {
+            self.initial_theta - absolute
+        } else {
+            absolute - self.initial_theta
+        };
+
+        (
+            theta,
+            Coords::default()
+                .with_x(self.radius.as_meters())
+                .transform(rotation),
+        )
+    }
+
     /// Returns the length of the arc.
     pub fn length(&self) -> Distance {
         self.radius * Radian::TWO_PI.as_f64()
     }
 }
+
+/// A portion of a circumference in an arbitrary plane of the space.
+///
+/// The arc begins at the start point, rotated by the initial radiant, and sweeps theta radiants
+/// around the axis, according to the right hand rule, keeping the same distance to the axis all
+/// along the way.
+#[derive(Debug, Clone, Copy)]
+pub struct Arc {
+    /// The center of the circumference the arc belongs to.
+    pub center: Coords,
+    /// The point at which the arc begins.
+    pub start: Coords,
+    /// The axis, passing through the center, about which the start point rotates.
+    pub axis: Coords,
+    /// The initial radiant of the arc, relative to the start point.
+    pub initial_theta: Radian,
+    /// The total radiants of the arc.
+    pub theta: Radian,
+}
+
+impl Default for Arc {
+    fn default() -> Self {
+        Self {
+            center: Default::default(),
+            start: Default::default(),
+            axis: Coords::default().with_z(1.),
+            initial_theta: Default::default(),
+            theta: Radian::TWO_PI,
+        }
+    }
+}
+
+impl Sample for Arc {
+    fn with_initial_theta(mut self, theta: Radian) -> Self {
+        self.initial_theta = theta;
+        self
+    }
+
+    /// Samples the arc into points equidistant from each other, including both ends of the arc,
+    /// unless it is a full circumference, in which case the end is omitted for being the same as
+    /// the start.
+    fn sample(&self, segments: usize) -> Shape {
+        if segments == 0 {
+            return Shape::default();
+        }
+
+        let vertices = if self.theta.is_full() {
+            segments
+        } else {
+            segments + 1
+        };
+
+        // rotating by a multiple of the same angle, instead of accumulating a rotation over the
+        // previous point, keeps the vertices equidistant despite the precision error.
+        let step = self.theta.as_f64() / segments as f64;
+        Shape {
+            points: (0..vertices)
+                .map(|vertex_index| self.point_at(step * vertex_index as f64))
+                .collect(),
+        }
+    }
+}
+
+impl Arc {
+    pub fn with_center(mut self, center: Coords) -> Self {
+        self.center = center;
+        self
+    }
+
+    pub fn with_start(mut self, start: Coords) -> Self {
+        self.start = start;
+        self
+    }
+
+    pub fn with_axis(mut self, axis: Coords) -> Self {
+        self.axis = axis;
+        self
+    }
+
+    pub fn with_theta(mut self, theta: Radian) -> Self {
+        self.theta = theta;
+        self
+    }
+
+    /// Returns the distance from the arc to its axis.
+    pub fn radius(&self) -> Distance {
+        let relative = self.start - self.center;
+        Distance::meters((relative - relative.project_onto(&self.axis)).magnitude())
+    }
+
+    /// Returns the length of the arc.
+    pub fn length(&self) -> Distance {
+        self.radius() * self.theta.as_f64()
+    }
+
+    /// Returns the point at which the arc begins, once rotated by the initial radiant.
+    pub fn begin(&self) -> Coords {
+        self.point_at(0.)
+    }
+
+    /// Returns the point at which the arc ends.
+    pub fn end(&self) -> Coords {
+        self.point_at(self.theta.as_f64())
+    }
+
+    /// Returns the point of the arc at the given radiants from its beginning.
+    fn point_at(&self, theta: f64) -> Coords {
+        let rotation = Rotation::default()
+            .with_axis(self.axis)
+            .with_theta(Radian::from(self.initial_theta.as_f64() + theta));
+
+        (self.start - self.center).transform(rotation) + self.center
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        f64::consts::{FRAC_PI_2, PI},
+        str::FromStr,
+    };
+
+    use alvidir::name::Name;
+
+    use crate::{
+        cartesian::{
+            shape::{Arc, Circle, Sample},
+            Coords,
+        },
+        tests::approx_eq,
+        Body, Distance, Mass, Orbit, Radian,
+    };
+
+    #[test]
+    fn position_at_must_follow_the_direction_of_the_circle() {
+        // durations are truncated to nanoseconds.
+        const ABS_ERROR: f64 = 1e-6;
+        const RADIUS: f64 = 1_000.;
+
+        let orbitee = Body::new(Name::from_str("orbitee").unwrap()).with_mass(Mass::kg(1e20));
+
+        struct Test {
+            name: &'static str,
+            clockwise: bool,
+            quarters: u32,
+            theta: Radian,
+            position: Coords,
+        }
+
+        vec![
+            Test {
+                name: "counter clockwise at the start",
+                clockwise: false,
+                quarters: 0,
+                theta: Radian::from(0.),
+                position: Coords::from([RADIUS, 0., 0.]),
+            },
+            Test {
+                name: "clockwise at the start",
+                clockwise: true,
+                quarters: 0,
+                theta: Radian::from(0.),
+                position: Coords::from([RADIUS, 0., 0.]),
+            },
+            Test {
+                name: "counter clockwise after a quarter",
+                clockwise: false,
+                quarters: 1,
+                theta: Radian::from(FRAC_PI_2),
+                position: Coords::from([0., RADIUS, 0.]),
+            },
+            Test {
+                name: "clockwise after a quarter",
+                clockwise: true,
+                quarters: 1,
+                theta: Radian::from(3. * FRAC_PI_2),
+                position: Coords::from([0., -RADIUS, 0.]),
+            },
+            Test {
+                name: "counter clockwise after half the period",
+                clockwise: false,
+                quarters: 2,
+                theta: Radian::from(PI),
+                position: Coords::from([-RADIUS, 0., 0.]),
+            },
+            Test {
+                name: "clockwise after half the period",
+                clockwise: true,
+                quarters: 2,
+                theta: Radian::from(PI),
+                position: Coords::from([-RADIUS, 0., 0.]),
+            },
+            Test {
+                name: "counter clockwise after three quarters",
+                clockwise: false,
+                quarters: 3,
+                theta: Radian::from(3. * FRAC_PI_2),
+                position: Coords::from([0., -RADIUS, 0.]),
+            },
+            Test {
+                name: "clockwise after three quarters",
+                clockwise: true,
+                quarters: 3,
+                theta: Radian::from(FRAC_PI_2),
+                position: Coords::from([0., RADIUS, 0.]),
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let circle = Circle::default()
+                .with_radius(Distance::meters(RADIUS))
+                .with_clockwise(test.clockwise);
+
+            let time = circle.period(&orbitee).mul_f64(test.quarters as f64 / 4.);
+
+            let theta = circle.theta_at(time, &orbitee);
+            assert!(
+                approx_eq(theta.as_f64(), test.theta.as_f64(), ABS_ERROR),
+                "{}: got theta = {theta:?}, want {:?}",
+                test.name,
+                test.theta
+            );
+
+            let position = circle.position_at(time, &orbitee);
+            assert!(
+                position.distance(&test.position) <= RADIUS * ABS_ERROR,
+                "{}: got position = {position:?}, want ± e = {:?}",
+                test.name,
+                test.position
+            );
+
+            let velocity = circle.velocity_at(time, &orbitee);
+            assert_eq!(
+                velocity,
+                circle.min_velocity(&orbitee),
+                "{}: the velocity must not depend on the direction",
+                test.name
+            );
+        });
+    }
+
+    #[test]
+    fn closest_point_must_not_fail() {
+        const ABS_ERROR: f64 = 0.000000000000001;
+
+        struct Test {
+            name: &'static str,
+            circle: Circle,
+            to: Coords,
+            theta: f64,
+            closest: Coords,
+        }
+
+        let circle = Circle::default().with_radius(Distance::meters(2.));
+
+        vec![
+            Test {
+                name: "point outside the circle",
+                circle,
+                to: Coords::from([0., 4., 0.]),
+                theta: FRAC_PI_2,
+                closest: Coords::from([0., 2., 0.]),
+            },
+            Test {
+                name: "point inside the circle",
+                circle,
+                to: Coords::from([-1., 0., 0.]),
+                theta: PI,
+                closest: Coords::from([-2., 0., 0.]),
+            },
+            Test {
+                name: "center of the circle must not fail",
+                circle,
+                to: Coords::default(),
+                theta: 0.,
+                closest: Coords::from([2., 0., 0.]),
+            },
+            Test {
+                name: "radiant must be relative to the initial one",
+                circle: circle.with_initial_theta(Radian::from(FRAC_PI_2)),
+                to: Coords::from([-4., 0., 0.]),
+                theta: FRAC_PI_2,
+                closest: Coords::from([-2., 0., 0.]),
+            },
+            Test {
+                name: "radiant must follow the direction of the circle",
+                circle: circle
+                    .with_initial_theta(Radian::from(FRAC_PI_2))
+                    .with_clockwise(true),
+                to: Coords::from([4., 0., 0.]),
+                theta: FRAC_PI_2,
+                closest: Coords::from([2., 0., 0.]),
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let (theta, closest) = test.circle.closest_point(test.to);
+
+            assert!(
+                approx_eq(theta.as_f64(), test.theta, ABS_ERROR),
+                "{}: got theta = {:?}, want {}",
+                test.name,
+                theta,
+                test.theta
+            );
+
+            closest
+                .into_iter()
+                .zip(&test.closest)
+                .for_each(|(&got, &want)| {
+                    assert!(
+                        approx_eq(got, want, ABS_ERROR),
+                        "{}: got closest = {:?}, want {:?}",
+                        test.name,
+                        closest,
+                        test.closest
+                    );
+                });
+        });
+    }
+
+    #[test]
+    fn arc_sample_must_not_fail() {
+        const ABS_ERROR: f64 = 1e-12;
+
+        struct Test {
+            name: &'static str,
+            arc: Arc,
+            segments: usize,
+            output: Vec<Coords>,
+        }
+
+        vec![
+            Test {
+                name: "no segments",
+                arc: Arc::default().with_start(Coords::from([1., 0., 0.])),
+                segments: 0,
+                output: vec![],
+            },
+            Test {
+                name: "full circumference must omit the end",
+                arc: Arc::default().with_start(Coords::from([1., 0., 0.])),
+                segments: 4,
+                output: vec![
+                    Coords::from([1., 0., 0.]),
+                    Coords::from([0., 1., 0.]),
+                    Coords::from([-1., 0., 0.]),
+                    Coords::from([0., -1., 0.]),
+                ],
+            },
+            Test {
+                name: "half circumference must include both ends",
+                arc: Arc::default()
+                    .with_start(Coords::from([1., 0., 0.]))
+                    .with_theta(Radian::from(PI)),
+                segments: 2,
+                output: vec![
+                    Coords::from([1., 0., 0.]),
+                    Coords::from([0., 1., 0.]),
+                    Coords::from([-1., 0., 0.]),
+                ],
+            },
+            Test {
+                name: "quarter in an arbitrary plane around a displaced center",
+                arc: Arc::default()
+                    .with_center(Coords::from([1., 1., 1.]))
+                    .with_start(Coords::from([1., 3., 1.]))
+                    .with_axis(Coords::from([1., 0., 0.]))
+                    .with_theta(Radian::from(FRAC_PI_2)),
+                segments: 1,
+                output: vec![Coords::from([1., 3., 1.]), Coords::from([1., 1., 3.])],
+            },
+            Test {
+                name: "initial theta must displace the beginning",
+                arc: Arc::default()
+                    .with_start(Coords::from([1., 0., 0.]))
+                    .with_theta(Radian::from(FRAC_PI_2))
+                    .with_initial_theta(Radian::from(PI)),
+                segments: 1,
+                output: vec![Coords::from([-1., 0., 0.]), Coords::from([0., -1., 0.])],
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let shape = test.arc.sample(test.segments);
+            assert_eq!(
+                shape.points.len(),
+                test.output.len(),
+                "{}: got {} points, want {}",
+                test.name,
+                shape.points.len(),
+                test.output.len()
+            );
+
+            shape
+                .points
+                .iter()
+                .zip(&test.output)
+                .for_each(|(got, want)| {
+                    assert!(
+                        got.distance(want) <= ABS_ERROR,
+                        "{}: got points = {:?}, want ± e = {:?}",
+                        test.name,
+                        shape.points,
+                        test.output
+                    );
+                });
+        });
+    }
+
+    #[test]
+    fn arc_must_be_equidistant() {
+        const SEGMENTS: usize = 100;
+
+        let arc = Arc::default()
+            .with_center(Coords::from([5., -3., 2.]))
+            .with_start(Coords::from([8., -3., 6.]))
+            .with_axis(Coords::from([1., 1., 1.]))
+            .with_theta(Radian::from(2.5));
+
+        let shape = arc.sample(SEGMENTS);
+        let length = arc.length().as_meters();
+
+        assert!(
+            approx_eq(shape.length().as_meters(), length, length * 1e-3),
+            "got sampled length = {:?}, want ± 0.1% = {length}",
+            shape.length()
+        );
+
+        let step = shape.points[0].distance(&shape.points[1]);
+        shape.points.windows(2).for_each(|segment| {
+            let distance = segment[0].distance(&segment[1]);
+            assert!(
+                approx_eq(distance, step, 1e-9),
+                "got segment length = {distance}, want ± e = {step}"
+            );
+        });
+
+        assert_eq!(shape.points.first(), Some(&arc.begin()));
+        assert_eq!(shape.points.last(), Some(&arc.end()));
+    }
+}
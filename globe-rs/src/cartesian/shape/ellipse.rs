@@ -1,4 +1,7 @@
-use std::{f64::consts::PI, time::Duration};
+use std::{
+    f64::consts::{FRAC_1_SQRT_2, FRAC_PI_2, PI},
+    time::Duration,
+};
 
 use serde::{Deserialize, Serialize};
 
@@ -8,10 +11,13 @@ use super::{Sample, Shape};
 
 /// An ellipse.
 #[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[serde(default)]
 pub struct Ellipse {
     /// The semi-major axis of the ellipse.
     pub semi_major_axis: Distance,
-    /// The eccentricity of the ellipse.
+    /// The eccentricity of the ellipse, which must be lower than 1 for the orbit to be closed.
+    ///
+    /// Hyperbolic trajectories are not supported, hence the [Ratio] instead of an unbounded value.
     pub eccentricity: Ratio,
     /// The initial radiant of the ellipse.
     pub initial_theta: Radian,
@@ -43,14 +49,7 @@ impl Sample for Ellipse {
         Shape {
             points: (0..segments)
                 .map(|vertex_index| self.theta / segments as f64 * vertex_index as f64)
-                .map(|theta| {
-                    if self.clockwise {
-                        self.initial_theta - theta
-                    } else {
-                        self.initial_theta + theta
-                    }
-                })
-                .map(|theta| self.position(theta))
+                .map(|theta| self.position(self.relative_theta(theta)))
                 .collect(),
         }
     }
@@ -62,32 +61,31 @@ impl Orbit for Ellipse {
     }
 
     fn max_velocity(&self, orbitee: &Body) -> Velocity {
+        // the periapsis, since the linear eccentricity never exceeds the semi-major axis
         self.velocity(
-            self.linear_eccentricity().abs_diff(self.semi_major_axis),
+            self.semi_major_axis
+                .saturating_sub(self.linear_eccentricity()),
             orbitee,
         )
     }
 
     /// Assumes the central body is located on the right foci of the ellipse.
-    fn velocity_at(&self, mut time: Duration, orbitee: &Body) -> Velocity {
-        time = Duration::from_secs_f64(time.as_secs_f64() % self.period(orbitee).as_secs_f64());
-
+    fn velocity_at_theta(&self, theta: Radian, orbitee: &Body) -> Velocity {
         let radius = Coords::default()
             .with_x(self.linear_eccentricity().as_meters())
-            .distance(&self.position_at(time, orbitee));
+            .distance(&self.position(theta));
 
         self.velocity(Distance::meters(radius), orbitee)
     }
 
-    fn position_at(&self, time: Duration, orbitee: &Body) -> Coords {
-        self.position(self.theta_at(time, orbitee))
+    fn position_at_theta(&self, theta: Radian) -> Coords {
+        self.position(theta)
     }
 
-    fn theta_at(&self, mut time: Duration, orbitee: &Body) -> Radian {
-        time = Duration::from_secs_f64(time.as_secs_f64() % self.period(orbitee).as_secs_f64());
+    fn theta_in_period(&self, mut time: Duration, period: Duration) -> Radian {
+        time = Duration::from_secs_f64(time.as_secs_f64() % period.as_secs_f64());
 
-        let mean_anomaly =
-            Radian::TWO_PI.as_f64() / self.period(orbitee).as_secs_f64() * time.as_secs_f64();
+        let mean_anomaly = Radian::TWO_PI.as_f64() / period.as_secs_f64() * time.as_secs_f64();
 
         let mut eccentric_anomaly = if self.eccentricity.as_f64() < 0.8 {
             mean_anomaly
@@ -105,10 +103,10 @@ impl Orbit for Ellipse {
             eccentric_anomaly -= f / f_prime;
         }
 
-        let theta = Radian::from(
-            2.0 * ((1.0 + self.eccentricity.as_f64()).sqrt() * (eccentric_anomaly / 2.0).sin())
-                .atan2((1.0 - self.eccentricity.as_f64()).sqrt() * (eccentric_anomaly / 2.0).cos()),
-        );
+        let theta = Radian::atan2(
+            (1.0 + self.eccentricity.as_f64()).sqrt() * (eccentric_anomaly / 2.0).sin(),
+            (1.0 - self.eccentricity.as_f64()).sqrt() * (eccentric_anomaly / 2.0).cos(),
+        ) * 2.;
 
         if self.clockwise {
             return -theta;
@@ -125,17 +123,35 @@ impl Orbit for Ellipse {
         )
     }
 
+    /// Computes the perimeter of the ellipse through the [arithmetic-geometric
+    /// mean](https://en.wikipedia.org/wiki/Arithmetic%E2%80%93geometric_mean#Complete_elliptic_integral_E(sin_%CE%B1)),
+    /// which converges quadratically to the exact value of the elliptic integral.
     fn perimeter(&self) -> Distance {
-        let a = self.semi_major_axis;
-        let b = self.semi_minor_axis();
-        let h = (a.abs_diff(b).as_meters() / (a + b).as_meters()).powi(2);
-
-        Distance::meters(
-            PI * (a + b).as_meters()
-                * (1.
-                    + 3. * h / (10. + (4. - 3. * h).sqrt())
-                    + ((4. / PI - 14. / 11.) * h.powi(12))),
-        )
+        let a = self.semi_major_axis.as_meters();
+        let b = self.semi_minor_axis().as_meters();
+
+        if b == 0. {
+            // a degenerated ellipse is a segment travelled back and forth.
+            return Distance::meters(4. * a);
+        }
+
+        let (mut arithmetic, mut geometric) = (a, b);
+        // the sum of 2^(n-1) * c_n^2, being c_0^2 = a^2 - b^2 and c_n half the difference
+        // between the previous means.
+        let (mut weight, mut sum) = (0.5, 0.5 * (a.powi(2) - b.powi(2)));
+
+        while arithmetic - geometric > arithmetic * f64::EPSILON {
+            let half_diff = (arithmetic - geometric) / 2.;
+            (arithmetic, geometric) = (
+                (arithmetic + geometric) / 2.,
+                (arithmetic * geometric).sqrt(),
+            );
+
+            weight *= 2.;
+            sum += weight * half_diff.powi(2);
+        }
+
+        Distance::meters(2. * PI * (a.powi(2) - sum) / arithmetic)
     }
 
     fn focus(&self) -> Coords {
@@ -149,6 +165,10 @@ impl Orbit for Ellipse {
     fn is_clockwise(&self) -> bool {
         self.clockwise
     }
+
+    fn eccentricity(&self) -> Ratio {
+        self.eccentricity
+    }
 }
 
 impl Ellipse {
@@ -175,8 +195,152 @@ impl Ellipse {
     /// Return the position (in meters) of the given theta.
     pub fn position(&self, theta: Radian) -> Coords {
         Coords::default()
-            .with_x(self.semi_major_axis.as_meters() * theta.as_f64().cos())
-            .with_y(self.semi_minor_axis().as_meters() * theta.as_f64().sin())
+            .with_x(self.semi_major_axis.as_meters() * theta.cos())
+            .with_y(self.semi_minor_axis().as_meters() * theta.sin())
+    }
+
+    /// Returns the radiant and position (in meters), relative to the center of the ellipse, of
+    /// the point of the ellipse closest to the given one.
+    ///
+    /// Returns None if the ellipse is degenerate, which is having a semi-minor axis of zero, since
+    /// the iteration would divide by it.
+    pub fn closest_point(&self, to: Coords) -> Option<(Radian, Coords)> {
+        const ITERATIONS: usize = 8;
+
+        let a = self.semi_major_axis.as_meters();
+        let b = self.semi_minor_axis().as_meters();
+
+        if b == 0. {
+            return None;
+        }
+
+        // by symmetry, the closest point is in the same quadrant as the given one.
+        let px = to.x().abs();
+        let py = to.y().abs();
+
+        let (mut tx, mut ty) = (FRAC_1_SQRT_2, FRAC_1_SQRT_2);
+        for _ in 0..ITERATIONS {
+            // the center of curvature of the ellipse at the current approximation
+            let ex = (a.powi(2) - b.powi(2)) * tx.powi(3) / a;
+            let ey = (b.powi(2) - a.powi(2)) * ty.powi(3) / b;
+
+            let r = (a * tx - ex).hypot(b * ty - ey);
+            let q = (px - ex).hypot(py - ey);
+            if q == 0. {
+                break;
+            }
+
+            tx = ((px - ex) * r / q + ex) / a;
+            ty = ((py - ey) * r / q + ey) / b;
+            tx = tx.clamp(0., 1.);
+            ty = ty.clamp(0., 1.);
+
+            let t = tx.hypot(ty);
+            tx /= t;
+            ty /= t;
+        }
+
+        let theta = Radian::atan2(ty.copysign(to.y()), tx.copysign(to.x()));
+        Some((theta, self.position(theta)))
+    }
+
+    /// Returns the unit vector tangent to the ellipse at the given theta, relative to the initial
+    /// theta, pointing towards the direction of the ellipse.
+    pub fn tangent_at(&self, theta: Radian) -> Coords {
+        let theta = self.relative_theta(theta);
+        let tangent = Coords::default()
+            .with_x(-self.semi_major_axis.as_meters() * theta.sin())
+            .with_y(self.semi_minor_axis().as_meters() * theta.cos())
+            .unit();
+
+        if self.clockwise {
+            return -tangent;
+        }
+
+        tangent
+    }
+
+    /// Returns the unit vector normal to the ellipse at the given theta, relative to the initial
+    /// theta, pointing outwards the ellipse.
+    pub fn normal_at(&self, theta: Radian) -> Coords {
+        let theta = self.relative_theta(theta);
+        Coords::default()
+            .with_x(self.semi_minor_axis().as_meters() * theta.cos())
+            .with_y(self.semi_major_axis.as_meters() * theta.sin())
+            .unit()
+    }
+
+    /// Returns the absolute radiant corresponding to the given theta, relative to the initial
+    /// theta and following the direction of the ellipse.
+    fn relative_theta(&self, theta: Radian) -> Radian {
+        if self.clockwise {
+            self.initial_theta - theta
+        } else {
+            self.initial_theta + theta
+        }
+    }
+
+    /// Samples the ellipse as a discrete set of [Coords] whose chords deviate from the ellipse no
+    /// more than the given distance, placing more points where the ellipse bends the most.
+    ///
+    /// Distances smaller than a billionth of the semi-major axis, including zero and NaN, are
+    /// taken as that minimum, since no finite amount of points could satisfy them.
+    pub fn sample_adaptive(&self, max_chord_error: Distance) -> Shape {
+        const MIN_RELATIVE_ERROR: f64 = 1e-9;
+        const MAX_STEP: f64 = FRAC_PI_2;
+        const SHRINK_FACTOR: f64 = 0.8;
+        const MAX_SHRINKS: usize = 32;
+
+        let a = self.semi_major_axis.as_meters();
+        let b = self.semi_minor_axis().as_meters();
+
+        // max returns the non-NaN operand, if any.
+        let tolerance = max_chord_error.as_meters().max(a * MIN_RELATIVE_ERROR);
+        let total = self.theta.as_f64();
+
+        let position = |offset: f64| self.position(self.relative_theta(offset.into()));
+        let chord_error = |from: f64, to: f64| {
+            let start = position(from);
+            let chord = position(to) + -start;
+            let deviation = position((from + to) / 2.) + -start;
+
+            if chord.magnitude() == 0. {
+                deviation.magnitude()
+            } else {
+                deviation.cross(&chord).magnitude() / chord.magnitude()
+            }
+        };
+
+        let mut points = Vec::new();
+        let mut from = 0.;
+        while from < total {
+            points.push(position(from));
+
+            // the chord error of a step h is about h²/8 times the normal component of the second
+            // derivative of the ellipse, which is ab over the speed at which it is traversed.
+            let absolute = self.relative_theta(from.into()).as_f64();
+            let speed =
+                (a.powi(2) * absolute.sin().powi(2) + b.powi(2) * absolute.cos().powi(2)).sqrt();
+
+            let mut step = (8. * tolerance * speed / (a * b)).sqrt();
+            if !step.is_finite() || step > MAX_STEP {
+                step = MAX_STEP;
+            }
+
+            // the curvature may grow along the step, hence it gets shorter until satisfying the
+            // tolerance.
+            for _ in 0..MAX_SHRINKS {
+                if chord_error(from, (from + step).min(total)) <= tolerance {
+                    break;
+                }
+
+                step *= SHRINK_FACTOR;
+            }
+
+            from += step;
+        }
+
+        Shape { points }
     }
 
     fn velocity(&self, radius: Distance, orbitee: &Body) -> Velocity {
@@ -187,3 +351,509 @@ impl Ellipse {
         )
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::{f64::consts::PI, str::FromStr};
+
+    use alvidir::name::Name;
+
+    use crate::{
+        cartesian::{
+            shape::{Ellipse, Sample, Shape},
+            Coords,
+        },
+        tests::approx_eq,
+        Body, Distance, Luminosity, Mass, Orbit, Radian, Ratio,
+    };
+
+    /// Returns the maximum distance between the ellipse and the chords of the given shape.
+    fn max_chord_error(ellipse: &Ellipse, shape: &Shape) -> f64 {
+        const STEPS: usize = 16;
+
+        let theta_of = |point: &Coords| {
+            Radian::from(
+                (point.y() / ellipse.semi_minor_axis().as_meters())
+                    .atan2(point.x() / ellipse.semi_major_axis.as_meters()),
+            )
+            .as_f64()
+        };
+
+        shape
+            .points
+            .iter()
+            .zip(shape.points.iter().cycle().skip(1))
+            .map(|(start, end)| {
+                let from = theta_of(start);
+                let mut to = theta_of(end);
+                if to <= from {
+                    to += Radian::TWO_PI.as_f64();
+                }
+
+                let chord = *end + -*start;
+                (1..STEPS)
+                    .map(|step| from + (to - from) * step as f64 / STEPS as f64)
+                    .map(|theta| ellipse.position(theta.into()) + -*start)
+                    .map(|deviation| deviation.cross(&chord).magnitude() / chord.magnitude())
+                    .fold(0., f64::max)
+            })
+            .fold(0., f64::max)
+    }
+
+    #[test]
+    fn perimeter_must_match_numerical_integration() {
+        // the trapezoidal rule converges exponentially for smooth periodic functions, such as the
+        // speed along the ellipse, which makes it a reliable reference even for high
+        // eccentricities. The tolerance covers the rounding error of adding up all its steps.
+        const STEPS: usize = 100_000;
+        const REL_ERROR: f64 = 1e-12;
+
+        let integrated_perimeter = |a: f64, b: f64| {
+            let step = 2. * PI / STEPS as f64;
+            (0..STEPS)
+                .map(|index| index as f64 * step)
+                .map(|t| (a.powi(2) * t.sin().powi(2) + b.powi(2) * t.cos().powi(2)).sqrt())
+                .sum::<f64>()
+                * step
+        };
+
+        [0., 0.3, 0.6, 0.9, 0.99]
+            .into_iter()
+            .for_each(|eccentricity| {
+                let ellipse = Ellipse::default()
+                    .with_semi_major_axis(Distance::km(1_000.))
+                    .with_eccentricity(Ratio::try_new(eccentricity).unwrap());
+
+                let perimeter = ellipse.perimeter().as_meters();
+                let want = integrated_perimeter(
+                    ellipse.semi_major_axis.as_meters(),
+                    ellipse.semi_minor_axis().as_meters(),
+                );
+
+                assert!(
+                    approx_eq(perimeter, want, want * REL_ERROR),
+                    "e = {eccentricity}: got perimeter = {perimeter}, want {want}"
+                );
+            });
+    }
+
+    #[test]
+    fn apsides_must_be_the_closest_and_farthest_points_to_the_orbitee() {
+        const REL_ERROR: f64 = 1e-12;
+
+        [0., 0.3, 0.6, 0.9, 0.99]
+            .into_iter()
+            .for_each(|eccentricity| {
+                let ellipse = Ellipse::default()
+                    .with_semi_major_axis(Distance::km(1_000.))
+                    .with_eccentricity(Ratio::try_new(eccentricity).unwrap());
+
+                let a = ellipse.semi_major_axis.as_meters();
+                [
+                    ("periapsis", ellipse.periapsis(), a * (1. - eccentricity)),
+                    ("apoapsis", ellipse.apoapsis(), a * (1. + eccentricity)),
+                ]
+                .into_iter()
+                .for_each(|(apsis, position, want)| {
+                    let got = (position.unwrap() + ellipse.focus()).magnitude();
+                    assert!(
+                        approx_eq(got, want, want * REL_ERROR),
+                        "e = {eccentricity}: got {apsis} distance = {got}, want {want}"
+                    );
+                });
+            });
+    }
+
+    #[test]
+    fn sample_by_time_must_be_sparser_at_periapsis() {
+        const SEGMENTS: usize = 64;
+
+        let orbitee = Body {
+            name: Name::from_str("orbitee").unwrap(),
+            radius: Distance::km(1.),
+            spin: Default::default(),
+            mass: Mass::kg(5.97219e24),
+            luminosity: Luminosity::ZERO,
+        };
+
+        let ellipse = Ellipse::default()
+            .with_semi_major_axis(Distance::km(10_000.))
+            .with_eccentricity(Ratio::from(0.8));
+
+        let points = ellipse.sample_by_time(SEGMENTS, &orbitee).points;
+        assert_eq!(points.len(), SEGMENTS);
+
+        let spacing: Vec<f64> = points
+            .iter()
+            .zip(points.iter().cycle().skip(1))
+            .map(|(from, to)| from.distance(to))
+            .collect();
+
+        let widest = spacing
+            .iter()
+            .enumerate()
+            .max_by(|(_, a), (_, b)| a.total_cmp(b))
+            .map(|(index, _)| index)
+            .unwrap();
+
+        let narrowest = spacing
+            .iter()
+            .enumerate()
+            .min_by(|(_, a), (_, b)| a.total_cmp(b))
+            .map(|(index, _)| index)
+            .unwrap();
+
+        assert!(
+            widest == 0 || widest == SEGMENTS - 1,
+            "got widest segment at index {widest}, want it around the periapsis"
+        );
+
+        assert!(
+            narrowest.abs_diff(SEGMENTS / 2) <= 1,
+            "got narrowest segment at index {narrowest}, want it around the apoapsis"
+        );
+    }
+
+    #[test]
+    fn sample_adaptive_must_be_more_precise_than_uniform_sample() {
+        const MAX_CHORD_ERROR: f64 = 300.;
+
+        let ellipse = Ellipse::default()
+            .with_semi_major_axis(Distance::km(10_000.))
+            .with_eccentricity(Ratio::from(0.95));
+
+        let adaptive = ellipse.sample_adaptive(Distance::meters(MAX_CHORD_ERROR));
+        let uniform = ellipse.sample(adaptive.points.len());
+
+        let adaptive_error = max_chord_error(&ellipse, &adaptive);
+        let uniform_error = max_chord_error(&ellipse, &uniform);
+
+        assert!(
+            adaptive_error <= MAX_CHORD_ERROR,
+            "got adaptive error = {adaptive_error}, want no greater than {MAX_CHORD_ERROR}"
+        );
+
+        assert!(
+            adaptive_error < uniform_error,
+            "got adaptive error = {adaptive_error}, want less than uniform error = {uniform_error} for {} points",
+            adaptive.points.len()
+        );
+    }
+
+    #[test]
+    fn sample_adaptive_must_spend_its_points_better_than_uniform_sample() {
+        const MAX_CHORD_ERROR: f64 = 300.;
+        const ADAPTIVE_POINTS: usize = 300;
+        const UNIFORM_POINTS: usize = 1024;
+
+        let ellipse = Ellipse::default()
+            .with_semi_major_axis(Distance::km(10_000.))
+            .with_eccentricity(Ratio::from(0.95));
+
+        let adaptive = ellipse.sample_adaptive(Distance::meters(MAX_CHORD_ERROR));
+        assert!(
+            adaptive.points.len().abs_diff(ADAPTIVE_POINTS) <= ADAPTIVE_POINTS / 10,
+            "got {} adaptive points, want about {ADAPTIVE_POINTS}",
+            adaptive.points.len()
+        );
+
+        let uniform = ellipse.sample(UNIFORM_POINTS);
+
+        let adaptive_error = max_chord_error(&ellipse, &adaptive);
+        let uniform_error = max_chord_error(&ellipse, &uniform);
+
+        // the uniform sample already steps by the eccentric anomaly, which bends evenly enough
+        // for ~300 points to never beat 1024 of them. Instead, since the chord error decreases
+        // with the square of the amount of points, both errors get scaled to the same amount.
+        let scale = |error: f64, points: usize| error * (points as f64).powi(2);
+
+        assert!(
+            scale(adaptive_error, adaptive.points.len()) < scale(uniform_error, UNIFORM_POINTS),
+            "got adaptive error = {adaptive_error} for {} points, want less than uniform error = {uniform_error} for {UNIFORM_POINTS} points once scaled to the same amount",
+            adaptive.points.len()
+        );
+    }
+
+    #[test]
+    fn sample_adaptive_must_require_fewer_points_than_uniform_sample() {
+        const UNIFORM_POINTS: usize = 1024;
+
+        let ellipse = Ellipse::default()
+            .with_semi_major_axis(Distance::km(10_000.))
+            .with_eccentricity(Ratio::from(0.999));
+
+        let uniform = ellipse.sample(UNIFORM_POINTS);
+        let uniform_error = max_chord_error(&ellipse, &uniform);
+
+        let adaptive = ellipse.sample_adaptive(Distance::meters(uniform_error));
+        let adaptive_error = max_chord_error(&ellipse, &adaptive);
+
+        assert!(
+            adaptive_error <= uniform_error,
+            "got adaptive error = {adaptive_error}, want no greater than uniform error = {uniform_error}"
+        );
+
+        assert!(
+            adaptive.points.len() < UNIFORM_POINTS / 2,
+            "got {} adaptive points, want less than half of {UNIFORM_POINTS} for the same error",
+            adaptive.points.len()
+        );
+    }
+
+    #[test]
+    fn sample_adaptive_must_end_for_any_tolerance() {
+        struct Test {
+            name: &'static str,
+            max_chord_error: Distance,
+            max_points: usize,
+        }
+
+        vec![
+            Test {
+                name: "zero tolerance",
+                max_chord_error: Distance::ZERO,
+                max_points: 1_000_000,
+            },
+            Test {
+                name: "infinite tolerance",
+                max_chord_error: Distance::meters(f64::INFINITY),
+                max_points: 4,
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let ellipse = Ellipse::default()
+                .with_semi_major_axis(Distance::km(10_000.))
+                .with_eccentricity(Ratio::from(0.5));
+
+            let points = ellipse.sample_adaptive(test.max_chord_error).points.len();
+            assert!(
+                (1..=test.max_points).contains(&points),
+                "{}: got {points} points, want between 1 and {}",
+                test.name,
+                test.max_points
+            );
+        });
+    }
+
+    #[test]
+    fn tangent_must_be_perpendicular_to_normal() {
+        const REL_ERROR: f64 = 1e-9;
+        const SEGMENTS: usize = 64;
+
+        [false, true].into_iter().for_each(|clockwise| {
+            let ellipse = Ellipse {
+                clockwise,
+                initial_theta: Radian::from(1.),
+                ..Default::default()
+            }
+            .with_semi_major_axis(Distance::km(10_000.))
+            .with_eccentricity(Ratio::from(0.6));
+
+            (0..SEGMENTS)
+                .map(|vertex_index| Radian::TWO_PI / SEGMENTS as f64 * vertex_index as f64)
+                .for_each(|theta| {
+                    let tangent = ellipse.tangent_at(theta);
+                    let normal = ellipse.normal_at(theta);
+                    let dot = tangent.x() * normal.x() + tangent.y() * normal.y();
+                    let tolerance = REL_ERROR * tangent.magnitude() * normal.magnitude();
+
+                    assert!(
+                        approx_eq(dot, 0., tolerance),
+                        "got tangent · normal = {dot} at {theta:?}, want 0"
+                    );
+                });
+        });
+    }
+
+    #[test]
+    fn tangent_must_follow_the_direction_of_the_ellipse() {
+        const REL_ERROR: f64 = 1e-9;
+
+        struct Test {
+            name: &'static str,
+            ellipse: Ellipse,
+            theta: Radian,
+            tangent: Coords,
+            normal: Coords,
+        }
+
+        let ellipse = Ellipse::default()
+            .with_semi_major_axis(Distance::km(10_000.))
+            .with_eccentricity(Ratio::from(0.6));
+
+        vec![
+            Test {
+                name: "counter clockwise ellipse at periapsis must point upwards",
+                ellipse,
+                theta: Radian::default(),
+                tangent: Coords::from([0., 1., 0.]),
+                normal: Coords::from([1., 0., 0.]),
+            },
+            Test {
+                name: "clockwise ellipse at periapsis must point downwards",
+                ellipse: Ellipse {
+                    clockwise: true,
+                    ..ellipse
+                },
+                theta: Radian::default(),
+                tangent: Coords::from([0., -1., 0.]),
+                normal: Coords::from([1., 0., 0.]),
+            },
+            Test {
+                name: "initial theta must be taken into account",
+                ellipse: ellipse.with_initial_theta(Radian::from(PI)),
+                theta: Radian::default(),
+                tangent: Coords::from([0., -1., 0.]),
+                normal: Coords::from([-1., 0., 0.]),
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let tangent = test.ellipse.tangent_at(test.theta);
+            let normal = test.ellipse.normal_at(test.theta);
+
+            assert!(
+                tangent.distance(&test.tangent) <= REL_ERROR * test.tangent.magnitude(),
+                "{}: got tangent = {:?}, want {:?}",
+                test.name,
+                tangent,
+                test.tangent
+            );
+
+            assert!(
+                normal.distance(&test.normal) <= REL_ERROR * test.normal.magnitude(),
+                "{}: got normal = {:?}, want {:?}",
+                test.name,
+                normal,
+                test.normal
+            );
+        });
+    }
+
+    #[test]
+    fn closest_point_must_not_fail() {
+        const ABS_ERROR: f64 = 0.000001;
+
+        struct Test {
+            name: &'static str,
+            to: Coords,
+            closest: Coords,
+        }
+
+        let ellipse = Ellipse::default()
+            .with_semi_major_axis(Distance::meters(5.))
+            .with_eccentricity(Ratio::from(0.8));
+
+        vec![
+            Test {
+                name: "point on the ellipse must be itself",
+                to: ellipse.position(Radian::from(1.)),
+                closest: ellipse.position(Radian::from(1.)),
+            },
+            Test {
+                name: "point outside the ellipse on the major axis",
+                to: Coords::from([10., 0., 0.]),
+                closest: Coords::from([5., 0., 0.]),
+            },
+            Test {
+                name: "point outside the ellipse on the minor axis",
+                to: Coords::from([0., -10., 0.]),
+                closest: Coords::from([0., -3., 0.]),
+            },
+            Test {
+                name: "point inside the ellipse on the minor axis",
+                to: Coords::from([0., 1., 0.]),
+                closest: Coords::from([0., 3., 0.]),
+            },
+            Test {
+                name: "center of the ellipse must be at the minor axis",
+                to: Coords::default(),
+                closest: Coords::from([0., 3., 0.]),
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let (theta, closest) = ellipse.closest_point(test.to).unwrap();
+
+            assert!(
+                closest.distance(&test.closest) <= ABS_ERROR,
+                "{}: got closest = {:?}, want {:?}",
+                test.name,
+                closest,
+                test.closest
+            );
+
+            assert!(
+                ellipse.position(theta).distance(&closest) <= ABS_ERROR,
+                "{}: got theta = {:?} for closest = {:?}",
+                test.name,
+                theta,
+                closest
+            );
+        });
+    }
+
+    #[test]
+    fn closest_point_must_reject_degenerate_ellipses() {
+        struct Test {
+            name: &'static str,
+            ellipse: Ellipse,
+        }
+
+        vec![
+            Test {
+                name: "zero semi-major axis",
+                ellipse: Ellipse::default(),
+            },
+            Test {
+                name: "eccentricity of one",
+                ellipse: Ellipse::default()
+                    .with_semi_major_axis(Distance::meters(5.))
+                    .with_eccentricity(Ratio::from(1.)),
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            assert_eq!(
+                test.ellipse.closest_point(Coords::from([1., 1., 0.])),
+                None,
+                "{}",
+                test.name
+            );
+        });
+    }
+
+    #[test]
+    fn closest_point_inside_the_ellipse_must_be_the_nearest() {
+        const SEGMENTS: usize = 4096;
+
+        let ellipse = Ellipse::default()
+            .with_semi_major_axis(Distance::meters(5.))
+            .with_eccentricity(Ratio::from(0.8));
+
+        [
+            Coords::from([1., 0.5, 0.]),
+            Coords::from([-4., -0.1, 0.]),
+            Coords::from([3., 0., 0.]),
+            Coords::from([-7., 2., 0.]),
+        ]
+        .into_iter()
+        .for_each(|to| {
+            let (_, closest) = ellipse.closest_point(to).unwrap();
+            let distance = closest.distance(&to);
+
+            let nearest = ellipse
+                .sample(SEGMENTS)
+                .points
+                .iter()
+                .map(|point| point.distance(&to))
+                .fold(f64::MAX, f64::min);
+
+            assert!(
+                !distance.is_nan() && distance <= nearest,
+                "got distance = {distance} from {to:?}, want no greater than {nearest}"
+            );
+        });
+    }
+}
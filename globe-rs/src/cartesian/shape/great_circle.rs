@@ -0,0 +1,91 @@
+use crate::{cartesian::Coords, geographic, Radian};
+
+use super::{Sample, Shape};
+
+/// The shortest route between two points on the surface of a sphere, which follows a [great
+/// circle](https://en.wikipedia.org/wiki/Great_circle).
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct GreatCircleArc {
+    /// The point at which the route begins.
+    pub from: geographic::Coords,
+    /// The point at which the route ends.
+    pub to: geographic::Coords,
+}
+
+impl Sample for GreatCircleArc {
+    /// Routes have no angular position other than their ends, hence this is a no-op.
+    fn with_initial_theta(self, _: Radian) -> Self {
+        self
+    }
+
+    /// Samples the route into points equidistant from each other, including both ends.
+    fn sample(&self, segments: usize) -> Shape {
+        Shape {
+            points: self
+                .from
+                .great_circle_path(&self.to, segments)
+                .into_iter()
+                .map(Coords::from)
+                .collect(),
+        }
+    }
+}
+
+impl GreatCircleArc {
+    pub fn with_from(mut self, from: geographic::Coords) -> Self {
+        self.from = from;
+        self
+    }
+
+    pub fn with_to(mut self, to: geographic::Coords) -> Self {
+        self.to = to;
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::FRAC_PI_2;
+
+    use crate::{
+        cartesian::{
+            shape::{GreatCircleArc, Sample},
+            Coords,
+        },
+        geographic::{self, Altitude, Longitude},
+        tests::approx_eq,
+    };
+
+    #[test]
+    fn sample_must_not_fail() {
+        const ABS_ERROR: f64 = 1e-12;
+        const SEGMENTS: usize = 8;
+
+        let arc = GreatCircleArc::default()
+            .with_from(geographic::Coords::default().with_altitude(Altitude::from(2.)))
+            .with_to(
+                geographic::Coords::default()
+                    .with_longitude(Longitude::from(FRAC_PI_2))
+                    .with_altitude(Altitude::from(2.)),
+            );
+
+        let shape = arc.sample(SEGMENTS);
+        assert_eq!(shape.points.len(), SEGMENTS + 1);
+
+        shape.points.iter().for_each(|point| {
+            assert!(
+                approx_eq(point.magnitude(), 2., ABS_ERROR),
+                "got point = {point:?}, want it at radius 2"
+            );
+
+            assert!(
+                approx_eq(point.z(), 0., ABS_ERROR),
+                "got point = {point:?}, want it on the equator"
+            );
+        });
+
+        let (first, last) = (shape.points[0], shape.points[SEGMENTS]);
+        assert!(first.distance(&Coords::from([2., 0., 0.])) <= ABS_ERROR);
+        assert!(last.distance(&Coords::from([0., 2., 0.])) <= ABS_ERROR);
+    }
+}
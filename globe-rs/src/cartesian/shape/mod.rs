@@ -1,6 +1,6 @@
-use crate::Radian;
+use crate::{Distance, Radian};
 
-use super::Coords;
+use super::{transform::Transform, Coords};
 
 mod arc;
 pub use arc::*;
@@ -8,12 +8,200 @@ pub use arc::*;
 mod ellipse;
 pub use ellipse::*;
 
+mod great_circle;
+pub use great_circle::*;
+
+mod segment;
+pub use segment::*;
+
+mod spiral;
+pub use spiral::*;
+
 /// A succession of [Cartesian]s representing an arbitrary shape.
-#[derive(Default)]
+#[derive(Debug, Default, Clone, PartialEq)]
 pub struct Shape {
     pub points: Vec<Coords>,
 }
 
+impl Shape {
+    /// Returns the shape resulting of performing the given transformation over each of its
+    /// points.
+    pub fn transform<T: Transform>(&self, transformation: T) -> Shape {
+        Shape {
+            points: self
+                .points
+                .iter()
+                .map(|point| point.transform(transformation))
+                .collect(),
+        }
+    }
+
+    /// Returns the sum of the lengths of all the segments in the shape.
+    pub fn length(&self) -> Distance {
+        Distance::meters(
+            self.points
+                .windows(2)
+                .map(|segment| segment[0].distance(&segment[1]))
+                .sum(),
+        )
+    }
+
+    /// Returns the arithmetic mean of all the points in the shape, or the origin of coordinates if
+    /// there is none.
+    pub fn centroid(&self) -> Coords {
+        if self.points.is_empty() {
+            return Coords::default();
+        }
+
+        self.points
+            .iter()
+            .fold(Coords::default(), |sum, &point| sum + point)
+            / self.points.len() as f64
+    }
+
+    /// Returns the lowest and greatest corners of the axis-aligned bounding box containing the
+    /// whole shape, which are both the origin of coordinates if there is no point.
+    pub fn aabb(&self) -> (Coords, Coords) {
+        let Some(&first) = self.points.first() else {
+            return Default::default();
+        };
+
+        self.points
+            .iter()
+            .fold((first, first), |(min, max), point| {
+                (Coords(min.0.inf(&point.0)), Coords(max.0.sup(&point.0)))
+            })
+    }
+
+    /// Returns the point of the shape closest to the given one, next to the index of the segment
+    /// it belongs to, which is the index of its first point, and the distance between both.
+    /// Returns [Option::None] if the shape has no point.
+    pub fn closest_point(&self, to: Coords) -> Option<(usize, Coords, f64)> {
+        if let [point] = self.points.as_slice() {
+            return Some((0, *point, point.distance(&to)));
+        }
+
+        self.points
+            .windows(2)
+            .enumerate()
+            .map(|(index, segment)| {
+                let closest = closest_in_segment(segment[0], segment[1], to);
+                (index, closest, closest.distance(&to))
+            })
+            .min_by(|a, b| a.2.total_cmp(&b.2))
+    }
+
+    /// Returns a shape of the given amount of points evenly distributed by arc length along self,
+    /// keeping the first and last points as they are, and so the shape closed if self was.
+    pub fn resample(&self, points: usize) -> Shape {
+        let (Some(&first), Some(&last)) = (self.points.first(), self.points.last()) else {
+            return Shape::default();
+        };
+
+        if points < 2 {
+            return Shape {
+                points: vec![first; points],
+            };
+        }
+
+        let step = self.length().as_meters() / (points - 1) as f64;
+        let mut segments = self.points.windows(2).peekable();
+        let mut travelled = 0.;
+
+        let mut resampled: Vec<Coords> = (0..points - 1)
+            .map(|index| {
+                let target = step * index as f64;
+                while let Some(segment) = segments.peek() {
+                    let length = segment[0].distance(&segment[1]);
+                    if travelled + length > target {
+                        return segment[0].lerp(segment[1], (target - travelled) / length);
+                    }
+
+                    travelled += length;
+                    segments.next();
+                }
+
+                last
+            })
+            .collect();
+
+        resampled.push(last);
+        Shape { points: resampled }
+    }
+
+    /// Returns the shape resulting of removing as many points as possible from self without any
+    /// of them being further than the given tolerance from the simplified shape, according to
+    /// the [Ramer–Douglas–Peucker algorithm](https://en.wikipedia.org/wiki/Ramer%E2%80%93Douglas%E2%80%93Peucker_algorithm).
+    ///
+    /// The first and last points are always kept, and so the shape closed if self was.
+    pub fn simplify(&self, tolerance: Distance) -> Shape {
+        if self.points.len() < 3 {
+            return self.clone();
+        }
+
+        let mut keep = vec![false; self.points.len()];
+        keep[0] = true;
+        keep[self.points.len() - 1] = true;
+
+        let mut pending = vec![(0, self.points.len() - 1)];
+        while let Some((start, end)) = pending.pop() {
+            let (from, to) = (self.points[start], self.points[end]);
+            let farthest = (start + 1..end)
+                .map(|index| {
+                    let point = self.points[index];
+                    (index, closest_in_segment(from, to, point).distance(&point))
+                })
+                .max_by(|a, b| a.1.total_cmp(&b.1));
+
+            if let Some((index, deviation)) = farthest {
+                if deviation > tolerance.as_meters() {
+                    keep[index] = true;
+                    pending.push((start, index));
+                    pending.push((index, end));
+                }
+            }
+        }
+
+        Shape {
+            points: self
+                .points
+                .iter()
+                .zip(keep)
+                .filter_map(|(&point, keep)| keep.then_some(point))
+                .collect(),
+        }
+    }
+
+    /// Returns true if, and only if, the shape has more than one point and the last one is the
+    /// same as the first one.
+    pub fn closed(&self) -> bool {
+        self.points.len() > 1 && self.points.first() == self.points.last()
+    }
+
+    /// Closes the shape by pushing its first point at the end of it, unless it is closed already.
+    pub fn close(&mut self) {
+        if self.closed() {
+            return;
+        }
+
+        if let Some(&first) = self.points.first() {
+            self.points.push(first);
+        }
+    }
+}
+
+/// Returns the point of the segment going from start to end that is the closest to the given one.
+fn closest_in_segment(start: Coords, end: Coords, point: Coords) -> Coords {
+    let direction = end - start;
+    let length_squared = direction.dot(&direction);
+    if length_squared == 0. {
+        return start;
+    }
+
+    let t = ((point - start).dot(&direction) / length_squared).clamp(0., 1.);
+    start + direction * t
+}
+
 /// A continious shape that can be sampled into a discrete [Shape].
 pub trait Sample {
     /// Determines the radiant at which the sample begins.
@@ -23,3 +211,268 @@ pub trait Sample {
     /// segments.
     fn sample(&self, segments: usize) -> Shape;
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        cartesian::{
+            shape::{Ellipse, Sample, Shape},
+            Coords,
+        },
+        tests::approx_eq,
+        Distance, Ratio,
+    };
+
+    fn ellipse(segments: usize) -> Shape {
+        let mut shape = Ellipse::default()
+            .with_semi_major_axis(Distance::km(10_000.))
+            .with_eccentricity(Ratio::from(0.6))
+            .sample(segments);
+
+        shape.close();
+        shape
+    }
+
+    fn square() -> Shape {
+        Shape {
+            points: vec![
+                Coords::from([0., 0., 0.]),
+                Coords::from([2., 0., 0.]),
+                Coords::from([2., 2., 0.]),
+                Coords::from([0., 2., 0.]),
+            ],
+        }
+    }
+
+    #[test]
+    fn length_must_not_fail() {
+        struct Test {
+            name: &'static str,
+            shape: Shape,
+            output: Distance,
+        }
+
+        vec![
+            Test {
+                name: "empty shape",
+                shape: Shape::default(),
+                output: Distance::ZERO,
+            },
+            Test {
+                name: "open square",
+                shape: square(),
+                output: Distance::meters(6.),
+            },
+            Test {
+                name: "closed square",
+                shape: {
+                    let mut shape = square();
+                    shape.close();
+                    shape
+                },
+                output: Distance::meters(8.),
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let length = test.shape.length();
+            assert_eq!(
+                length, test.output,
+                "{}: got length = {:?}, want {:?}",
+                test.name, length, test.output
+            );
+        });
+    }
+
+    #[test]
+    fn centroid_and_aabb_must_not_fail() {
+        struct Test {
+            name: &'static str,
+            shape: Shape,
+            centroid: Coords,
+            aabb: (Coords, Coords),
+        }
+
+        vec![
+            Test {
+                name: "empty shape",
+                shape: Shape::default(),
+                centroid: Coords::default(),
+                aabb: (Coords::default(), Coords::default()),
+            },
+            Test {
+                name: "single point",
+                shape: Shape {
+                    points: vec![Coords::from([1., -2., 3.])],
+                },
+                centroid: Coords::from([1., -2., 3.]),
+                aabb: (Coords::from([1., -2., 3.]), Coords::from([1., -2., 3.])),
+            },
+            Test {
+                name: "square",
+                shape: square(),
+                centroid: Coords::from([1., 1., 0.]),
+                aabb: (Coords::from([0., 0., 0.]), Coords::from([2., 2., 0.])),
+            },
+            Test {
+                name: "scattered points",
+                shape: Shape {
+                    points: vec![
+                        Coords::from([-1., 4., 2.]),
+                        Coords::from([3., -2., 0.]),
+                        Coords::from([1., 1., -5.]),
+                    ],
+                },
+                centroid: Coords::from([1., 1., -1.]),
+                aabb: (Coords::from([-1., -2., -5.]), Coords::from([3., 4., 2.])),
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let centroid = test.shape.centroid();
+            assert_eq!(
+                centroid, test.centroid,
+                "{}: got centroid = {:?}, want {:?}",
+                test.name, centroid, test.centroid
+            );
+
+            let aabb = test.shape.aabb();
+            assert_eq!(
+                aabb, test.aabb,
+                "{}: got aabb = {:?}, want {:?}",
+                test.name, aabb, test.aabb
+            );
+        });
+    }
+
+    #[test]
+    fn closest_point_must_not_fail() {
+        struct Test {
+            name: &'static str,
+            shape: Shape,
+            input: Coords,
+            output: Option<(usize, Coords, f64)>,
+        }
+
+        vec![
+            Test {
+                name: "empty shape",
+                shape: Shape::default(),
+                input: Coords::default(),
+                output: None,
+            },
+            Test {
+                name: "single point",
+                shape: Shape {
+                    points: vec![Coords::from([3., 4., 0.])],
+                },
+                input: Coords::default(),
+                output: Some((0, Coords::from([3., 4., 0.]), 5.)),
+            },
+            Test {
+                name: "middle of a segment",
+                shape: square(),
+                input: Coords::from([1., -3., 0.]),
+                output: Some((0, Coords::from([1., 0., 0.]), 3.)),
+            },
+            Test {
+                name: "beyond the last point",
+                shape: square(),
+                input: Coords::from([-1., 2., 0.]),
+                output: Some((2, Coords::from([0., 2., 0.]), 1.)),
+            },
+            Test {
+                name: "on the shape",
+                shape: square(),
+                input: Coords::from([2., 1.5, 0.]),
+                output: Some((1, Coords::from([2., 1.5, 0.]), 0.)),
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let output = test.shape.closest_point(test.input);
+            assert_eq!(
+                output, test.output,
+                "{}: got closest point = {:?}, want {:?}",
+                test.name, output, test.output
+            );
+        });
+    }
+
+    #[test]
+    fn close_must_not_fail() {
+        let mut shape = square();
+        assert!(!shape.closed(), "the square should not be closed yet");
+
+        shape.close();
+        assert!(shape.closed(), "the square should be closed");
+        assert_eq!(shape.points.len(), 5);
+
+        shape.close();
+        assert_eq!(shape.points.len(), 5, "closing twice must not push again");
+
+        let mut empty = Shape::default();
+        empty.close();
+        assert!(!empty.closed(), "an empty shape cannot be closed");
+        assert!(empty.points.is_empty());
+    }
+
+    #[test]
+    fn resample_must_distribute_points_evenly() {
+        const POINTS: usize = 64;
+
+        let shape = ellipse(512);
+        let resampled = shape.resample(POINTS);
+
+        assert_eq!(resampled.points.len(), POINTS);
+        assert_eq!(resampled.points[0], shape.points[0]);
+        assert!(resampled.closed(), "the resampled shape should be closed");
+
+        let step = shape.length().as_meters() / (POINTS - 1) as f64;
+        resampled.points.windows(2).for_each(|segment| {
+            let length = segment[0].distance(&segment[1]);
+            assert!(
+                approx_eq(length, step, step * 0.01),
+                "got segment length = {length}, want ± 1% = {step}"
+            );
+        });
+
+        assert!(Shape::default().resample(POINTS).points.is_empty());
+        assert_eq!(shape.resample(1).points, vec![shape.points[0]]);
+    }
+
+    #[test]
+    fn simplify_must_keep_deviation_within_tolerance() {
+        const TOLERANCE: f64 = 50_000.;
+
+        let shape = ellipse(512);
+        let simplified = shape.simplify(Distance::meters(TOLERANCE));
+
+        assert!(
+            simplified.points.len() < shape.points.len() / 4,
+            "got {} points, want less than {}",
+            simplified.points.len(),
+            shape.points.len() / 4
+        );
+
+        assert_eq!(simplified.points[0], shape.points[0]);
+        assert!(simplified.closed(), "the simplified shape should be closed");
+
+        let deviation = shape
+            .points
+            .iter()
+            .filter_map(|&point| simplified.closest_point(point))
+            .map(|(_, _, distance)| distance)
+            .fold(0., f64::max);
+
+        assert!(
+            deviation <= TOLERANCE,
+            "got deviation = {deviation}, want no greater than {TOLERANCE}"
+        );
+
+        let segment = Shape {
+            points: vec![Coords::from([0., 0., 0.]), Coords::from([1., 0., 0.])],
+        };
+        assert_eq!(segment.simplify(Distance::meters(TOLERANCE)), segment);
+    }
+}
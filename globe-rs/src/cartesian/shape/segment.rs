@@ -0,0 +1,124 @@
+use crate::{cartesian::Coords, Distance, Radian};
+
+use super::{Sample, Shape};
+
+/// A straight line between two points.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct Segment {
+    /// The point at which the segment begins.
+    pub from: Coords,
+    /// The point at which the segment ends.
+    pub to: Coords,
+}
+
+impl Sample for Segment {
+    /// Segments have no angular position, hence this is a no-op.
+    fn with_initial_theta(self, _: Radian) -> Self {
+        self
+    }
+
+    /// Samples the segment into points equidistant from each other, including both ends.
+    fn sample(&self, segments: usize) -> Shape {
+        if segments == 0 {
+            return Shape::default();
+        }
+
+        Shape {
+            points: (0..=segments)
+                .map(|vertex_index| self.point_at(vertex_index as f64 / segments as f64))
+                .collect(),
+        }
+    }
+}
+
+impl Segment {
+    pub fn with_from(mut self, from: Coords) -> Self {
+        self.from = from;
+        self
+    }
+
+    pub fn with_to(mut self, to: Coords) -> Self {
+        self.to = to;
+        self
+    }
+
+    /// Returns the length of the segment.
+    pub fn length(&self) -> Distance {
+        Distance::meters(self.from.distance(&self.to))
+    }
+
+    /// Returns the point in the middle of the segment.
+    pub fn midpoint(&self) -> Coords {
+        self.point_at(0.5)
+    }
+
+    /// Returns the point at the given fraction of the segment, where 0 is the beginning of the
+    /// segment and 1 its end.
+    pub fn point_at(&self, t: f64) -> Coords {
+        self.from.lerp(self.to, t)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        cartesian::{
+            shape::{Sample, Segment, Shape},
+            Coords,
+        },
+        Distance, Radian,
+    };
+
+    #[test]
+    fn segment_must_not_fail() {
+        let segment = Segment::default()
+            .with_from(Coords::from([1., 2., 3.]))
+            .with_to(Coords::from([4., 6., 3.]));
+
+        assert_eq!(segment.length(), Distance::meters(5.));
+        assert_eq!(segment.midpoint(), Coords::from([2.5, 4., 3.]));
+        assert_eq!(segment.point_at(0.), segment.from);
+        assert_eq!(segment.point_at(1.), segment.to);
+        assert_eq!(segment.with_initial_theta(Radian::from(1.)), segment);
+    }
+
+    #[test]
+    fn sample_must_not_fail() {
+        struct Test {
+            name: &'static str,
+            input: Shape,
+            output: Vec<Coords>,
+        }
+
+        vec![
+            Test {
+                name: "segment with no segments",
+                input: Segment::default()
+                    .with_to(Coords::from([4., 0., 0.]))
+                    .sample(0),
+                output: vec![],
+            },
+            Test {
+                name: "segment into four",
+                input: Segment::default()
+                    .with_to(Coords::from([4., 0., 0.]))
+                    .sample(4),
+                output: vec![
+                    Coords::from([0., 0., 0.]),
+                    Coords::from([1., 0., 0.]),
+                    Coords::from([2., 0., 0.]),
+                    Coords::from([3., 0., 0.]),
+                    Coords::from([4., 0., 0.]),
+                ],
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            assert_eq!(
+                test.input.points, test.output,
+                "{}: got points = {:?}, want {:?}",
+                test.name, test.input.points, test.output
+            );
+        });
+    }
+}
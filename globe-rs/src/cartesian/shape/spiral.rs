@@ -0,0 +1,376 @@
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    cartesian::{transform::Rotation, Coords},
+    orbit::{Orbit, GRAVITATIONAL_CONSTANT},
+    Body, Distance, Radian, Ratio, Velocity,
+};
+
+use super::{Sample, Shape};
+
+/// The amount of segments into which a spiral is divided when computing its length.
+const PERIMETER_SEGMENTS: usize = 1024;
+
+/// How the radius of a [Spiral] evolves from its start to its end.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SpiralGrowth {
+    /// The radius changes by the same amount on each turn, as in an Archimedean spiral.
+    Linear,
+    /// The radius changes by the same ratio on each turn, as in a logarithmic spiral.
+    #[default]
+    Exponential,
+}
+
+/// A spiral around the origin of coordinates, in the plane XY, going from a starting radius to an
+/// ending one in the given amount of turns.
+///
+/// As an [Orbit], the spiral is travelled once in the configured duration, after which the object
+/// remains at its end. Since the angular position alone cannot tell in which turn the object is,
+/// the radiants of a spiral orbit stand for the fraction of the spiral already travelled, being
+/// zero its start and 2π its end.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Spiral {
+    /// The distance from the center at which the spiral begins.
+    pub start_radius: Distance,
+    /// The distance from the center at which the spiral ends.
+    pub end_radius: Distance,
+    /// The amount of turns from the start to the end of the spiral.
+    pub turns: f64,
+    /// How the radius evolves along the spiral.
+    pub growth: SpiralGrowth,
+    /// The time it takes to travel the whole spiral.
+    #[serde(with = "crate::quantity::duration")]
+    pub duration: Duration,
+    /// The initial radiant of the spiral.
+    pub initial_theta: Radian,
+    /// The direction of the spiral.
+    pub clockwise: bool,
+}
+
+impl Default for Spiral {
+    fn default() -> Self {
+        Self {
+            start_radius: Default::default(),
+            end_radius: Default::default(),
+            turns: 1.,
+            growth: Default::default(),
+            duration: Default::default(),
+            initial_theta: Default::default(),
+            clockwise: Default::default(),
+        }
+    }
+}
+
+impl Sample for Spiral {
+    fn with_initial_theta(mut self, theta: Radian) -> Self {
+        self.initial_theta = theta;
+        self
+    }
+
+    /// Samples the spiral into points evenly distributed along its turns, including both ends.
+    fn sample(&self, segments: usize) -> Shape {
+        if segments == 0 {
+            return Shape::default();
+        }
+
+        Shape {
+            points: (0..=segments)
+                .map(|vertex_index| self.point_at(vertex_index as f64 / segments as f64))
+                .collect(),
+        }
+    }
+}
+
+/// A simplified orbit in which the object moves as if it was in a circular orbit of the radius
+/// it is at.
+impl Orbit for Spiral {
+    fn min_velocity(&self, orbitee: &Body) -> Velocity {
+        self.velocity(self.start_radius.max(self.end_radius), orbitee)
+    }
+
+    fn max_velocity(&self, orbitee: &Body) -> Velocity {
+        self.velocity(self.start_radius.min(self.end_radius), orbitee)
+    }
+
+    fn velocity_at_theta(&self, theta: Radian, orbitee: &Body) -> Velocity {
+        self.velocity(self.radius_at(Self::progress(theta)), orbitee)
+    }
+
+    fn position_at_theta(&self, theta: Radian) -> Coords {
+        self.point_at(Self::progress(theta))
+    }
+
+    fn theta_in_period(&self, time: Duration, period: Duration) -> Radian {
+        if period.is_zero() || time >= period {
+            return Radian::TWO_PI;
+        }
+
+        Radian::TWO_PI * (time.as_secs_f64() / period.as_secs_f64())
+    }
+
+    fn period(&self, _: &Body) -> Duration {
+        self.duration
+    }
+
+    fn perimeter(&self) -> Distance {
+        self.sample(PERIMETER_SEGMENTS).length()
+    }
+
+    fn focus(&self) -> Coords {
+        Coords::default()
+    }
+
+    fn radius(&self) -> Distance {
+        self.start_radius.max(self.end_radius)
+    }
+
+    fn is_clockwise(&self) -> bool {
+        self.clockwise
+    }
+
+    fn eccentricity(&self) -> Ratio {
+        Ratio::default()
+    }
+
+    fn is_closed(&self) -> bool {
+        false
+    }
+}
+
+impl Spiral {
+    pub fn with_start_radius(mut self, radius: Distance) -> Self {
+        self.start_radius = radius;
+        self
+    }
+
+    pub fn with_end_radius(mut self, radius: Distance) -> Self {
+        self.end_radius = radius;
+        self
+    }
+
+    pub fn with_turns(mut self, turns: f64) -> Self {
+        self.turns = turns;
+        self
+    }
+
+    pub fn with_growth(mut self, growth: SpiralGrowth) -> Self {
+        self.growth = growth;
+        self
+    }
+
+    pub fn with_duration(mut self, duration: Duration) -> Self {
+        self.duration = duration;
+        self
+    }
+
+    pub fn with_clockwise(mut self, clockwise: bool) -> Self {
+        self.clockwise = clockwise;
+        self
+    }
+
+    /// Returns the distance from the center of the spiral at the given fraction of it.
+    pub fn radius_at(&self, progress: f64) -> Distance {
+        let progress = progress.clamp(0., 1.);
+        let (start, end) = (self.start_radius.as_meters(), self.end_radius.as_meters());
+
+        Distance::meters(match self.growth {
+            SpiralGrowth::Exponential if start > 0. && end > 0. => {
+                start * (end / start).powf(progress)
+            }
+            // a logarithmic spiral never reaches the center, hence the linear fallback
+            _ => start + (end - start) * progress,
+        })
+    }
+
+    /// Returns the point of the spiral at the given fraction of it.
+    pub fn point_at(&self, progress: f64) -> Coords {
+        let progress = progress.clamp(0., 1.);
+        let theta = Radian::TWO_PI.as_f64() * self.turns * progress;
+        let theta = if self.clockwise {
+            self.initial_theta.as_f64() - theta
+        } else {
+            self.initial_theta.as_f64() + theta
+        };
+
+        Coords::default()
+            .with_x(self.radius_at(progress).as_meters())
+            .transform(
+                Rotation::default()
+                    .with_axis(Coords::default().with_z(1.))
+                    .with_theta(theta.into()),
+            )
+    }
+
+    /// Returns the fraction of the spiral corresponding to the given radiants of the orbit.
+    fn progress(theta: Radian) -> f64 {
+        theta.as_f64() / Radian::TWO_PI.as_f64()
+    }
+
+    /// Returns the velocity of a circular orbit of the given radius.
+    fn velocity(&self, radius: Distance, orbitee: &Body) -> Velocity {
+        Velocity::meters_sec(
+            (GRAVITATIONAL_CONSTANT * orbitee.mass.as_kg() / radius.as_meters()).sqrt(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{f64::consts::PI, str::FromStr, time::Duration};
+
+    use alvidir::name::Name;
+
+    use crate::{
+        cartesian::{
+            shape::{Sample, Spiral, SpiralGrowth},
+            Coords,
+        },
+        tests::approx_eq,
+        Body, Distance, Orbit,
+    };
+
+    /// Returns the amount of whole turns the given points wind around the origin of coordinates.
+    fn winding_count(points: &[Coords]) -> f64 {
+        points
+            .windows(2)
+            .map(|segment| {
+                let from = segment[0].y().atan2(segment[0].x());
+                let to = segment[1].y().atan2(segment[1].x());
+                (to - from + PI).rem_euclid(2. * PI) - PI
+            })
+            .sum::<f64>()
+            / (2. * PI)
+    }
+
+    #[test]
+    fn sample_must_not_fail() {
+        const SEGMENTS: usize = 360;
+
+        struct Test {
+            name: &'static str,
+            spiral: Spiral,
+            winding: f64,
+        }
+
+        vec![
+            Test {
+                name: "decaying logarithmic spiral",
+                spiral: Spiral::default()
+                    .with_start_radius(Distance::km(7_000.))
+                    .with_end_radius(Distance::km(6_500.))
+                    .with_turns(3.),
+                winding: 3.,
+            },
+            Test {
+                name: "raising archimedean spiral",
+                spiral: Spiral::default()
+                    .with_start_radius(Distance::km(1_000.))
+                    .with_end_radius(Distance::km(4_000.))
+                    .with_turns(2.5)
+                    .with_growth(SpiralGrowth::Linear),
+                winding: 2.5,
+            },
+            Test {
+                name: "clockwise spiral",
+                spiral: Spiral::default()
+                    .with_start_radius(Distance::km(1_000.))
+                    .with_end_radius(Distance::km(2_000.))
+                    .with_turns(4.)
+                    .with_clockwise(true),
+                winding: -4.,
+            },
+            Test {
+                name: "spiral from the center",
+                spiral: Spiral::default()
+                    .with_end_radius(Distance::km(2_000.))
+                    .with_turns(1.5),
+                winding: 1.5,
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let shape = test.spiral.sample(SEGMENTS);
+            assert_eq!(shape.points.len(), SEGMENTS + 1);
+
+            let first = shape.points[0].magnitude();
+            let start = test.spiral.start_radius.as_meters();
+            assert!(
+                approx_eq(first, start, start * 1e-12),
+                "{}: got first radius = {first}, want {start}",
+                test.name,
+            );
+
+            let last = shape.points[SEGMENTS].magnitude();
+            let end = test.spiral.end_radius.as_meters();
+            assert!(
+                approx_eq(last, end, end * 1e-12),
+                "{}: got last radius = {last}, want {end}",
+                test.name,
+            );
+
+            let winding = winding_count(&shape.points);
+            assert!(
+                approx_eq(winding, test.winding, 1e-9),
+                "{}: got winding = {winding}, want {}",
+                test.name,
+                test.winding
+            );
+        });
+    }
+
+    #[test]
+    fn position_at_must_follow_the_spiral_in_time() {
+        let spiral = Spiral::default()
+            .with_start_radius(Distance::km(4_000.))
+            .with_end_radius(Distance::km(1_000.))
+            .with_turns(2.)
+            .with_duration(Duration::from_secs(1_000));
+
+        let body = Body::new(Name::from_str("Earth").unwrap());
+
+        struct Test {
+            name: &'static str,
+            time: Duration,
+            radius: Distance,
+        }
+
+        vec![
+            Test {
+                name: "at the start",
+                time: Duration::ZERO,
+                radius: Distance::km(4_000.),
+            },
+            Test {
+                name: "halfway, on a logarithmic spiral",
+                time: Duration::from_secs(500),
+                radius: Distance::km(2_000.),
+            },
+            Test {
+                name: "at the end",
+                time: Duration::from_secs(1_000),
+                radius: Distance::km(1_000.),
+            },
+            Test {
+                name: "beyond the end",
+                time: Duration::from_secs(5_000),
+                radius: Distance::km(1_000.),
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let radius = spiral.position_at(test.time, &body).magnitude();
+            assert!(
+                approx_eq(radius, test.radius.as_meters(), 1e-6),
+                "{}: got radius = {radius}, want {:?}",
+                test.name,
+                test.radius
+            );
+        });
+
+        assert!(!spiral.is_closed(), "a spiral must not be closed");
+    }
+}
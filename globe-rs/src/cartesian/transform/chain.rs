@@ -0,0 +1,216 @@
+use nalgebra::{Matrix4, Vector4};
+
+use super::{Coords, NonUniformScaling, Rotation, Scaling, Transform, Translation};
+
+/// Implements the composition of an arbitrary succession of [Rotation]s, [Translation]s and
+/// [Scaling]s as a single [affine transformation](https://en.wikipedia.org/wiki/Affine_transformation),
+/// so transforming a point costs a single matrix product no matter how long the chain is.
+///
+/// ## Composition order
+/// Transformations are performed in the same order they are chained, which means that
+/// `TransformChain::new().then(a).then(b)` transforms any point as `point.transform(a).transform(b)`
+/// would.
+///
+/// ## Example
+/// ```
+/// use std::f64::consts::FRAC_PI_2;
+///
+/// use globe_rs::cartesian::{
+///     transform::{Rotation, Transform, TransformChain, Translation},
+///     Coords,
+/// };
+///
+/// let chain = TransformChain::new()
+///     .then(
+///         Rotation::default()
+///             .with_axis(Coords::from([0., 0., 1.]))
+///             .with_theta(FRAC_PI_2.into()),
+///     )
+///     .then(Translation::default().with_vector(Coords::from([1., 0., 0.])));
+///
+/// let point = chain.transform(Coords::from([1., 0., 0.]));
+/// assert!(point.distance(&Coords::from([1., 1., 0.])) < 1e-15);
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct TransformChain {
+    matrix: Matrix4<f64>,
+}
+
+impl Default for TransformChain {
+    fn default() -> Self {
+        Self {
+            matrix: Matrix4::identity(),
+        }
+    }
+}
+
+impl From<Rotation> for TransformChain {
+    /// Conjugates the rotation with the translation to its pivot, if any.
+    fn from(rotation: Rotation) -> Self {
+        let pivot = Translation::default().with_vector(rotation.pivot);
+        Self {
+            matrix: pivot.matrix() * rotation.matrix().to_homogeneous() * (-pivot).matrix(),
+        }
+    }
+}
+
+impl From<Translation> for TransformChain {
+    fn from(translation: Translation) -> Self {
+        Self {
+            matrix: translation.matrix(),
+        }
+    }
+}
+
+impl From<Scaling> for TransformChain {
+    fn from(scaling: Scaling) -> Self {
+        Self {
+            matrix: scaling.matrix().to_homogeneous(),
+        }
+    }
+}
+
+impl From<NonUniformScaling> for TransformChain {
+    fn from(scaling: NonUniformScaling) -> Self {
+        Self {
+            matrix: scaling.matrix().to_homogeneous(),
+        }
+    }
+}
+
+impl Transform for TransformChain {
+    fn transform(&self, point: Coords) -> Coords {
+        let point = self.matrix * Vector4::new(point.x(), point.y(), point.z(), 1.);
+        [point.x, point.y, point.z].into()
+    }
+}
+
+impl TransformChain {
+    /// Returns the identity transformation, which does not change any point.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the chain resulting of performing the given transformation after self.
+    pub fn then<T: Into<TransformChain>>(self, transformation: T) -> Self {
+        Self {
+            matrix: transformation.into().matrix * self.matrix,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::FRAC_PI_2;
+
+    use crate::cartesian::{
+        transform::{Rotation, Scaling, Transform, TransformChain, Translation},
+        Coords,
+    };
+
+    #[test]
+    fn transform_chain_must_not_fail() {
+        const ABS_ERROR: f64 = 1e-15;
+
+        let rotation = Rotation::default()
+            .with_axis(Coords::from([0., 0., 1.]))
+            .with_theta(FRAC_PI_2.into());
+        let translation = Translation::default().with_vector(Coords::from([1., 0., 0.]));
+        let scaling = Scaling::default().with_factor(2.);
+
+        struct Test {
+            name: &'static str,
+            chain: TransformChain,
+            input: Coords,
+            output: Coords,
+        }
+
+        vec![
+            Test {
+                name: "empty chain should not change the point",
+                chain: TransformChain::new(),
+                input: Coords::from([1., 2., 3.]),
+                output: Coords::from([1., 2., 3.]),
+            },
+            Test {
+                name: "rotate then translate",
+                chain: TransformChain::new().then(rotation).then(translation),
+                input: Coords::from([1., 0., 0.]),
+                output: Coords::from([1., 1., 0.]),
+            },
+            Test {
+                name: "translate then rotate",
+                chain: TransformChain::new().then(translation).then(rotation),
+                input: Coords::from([1., 0., 0.]),
+                output: Coords::from([0., 2., 0.]),
+            },
+            Test {
+                name: "translate then scale",
+                chain: TransformChain::new().then(translation).then(scaling),
+                input: Coords::from([1., 0., 0.]),
+                output: Coords::from([4., 0., 0.]),
+            },
+            Test {
+                name: "scale then translate",
+                chain: TransformChain::new().then(scaling).then(translation),
+                input: Coords::from([1., 0., 0.]),
+                output: Coords::from([3., 0., 0.]),
+            },
+            Test {
+                name: "rotate about a pivot then translate",
+                chain: TransformChain::new()
+                    .then(rotation.about(Coords::from([1., 1., 0.])))
+                    .then(translation),
+                input: Coords::from([2., 1., 0.]),
+                output: Coords::from([2., 2., 0.]),
+            },
+            Test {
+                name: "chain of chains",
+                chain: TransformChain::new()
+                    .then(TransformChain::new().then(rotation))
+                    .then(TransformChain::new().then(translation).then(scaling)),
+                input: Coords::from([1., 0., 0.]),
+                output: Coords::from([2., 2., 0.]),
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let transformed = test.chain.transform(test.input);
+            assert!(
+                transformed.distance(&test.output) <= ABS_ERROR,
+                "{}: got transformed = {:?}, want ± e = {:?}",
+                test.name,
+                transformed,
+                test.output
+            );
+        });
+    }
+
+    #[test]
+    fn transform_chain_must_match_successive_transforms() {
+        const ABS_ERROR: f64 = 1e-9;
+
+        let rotation = Rotation::default()
+            .with_axis(Coords::from([1., 1., 0.]))
+            .with_theta(1.2.into());
+        let translation = Translation::default().with_vector(Coords::from([-3., 5., 7.]));
+        let scaling = Scaling::default().with_factor(0.3);
+
+        let chain = TransformChain::new()
+            .then(translation)
+            .then(rotation)
+            .then(scaling);
+
+        let point = Coords::from([4., -2., 9.]);
+        let want = point
+            .transform(translation)
+            .transform(rotation)
+            .transform(scaling);
+
+        let got = point.transform(chain);
+        assert!(
+            got.distance(&want) <= ABS_ERROR,
+            "got transformed = {got:?}, want ± e = {want:?}"
+        );
+    }
+}
@@ -1,5 +1,8 @@
 use super::Coords;
 
+mod chain;
+pub use chain::*;
+
 mod rotation;
 pub use rotation::*;
 
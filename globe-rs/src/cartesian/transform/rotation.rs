@@ -1,6 +1,9 @@
-use std::ops::Neg;
+use std::{
+    f64::consts::{FRAC_PI_2, PI},
+    ops::Neg,
+};
 
-use nalgebra::Matrix3;
+use nalgebra::{Matrix3, Quaternion, Unit, UnitQuaternion};
 
 use crate::Radian;
 
@@ -50,19 +53,40 @@ pub struct Rotation {
     pub axis: Coords,
     /// The angle of rotation.
     pub theta: Radian,
+    /// The point through which the axis of rotation passes, being the origin of coordinates by
+    /// default.
+    pub pivot: Coords,
 }
 
 impl Transform for Rotation {
     fn transform(&self, point: Coords) -> Coords {
-        let sin_theta = self.theta.as_f64().sin();
-        let cos_theta = self.theta.as_f64().cos();
+        Coords::from(self.matrix() * (point - self.pivot).0) + self.pivot
+    }
+}
+
+impl Neg for Rotation {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self {
+            theta: Radian::from(-self.theta.as_f64()),
+            ..self
+        }
+    }
+}
+
+impl Rotation {
+    /// Returns the rotation matrix of the transformation.
+    pub(super) fn matrix(&self) -> Matrix3<f64> {
+        let sin_theta = self.theta.sin();
+        let cos_theta = self.theta.cos();
         let sub_1_cos_theta = 1. - cos_theta;
 
         let x = self.axis.x();
         let y = self.axis.y();
         let z = self.axis.z();
 
-        let rotation = Matrix3::new(
+        Matrix3::new(
             cos_theta + x.powi(2) * sub_1_cos_theta,
             x * y * sub_1_cos_theta - z * sin_theta,
             x * z * sub_1_cos_theta + y * sin_theta,
@@ -72,24 +96,81 @@ impl Transform for Rotation {
             z * x * sub_1_cos_theta - y * sin_theta,
             z * y * sub_1_cos_theta + x * sin_theta,
             cos_theta + z.powi(2) * sub_1_cos_theta,
-        );
-
-        (rotation * point.0).into()
+        )
     }
-}
 
-impl Neg for Rotation {
-    type Output = Self;
+    /// Returns the rotation that aligns the direction of from with the direction of to.
+    ///
+    /// If both directions are opposite, the axis of rotation is any perpendicular to them. If they
+    /// are the same, or any of them is the origin of coordinates, the rotation is the identity.
+    pub fn between(from: Coords, to: Coords) -> Self {
+        let theta = from.angle_between(&to);
+        let axis = from.cross(&to);
 
-    fn neg(self) -> Self::Output {
-        Self {
-            theta: Radian::from(-self.theta.as_f64()),
-            axis: self.axis,
+        if axis.magnitude() > f64::EPSILON * from.magnitude() * to.magnitude() {
+            return Self::default().with_axis(axis).with_theta(theta);
+        }
+
+        if theta.as_f64() < FRAC_PI_2 {
+            return Self::default();
         }
+
+        let perpendicular = [Coords::from([1., 0., 0.]), Coords::from([0., 1., 0.])]
+            .into_iter()
+            .map(|axis| from.cross(&axis))
+            .max_by(|a, b| a.magnitude().total_cmp(&b.magnitude()))
+            .unwrap_or_default();
+
+        Self::default()
+            .with_axis(perpendicular)
+            .with_theta(Radian::from(PI))
+    }
+
+    /// Returns the rotation undoing self, which is the same as negating it.
+    pub fn inverse(self) -> Self {
+        -self
+    }
+
+    /// Returns the unit quaternion equivalent to self as `[x, y, z, w]`, which is the same layout
+    /// as the one expected by Bevy's `Quat::from_array`. The pivot is not part of the quaternion.
+    ///
+    /// ## Example
+    /// ```
+    /// use std::f64::consts::{FRAC_PI_2, FRAC_PI_4};
+    ///
+    /// use globe_rs::cartesian::{transform::Rotation, Coords};
+    ///
+    /// let rotation = Rotation::default()
+    ///     .with_axis(Coords::from([0., 0., 1.]))
+    ///     .with_theta(FRAC_PI_2.into());
+    ///
+    /// let [x, y, z, w] = rotation.to_quaternion();
+    /// assert_eq!([x, y], [0., 0.]);
+    /// assert!((z - FRAC_PI_4.sin()).abs() < 1e-15);
+    /// assert!((w - FRAC_PI_4.cos()).abs() < 1e-15);
+    /// ```
+    pub fn to_quaternion(&self) -> [f64; 4] {
+        Unit::try_new(self.axis.0, f64::EPSILON)
+            .map(|axis| UnitQuaternion::from_axis_angle(&axis, self.theta.as_f64()))
+            .unwrap_or_else(UnitQuaternion::identity)
+            .into_inner()
+            .coords
+            .into()
+    }
+
+    /// Returns the rotation equivalent to the given quaternion as `[x, y, z, w]`, which does not
+    /// need to be normalized. A quaternion with no magnitude is taken as the identity.
+    pub fn from_quaternion([x, y, z, w]: [f64; 4]) -> Self {
+        Unit::try_new(Quaternion::new(w, x, y, z), f64::EPSILON)
+            .and_then(|quaternion| quaternion.axis_angle())
+            .map(|(axis, theta)| Self {
+                axis: axis.into_inner().into(),
+                theta: theta.into(),
+                ..Default::default()
+            })
+            .unwrap_or_default()
     }
-}
 
-impl Rotation {
     pub fn with_axis(mut self, axis: Coords) -> Self {
         self.axis = axis.unit();
         self
@@ -99,6 +180,13 @@ impl Rotation {
         self.theta = theta;
         self
     }
+
+    /// Makes the axis of rotation pass through the given point instead of through the origin of
+    /// coordinates.
+    pub fn about(mut self, pivot: Coords) -> Self {
+        self.pivot = pivot;
+        self
+    }
 }
 
 #[cfg(test)]
@@ -198,4 +286,219 @@ mod tests {
                 });
         });
     }
+
+    #[test]
+    fn rotation_between_must_not_fail() {
+        const ABS_ERROR: f64 = 1e-15;
+
+        struct Test {
+            name: &'static str,
+            from: Coords,
+            to: Coords,
+        }
+
+        vec![
+            Test {
+                name: "orthogonal vectors",
+                from: Coords::from([1., 0., 0.]),
+                to: Coords::from([0., 3., 0.]),
+            },
+            Test {
+                name: "arbitrary vectors",
+                from: Coords::from([1., 2., 3.]),
+                to: Coords::from([-4., 0.5, 2.]),
+            },
+            Test {
+                name: "same direction",
+                from: Coords::from([0., 0., 1.]),
+                to: Coords::from([0., 0., 5.]),
+            },
+            Test {
+                name: "opposite directions",
+                from: Coords::from([1., 0., 0.]),
+                to: Coords::from([-2., 0., 0.]),
+            },
+            Test {
+                name: "opposite arbitrary directions",
+                from: Coords::from([1., -2., 3.]),
+                to: Coords::from([-1., 2., -3.]),
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let rotation = Rotation::between(test.from, test.to);
+            let rotated = rotation.transform(test.from.unit());
+            assert!(
+                rotated.distance(&test.to.unit()) <= ABS_ERROR * 10.,
+                "{}: got rotated = {:?}, want ± e = {:?}",
+                test.name,
+                rotated,
+                test.to.unit()
+            );
+
+            let restored = rotation.inverse().transform(rotated);
+            assert!(
+                restored.distance(&test.from.unit()) <= ABS_ERROR * 10.,
+                "{}: got restored = {:?}, want ± e = {:?}",
+                test.name,
+                restored,
+                test.from.unit()
+            );
+        });
+
+        let identity = Rotation::between(Coords::default(), Coords::from([1., 0., 0.]));
+        let point = Coords::from([1., 2., 3.]);
+        assert_eq!(identity.transform(point), point);
+    }
+
+    #[test]
+    fn quaternion_must_round_trip() {
+        const ABS_ERROR: f64 = 1e-15;
+
+        struct Test {
+            name: &'static str,
+            axis: Coords,
+            theta: Radian,
+        }
+
+        vec![
+            Test {
+                name: "no rotation",
+                axis: Coords::from([1., 0., 0.]),
+                theta: Radian::from(0.),
+            },
+            Test {
+                name: "a quarter of a whole rotation on the x axis",
+                axis: Coords::from([1., 0., 0.]),
+                theta: Radian::from(FRAC_PI_2),
+            },
+            Test {
+                name: "half of a whole rotation on the y axis",
+                axis: Coords::from([0., 1., 0.]),
+                theta: Radian::from(PI),
+            },
+            Test {
+                name: "three quarters of a whole rotation on the z axis",
+                axis: Coords::from([0., 0., 1.]),
+                theta: Radian::from(3. * FRAC_PI_2),
+            },
+            Test {
+                name: "arbitrary rotation on an arbitrary axis",
+                axis: Coords::from([1., -2., 3.]),
+                theta: Radian::from(1.2),
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let rotation = Rotation::default()
+                .with_axis(test.axis)
+                .with_theta(test.theta);
+
+            let quaternion = rotation.to_quaternion();
+            let norm = quaternion.iter().map(|v| v.powi(2)).sum::<f64>().sqrt();
+            assert!(
+                approx_eq(norm, 1., ABS_ERROR * 10.),
+                "{}: got quaternion = {:?}, want a unit quaternion",
+                test.name,
+                quaternion
+            );
+
+            let restored = Rotation::from_quaternion(quaternion);
+            [
+                Coords::from([1., 0., 0.]),
+                Coords::from([0., 1., 0.]),
+                Coords::from([0., 0., 1.]),
+            ]
+            .into_iter()
+            .for_each(|point| {
+                let want = rotation.transform(point);
+                let got = restored.transform(point);
+                assert!(
+                    got.distance(&want) <= ABS_ERROR * 10.,
+                    "{}: got rotated = {:?}, want ± e = {:?}",
+                    test.name,
+                    got,
+                    want
+                );
+            });
+        });
+
+        assert_eq!(Rotation::default().to_quaternion(), [0., 0., 0., 1.]);
+
+        let identity = Rotation::from_quaternion([0., 0., 0., 0.]);
+        let point = Coords::from([1., 2., 3.]);
+        assert_eq!(identity.transform(point), point);
+    }
+
+    #[test]
+    fn rotation_about_a_pivot_must_not_fail() {
+        const ABS_ERROR: f64 = 1e-15;
+
+        struct Test {
+            name: &'static str,
+            rotation: Rotation,
+            input: Coords,
+            output: Coords,
+        }
+
+        vec![
+            Test {
+                name: "half of a whole rotation about a displaced pivot",
+                rotation: Rotation::default()
+                    .with_axis(Coords::from([0., 0., 1.]))
+                    .with_theta(Radian::from(PI))
+                    .about(Coords::from([1., 0., 0.])),
+                input: Coords::from([2., 0., 0.]),
+                output: Coords::from([0., 0., 0.]),
+            },
+            Test {
+                name: "a quarter of a whole rotation about a displaced pivot",
+                rotation: Rotation::default()
+                    .with_axis(Coords::from([0., 0., 1.]))
+                    .with_theta(Radian::from(FRAC_PI_2))
+                    .about(Coords::from([1., 1., 0.])),
+                input: Coords::from([2., 1., 5.]),
+                output: Coords::from([1., 2., 5.]),
+            },
+            Test {
+                name: "the pivot itself must not move",
+                rotation: Rotation::default()
+                    .with_axis(Coords::from([1., 1., 1.]))
+                    .with_theta(Radian::from(1.))
+                    .about(Coords::from([3., -2., 7.])),
+                input: Coords::from([3., -2., 7.]),
+                output: Coords::from([3., -2., 7.]),
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let rotated = test.rotation.transform(test.input);
+            assert!(
+                rotated.distance(&test.output) <= ABS_ERROR,
+                "{}: got rotated = {:?}, want ± e = {:?}",
+                test.name,
+                rotated,
+                test.output
+            );
+
+            let restored = test.rotation.inverse().transform(rotated);
+            assert!(
+                restored.distance(&test.input) <= ABS_ERROR * 10.,
+                "{}: got restored = {:?}, want ± e = {:?}",
+                test.name,
+                restored,
+                test.input
+            );
+        });
+
+        let rotation = Rotation::default()
+            .with_axis(Coords::from([1., -2., 3.]))
+            .with_theta(Radian::from(2.));
+        let point = Coords::from([4., 5., 6.]);
+        assert_eq!(
+            rotation.about(Coords::default()).transform(point),
+            rotation.transform(point),
+            "rotating about the origin must be the same as the pure rotation"
+        );
+    }
 }
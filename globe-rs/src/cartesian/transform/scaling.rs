@@ -1,17 +1,37 @@
+use std::ops::Neg;
+
 use nalgebra::Matrix3;
 
 use super::{Coords, Transform};
 
 /// Implements the [geometric transformation](https://en.wikipedia.org/wiki/Scaling_(geometry))
 /// through which an arbitrary [Cartesian]s can be scaled given a scale factor.
-#[derive(Default, Clone, Copy)]
+#[derive(Debug, Default, Clone, Copy)]
 pub struct Scaling {
     pub factor: f64,
 }
 
+impl Neg for Scaling {
+    type Output = Self;
+
+    /// Returns the inverse scaling, which is only meaningful for factors other than zero.
+    fn neg(self) -> Self::Output {
+        Self {
+            factor: self.factor.recip(),
+        }
+    }
+}
+
 impl Transform for Scaling {
     fn transform(&self, point: Coords) -> Coords {
-        let scaling = Matrix3::new(
+        Coords::from(self.matrix() * point.0)
+    }
+}
+
+impl Scaling {
+    /// Returns the scaling matrix of the transformation.
+    pub(super) fn matrix(&self) -> Matrix3<f64> {
+        Matrix3::new(
             self.factor,
             0.,
             0.,
@@ -21,23 +41,65 @@ impl Transform for Scaling {
             0.,
             0.,
             self.factor,
-        );
-
-        Coords::from(scaling * point.0)
+        )
     }
-}
 
-impl Scaling {
     pub fn with_factor(mut self, factor: f64) -> Self {
         self.factor = factor;
         self
     }
 }
 
+/// Implements the [geometric transformation](https://en.wikipedia.org/wiki/Scaling_(geometry))
+/// through which an arbitrary [Cartesian]s can be scaled given a different scale factor for each
+/// axis.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct NonUniformScaling {
+    /// The scale factor of each axis.
+    pub factors: Coords,
+}
+
+impl From<Scaling> for NonUniformScaling {
+    fn from(scaling: Scaling) -> Self {
+        Self {
+            factors: [scaling.factor; 3].into(),
+        }
+    }
+}
+
+impl Neg for NonUniformScaling {
+    type Output = Self;
+
+    /// Returns the inverse scaling, which is only meaningful for factors other than zero.
+    fn neg(self) -> Self::Output {
+        Self {
+            factors: self.factors.0.map(f64::recip).into(),
+        }
+    }
+}
+
+impl Transform for NonUniformScaling {
+    fn transform(&self, point: Coords) -> Coords {
+        Coords::from(self.matrix() * point.0)
+    }
+}
+
+impl NonUniformScaling {
+    /// Returns the scaling matrix of the transformation.
+    pub(super) fn matrix(&self) -> Matrix3<f64> {
+        Matrix3::from_diagonal(&self.factors.0)
+    }
+
+    pub fn with_factors(mut self, factors: Coords) -> Self {
+        self.factors = factors;
+        self
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::cartesian::{
-        transform::{Scaling, Transform},
+        transform::{NonUniformScaling, Scaling, Transform},
         Coords,
     };
 
@@ -83,4 +145,75 @@ mod tests {
             );
         });
     }
+
+    #[test]
+    fn non_uniform_scaling_must_not_fail() {
+        struct Test {
+            name: &'static str,
+            factors: Coords,
+            input: Coords,
+            output: Coords,
+        }
+
+        vec![
+            Test {
+                name: "factors of 1 should not change the point",
+                factors: Coords::from([1., 1., 1.]),
+                input: Coords::from([1., 2., 3.]),
+                output: Coords::from([1., 2., 3.]),
+            },
+            Test {
+                name: "each axis should be scaled by its own factor",
+                factors: Coords::from([2., 0.5, -1.]),
+                input: Coords::from([1., 2., 3.]),
+                output: Coords::from([2., 1., -3.]),
+            },
+            Test {
+                name: "a factor of zero should flatten the point into a plane",
+                factors: Coords::from([1., 1., 0.]),
+                input: Coords::from([1., 2., 3.]),
+                output: Coords::from([1., 2., 0.]),
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let scaled = NonUniformScaling::default()
+                .with_factors(test.factors)
+                .transform(test.input);
+
+            assert_eq!(
+                scaled, test.output,
+                "{}: got scaled = {:?}, want {:?}",
+                test.name, scaled, test.output
+            );
+        });
+    }
+
+    #[test]
+    fn inverse_scaling_must_restore_the_point() {
+        let point = Coords::from([1., -2., 4.]);
+
+        let scaling = Scaling::default().with_factor(4.);
+        let restored = (-scaling).transform(scaling.transform(point));
+        assert_eq!(
+            restored, point,
+            "got restored = {restored:?}, want {point:?}"
+        );
+
+        let scaling = NonUniformScaling::default().with_factors(Coords::from([2., 0.25, 8.]));
+        let restored = (-scaling).transform(scaling.transform(point));
+        assert_eq!(
+            restored, point,
+            "got restored = {restored:?}, want {point:?}"
+        );
+
+        let scaling = NonUniformScaling::from(Scaling::default().with_factor(2.));
+        let scaled = scaling.transform(point);
+        assert_eq!(
+            scaled,
+            point * 2.,
+            "got scaled = {scaled:?}, want {:?}",
+            point * 2.
+        );
+    }
 }
@@ -38,7 +38,15 @@ impl AddAssign for Translation {
 
 impl Transform for Translation {
     fn transform(&self, point: Coords) -> Coords {
-        let translation = Matrix4::new(
+        let point = self.matrix() * Vector4::new(point.x(), point.y(), point.z(), 1.);
+        [point.x, point.y, point.z].into()
+    }
+}
+
+impl Translation {
+    /// Returns the homogeneous matrix of the transformation.
+    pub(super) fn matrix(&self) -> Matrix4<f64> {
+        Matrix4::new(
             1.,
             0.,
             0.,
@@ -55,14 +63,9 @@ impl Transform for Translation {
             0.,
             0.,
             1.,
-        );
-
-        let point = translation * Vector4::new(point.x(), point.y(), point.z(), 1.);
-        [point.x, point.y, point.z].into()
+        )
     }
-}
 
-impl Translation {
     pub fn with_vector(mut self, vector: Coords) -> Self {
         self.vector = vector;
         self
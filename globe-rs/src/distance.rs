@@ -1,23 +1,56 @@
 use std::{
-    fmt::Debug,
-    ops::{Add, Div, Mul},
+    fmt::{Debug, Display},
+    iter::Sum,
+    ops::{Add, Div, Mul, Sub},
+    time::Duration,
 };
 
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
-use crate::PositiveFloat;
+use crate::{
+    quantity::{self, Quantity},
+    PositiveFloat, Velocity,
+};
 
 const METERS_PER_KM: f64 = 1000.;
+const METERS_PER_LIGHT_SECOND: f64 = 299_792_458.;
+const METERS_PER_LIGHT_YEAR: f64 = 9_460_730_472_580_800.;
+const METERS_PER_PARSEC: f64 = 30_856_775_814_913_673.;
+
+/// The symbols of the units a distance may be deserialized from, and how many kilometers each of
+/// them represents.
+const UNITS: &[(&str, f64)] = &[
+    ("km", 1.),
+    ("m", 1. / METERS_PER_KM),
+    ("AU", Distance::ASTRONOMICAL_UNIT.0 .0),
+];
 
 /// The distance between two points in space, which is always a positive number.
-#[derive(Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[derive(Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
 pub struct Distance(PositiveFloat);
 
 impl Add for Distance {
     type Output = Self;
 
     fn add(self, rhs: Self) -> Self::Output {
-        Self((self.0 .0 + rhs.0 .0).into())
+        Self(self.0 + rhs.0)
+    }
+}
+
+impl Sub for Distance {
+    type Output = Self;
+
+    /// Returns the difference between both distances, saturating at zero.
+    ///
+    /// See [Distance::checked_sub] to tell apart reversed operands.
+    fn sub(self, rhs: Self) -> Self::Output {
+        self.saturating_sub(rhs)
+    }
+}
+
+impl Sum for Distance {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::default(), Add::add)
     }
 }
 
@@ -25,7 +58,7 @@ impl Mul<f64> for Distance {
     type Output = Self;
 
     fn mul(self, rhs: f64) -> Self::Output {
-        Self((self.0 .0 * rhs).into())
+        Self(PositiveFloat::from_abs(self.0 .0 * rhs))
     }
 }
 
@@ -33,7 +66,41 @@ impl Div<f64> for Distance {
     type Output = Self;
 
     fn div(self, rhs: f64) -> Self::Output {
-        Self((self.0 .0 / rhs).into())
+        Self(PositiveFloat::from_abs(self.0 .0 / rhs))
+    }
+}
+
+impl Div<Duration> for Distance {
+    type Output = Velocity;
+
+    /// Returns the velocity required to cover the distance in the given time.
+    fn div(self, rhs: Duration) -> Self::Output {
+        Velocity::meters_sec(self.as_meters() / rhs.as_secs_f64())
+    }
+}
+
+impl Div<Velocity> for Distance {
+    type Output = Duration;
+
+    /// Returns the time it takes to cover the distance at the given velocity, saturating at
+    /// [Duration::MAX].
+    fn div(self, rhs: Velocity) -> Self::Output {
+        let secs = self.as_meters() / rhs.as_meters_sec();
+        if secs.is_nan() {
+            // covering no distance takes no time, no matter the velocity.
+            return Duration::ZERO;
+        }
+
+        Duration::try_from_secs_f64(secs).unwrap_or(Duration::MAX)
+    }
+}
+
+impl Div for Distance {
+    type Output = f64;
+
+    /// Returns the ratio between both distances.
+    fn div(self, rhs: Self) -> Self::Output {
+        self.as_km() / rhs.as_km()
     }
 }
 
@@ -45,18 +112,88 @@ impl Debug for Distance {
     }
 }
 
+impl Display for Distance {
+    /// Displays the distance in the most readable unit among meters, kilometers, astronomical
+    /// units and light-years, honoring the precision of the formatter, if any.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let au = Self::ASTRONOMICAL_UNIT.as_km();
+        let (value, unit) = if self.as_light_years() >= 0.1 {
+            (self.as_light_years(), "ly")
+        } else if self.as_km() >= au / 10. {
+            (self.as_km() / au, "AU")
+        } else if self.as_km() >= 1. {
+            (self.as_km(), "km")
+        } else {
+            (self.as_meters(), "m")
+        };
+
+        match f.precision() {
+            Some(precision) => write!(f, "{value:.precision$} {unit}"),
+            None => write!(f, "{value} {unit}"),
+        }
+    }
+}
+
+impl Serialize for Distance {
+    /// Serializes the distance in the most readable unit among meters, kilometers and
+    /// astronomical units, falling back to kilometers if the readable one would not deserialize
+    /// into the very same distance.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let au = Self::ASTRONOMICAL_UNIT.as_km();
+        let readable = if self.as_km() >= au / 10. {
+            quantity::format(self.as_km() / au, "AU")
+        } else if self.as_km() >= 1. {
+            quantity::format(self.as_km(), "km")
+        } else {
+            quantity::format(self.as_meters(), "m")
+        };
+
+        let distance = match quantity::parse(&readable, UNITS) {
+            Ok(km) if km == self.as_km() => readable,
+            _ => quantity::format(self.as_km(), "km"),
+        };
+
+        serializer.serialize_str(&distance)
+    }
+}
+
+impl<'de> Deserialize<'de> for Distance {
+    /// Deserializes a distance from either a number of kilometers, as it has always been stored,
+    /// or a number followed by its unit.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Quantity::deserialize(deserializer)?
+            .positive_value(UNITS)
+            .map(Self::km)
+    }
+}
+
 impl Distance {
     pub const ZERO: Self = Self(PositiveFloat::ZERO);
     pub const ASTRONOMICAL_UNIT: Self = Self(PositiveFloat(149_597_870.7));
 
-    /// Returns a new distance of m meters.
+    /// Returns a new distance of m meters, saturating at zero.
     pub fn meters(m: f64) -> Self {
-        Self((m / METERS_PER_KM).into())
+        Self(PositiveFloat::new_saturating(m / METERS_PER_KM))
     }
 
-    /// Returns a new distance of km kilometers.
+    /// Returns a new distance of km kilometers, saturating at zero.
     pub fn km(km: f64) -> Self {
-        Self((km).into())
+        Self(PositiveFloat::new_saturating(km))
+    }
+
+    /// Returns a new distance of ls light-seconds.
+    pub fn light_seconds(ls: f64) -> Self {
+        Self::meters(ls * METERS_PER_LIGHT_SECOND)
+    }
+
+    /// Returns a new distance of ly light-years.
+    pub fn light_years(ly: f64) -> Self {
+        Self::meters(ly * METERS_PER_LIGHT_YEAR)
+    }
+
+    /// Returns a new distance of pc parsecs.
+    pub fn parsecs(pc: f64) -> Self {
+        Self::meters(pc * METERS_PER_PARSEC)
     }
 
     /// Returns a [f64] representing the distance in meters.
@@ -69,8 +206,445 @@ impl Distance {
         self.0 .0
     }
 
+    /// Returns a [f64] representing the distance in light-seconds.
+    pub fn as_light_seconds(&self) -> f64 {
+        self.as_meters() / METERS_PER_LIGHT_SECOND
+    }
+
+    /// Returns a [f64] representing the distance in light-years.
+    pub fn as_light_years(&self) -> f64 {
+        self.as_meters() / METERS_PER_LIGHT_YEAR
+    }
+
+    /// Returns a [f64] representing the distance in parsecs.
+    pub fn as_parsecs(&self) -> f64 {
+        self.as_meters() / METERS_PER_PARSEC
+    }
+
     /// Returns the absolute difference between self and the given distance.
     pub fn abs_diff(self, rhs: Self) -> Self {
-        Self((self.0 .0 - rhs.0 .0).into())
+        Self(PositiveFloat::from_abs(self.0 .0 - rhs.0 .0))
+    }
+
+    /// Returns the difference between self and the given distance, or [None] if the latter is
+    /// greater.
+    pub fn checked_sub(self, rhs: Self) -> Option<Self> {
+        self.0.checked_sub(rhs.0).map(Self)
+    }
+
+    /// Returns the difference between self and the given distance, saturating at zero.
+    pub fn saturating_sub(self, rhs: Self) -> Self {
+        Self(self.0 - rhs.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use crate::{tests::approx_eq, Distance, Luminosity, Mass, Velocity};
+
+    #[test]
+    fn subtraction_must_not_fail() {
+        struct Test {
+            name: &'static str,
+            checked: Option<f64>,
+            saturating: f64,
+            sub: f64,
+            want: Option<f64>,
+        }
+
+        let (inner, outer) = (Distance::km(1.), Distance::km(3.));
+        let (light, heavy) = (Mass::kg(1.), Mass::kg(3.));
+        let (slow, fast) = (Velocity::meters_sec(1.), Velocity::meters_sec(3.));
+        let (dim, bright) = (Luminosity::watts(1.), Luminosity::watts(3.));
+
+        vec![
+            Test {
+                name: "distances",
+                checked: outer.checked_sub(inner).map(|d| d.as_km()),
+                saturating: outer.saturating_sub(inner).as_km(),
+                sub: (outer - inner).as_km(),
+                want: Some(2.),
+            },
+            Test {
+                name: "reversed distances",
+                checked: inner.checked_sub(outer).map(|d| d.as_km()),
+                saturating: inner.saturating_sub(outer).as_km(),
+                sub: (inner - outer).as_km(),
+                want: None,
+            },
+            Test {
+                name: "equal distances",
+                checked: inner.checked_sub(inner).map(|d| d.as_km()),
+                saturating: inner.saturating_sub(inner).as_km(),
+                sub: (inner - inner).as_km(),
+                want: Some(0.),
+            },
+            Test {
+                name: "masses",
+                checked: heavy.checked_sub(light).map(|m| m.as_kg()),
+                saturating: heavy.saturating_sub(light).as_kg(),
+                sub: (heavy - light).as_kg(),
+                want: Some(2.),
+            },
+            Test {
+                name: "reversed masses",
+                checked: light.checked_sub(heavy).map(|m| m.as_kg()),
+                saturating: light.saturating_sub(heavy).as_kg(),
+                sub: (light - heavy).as_kg(),
+                want: None,
+            },
+            Test {
+                name: "velocities",
+                checked: fast.checked_sub(slow).map(|v| v.as_meters_sec()),
+                saturating: fast.saturating_sub(slow).as_meters_sec(),
+                sub: (fast - slow).as_meters_sec(),
+                want: Some(2.),
+            },
+            Test {
+                name: "reversed velocities",
+                checked: slow.checked_sub(fast).map(|v| v.as_meters_sec()),
+                saturating: slow.saturating_sub(fast).as_meters_sec(),
+                sub: (slow - fast).as_meters_sec(),
+                want: None,
+            },
+            Test {
+                name: "luminosities",
+                checked: bright.checked_sub(dim).map(|l| l.as_watts()),
+                saturating: bright.saturating_sub(dim).as_watts(),
+                sub: (bright - dim).as_watts(),
+                want: Some(2.),
+            },
+            Test {
+                name: "reversed luminosities",
+                checked: dim.checked_sub(bright).map(|l| l.as_watts()),
+                saturating: dim.saturating_sub(bright).as_watts(),
+                sub: (dim - bright).as_watts(),
+                want: None,
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            assert_eq!(
+                test.checked, test.want,
+                "{}: got checked = {:?}, want {:?}",
+                test.name, test.checked, test.want
+            );
+
+            let saturated = test.want.unwrap_or_default();
+            assert_eq!(
+                test.saturating, saturated,
+                "{}: got saturating = {}, want {saturated}",
+                test.name, test.saturating
+            );
+            assert_eq!(
+                test.sub, saturated,
+                "{}: got sub = {}, want {saturated}",
+                test.name, test.sub
+            );
+        });
+    }
+
+    #[test]
+    fn dimensional_arithmetic_must_not_fail() {
+        struct Test {
+            name: &'static str,
+            output: f64,
+            want: f64,
+        }
+
+        vec![
+            Test {
+                name: "distance over duration",
+                output: (Distance::km(3.) / Duration::from_secs(2)).as_meters_sec(),
+                want: 1_500.,
+            },
+            Test {
+                name: "distance over velocity",
+                output: (Distance::km(3.) / Velocity::meters_sec(1_500.)).as_secs_f64(),
+                want: 2.,
+            },
+            Test {
+                name: "distance over zero velocity",
+                output: (Distance::km(3.) / Velocity::meters_sec(0.)).as_secs_f64(),
+                want: Duration::MAX.as_secs_f64(),
+            },
+            Test {
+                name: "no distance over zero velocity",
+                output: (Distance::ZERO / Velocity::meters_sec(0.)).as_secs_f64(),
+                want: 0.,
+            },
+            Test {
+                name: "distance over distance",
+                output: Distance::km(3.) / Distance::meters(1_500.),
+                want: 2.,
+            },
+            Test {
+                name: "velocity times duration",
+                output: (Velocity::meters_sec(1_500.) * Duration::from_secs(2)).as_km(),
+                want: 3.,
+            },
+            Test {
+                name: "duration times velocity",
+                output: (Duration::from_secs(2) * Velocity::meters_sec(1_500.)).as_km(),
+                want: 3.,
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            assert!(
+                approx_eq(test.output, test.want, 1e-9),
+                "{}: got {}, want {}",
+                test.name,
+                test.output,
+                test.want
+            );
+        });
+    }
+
+    #[test]
+    fn conversions_must_not_fail() {
+        struct Test {
+            name: &'static str,
+            distance: Distance,
+            meters: f64,
+            back: fn(&Distance) -> f64,
+            value: f64,
+        }
+
+        vec![
+            Test {
+                name: "light-seconds",
+                distance: Distance::light_seconds(1.),
+                meters: 299_792_458.,
+                back: Distance::as_light_seconds,
+                value: 1.,
+            },
+            Test {
+                name: "light-years",
+                distance: Distance::light_years(1.),
+                meters: 9.4607304725808e15,
+                back: Distance::as_light_years,
+                value: 1.,
+            },
+            Test {
+                name: "parsecs",
+                distance: Distance::parsecs(2.),
+                meters: 2. * 3.085_677_581_491_367e16,
+                back: Distance::as_parsecs,
+                value: 2.,
+            },
+            Test {
+                name: "parsecs in light-years",
+                distance: Distance::parsecs(1.),
+                meters: 3.085_677_581_491_367e16,
+                back: Distance::as_light_years,
+                value: 3.261563777,
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let meters = test.distance.as_meters();
+            assert!(
+                approx_eq(meters, test.meters, test.meters * 1e-12),
+                "{}: got meters = {meters}, want {}",
+                test.name,
+                test.meters
+            );
+
+            let value = (test.back)(&test.distance);
+            assert!(
+                approx_eq(value, test.value, 1e-9),
+                "{}: got value = {value}, want {}",
+                test.name,
+                test.value
+            );
+        });
+    }
+
+    #[test]
+    fn display_must_not_fail() {
+        struct Test {
+            name: &'static str,
+            input: String,
+            output: &'static str,
+        }
+
+        vec![
+            Test {
+                name: "meters",
+                input: Distance::meters(500.).to_string(),
+                output: "500 m",
+            },
+            Test {
+                name: "kilometers",
+                input: Distance::km(6_371.).to_string(),
+                output: "6371 km",
+            },
+            Test {
+                name: "astronomical units",
+                input: Distance::ASTRONOMICAL_UNIT.to_string(),
+                output: "1 AU",
+            },
+            Test {
+                name: "light-years",
+                input: Distance::light_years(4.2465).to_string(),
+                output: "4.2465 ly",
+            },
+            Test {
+                name: "with precision",
+                input: format!("{:.2}", Distance::ASTRONOMICAL_UNIT * 5.2044),
+                output: "5.20 AU",
+            },
+            Test {
+                name: "with zero precision",
+                input: format!("{:.0}", Distance::meters(1.6)),
+                output: "2 m",
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            assert_eq!(
+                test.input, test.output,
+                "{}: got output = {:?}, want {:?}",
+                test.name, test.input, test.output
+            );
+        });
+    }
+
+    #[test]
+    fn deserialize_must_not_fail() {
+        struct Test {
+            name: &'static str,
+            input: &'static str,
+            output: Option<Distance>,
+        }
+
+        vec![
+            Test {
+                name: "bare number in kilometers",
+                input: "1500",
+                output: Some(Distance::km(1_500.)),
+            },
+            Test {
+                name: "meters",
+                input: r#""1500 m""#,
+                output: Some(Distance::km(1.5)),
+            },
+            Test {
+                name: "kilometers",
+                input: r#""696340 km""#,
+                output: Some(Distance::km(696_340.)),
+            },
+            Test {
+                name: "astronomical units",
+                input: r#""1 AU""#,
+                output: Some(Distance::ASTRONOMICAL_UNIT),
+            },
+            Test {
+                name: "no whitespace",
+                input: r#""696340km""#,
+                output: Some(Distance::km(696_340.)),
+            },
+            Test {
+                name: "surrounding whitespace",
+                input: r#""  2 km  ""#,
+                output: Some(Distance::km(2.)),
+            },
+            Test {
+                name: "different case",
+                input: r#""2 KM""#,
+                output: Some(Distance::km(2.)),
+            },
+            Test {
+                name: "scientific notation",
+                input: r#""6.9634e5 km""#,
+                output: Some(Distance::km(696_340.)),
+            },
+            Test {
+                name: "invalid suffix",
+                input: r#""2 pc""#,
+                output: None,
+            },
+            Test {
+                name: "missing number",
+                input: r#""km""#,
+                output: None,
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let output = serde_json::from_str::<Distance>(test.input).ok();
+            assert_eq!(
+                output, test.output,
+                "{}: got output = {:?}, want {:?}",
+                test.name, output, test.output
+            );
+        });
+    }
+
+    #[test]
+    fn serialize_must_not_fail() {
+        struct Test {
+            name: &'static str,
+            input: Distance,
+            output: &'static str,
+        }
+
+        vec![
+            Test {
+                name: "meters",
+                input: Distance::meters(500.),
+                output: "500 m",
+            },
+            Test {
+                name: "kilometers",
+                input: Distance::km(696_340.),
+                output: "696340 km",
+            },
+            Test {
+                name: "astronomical units",
+                input: Distance::ASTRONOMICAL_UNIT,
+                output: "1 AU",
+            },
+            Test {
+                name: "inexact astronomical units",
+                input: Distance::km(120_000_000.),
+                output: "1.2e8 km",
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let output = serde_json::to_value(test.input).unwrap();
+            assert_eq!(
+                output, test.output,
+                "{}: got output = {:?}, want {:?}",
+                test.name, output, test.output
+            );
+        });
+    }
+
+    #[test]
+    fn serialize_must_round_trip() {
+        [
+            Distance::meters(0.1),
+            Distance::meters(123.456),
+            Distance::km(1.1),
+            Distance::km(384_748.),
+            Distance::ASTRONOMICAL_UNIT,
+            Distance::km(120_000_000.),
+            Distance::km(778_479_000.7),
+            Distance::light_years(4.2465),
+        ]
+        .into_iter()
+        .for_each(|distance| {
+            let source = serde_json::to_string(&distance).unwrap();
+            let got = serde_json::from_str::<Distance>(&source).unwrap();
+            assert_eq!(
+                got, distance,
+                "got {got:?} from {source}, want {distance:?}"
+            );
+        });
     }
 }
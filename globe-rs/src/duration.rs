@@ -0,0 +1,310 @@
+use std::{fmt::Display, time::Duration};
+
+use crate::{quantity, ParseQuantityError};
+
+pub(crate) const SECONDS_PER_MINUTE: u64 = 60;
+pub(crate) const SECONDS_PER_HOUR: u64 = 60 * SECONDS_PER_MINUTE;
+pub(crate) const SECONDS_PER_DAY: u64 = 24 * SECONDS_PER_HOUR;
+/// The seconds in a Julian year, of 365.25 days.
+pub(crate) const SECONDS_PER_YEAR: u64 = 36_525 * SECONDS_PER_DAY / 100;
+
+/// The symbols of the units a duration may be displayed in, from the greatest to the smallest, and
+/// how many seconds each of them represents.
+const UNITS: &[(&str, u64)] = &[
+    ("y", SECONDS_PER_YEAR),
+    ("d", SECONDS_PER_DAY),
+    ("h", SECONDS_PER_HOUR),
+    ("min", SECONDS_PER_MINUTE),
+    ("s", 1),
+];
+
+/// A [Duration] displayed in the most readable unit among seconds, minutes, hours, days and years.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct HumanDuration(pub Duration);
+
+impl Display for HumanDuration {
+    /// Displays the duration in the greatest unit it reaches at least once, honoring the precision
+    /// of the formatter, if any.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let (unit, seconds) = UNITS
+            .iter()
+            .find(|(_, seconds)| self.0.as_secs() >= *seconds)
+            .unwrap_or(&("s", 1));
+
+        let value = self.0.as_secs_f64() / *seconds as f64;
+        match f.precision() {
+            Some(precision) => write!(f, "{value:.precision$} {unit}"),
+            None => write!(f, "{value} {unit}"),
+        }
+    }
+}
+
+/// Returns the given duration as its two most significant units, rounded down to whole seconds,
+/// e.g. "27 d 7 h".
+pub fn format_duration(duration: Duration) -> String {
+    let mut remaining = duration.as_secs();
+    let parts: Vec<String> = UNITS
+        .iter()
+        .map(|(unit, seconds)| {
+            let value = remaining / seconds;
+            remaining %= seconds;
+            (value, unit)
+        })
+        .skip_while(|(value, _)| *value == 0)
+        .take(2)
+        .filter(|(value, _)| *value > 0)
+        .map(|(value, unit)| format!("{value} {unit}"))
+        .collect();
+
+    if parts.is_empty() {
+        return "0 s".to_string();
+    }
+
+    parts.join(" ")
+}
+
+/// Parses a duration from either a number of seconds or a number followed by its time unit, e.g.
+/// "365d" or "2.5 y", as accepted in serialized documents.
+pub fn parse_duration(text: &str) -> Result<Duration, ParseQuantityError> {
+    quantity::duration::parse(text)
+}
+
+/// Returns the given duration as a clock reading of years, days, hours and minutes, rounded down
+/// to whole minutes, e.g. "2 y 114 d 07:45". Years and days are omitted until they are reached.
+pub fn format_elapsed(duration: Duration) -> String {
+    let seconds = duration.as_secs();
+    let (years, seconds) = (seconds / SECONDS_PER_YEAR, seconds % SECONDS_PER_YEAR);
+    let (days, seconds) = (seconds / SECONDS_PER_DAY, seconds % SECONDS_PER_DAY);
+    let (hours, seconds) = (seconds / SECONDS_PER_HOUR, seconds % SECONDS_PER_HOUR);
+    let minutes = seconds / SECONDS_PER_MINUTE;
+
+    let time = format!("{hours:02}:{minutes:02}");
+    if years > 0 {
+        format!("{years} y {days} d {time}")
+    } else if days > 0 {
+        format!("{days} d {time}")
+    } else {
+        time
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::{
+        format_duration, format_elapsed, parse_duration, HumanDuration, SECONDS_PER_DAY,
+        SECONDS_PER_HOUR, SECONDS_PER_YEAR,
+    };
+
+    #[test]
+    fn format_duration_must_not_fail() {
+        struct Test {
+            name: &'static str,
+            input: Duration,
+            output: &'static str,
+        }
+
+        vec![
+            Test {
+                name: "zero",
+                input: Duration::ZERO,
+                output: "0 s",
+            },
+            Test {
+                name: "less than a second",
+                input: Duration::from_millis(500),
+                output: "0 s",
+            },
+            Test {
+                name: "seconds",
+                input: Duration::from_secs(42),
+                output: "42 s",
+            },
+            Test {
+                name: "minutes and seconds",
+                input: Duration::from_secs(125),
+                output: "2 min 5 s",
+            },
+            Test {
+                name: "whole hours",
+                input: Duration::from_secs(3 * 3_600),
+                output: "3 h",
+            },
+            Test {
+                name: "days and hours",
+                input: Duration::from_secs(27 * SECONDS_PER_DAY + 7 * 3_600 + 43 * 60),
+                output: "27 d 7 h",
+            },
+            Test {
+                name: "days and no hours",
+                input: Duration::from_secs(2 * SECONDS_PER_DAY + 59),
+                output: "2 d",
+            },
+            Test {
+                name: "years and days",
+                input: Duration::from_secs(SECONDS_PER_YEAR + 30 * SECONDS_PER_DAY),
+                output: "1 y 30 d",
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let output = format_duration(test.input);
+            assert_eq!(
+                output, test.output,
+                "{}: got output = {output}, want {}",
+                test.name, test.output
+            );
+        });
+    }
+
+    #[test]
+    fn format_elapsed_must_not_fail() {
+        struct Test {
+            name: &'static str,
+            input: Duration,
+            output: &'static str,
+        }
+
+        vec![
+            Test {
+                name: "zero",
+                input: Duration::ZERO,
+                output: "00:00",
+            },
+            Test {
+                name: "less than a minute",
+                input: Duration::from_secs(59),
+                output: "00:00",
+            },
+            Test {
+                name: "hours and minutes",
+                input: Duration::from_secs(7 * SECONDS_PER_HOUR + 45 * 60 + 12),
+                output: "07:45",
+            },
+            Test {
+                name: "days",
+                input: Duration::from_secs(114 * SECONDS_PER_DAY + 7 * SECONDS_PER_HOUR),
+                output: "114 d 07:00",
+            },
+            Test {
+                name: "years and no days",
+                input: Duration::from_secs(SECONDS_PER_YEAR + 60),
+                output: "1 y 0 d 00:01",
+            },
+            Test {
+                name: "years, days, hours and minutes",
+                input: Duration::from_secs(
+                    2 * SECONDS_PER_YEAR + 114 * SECONDS_PER_DAY + 7 * SECONDS_PER_HOUR + 45 * 60,
+                ),
+                output: "2 y 114 d 07:45",
+            },
+            Test {
+                name: "thousands of years",
+                input: Duration::from_secs(4_500 * SECONDS_PER_YEAR + 23 * SECONDS_PER_HOUR),
+                output: "4500 y 0 d 23:00",
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let output = format_elapsed(test.input);
+            assert_eq!(
+                output, test.output,
+                "{}: got output = {output}, want {}",
+                test.name, test.output
+            );
+        });
+    }
+
+    #[test]
+    fn parse_duration_must_not_fail() {
+        struct Test {
+            name: &'static str,
+            input: &'static str,
+            output: Option<Duration>,
+        }
+
+        vec![
+            Test {
+                name: "bare number of seconds",
+                input: "90",
+                output: Some(Duration::from_secs(90)),
+            },
+            Test {
+                name: "days with no space",
+                input: "365d",
+                output: Some(Duration::from_secs(365 * SECONDS_PER_DAY)),
+            },
+            Test {
+                name: "fraction of years",
+                input: "2.5 y",
+                output: Some(Duration::from_secs(5 * SECONDS_PER_YEAR / 2)),
+            },
+            Test {
+                name: "negative duration",
+                input: "-1h",
+                output: None,
+            },
+            Test {
+                name: "unknown unit",
+                input: "3 fortnights",
+                output: None,
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let output = parse_duration(test.input).ok();
+            assert_eq!(
+                output, test.output,
+                "{}: got output = {output:?}, want {:?}",
+                test.name, test.output
+            );
+        });
+    }
+
+    #[test]
+    fn display_must_not_fail() {
+        struct Test {
+            name: &'static str,
+            input: String,
+            output: &'static str,
+        }
+
+        vec![
+            Test {
+                name: "less than a second",
+                input: HumanDuration(Duration::from_millis(250)).to_string(),
+                output: "0.25 s",
+            },
+            Test {
+                name: "minutes",
+                input: HumanDuration(Duration::from_secs(90)).to_string(),
+                output: "1.5 min",
+            },
+            Test {
+                name: "days with precision",
+                input: format!(
+                    "{:.2}",
+                    HumanDuration(Duration::from_secs_f64(27.321_661 * 86_400.))
+                ),
+                output: "27.32 d",
+            },
+            Test {
+                name: "years",
+                input: format!(
+                    "{:.1}",
+                    HumanDuration(Duration::from_secs(SECONDS_PER_YEAR))
+                ),
+                output: "1.0 y",
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            assert_eq!(
+                test.input, test.output,
+                "{}: got output = {}, want {}",
+                test.name, test.input, test.output
+            );
+        });
+    }
+}
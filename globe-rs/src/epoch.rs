@@ -0,0 +1,566 @@
+use std::{
+    fmt::Display,
+    ops::{Add, Sub},
+    time::Duration,
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::duration::{SECONDS_PER_DAY, SECONDS_PER_HOUR, SECONDS_PER_MINUTE};
+
+/// The Julian date of the J2000 epoch.
+const J2000_JULIAN_DATE: f64 = 2_451_545.;
+/// The days from 1970-01-01 to 2000-01-01.
+const J2000_UNIX_DAYS: i64 = 10_957;
+/// The seconds from the midnight to the noon of the J2000 epoch.
+const J2000_SECONDS_OF_DAY: u64 = 12 * SECONDS_PER_HOUR;
+
+/// An error while building an [Epoch] from a calendar date.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DateError {
+    /// The month is not in the range of [1, 12].
+    Month(u32),
+    /// The day does not exist in the given month of the given year.
+    Day { year: i32, month: u32, day: u32 },
+    /// The hour is not in the range of [0, 24).
+    Hour(u32),
+    /// The minute is not in the range of [0, 60).
+    Minute(u32),
+    /// The second is not in the range of [0, 60).
+    Second(f64),
+    /// The epoch is out of the range of dates the target calendar can represent.
+    OutOfRange(Epoch),
+}
+
+impl Display for DateError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DateError::Month(month) => write!(f, "a month must be in [1, 12], got {month}"),
+            DateError::Day { year, month, day } => {
+                write!(f, "{year:04}-{month:02} has no day {day}")
+            }
+            DateError::Hour(hour) => write!(f, "an hour must be lower than 24, got {hour}"),
+            DateError::Minute(minute) => {
+                write!(f, "a minute must be lower than 60, got {minute}")
+            }
+            DateError::Second(second) => {
+                write!(f, "a second must be in [0, 60), got {second}")
+            }
+            DateError::OutOfRange(epoch) => {
+                write!(f, "the epoch {} is out of the representable range", epoch.0)
+            }
+        }
+    }
+}
+
+impl std::error::Error for DateError {}
+
+/// An instant in time, as the seconds elapsed since (or before, if negative) the
+/// [J2000](https://en.wikipedia.org/wiki/Epoch_(astronomy)#Julian_years_and_J2000) epoch, which is
+/// the noon of January 1, 2000.
+///
+/// Dates are those of the proleptic Gregorian calendar, made of days of exactly 86,400 seconds.
+/// Hence, leap seconds and the difference between time scales (e.g. TT and UTC) are ignored.
+///
+/// ## Example
+/// ```
+/// use globe_rs::Epoch;
+///
+/// let epoch = Epoch::from_ymd_hms(2000, 1, 2, 0, 0, 0.).unwrap();
+/// assert_eq!(epoch.as_seconds(), 43_200.);
+/// assert_eq!(epoch.as_julian_date(), 2_451_545.5);
+/// assert_eq!(epoch.to_string(), "2000-01-02 00:00:00");
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, PartialOrd, Serialize, Deserialize)]
+pub struct Epoch(f64);
+
+impl Display for Epoch {
+    /// Displays the epoch as its date and time, rounded down to whole seconds, or as its seconds
+    /// since J2000 if it has no date.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let Some((year, month, day, hour, minute, second)) = self.as_ymd_hms() else {
+            return write!(f, "{} s since J2000 (out of range)", self.0);
+        };
+
+        write!(
+            f,
+            "{year:04}-{month:02}-{day:02} {hour:02}:{minute:02}:{:02}",
+            second as u32
+        )
+    }
+}
+
+impl Add<Duration> for Epoch {
+    type Output = Self;
+
+    fn add(self, rhs: Duration) -> Self::Output {
+        Self(self.0 + rhs.as_secs_f64())
+    }
+}
+
+impl Sub<Duration> for Epoch {
+    type Output = Self;
+
+    fn sub(self, rhs: Duration) -> Self::Output {
+        Self(self.0 - rhs.as_secs_f64())
+    }
+}
+
+impl Epoch {
+    /// The noon of January 1, 2000, which is the origin of time of any simulation.
+    pub const J2000: Self = Self(0.);
+
+    /// Returns the epoch of the given seconds since J2000.
+    pub fn from_seconds(seconds: f64) -> Self {
+        Self(seconds)
+    }
+
+    /// Returns the seconds since J2000.
+    pub fn as_seconds(&self) -> f64 {
+        self.0
+    }
+
+    /// Returns the epoch of the given date and time, or the first of its fields out of range.
+    pub fn from_ymd_hms(
+        year: i32,
+        month: u32,
+        day: u32,
+        hour: u32,
+        minute: u32,
+        second: f64,
+    ) -> Result<Self, DateError> {
+        if !(1..=12).contains(&month) {
+            return Err(DateError::Month(month));
+        }
+
+        if !(1..=days_in_month(year, month)).contains(&day) {
+            return Err(DateError::Day { year, month, day });
+        }
+
+        if hour >= 24 {
+            return Err(DateError::Hour(hour));
+        }
+
+        if minute >= 60 {
+            return Err(DateError::Minute(minute));
+        }
+
+        if !(0. ..60.).contains(&second) {
+            return Err(DateError::Second(second));
+        }
+
+        let days = days_from_civil(year, month, day) - J2000_UNIX_DAYS;
+        let seconds = hour as u64 * SECONDS_PER_HOUR + minute as u64 * SECONDS_PER_MINUTE;
+
+        Ok(Self(
+            (days * SECONDS_PER_DAY as i64) as f64 + seconds as f64 + second
+                - J2000_SECONDS_OF_DAY as f64,
+        ))
+    }
+
+    /// Returns the year, month, day, hour, minute and second of the epoch.
+    ///
+    /// Returns None if the epoch is not finite or its year does not fit in an [i32].
+    pub fn as_ymd_hms(&self) -> Option<(i32, u32, u32, u32, u32, f64)> {
+        let seconds = self.0 + J2000_SECONDS_OF_DAY as f64;
+        let days = (seconds / SECONDS_PER_DAY as f64).floor();
+        if !(i64::MIN as f64..i64::MAX as f64).contains(&days) {
+            return None;
+        }
+
+        // clamping prevents rounding errors from reaching the next day.
+        let seconds = (seconds - days * SECONDS_PER_DAY as f64).clamp(0., SECONDS_PER_DAY as f64);
+
+        let (year, month, day) = (days as i64)
+            .checked_add(J2000_UNIX_DAYS)
+            .and_then(civil_from_days)?;
+        let hour = (seconds / SECONDS_PER_HOUR as f64).floor().min(23.);
+        let seconds = seconds - hour * SECONDS_PER_HOUR as f64;
+        let minute = (seconds / SECONDS_PER_MINUTE as f64).floor().min(59.);
+        let second = seconds - minute * SECONDS_PER_MINUTE as f64;
+
+        Some((year, month, day, hour as u32, minute as u32, second))
+    }
+
+    /// Returns the epoch of the given [Julian date](https://en.wikipedia.org/wiki/Julian_day).
+    pub fn from_julian_date(julian_date: f64) -> Self {
+        Self((julian_date - J2000_JULIAN_DATE) * SECONDS_PER_DAY as f64)
+    }
+
+    /// Returns the [Julian date](https://en.wikipedia.org/wiki/Julian_day) of the epoch.
+    pub fn as_julian_date(&self) -> f64 {
+        J2000_JULIAN_DATE + self.0 / SECONDS_PER_DAY as f64
+    }
+
+    /// Returns the time elapsed from the given epoch to self, if any.
+    pub fn duration_since(&self, earlier: Epoch) -> Option<Duration> {
+        Duration::try_from_secs_f64(self.0 - earlier.0).ok()
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl From<chrono::NaiveDateTime> for Epoch {
+    fn from(datetime: chrono::NaiveDateTime) -> Self {
+        let j2000 = chrono::NaiveDate::from_ymd_opt(2000, 1, 1)
+            .and_then(|date| date.and_hms_opt(12, 0, 0))
+            .expect("J2000 must be a valid date");
+
+        let elapsed = datetime - j2000;
+        Self(
+            elapsed.num_seconds() as f64
+                + elapsed.subsec_nanos() as f64 / Duration::from_secs(1).as_nanos() as f64,
+        )
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl TryFrom<Epoch> for chrono::NaiveDateTime {
+    type Error = DateError;
+
+    fn try_from(epoch: Epoch) -> Result<Self, Self::Error> {
+        epoch
+            .as_ymd_hms()
+            .and_then(|(year, month, day, hour, minute, second)| {
+                let nanos = (second.fract() * Duration::from_secs(1).as_nanos() as f64) as u32;
+                chrono::NaiveDate::from_ymd_opt(year, month, day)
+                    .and_then(|date| date.and_hms_nano_opt(hour, minute, second as u32, nanos))
+            })
+            .ok_or(DateError::OutOfRange(epoch))
+    }
+}
+
+/// Returns true if, and only if, the given year has 366 days.
+fn is_leap_year(year: i32) -> bool {
+    (year % 4 == 0 && year % 100 != 0) || year % 400 == 0
+}
+
+/// Returns the amount of days in the given month of the given year.
+fn days_in_month(year: i32, month: u32) -> u32 {
+    match month {
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+/// Returns the days from 1970-01-01 to the given date, as described in
+/// [chrono-Compatible Low-Level Date Algorithms](https://howardhinnant.github.io/date_algorithms.html#days_from_civil).
+fn days_from_civil(year: i32, month: u32, day: u32) -> i64 {
+    // years start in March, so the leap day is the last one of the year.
+    let year = year as i64 - (month <= 2) as i64;
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let day_of_year = (153 * ((month as i64 + 9) % 12) + 2) / 5 + day as i64 - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+
+    era * 146_097 + day_of_era - 719_468
+}
+
+/// Returns the date at the given days since 1970-01-01, as described in
+/// [chrono-Compatible Low-Level Date Algorithms](https://howardhinnant.github.io/date_algorithms.html#civil_from_days).
+///
+/// Returns None if the year of the date does not fit in an [i32].
+fn civil_from_days(days: i64) -> Option<(i32, u32, u32)> {
+    let days = days.checked_add(719_468)?;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days - era * 146_097;
+    let year_of_era =
+        (day_of_era - day_of_era / 1_460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_from_march = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * month_from_march + 2) / 5 + 1;
+    let month = if month_from_march < 10 {
+        month_from_march + 3
+    } else {
+        month_from_march - 9
+    };
+
+    let year = year_of_era + era * 400 + (month <= 2) as i64;
+    i32::try_from(year)
+        .ok()
+        .map(|year| (year, month as u32, day as u32))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use crate::{tests::approx_eq, DateError, Epoch};
+
+    #[test]
+    fn from_ymd_hms_must_not_fail() {
+        struct Test {
+            name: &'static str,
+            date: (i32, u32, u32, u32, u32, f64),
+            output: Result<Epoch, DateError>,
+        }
+
+        vec![
+            Test {
+                name: "J2000",
+                date: (2000, 1, 1, 12, 0, 0.),
+                output: Ok(Epoch::J2000),
+            },
+            Test {
+                name: "unix epoch",
+                date: (1970, 1, 1, 0, 0, 0.),
+                output: Ok(Epoch::from_seconds(-946_728_000.)),
+            },
+            Test {
+                name: "leap day of a leap year multiple of 400",
+                date: (2000, 2, 29, 12, 0, 0.),
+                output: Ok(Epoch::from_seconds(59. * 86_400.)),
+            },
+            Test {
+                name: "leap day of a leap year multiple of 4",
+                date: (2024, 2, 29, 0, 0, 0.),
+                output: Ok(Epoch::from_seconds(8_825. * 86_400. - 43_200.)),
+            },
+            Test {
+                name: "leap day of a common year multiple of 100",
+                date: (1900, 2, 29, 0, 0, 0.),
+                output: Err(DateError::Day {
+                    year: 1900,
+                    month: 2,
+                    day: 29,
+                }),
+            },
+            Test {
+                name: "leap day of a common year",
+                date: (2023, 2, 29, 0, 0, 0.),
+                output: Err(DateError::Day {
+                    year: 2023,
+                    month: 2,
+                    day: 29,
+                }),
+            },
+            Test {
+                name: "thirty-first of a thirty days month",
+                date: (2000, 4, 31, 0, 0, 0.),
+                output: Err(DateError::Day {
+                    year: 2000,
+                    month: 4,
+                    day: 31,
+                }),
+            },
+            Test {
+                name: "thirteenth month",
+                date: (2000, 13, 1, 0, 0, 0.),
+                output: Err(DateError::Month(13)),
+            },
+            Test {
+                name: "twenty-fourth hour",
+                date: (2000, 1, 1, 24, 0, 0.),
+                output: Err(DateError::Hour(24)),
+            },
+            Test {
+                name: "sixtieth second",
+                date: (2000, 1, 1, 0, 0, 60.),
+                output: Err(DateError::Second(60.)),
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let (year, month, day, hour, minute, second) = test.date;
+            let output = Epoch::from_ymd_hms(year, month, day, hour, minute, second);
+            assert_eq!(
+                output, test.output,
+                "{}: got epoch = {output:?}, want {:?}",
+                test.name, test.output
+            );
+        });
+    }
+
+    #[test]
+    fn ymd_hms_must_round_trip() {
+        const ABS_ERROR: f64 = 1e-6;
+
+        vec![
+            (2000, 1, 1, 12, 0, 0.),
+            (2000, 2, 29, 23, 59, 59.5),
+            (2000, 3, 1, 0, 0, 0.),
+            (1999, 12, 31, 23, 59, 59.),
+            (1900, 3, 1, 6, 30, 15.25),
+            (1600, 2, 29, 0, 0, 0.),
+            (-4713, 11, 24, 12, 0, 0.),
+            (2100, 2, 28, 18, 45, 0.),
+        ]
+        .into_iter()
+        .for_each(|(year, month, day, hour, minute, second)| {
+            let epoch = Epoch::from_ymd_hms(year, month, day, hour, minute, second).unwrap();
+            let (got_year, got_month, got_day, got_hour, got_minute, got_second) =
+                epoch.as_ymd_hms().unwrap();
+
+            assert_eq!(
+                (got_year, got_month, got_day, got_hour, got_minute),
+                (year, month, day, hour, minute),
+                "got date = {:?}, want {:?}",
+                epoch.as_ymd_hms(),
+                (year, month, day, hour, minute, second)
+            );
+
+            assert!(
+                approx_eq(got_second, second, ABS_ERROR),
+                "got second = {got_second}, want {second}"
+            );
+        });
+    }
+
+    #[test]
+    fn display_must_not_fail() {
+        struct Test {
+            name: &'static str,
+            epoch: Epoch,
+            output: &'static str,
+        }
+
+        vec![
+            Test {
+                name: "j2000",
+                epoch: Epoch::J2000,
+                output: "2000-01-01 12:00:00",
+            },
+            Test {
+                name: "before the common era",
+                epoch: Epoch::from_ymd_hms(-4713, 11, 24, 12, 0, 0.).unwrap(),
+                output: "-4713-11-24 12:00:00",
+            },
+            Test {
+                name: "year beyond i32",
+                epoch: Epoch::from_seconds(1e17),
+                output: "100000000000000000 s since J2000 (out of range)",
+            },
+            Test {
+                name: "day beyond i64",
+                epoch: Epoch::from_seconds(1e30),
+                output: "1000000000000000000000000000000 s since J2000 (out of range)",
+            },
+            Test {
+                name: "infinite",
+                epoch: Epoch::from_seconds(f64::NEG_INFINITY),
+                output: "-inf s since J2000 (out of range)",
+            },
+            Test {
+                name: "not a number",
+                epoch: Epoch::from_seconds(f64::NAN),
+                output: "NaN s since J2000 (out of range)",
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let output = test.epoch.to_string();
+            assert_eq!(
+                output, test.output,
+                "{}: got output = {output}, want {}",
+                test.name, test.output
+            );
+        });
+    }
+
+    #[test]
+    fn julian_date_must_not_fail() {
+        const ABS_ERROR: f64 = 1e-6;
+
+        struct Test {
+            name: &'static str,
+            date: (i32, u32, u32, u32, u32, f64),
+            julian_date: f64,
+        }
+
+        vec![
+            Test {
+                name: "J2000",
+                date: (2000, 1, 1, 12, 0, 0.),
+                julian_date: 2_451_545.,
+            },
+            Test {
+                name: "origin of the Julian dates",
+                date: (-4713, 11, 24, 12, 0, 0.),
+                julian_date: 0.,
+            },
+            Test {
+                name: "unix epoch",
+                date: (1970, 1, 1, 0, 0, 0.),
+                julian_date: 2_440_587.5,
+            },
+            Test {
+                name: "after a leap day",
+                date: (2024, 3, 1, 18, 0, 0.),
+                julian_date: 2_460_371.25,
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let (year, month, day, hour, minute, second) = test.date;
+            let epoch = Epoch::from_ymd_hms(year, month, day, hour, minute, second).unwrap();
+
+            let julian_date = epoch.as_julian_date();
+            assert!(
+                approx_eq(julian_date, test.julian_date, ABS_ERROR),
+                "{}: got julian date = {julian_date}, want {}",
+                test.name,
+                test.julian_date
+            );
+
+            let seconds = Epoch::from_julian_date(test.julian_date).as_seconds();
+            assert!(
+                approx_eq(seconds, epoch.as_seconds(), ABS_ERROR * 86_400.),
+                "{}: got seconds = {seconds}, want {}",
+                test.name,
+                epoch.as_seconds()
+            );
+        });
+    }
+
+    #[test]
+    fn duration_since_must_not_fail() {
+        let epoch = Epoch::J2000 + Duration::from_secs(90);
+
+        assert_eq!(
+            epoch.duration_since(Epoch::J2000),
+            Some(Duration::from_secs(90))
+        );
+        assert_eq!(Epoch::J2000.duration_since(epoch), None);
+        assert_eq!(epoch - Duration::from_secs(90), Epoch::J2000);
+    }
+
+    #[test]
+    #[cfg(feature = "chrono")]
+    fn chrono_must_agree_on_leap_years() {
+        use chrono::Datelike;
+
+        (1590..2410).for_each(|year| {
+            let february = chrono::NaiveDate::from_ymd_opt(year, 3, 1)
+                .and_then(|date| date.pred_opt())
+                .unwrap();
+
+            let leap_day = Epoch::from_ymd_hms(year, 2, 29, 0, 0, 0.);
+            assert_eq!(
+                leap_day.is_ok(),
+                february.day() == 29,
+                "{year}: got leap day = {leap_day:?}"
+            );
+
+            let datetime = february.and_hms_opt(7, 30, 0).unwrap();
+            let epoch = Epoch::from(datetime);
+            assert_eq!(
+                Epoch::from_ymd_hms(year, 2, february.day(), 7, 30, 0.),
+                Ok(epoch),
+                "{year}: got epoch = {epoch:?}"
+            );
+            assert_eq!(chrono::NaiveDateTime::try_from(epoch), Ok(datetime));
+        });
+    }
+
+    #[test]
+    #[cfg(feature = "chrono")]
+    fn chrono_must_reject_out_of_range_epochs() {
+        // a billion years are far beyond the range of chrono.
+        let epoch = Epoch::from_seconds(1e9 * 365.25 * 86_400.);
+        assert_eq!(
+            chrono::NaiveDateTime::try_from(epoch),
+            Err(DateError::OutOfRange(epoch))
+        );
+    }
+}
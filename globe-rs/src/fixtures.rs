@@ -0,0 +1,36 @@
+//! Fixtures shared by the tests of this crate and the ones of the crates depending on it.
+//!
+//! This module only exists for tests: other crates must enable the `fixtures` feature from their
+//! dev-dependencies, never from their regular ones, since fixtures unwrap on invalid input.
+
+use std::{str::FromStr, time::Duration};
+
+use alvidir::name::Name;
+
+use crate::{Body, Distance, Mass, OrbitalSystem, Rotation};
+
+/// Returns a body with the given name, an Earth-like mass, a radius of one kilometer and spinning
+/// once an hour.
+pub fn body(name: &str) -> Body {
+    Body::new(Name::from_str(name).unwrap())
+        .with_radius(Distance::km(1.))
+        .with_mass(Mass::kg(1e24))
+        .with_spin(Rotation {
+            period: Duration::from_secs(3600),
+            ..Default::default()
+        })
+}
+
+/// Returns a system made of a planet orbiting a sun in the given orbit, both of them as returned
+/// by [body].
+pub fn system<O>(orbit: O) -> OrbitalSystem<O> {
+    OrbitalSystem {
+        primary: body("sun"),
+        orbit: None,
+        secondary: vec![OrbitalSystem {
+            primary: body("planet"),
+            orbit: Some(orbit),
+            secondary: Default::default(),
+        }],
+    }
+}
@@ -0,0 +1,259 @@
+use std::{fmt::Debug, ops::Mul, time::Duration};
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::{
+    quantity::{self, Quantity},
+    PositiveFloat, Radian,
+};
+
+/// The symbols of the units a frequency may be deserialized from, and how many hertz each of them
+/// represents.
+const UNITS: &[(&str, f64)] = &[("Hz", 1.), ("mHz", 1e-3), ("µHz", 1e-6), ("uHz", 1e-6)];
+
+/// The number of cycles an arbitrary event completes per second, which is always a positive
+/// number.
+#[derive(Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Frequency(PositiveFloat);
+
+impl From<Duration> for Frequency {
+    /// The frequency of an event repeating once every period.
+    ///
+    /// An event with no period never repeats, hence its frequency is zero instead of infinite.
+    fn from(period: Duration) -> Self {
+        if period.is_zero() {
+            return Self::ZERO;
+        }
+
+        Self::hz(1. / period.as_secs_f64())
+    }
+}
+
+impl Mul<Duration> for Frequency {
+    type Output = Radian;
+
+    /// Returns the angle swept at this frequency during the given time, being each cycle a full
+    /// rotation.
+    fn mul(self, rhs: Duration) -> Self::Output {
+        let cycles = (self.as_hz() * rhs.as_secs_f64()).fract();
+        Radian::from(cycles * Radian::TWO_PI.as_f64())
+    }
+}
+
+impl Mul<Frequency> for Duration {
+    type Output = Radian;
+
+    fn mul(self, rhs: Frequency) -> Self::Output {
+        rhs * self
+    }
+}
+
+impl Debug for Frequency {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("Frequency")
+            .field(&format!("{} Hz", self.0))
+            .finish()
+    }
+}
+
+impl Serialize for Frequency {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&quantity::format(self.as_hz(), "Hz"))
+    }
+}
+
+impl<'de> Deserialize<'de> for Frequency {
+    /// Deserializes a frequency from either a number of hertz or a number followed by its unit.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Quantity::deserialize(deserializer)?
+            .positive_value(UNITS)
+            .map(Self::hz)
+    }
+}
+
+impl Frequency {
+    pub const ZERO: Self = Self(PositiveFloat::ZERO);
+
+    /// Returns a new frequency of f hertz, saturating at zero.
+    pub fn hz(f: f64) -> Self {
+        Self(PositiveFloat::new_saturating(f))
+    }
+
+    /// Returns a [f64] representing the frequency in hertz.
+    pub fn as_hz(&self) -> f64 {
+        self.0 .0
+    }
+
+    /// Returns true if, and only if, the frequency is zero.
+    pub fn is_zero(&self) -> bool {
+        self.0 == PositiveFloat::ZERO
+    }
+
+    /// Returns the time it takes to complete a cycle, if any.
+    pub fn period(&self) -> Option<Duration> {
+        if self.is_zero() {
+            return None;
+        }
+
+        Duration::try_from_secs_f64(1. / self.as_hz()).ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        f64::consts::{FRAC_PI_2, PI},
+        time::Duration,
+    };
+
+    use crate::{tests::approx_eq, Frequency, Radian};
+
+    #[test]
+    fn frequency_from_duration_must_not_fail() {
+        struct Test {
+            name: &'static str,
+            input: Duration,
+            output: Frequency,
+        }
+
+        vec![
+            Test {
+                name: "one second",
+                input: Duration::from_secs(1),
+                output: Frequency::hz(1.),
+            },
+            Test {
+                name: "half a second",
+                input: Duration::from_millis(500),
+                output: Frequency::hz(2.),
+            },
+            Test {
+                name: "one day",
+                input: Duration::from_secs(86_400),
+                output: Frequency::hz(1. / 86_400.),
+            },
+            Test {
+                name: "zero",
+                input: Duration::ZERO,
+                output: Frequency::ZERO,
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let output = Frequency::from(test.input);
+            assert_eq!(
+                output, test.output,
+                "{}: got frequency = {output:?}, want {:?}",
+                test.name, test.output
+            );
+
+            let period = output.period();
+            let want = (!test.input.is_zero()).then_some(test.input);
+            assert_eq!(
+                period, want,
+                "{}: got period = {period:?}, want {want:?}",
+                test.name
+            );
+        });
+    }
+
+    #[test]
+    fn frequency_times_duration_must_not_fail() {
+        struct Test {
+            name: &'static str,
+            frequency: Frequency,
+            time: Duration,
+            output: f64,
+        }
+
+        vec![
+            Test {
+                name: "quarter of cycle",
+                frequency: Frequency::hz(1.),
+                time: Duration::from_millis(250),
+                output: FRAC_PI_2,
+            },
+            Test {
+                name: "several cycles and a half",
+                frequency: Frequency::hz(2.),
+                time: Duration::from_millis(1_250),
+                output: PI,
+            },
+            Test {
+                name: "high frequency",
+                frequency: Frequency::hz(10.),
+                time: Duration::from_millis(125),
+                output: FRAC_PI_2,
+            },
+            Test {
+                name: "zero frequency",
+                frequency: Frequency::ZERO,
+                time: Duration::from_secs(1_000),
+                output: 0.,
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let output = test.frequency * test.time;
+            assert!(
+                approx_eq(output.as_f64(), test.output, 1e-9),
+                "{}: got output = {output:?}, want {:?}",
+                test.name,
+                Radian::from(test.output)
+            );
+        });
+    }
+
+    #[test]
+    fn radian_from_frequency_must_not_fail() {
+        let radian = Radian::from(Frequency::hz(0.25));
+        assert!(
+            approx_eq(radian.as_f64(), FRAC_PI_2, 1e-12),
+            "got radian = {radian:?}, want π/2"
+        );
+
+        let radian = Radian::from(Duration::ZERO);
+        assert_eq!(radian, Radian::default(), "a zero period must not spin");
+    }
+
+    #[test]
+    fn deserialize_must_not_fail() {
+        struct Test {
+            name: &'static str,
+            input: &'static str,
+            output: Option<Frequency>,
+        }
+
+        vec![
+            Test {
+                name: "bare number in hertz",
+                input: "2",
+                output: Some(Frequency::hz(2.)),
+            },
+            Test {
+                name: "hertz",
+                input: r#""2 Hz""#,
+                output: Some(Frequency::hz(2.)),
+            },
+            Test {
+                name: "millihertz",
+                input: r#""500 mHz""#,
+                output: Some(Frequency::hz(0.5)),
+            },
+            Test {
+                name: "invalid suffix",
+                input: r#""2 rpm""#,
+                output: None,
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let output = serde_json::from_str::<Frequency>(test.input).ok();
+            assert_eq!(
+                output, test.output,
+                "{}: got output = {:?}, want {:?}",
+                test.name, output, test.output
+            );
+        });
+    }
+}
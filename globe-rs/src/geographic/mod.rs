@@ -0,0 +1,1714 @@
+use std::{
+    f64::consts::{FRAC_PI_2, PI},
+    fmt::Display,
+    str::FromStr,
+};
+
+use serde::{de::Error, Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::{
+    cartesian::{self, transform::Rotation},
+    quantity::Quantity,
+    Distance, PositiveFloat, Radian,
+};
+
+mod polygon;
+pub use polygon::*;
+
+mod region;
+pub use region::*;
+
+/// Represents the horizontal axis in a geographic system of coordinates.
+///
+/// ## Definition
+/// Since the longitude of a point on a sphere is the angle east (positive) or west (negative) in
+/// reference of the maridian zero, the longitude value must be in the range __[-π, +π)__. Any
+/// other value will be computed in order to set its equivalent inside the range.
+///
+/// ### Overflow
+/// Both boundaries of the longitude range are consecutive, which means that overflowing one is the
+/// same as continuing from the other in the same direction.
+///
+/// ## Example
+/// ```
+/// use globe_rs::geographic::Longitude;
+/// use std::f64::consts::PI;
+///
+/// assert_eq!(
+///     Longitude::from(PI + 1_f64),
+///     Longitude::from(-PI + 1_f64)
+/// );
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, PartialOrd)]
+pub struct Longitude(f64);
+
+impl From<f64> for Longitude {
+    fn from(value: f64) -> Self {
+        Self(
+            (-PI..=PI)
+                .contains(&value)
+                .then_some(value)
+                .unwrap_or_else(|| {
+                    // Both boundaries of the range are consecutive, which means that
+                    // overflowing one is the same as continuing from the other in the
+                    // same direction.
+                    (value + PI).rem_euclid(2_f64 * PI) - PI
+                }),
+        )
+    }
+}
+
+impl From<Longitude> for f64 {
+    fn from(value: Longitude) -> Self {
+        value.0
+    }
+}
+
+impl From<cartesian::Coords> for Longitude {
+    /// Computes the [Longitude] of the given [Cartesian] as specified by the [Spherical
+    /// coordinate system](https://en.wikipedia.org/wiki/Spherical_coordinate_system).
+    fn from(point: cartesian::Coords) -> Self {
+        match (point.x(), point.y()) {
+            (x, y) if x > 0. => (y / x).atan(),
+            (x, y) if x < 0. && y >= 0. => (y / x).atan() + PI,
+            (x, y) if x < 0. && y < 0. => (y / x).atan() - PI,
+            (x, y) if x == 0. && y > 0. => FRAC_PI_2,
+            (x, y) if x == 0. && y < 0. => -FRAC_PI_2,
+            (x, y) if x == 0. && y == 0. => 0., // fallback value
+
+            _ => 0., // fallback value
+        }
+        .into()
+    }
+}
+
+impl Display for Longitude {
+    /// Displays the longitude in degrees, minutes and seconds followed by its hemisphere, honoring
+    /// the precision of the formatter, if any, for the seconds.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let hemisphere = if self.0 < 0. { 'W' } else { 'E' };
+        format_dms(f, self.as_degrees(), hemisphere)
+    }
+}
+
+impl FromStr for Longitude {
+    type Err = ParseAngleError;
+
+    /// Parses a longitude from either decimal degrees or degrees, minutes and seconds, optionally
+    /// followed by its hemisphere (E or W).
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_degrees(s, ('E', 'W')).map(Self::from_degrees)
+    }
+}
+
+impl Serialize for Longitude {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_f64(self.0)
+    }
+}
+
+impl<'de> Deserialize<'de> for Longitude {
+    /// Deserializes a longitude from either a number of radians or a string of degrees, as
+    /// accepted by [Longitude::from_str].
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        match Quantity::deserialize(deserializer)? {
+            Quantity::Number(radians) => Ok(Self::from(radians)),
+            Quantity::Text(degrees) => degrees.parse().map_err(D::Error::custom),
+        }
+    }
+}
+
+impl Longitude {
+    /// Returns the [f64] representation of tha longitude in the range of __[-1.0, 1.0)__,
+    /// resulting from dividing self with `π`.
+    pub fn normal(&self) -> f64 {
+        self.0 / PI
+    }
+
+    /// Returns the longitude of the given degrees, wrapped as any other longitude.
+    pub fn from_degrees(degrees: f64) -> Self {
+        Self::from(degrees.to_radians())
+    }
+
+    /// Returns the longitude in degrees, in the range of __[-180, 180]__.
+    pub fn as_degrees(&self) -> f64 {
+        self.0.to_degrees()
+    }
+}
+
+/// Represents the vertical axis in a geographic system of coordinates.
+///
+/// ## Definition
+/// Since the latitude of a point on a sphere is the angle between the equatorial plane and the
+/// straight line that goes through that point and the center of the sphere, the latitude value
+/// must be in the range __\[-π/2, +π/2\]__. Any other value must be computed in order to set
+/// its equivalent inside the range.
+///
+/// ### Overflow
+/// Overflowing any of both boundaries of the latitude range behaves like moving away from that
+/// boundary and getting closer to the oposite one.
+///
+/// ## Example
+/// ```
+/// use globe_rs::geographic::Latitude;
+/// use std::f64::consts::PI;
+///
+/// let overflowing_latitude = Latitude::from(-5. * PI / 4.);
+/// let equivalent_latitude = Latitude::from(PI / 4.);
+///
+/// // due precision error both values may not be exactly the same  
+/// let abs_error = 0.0000000000000002;
+///
+/// assert!(
+///     (f64::from(equivalent_latitude) - f64::from(overflowing_latitude)).abs() <= abs_error,
+///     "the overflowing latitude should be as the equivalent latitude ± e"
+/// );
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, PartialOrd)]
+pub struct Latitude(f64);
+
+impl From<f64> for Latitude {
+    fn from(value: f64) -> Self {
+        Self(if (-FRAC_PI_2..=FRAC_PI_2).contains(&value) {
+            value
+        } else {
+            value.sin().asin()
+        })
+    }
+}
+
+impl From<Latitude> for f64 {
+    fn from(value: Latitude) -> Self {
+        value.0
+    }
+}
+
+impl From<cartesian::Coords> for Latitude {
+    /// Computes the [Latitude] of the given [Cartesian] as specified by the [Spherical
+    /// coordinate system](https://en.wikipedia.org/wiki/Spherical_coordinate_system).
+    ///
+    /// Any point on the plane XY, including the origin of coordinates, which has no latitude at
+    /// all, is considered to be on the equator.
+    fn from(point: cartesian::Coords) -> Self {
+        let horizontal = f64::sqrt(point.x().powi(2) + point.y().powi(2));
+        match point.z() {
+            0. => 0.,
+            z if horizontal == 0. => FRAC_PI_2.copysign(z),
+            z => z.atan2(horizontal),
+        }
+        .into()
+    }
+}
+
+impl Display for Latitude {
+    /// Displays the latitude in degrees, minutes and seconds followed by its hemisphere, honoring
+    /// the precision of the formatter, if any, for the seconds.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let hemisphere = if self.0 < 0. { 'S' } else { 'N' };
+        format_dms(f, self.as_degrees(), hemisphere)
+    }
+}
+
+impl FromStr for Latitude {
+    type Err = ParseAngleError;
+
+    /// Parses a latitude from either decimal degrees or degrees, minutes and seconds, optionally
+    /// followed by its hemisphere (N or S).
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_degrees(s, ('N', 'S')).map(Self::from_degrees)
+    }
+}
+
+impl Serialize for Latitude {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_f64(self.0)
+    }
+}
+
+impl<'de> Deserialize<'de> for Latitude {
+    /// Deserializes a latitude from either a number of radians or a string of degrees, as
+    /// accepted by [Latitude::from_str].
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        match Quantity::deserialize(deserializer)? {
+            Quantity::Number(radians) => Ok(Self::from(radians)),
+            Quantity::Text(degrees) => degrees.parse().map_err(D::Error::custom),
+        }
+    }
+}
+
+impl Latitude {
+    /// Returns the [f64] representation of tha latitude in the range of __[-1.0, 1.0)__, resulting
+    /// from dividing self with `π/2`.
+    pub fn normal(&self) -> f64 {
+        self.0 / FRAC_PI_2
+    }
+
+    /// Returns the latitude of the given degrees, reflected as any other latitude.
+    pub fn from_degrees(degrees: f64) -> Self {
+        Self::from(degrees.to_radians())
+    }
+
+    /// Returns the latitude in degrees, in the range of __[-90, 90]__.
+    pub fn as_degrees(&self) -> f64 {
+        self.0.to_degrees()
+    }
+}
+
+/// An error while parsing a [Latitude] or [Longitude] from a string.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseAngleError {
+    /// The string is empty.
+    Empty,
+    /// Some of the components of the angle is not a valid number.
+    NotANumber(String),
+    /// The minutes or seconds are not in the range of [0, 60).
+    OutOfRange(String),
+    /// The hemisphere does not belong to the parsed axis.
+    Hemisphere(char),
+    /// The angle has more components than degrees, minutes and seconds, or it is both negative
+    /// and followed by its hemisphere.
+    Malformed(String),
+}
+
+impl Display for ParseAngleError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ParseAngleError::Empty => write!(f, "an angle cannot be empty"),
+            ParseAngleError::NotANumber(value) => write!(f, "{value:?} is not a number"),
+            ParseAngleError::OutOfRange(value) => {
+                write!(f, "minutes and seconds must be lower than 60, got {value}")
+            }
+            ParseAngleError::Hemisphere(hemisphere) => {
+                write!(f, "{hemisphere:?} is not a valid hemisphere")
+            }
+            ParseAngleError::Malformed(value) => write!(f, "{value:?} is not a valid angle"),
+        }
+    }
+}
+
+impl std::error::Error for ParseAngleError {}
+
+/// The symbols of degrees, minutes and seconds, including their ASCII equivalents.
+const DMS_SYMBOLS: &[char] = &['°', '′', '\'', '″', '"'];
+
+/// Writes the given degrees as degrees, minutes and seconds followed by the given hemisphere.
+fn format_dms(f: &mut std::fmt::Formatter<'_>, degrees: f64, hemisphere: char) -> std::fmt::Result {
+    let precision = f.precision().unwrap_or_default();
+    let scale = 10_f64.powi(precision as i32);
+
+    // rounding the total of seconds first makes any carry propagate to minutes and degrees.
+    let seconds = (degrees.abs() * 3_600. * scale).round() / scale;
+    let whole_degrees = (seconds / 3_600.).floor();
+    let minutes = ((seconds - whole_degrees * 3_600.) / 60.).floor();
+    let seconds = seconds - whole_degrees * 3_600. - minutes * 60.;
+
+    write!(
+        f,
+        "{whole_degrees}°{minutes}′{seconds:.precision$}″{hemisphere}"
+    )
+}
+
+/// Parses the degrees represented by the given string, either in decimal or as degrees, minutes
+/// and seconds, optionally followed by any of the given positive or negative hemispheres.
+fn parse_degrees(s: &str, (positive, negative): (char, char)) -> Result<f64, ParseAngleError> {
+    let s = s.trim();
+    let (s, sign) = match s.chars().last() {
+        None => return Err(ParseAngleError::Empty),
+        Some(hemisphere) if hemisphere.is_alphabetic() => {
+            let sign = match hemisphere.to_ascii_uppercase() {
+                hemisphere if hemisphere == positive => 1.,
+                hemisphere if hemisphere == negative => -1.,
+                _ => return Err(ParseAngleError::Hemisphere(hemisphere)),
+            };
+
+            let s = s[..s.len() - hemisphere.len_utf8()].trim_end();
+            if s.starts_with('-') {
+                return Err(ParseAngleError::Malformed(s.to_string()));
+            }
+
+            (s, sign)
+        }
+        Some(_) => (s, 1.),
+    };
+
+    let components = s
+        .split(|c: char| c.is_whitespace() || DMS_SYMBOLS.contains(&c))
+        .filter(|component| !component.is_empty())
+        .map(|component| {
+            component
+                .parse::<f64>()
+                .ok()
+                .filter(|value| value.is_finite())
+                .ok_or_else(|| ParseAngleError::NotANumber(component.to_string()))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let degrees = match components.as_slice() {
+        [] => return Err(ParseAngleError::Empty),
+        [degrees] => *degrees,
+        [degrees, rest @ ..] if rest.len() <= 2 => {
+            if let Some(value) = rest.iter().find(|value| !(0. ..60.).contains(*value)) {
+                return Err(ParseAngleError::OutOfRange(value.to_string()));
+            }
+
+            let fraction = rest
+                .iter()
+                .zip([60., 3_600.])
+                .map(|(value, divisor)| value / divisor)
+                .sum::<f64>();
+
+            degrees.signum() * (degrees.abs() + fraction)
+        }
+        _ => return Err(ParseAngleError::Malformed(s.to_string())),
+    };
+
+    Ok(sign * degrees)
+}
+
+/// Represents the radius in a geographic system of coordinates.
+///
+/// ## Definition
+/// Since the altitude of a point on a sphere is the distance between that point and the center of
+/// the sphere, the altitude value must be positive. The absolute of any other value must be
+/// computed in order to get a proper radius notation.
+///
+/// ## Example
+/// ```
+/// use globe_rs::geographic::Altitude;
+///
+/// assert_eq!(
+///     Altitude::from(-1.56),
+///     Altitude::from(1.56)
+/// );
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[serde(from = "f64", into = "f64")]
+pub struct Altitude(PositiveFloat);
+
+impl From<f64> for Altitude {
+    fn from(value: f64) -> Self {
+        Self(PositiveFloat::from_abs(value))
+    }
+}
+
+impl From<Altitude> for f64 {
+    fn from(value: Altitude) -> Self {
+        value.0 .0
+    }
+}
+
+impl From<cartesian::Coords> for Altitude {
+    /// Computes the [Altitude] of the given [Cartesian] as specified by the [Spherical
+    /// coordinate system](https://en.wikipedia.org/wiki/Spherical_coordinate_system).
+    fn from(point: cartesian::Coords) -> Self {
+        f64::sqrt(point.x().powi(2) + point.y().powi(2) + point.z().powi(2)).into()
+    }
+}
+
+/// Coordinates accodring to the geographical system of coordinates.
+///
+/// When deserialized, the longitude and latitude are either numbers of radians or strings of
+/// degrees, and any missing field defaults to zero.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Coords {
+    pub longitude: Longitude,
+    pub latitude: Latitude,
+    pub altitude: Altitude,
+}
+
+impl From<cartesian::Coords> for Coords {
+    fn from(point: cartesian::Coords) -> Self {
+        Self::default()
+            .with_longitude(point.into())
+            .with_latitude(point.into())
+            .with_altitude(point.into())
+    }
+}
+
+impl Coords {
+    pub fn with_longitude(mut self, longitude: Longitude) -> Self {
+        self.longitude = longitude;
+        self
+    }
+
+    pub fn with_latitude(mut self, latitude: Latitude) -> Self {
+        self.latitude = latitude;
+        self
+    }
+
+    pub fn with_altitude(mut self, altitude: Altitude) -> Self {
+        self.altitude = altitude;
+        self
+    }
+
+    /// Computes the [great-circle distance](https://en.wikipedia.org/wiki/Great-circle_distance)
+    /// from self to the given point (in radiants).
+    ///
+    /// Uses the [haversine formula](https://en.wikipedia.org/wiki/Haversine_formula), which keeps
+    /// its precision for nearby points, unlike the spherical law of cosines.
+    pub fn distance(&self, other: &Coords) -> f64 {
+        let latitude_diff = f64::from(other.latitude) - f64::from(self.latitude);
+        let longitude_diff = f64::from(other.longitude) - f64::from(self.longitude);
+        let prod_latitude_cos = f64::from(self.latitude).cos() * f64::from(other.latitude).cos();
+
+        let haversine = (latitude_diff / 2.).sin().powi(2)
+            + prod_latitude_cos * (longitude_diff / 2.).sin().powi(2);
+
+        // rounding errors may push the haversine slightly out of the [0, 1] range.
+        2. * haversine.clamp(0., 1.).sqrt().asin()
+    }
+
+    /// Computes the great-circle distance from self to the given point on the surface of a
+    /// sphere of the given radius.
+    pub fn distance_on(&self, other: &Coords, radius: Distance) -> Distance {
+        radius * self.distance(other)
+    }
+
+    /// Computes the initial [bearing](https://en.wikipedia.org/wiki/Bearing_(navigation)) of the
+    /// great-circle route from self to the given point, clockwise from the north.
+    ///
+    /// At the poles, where the north is undefined, the bearing is relative to the meridian of
+    /// the longitude of the point.
+    pub fn bearing_to(&self, other: &Coords) -> Radian {
+        let (latitude, other_latitude) = (f64::from(self.latitude), f64::from(other.latitude));
+        let longitude_diff = f64::from(other.longitude) - f64::from(self.longitude);
+
+        Radian::from(f64::atan2(
+            longitude_diff.sin() * other_latitude.cos(),
+            latitude.cos() * other_latitude.sin()
+                - latitude.sin() * other_latitude.cos() * longitude_diff.cos(),
+        ))
+    }
+
+    /// Returns the point at the given angular distance from self along the great-circle route
+    /// departing with the given bearing, keeping the same altitude.
+    pub fn destination(&self, bearing: Radian, angular_distance: Radian) -> Coords {
+        let latitude = f64::from(self.latitude);
+        let (bearing, distance) = (bearing.as_f64(), angular_distance.as_f64());
+
+        let destination_latitude = (latitude.sin() * distance.cos()
+            + latitude.cos() * distance.sin() * bearing.cos())
+        .clamp(-1., 1.)
+        .asin();
+
+        let longitude_diff = f64::atan2(
+            bearing.sin() * distance.sin() * latitude.cos(),
+            distance.cos() - latitude.sin() * destination_latitude.sin(),
+        );
+
+        Self::default()
+            .with_longitude((f64::from(self.longitude) + longitude_diff).into())
+            .with_latitude(destination_latitude.into())
+            .with_altitude(self.altitude)
+    }
+
+    /// Returns the point at the given fraction of the great-circle route from self to the given
+    /// point, where 0 is self and 1 the other point, by [spherical linear interpolation](https://en.wikipedia.org/wiki/Slerp).
+    /// The altitude is interpolated linearly.
+    ///
+    /// Since any great circle joins two antipodal points, the route between them is the one
+    /// given by [Rotation::between](cartesian::transform::Rotation::between).
+    pub fn interpolate(&self, other: &Coords, t: f64) -> Coords {
+        let on_surface =
+            |point: &Coords| cartesian::Coords::from(point.with_altitude(Altitude::from(1.)));
+
+        let (from, to) = (on_surface(self), on_surface(other));
+        let rotation = Rotation::between(from, to);
+        let rotation = rotation.with_theta(rotation.theta * t);
+
+        let altitude =
+            f64::from(self.altitude) + (f64::from(other.altitude) - f64::from(self.altitude)) * t;
+
+        Coords::from(from.transform(rotation)).with_altitude(altitude.into())
+    }
+
+    /// Returns the given amount of segments of the great-circle route from self to the given
+    /// point, as the succession of points joining them, both ends included.
+    pub fn great_circle_path(&self, other: &Coords, segments: usize) -> Vec<Coords> {
+        if segments == 0 {
+            return Vec::new();
+        }
+
+        (0..=segments)
+            .map(|index| self.interpolate(other, index as f64 / segments as f64))
+            .collect()
+    }
+
+    /// Returns the point halfway the great-circle route from self to the given point, at the mean
+    /// altitude of both.
+    pub fn midpoint(&self, other: &Coords) -> Coords {
+        let (latitude, other_latitude) = (f64::from(self.latitude), f64::from(other.latitude));
+        let longitude_diff = f64::from(other.longitude) - f64::from(self.longitude);
+
+        let x = other_latitude.cos() * longitude_diff.cos();
+        let y = other_latitude.cos() * longitude_diff.sin();
+
+        let midpoint_latitude = f64::atan2(
+            latitude.sin() + other_latitude.sin(),
+            ((latitude.cos() + x).powi(2) + y.powi(2)).sqrt(),
+        );
+
+        let midpoint_longitude = f64::from(self.longitude) + f64::atan2(y, latitude.cos() + x);
+
+        Self::default()
+            .with_longitude(midpoint_longitude.into())
+            .with_latitude(midpoint_latitude.into())
+            .with_altitude(((f64::from(self.altitude) + f64::from(other.altitude)) / 2.).into())
+    }
+
+    /// Returns the cartesian coordinates of self, being its altitude the height (in meters) above
+    /// the surface of a sphere of the given radius centered at the origin of coordinates.
+    ///
+    /// Unlike the [From] implementation, which considers the unit sphere, an altitude of zero
+    /// stands for a point on the surface of the sphere.
+    pub fn to_cartesian_on(&self, radius: Distance) -> cartesian::Coords {
+        let direction = cartesian::Coords::from(self.with_altitude(Altitude::default()));
+        direction * (radius.as_meters() + f64::from(self.altitude))
+    }
+
+    /// Returns the geographic coordinates of the given point, being its altitude the height (in
+    /// meters) above the surface of a sphere of the given radius centered at the origin of
+    /// coordinates.
+    ///
+    /// Points below the surface of the sphere are projected onto it, since the altitude cannot be
+    /// negative.
+    pub fn from_cartesian_on(point: cartesian::Coords, radius: Distance) -> Self {
+        let height = point.magnitude() - radius.as_meters();
+        Self::from(point).with_altitude(height.max(0.).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::{FRAC_PI_2, PI};
+
+    use crate::{
+        cartesian,
+        geographic::{Altitude, Coords, Latitude, Longitude, ParseAngleError},
+        tests::approx_eq,
+        Distance, Radian,
+    };
+
+    #[test]
+    fn longitude_must_not_exceed_boundaries() {
+        struct Test {
+            name: &'static str,
+            input: f64,
+            output: f64,
+        }
+
+        vec![
+            Test {
+                name: "positive longitude value must not change",
+                input: 1.,
+                output: 1.,
+            },
+            Test {
+                name: "negative longitude value must not change",
+                input: -3.,
+                output: -3.,
+            },
+            Test {
+                name: "positive overflowing longitude must change",
+                input: PI + 1.,
+                output: -PI + 1.,
+            },
+            Test {
+                name: "negative overflowing longitude must change",
+                input: -PI - 1.,
+                output: PI - 1.,
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let longitude: f64 = Longitude::from(test.input).into();
+
+            assert_eq!(
+                longitude, test.output,
+                "{}: got longitude = {}, want {}",
+                test.name, longitude, test.output
+            );
+        });
+    }
+
+    #[test]
+    fn normal_longitude_must_not_exceed_boundaries() {
+        struct Test {
+            name: &'static str,
+            input: f64,
+            output: f64,
+        }
+
+        vec![
+            Test {
+                name: "zero longitude must be equals to zero",
+                input: 0.,
+                output: 0.,
+            },
+            Test {
+                name: "positive longitude boundary must equals to positive one",
+                input: PI,
+                output: 1.,
+            },
+            Test {
+                name: "arbitrary positive longitude must be positive",
+                input: FRAC_PI_2,
+                output: 0.5,
+            },
+            Test {
+                name: "negative longitude boundary must equals to negative one",
+                input: -PI,
+                output: -1.,
+            },
+            Test {
+                name: "arbitrary negative longitude must be negative",
+                input: -FRAC_PI_2,
+                output: -0.5,
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let normal = Longitude::from(test.input).normal();
+
+            assert_eq!(
+                normal, test.output,
+                "{}: got normal = {}, want {}",
+                test.name, normal, test.output
+            );
+        });
+    }
+
+    #[test]
+    fn latitude_must_not_exceed_boundaries() {
+        const ABS_ERROR: f64 = 0.0000000000000003;
+
+        struct Test {
+            name: &'static str,
+            input: f64,
+            output: f64,
+        }
+
+        vec![
+            Test {
+                name: "positive latitude value must not change",
+                input: 1.,
+                output: 1.,
+            },
+            Test {
+                name: "negative latitude value must not change",
+                input: -1.,
+                output: -1.,
+            },
+            Test {
+                name: "positive overflowing latitude must change",
+                input: 7. * PI / 4.,
+                output: -PI / 4.,
+            },
+            Test {
+                name: "negative overflowing latidude must change",
+                input: -7. * PI / 4.,
+                output: PI / 4.,
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let latitude: f64 = Latitude::from(test.input).into();
+
+            assert!(
+                approx_eq(latitude, test.output, ABS_ERROR),
+                "{}: got latitude = {}, want {}",
+                test.name,
+                latitude,
+                test.output
+            );
+        });
+    }
+
+    #[test]
+    fn normal_latitude_must_not_exceed_boundaries() {
+        struct Test {
+            name: &'static str,
+            input: f64,
+            output: f64,
+        }
+
+        vec![
+            Test {
+                name: "zero latitude must be equals to zero",
+                input: 0.,
+                output: 0.,
+            },
+            Test {
+                name: "positive latitude boundary must equals to one",
+                input: FRAC_PI_2,
+                output: 1.,
+            },
+            Test {
+                name: "arbitrary positive latitude must be positive",
+                input: FRAC_PI_2 / 2.,
+                output: 0.5,
+            },
+            Test {
+                name: "negative latitude boundary must equals to negative one",
+                input: -FRAC_PI_2,
+                output: -1.,
+            },
+            Test {
+                name: "arbitrary negative latitude must be negative",
+                input: -FRAC_PI_2 / 2.,
+                output: -0.5,
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let normal = Latitude::from(test.input).normal();
+
+            assert_eq!(
+                normal, test.output,
+                "{}: got normal = {}, want {}",
+                test.name, normal, test.output
+            );
+        });
+    }
+
+    #[test]
+    fn geographic_from_cartesian_must_not_fail() {
+        struct Test {
+            name: &'static str,
+            input: cartesian::Coords,
+            output: Coords,
+        }
+
+        vec![
+            Test {
+                name: "north point",
+                input: cartesian::Coords::from([0., 0., 1.]),
+                output: Coords::default()
+                    .with_latitude(Latitude::from(FRAC_PI_2))
+                    .with_altitude(Altitude::from(1.)),
+            },
+            Test {
+                name: "south point",
+                input: cartesian::Coords::from([0., 0., -1.]),
+                output: Coords::default()
+                    .with_latitude(Latitude::from(-FRAC_PI_2))
+                    .with_altitude(Altitude::from(1.)),
+            },
+            Test {
+                name: "east point",
+                input: cartesian::Coords::from([0., 1., 0.]),
+                output: Coords::default()
+                    .with_longitude(Longitude::from(FRAC_PI_2))
+                    .with_altitude(Altitude::from(1.)),
+            },
+            Test {
+                name: "weast point",
+                input: cartesian::Coords::from([0., -1., 0.]),
+                output: Coords::default()
+                    .with_longitude(Longitude::from(-FRAC_PI_2))
+                    .with_altitude(Altitude::from(1.)),
+            },
+            Test {
+                name: "front point",
+                input: cartesian::Coords::from([1., 0., 0.]),
+                output: Coords::default().with_altitude(Altitude::from(1.)),
+            },
+            Test {
+                name: "back point",
+                input: cartesian::Coords::from([-1., 0., 0.]),
+                output: Coords::default()
+                    .with_longitude(Longitude::from(PI))
+                    .with_altitude(Altitude::from(1.)),
+            },
+            Test {
+                name: "point on the plane xy",
+                input: cartesian::Coords::from([-1., 1., 0.]),
+                output: Coords::default()
+                    .with_longitude(Longitude::from(3. * PI / 4.))
+                    .with_altitude(Altitude::from(2_f64.sqrt())),
+            },
+            Test {
+                name: "origin of coordinates",
+                input: cartesian::Coords::default(),
+                output: Coords::default(),
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let point = Coords::from(test.input);
+
+            assert_eq!(
+                point.longitude,
+                test.output.longitude,
+                "{}: got longitude = {}, want {}",
+                test.name,
+                f64::from(point.longitude),
+                f64::from(test.output.longitude),
+            );
+
+            assert_eq!(
+                point.latitude,
+                test.output.latitude,
+                "{}: got latitude = {}, want {}",
+                test.name,
+                f64::from(point.latitude),
+                f64::from(test.output.latitude),
+            );
+
+            assert_eq!(
+                point.altitude,
+                test.output.altitude,
+                "{}: got altitude = {}, want {}",
+                test.name,
+                f64::from(point.altitude),
+                f64::from(test.output.altitude),
+            );
+        });
+    }
+
+    #[test]
+    fn latitude_from_cartesian_must_not_fail() {
+        const ABS_ERROR: f64 = 1e-15;
+
+        struct Test {
+            name: &'static str,
+            input: cartesian::Coords,
+            output: f64,
+        }
+
+        vec![
+            Test {
+                name: "tiny positive z must be close to the equator",
+                input: cartesian::Coords::from([1., 0., 1e-300]),
+                output: 1e-300,
+            },
+            Test {
+                name: "tiny negative z must be close to the equator",
+                input: cartesian::Coords::from([0., -1., -1e-300]),
+                output: -1e-300,
+            },
+            Test {
+                name: "negative zero z must be on the equator",
+                input: cartesian::Coords::from([0., 1., -0.]),
+                output: 0.,
+            },
+            Test {
+                name: "tiny z on the axis z must be the pole",
+                input: cartesian::Coords::from([0., 0., -1e-300]),
+                output: -FRAC_PI_2,
+            },
+            Test {
+                name: "point in between the equator and the north pole",
+                input: cartesian::Coords::from([1., 1., 2_f64.sqrt()]),
+                output: PI / 4.,
+            },
+            Test {
+                name: "point in between the equator and the south pole",
+                input: cartesian::Coords::from([-1., 0., -1.]),
+                output: -PI / 4.,
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let latitude = f64::from(Latitude::from(test.input));
+            assert!(
+                approx_eq(latitude, test.output, ABS_ERROR),
+                "{}: got latitude = {latitude}, want {}",
+                test.name,
+                test.output
+            );
+        });
+    }
+
+    #[test]
+    fn cartesian_on_sphere_must_round_trip() {
+        const EARTH_RADIUS_KM: f64 = 6_371.;
+
+        struct Test {
+            name: &'static str,
+            input: Coords,
+            output: cartesian::Coords,
+        }
+
+        vec![
+            Test {
+                name: "front point on the surface",
+                input: Coords::default(),
+                output: cartesian::Coords::from([6.371e6, 0., 0.]),
+            },
+            Test {
+                name: "back point on the surface",
+                input: Coords::default().with_longitude(Longitude::from(PI)),
+                output: cartesian::Coords::from([-6.371e6, 0., 0.]),
+            },
+            Test {
+                name: "east point on the surface",
+                input: Coords::default().with_longitude(Longitude::from(FRAC_PI_2)),
+                output: cartesian::Coords::from([0., 6.371e6, 0.]),
+            },
+            Test {
+                name: "west point on the surface",
+                input: Coords::default().with_longitude(Longitude::from(-FRAC_PI_2)),
+                output: cartesian::Coords::from([0., -6.371e6, 0.]),
+            },
+            Test {
+                name: "north pole on the surface",
+                input: Coords::default().with_latitude(Latitude::from(FRAC_PI_2)),
+                output: cartesian::Coords::from([0., 0., 6.371e6]),
+            },
+            Test {
+                name: "south pole above the surface",
+                input: Coords::default()
+                    .with_latitude(Latitude::from(-FRAC_PI_2))
+                    .with_altitude(Altitude::from(1_000.)),
+                output: cartesian::Coords::from([0., 0., -6.372e6]),
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let radius = Distance::km(EARTH_RADIUS_KM);
+
+            let point = test.input.to_cartesian_on(radius);
+            assert_eq!(
+                point, test.output,
+                "{}: got cartesian = {point:?}, want {:?}",
+                test.name, test.output
+            );
+
+            let geographic = Coords::from_cartesian_on(point, radius);
+            assert_eq!(
+                geographic, test.input,
+                "{}: got geographic = {geographic:?}, want {:?}",
+                test.name, test.input
+            );
+        });
+
+        let below = Coords::from_cartesian_on(
+            cartesian::Coords::from([1_000., 0., 0.]),
+            Distance::km(EARTH_RADIUS_KM),
+        );
+        assert_eq!(below, Coords::default());
+    }
+
+    #[test]
+    fn distance_must_not_fail() {
+        struct Test<'a> {
+            name: &'a str,
+            from: Coords,
+            to: Coords,
+            distance: f64,
+        }
+
+        vec![
+            Test {
+                name: "Same point must be zero",
+                from: Coords::default(),
+                to: Coords::default(),
+                distance: 0.,
+            },
+            Test {
+                name: "Oposite points in the horizontal",
+                from: Coords::default(),
+                to: Coords::default().with_longitude(Longitude::from(-PI)),
+                distance: PI,
+            },
+            Test {
+                name: "Oposite points in the vertical",
+                from: Coords::default().with_latitude(Latitude::from(FRAC_PI_2)),
+                to: Coords::default().with_latitude(Latitude::from(-FRAC_PI_2)),
+                distance: PI,
+            },
+            Test {
+                name: "Antipodal points off the axes",
+                from: degrees(30., 45.),
+                to: degrees(-150., -45.),
+                distance: PI,
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let distance = test.from.distance(&test.to);
+
+            assert_eq!(
+                distance, test.distance,
+                "{}: distance {} ± e == {}",
+                test.name, distance, test.distance,
+            )
+        });
+    }
+
+    #[test]
+    fn distance_on_must_not_fail() {
+        const EARTH_RADIUS_KM: f64 = 6_371.;
+
+        struct Test {
+            name: &'static str,
+            from: Coords,
+            to: Coords,
+            distance: Distance,
+            abs_error: f64,
+        }
+
+        vec![
+            Test {
+                name: "one meter along the equator",
+                from: Coords::default(),
+                to: Coords::default()
+                    .with_longitude(Longitude::from(1. / (EARTH_RADIUS_KM * 1_000.))),
+                distance: Distance::meters(1.),
+                abs_error: 1e-9,
+            },
+            Test {
+                name: "one meter along a meridian",
+                from: degrees(12., 41.9),
+                to: degrees(12., 41.9).with_latitude(Latitude::from(
+                    41.9_f64.to_radians() + 1. / (EARTH_RADIUS_KM * 1_000.),
+                )),
+                distance: Distance::meters(1.),
+                abs_error: 1e-9,
+            },
+            Test {
+                name: "half of the circumference",
+                from: Coords::default(),
+                to: Coords::default().with_longitude(Longitude::from(PI)),
+                distance: Distance::km(EARTH_RADIUS_KM * PI),
+                abs_error: 0.,
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let distance = test
+                .from
+                .distance_on(&test.to, Distance::km(EARTH_RADIUS_KM));
+
+            assert!(
+                approx_eq(
+                    distance.as_meters(),
+                    test.distance.as_meters(),
+                    test.abs_error
+                ),
+                "{}: got distance = {:?}, want ± e = {:?}",
+                test.name,
+                distance,
+                test.distance
+            );
+        });
+    }
+
+    /// Returns the geographic coordinates of the given longitude and latitude, in degrees.
+    pub fn degrees(longitude: f64, latitude: f64) -> Coords {
+        Coords::default()
+            .with_longitude(longitude.to_radians().into())
+            .with_latitude(latitude.to_radians().into())
+    }
+
+    #[test]
+    fn bearing_to_must_not_fail() {
+        const ABS_ERROR: f64 = 1e-12;
+
+        struct Test {
+            name: &'static str,
+            from: Coords,
+            to: Coords,
+            bearing: f64,
+        }
+
+        vec![
+            Test {
+                name: "eastward along the equator",
+                from: degrees(0., 0.),
+                to: degrees(10., 0.),
+                bearing: FRAC_PI_2,
+            },
+            Test {
+                name: "westward along the equator",
+                from: degrees(0., 0.),
+                to: degrees(-10., 0.),
+                bearing: 3. * FRAC_PI_2,
+            },
+            Test {
+                name: "northward along a meridian",
+                from: degrees(45., -20.),
+                to: degrees(45., 30.),
+                bearing: 0.,
+            },
+            Test {
+                name: "southward along a meridian",
+                from: degrees(45., 30.),
+                to: degrees(45., -20.),
+                bearing: PI,
+            },
+            Test {
+                name: "eastward across the antimeridian",
+                from: degrees(179., 0.),
+                to: degrees(-179., 0.),
+                bearing: FRAC_PI_2,
+            },
+            Test {
+                name: "to the north pole",
+                from: degrees(-120., 10.),
+                to: degrees(0., 90.),
+                bearing: 0.,
+            },
+            Test {
+                name: "from the north pole",
+                from: degrees(0., 90.),
+                to: degrees(0., 10.),
+                bearing: PI,
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let bearing = test.from.bearing_to(&test.to);
+            let diff = bearing.signed_diff(test.bearing.into()).abs();
+            assert!(
+                diff <= ABS_ERROR,
+                "{}: got bearing = {:?}, want {}",
+                test.name,
+                bearing,
+                test.bearing
+            );
+        });
+
+        // the initial bearing from Baghdad to Osaka is about 60 degrees.
+        let bearing = degrees(45., 35.).bearing_to(&degrees(135., 35.));
+        assert!(
+            approx_eq(bearing.as_degrees(), 60., 0.5),
+            "got bearing = {} degrees, want about 60",
+            bearing.as_degrees()
+        );
+    }
+
+    #[test]
+    fn destination_must_round_trip_with_bearing() {
+        const ABS_ERROR: f64 = 1e-12;
+
+        struct Test {
+            name: &'static str,
+            from: Coords,
+            to: Coords,
+        }
+
+        vec![
+            Test {
+                name: "arbitrary cities",
+                from: degrees(-0.1278, 51.5074),
+                to: degrees(139.6917, 35.6895),
+            },
+            Test {
+                name: "across the antimeridian",
+                from: degrees(170., -10.),
+                to: degrees(-160., 20.),
+            },
+            Test {
+                name: "across the equator",
+                from: degrees(-58.3816, -34.6037),
+                to: degrees(-74.0060, 40.7128),
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let bearing = test.from.bearing_to(&test.to);
+            let distance = Radian::from(test.from.distance(&test.to));
+            let destination = test.from.destination(bearing, distance);
+
+            let error = destination.distance(&test.to);
+            assert!(
+                error <= ABS_ERROR,
+                "{}: got destination = {:?}, want ± e = {:?}",
+                test.name,
+                destination,
+                test.to
+            );
+        });
+
+        let destination =
+            degrees(179., 0.).destination(FRAC_PI_2.into(), 2_f64.to_radians().into());
+        assert!(
+            approx_eq(
+                f64::from(destination.longitude),
+                (-179_f64).to_radians(),
+                ABS_ERROR
+            ),
+            "got longitude = {:?}, want -179 degrees",
+            destination.longitude
+        );
+
+        let destination = degrees(0., 90.).destination(PI.into(), FRAC_PI_2.into());
+        assert!(
+            approx_eq(f64::from(destination.latitude), 0., ABS_ERROR),
+            "got latitude = {:?}, want the equator",
+            destination.latitude
+        );
+    }
+
+    #[test]
+    fn midpoint_must_not_fail() {
+        const ABS_ERROR: f64 = 1e-12;
+
+        struct Test {
+            name: &'static str,
+            from: Coords,
+            to: Coords,
+            midpoint: Coords,
+        }
+
+        vec![
+            Test {
+                name: "along the equator",
+                from: degrees(0., 0.),
+                to: degrees(90., 0.),
+                midpoint: degrees(45., 0.),
+            },
+            Test {
+                name: "across the antimeridian",
+                from: degrees(179., 0.),
+                to: degrees(-179., 0.),
+                midpoint: degrees(180., 0.),
+            },
+            Test {
+                name: "along the prime meridian",
+                from: degrees(0., 60.),
+                to: degrees(0., -20.),
+                midpoint: degrees(0., 20.),
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let midpoint = test.from.midpoint(&test.to);
+            let error = midpoint.distance(&test.midpoint);
+            assert!(
+                error <= ABS_ERROR,
+                "{}: got midpoint = {:?}, want ± e = {:?}",
+                test.name,
+                midpoint,
+                test.midpoint
+            );
+
+            let (from, to) = (midpoint.distance(&test.from), midpoint.distance(&test.to));
+            assert!(
+                approx_eq(from, to, ABS_ERROR),
+                "{}: got distances = {from} and {to}, want both the same",
+                test.name,
+            );
+        });
+    }
+
+    #[test]
+    fn interpolate_must_not_fail() {
+        const ABS_ERROR: f64 = 1e-12;
+
+        struct Test {
+            name: &'static str,
+            from: Coords,
+            to: Coords,
+            t: f64,
+            output: Coords,
+        }
+
+        vec![
+            Test {
+                name: "start of the route",
+                from: degrees(10., 20.),
+                to: degrees(-40., 60.),
+                t: 0.,
+                output: degrees(10., 20.),
+            },
+            Test {
+                name: "end of the route",
+                from: degrees(10., 20.),
+                to: degrees(-40., 60.),
+                t: 1.,
+                output: degrees(-40., 60.),
+            },
+            Test {
+                name: "along the equator",
+                from: degrees(0., 0.),
+                to: degrees(90., 0.),
+                t: 1. / 3.,
+                output: degrees(30., 0.),
+            },
+            Test {
+                name: "identical points",
+                from: degrees(25., -5.),
+                to: degrees(25., -5.),
+                t: 0.5,
+                output: degrees(25., -5.),
+            },
+            Test {
+                name: "across the antimeridian",
+                from: degrees(170., 0.),
+                to: degrees(-170., 0.),
+                t: 0.25,
+                output: degrees(175., 0.),
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let output = test.from.interpolate(&test.to, test.t);
+            let error = output.distance(&test.output);
+            assert!(
+                error <= ABS_ERROR,
+                "{}: got point = {:?}, want ± e = {:?}",
+                test.name,
+                output,
+                test.output
+            );
+        });
+
+        let antipode = degrees(0., 0.).interpolate(&degrees(180., 0.), 0.5);
+        let (from, to) = (
+            antipode.distance(&degrees(0., 0.)),
+            antipode.distance(&degrees(180., 0.)),
+        );
+        assert!(
+            approx_eq(from, FRAC_PI_2, ABS_ERROR) && approx_eq(to, FRAC_PI_2, ABS_ERROR),
+            "got distances = {from} and {to}, want both a quarter of a great circle"
+        );
+    }
+
+    #[test]
+    fn great_circle_path_must_not_jump_across_the_seam() {
+        const SEGMENTS: usize = 16;
+
+        let from = degrees(160., 30.).with_altitude(Altitude::from(100.));
+        let to = degrees(-150., 10.).with_altitude(Altitude::from(300.));
+        let path = from.great_circle_path(&to, SEGMENTS);
+
+        assert_eq!(path.len(), SEGMENTS + 1);
+
+        let step = from.distance(&to) / SEGMENTS as f64;
+        path.windows(2).for_each(|segment| {
+            let distance = segment[0].distance(&segment[1]);
+            assert!(
+                approx_eq(distance, step, 1e-9),
+                "got step = {distance}, want {step}"
+            );
+        });
+
+        let altitude = f64::from(path[SEGMENTS / 2].altitude);
+        assert!(
+            approx_eq(altitude, 200., 1e-9),
+            "got altitude = {altitude}, want 200"
+        );
+
+        assert!(from.great_circle_path(&to, 0).is_empty());
+    }
+
+    #[test]
+    fn dms_must_round_trip() {
+        struct Test {
+            name: &'static str,
+            latitude: f64,
+            longitude: f64,
+            display: &'static str,
+        }
+
+        vec![
+            Test {
+                name: "north east",
+                latitude: 41. + 22. / 60. + 57. / 3_600.,
+                longitude: 2. + 10. / 60. + 40. / 3_600.,
+                display: "41°22′57″N 2°10′40″E",
+            },
+            Test {
+                name: "south west",
+                latitude: -(33. + 52. / 60. + 7. / 3_600.),
+                longitude: -(70. + 39. / 60. + 58. / 3_600.),
+                display: "33°52′7″S 70°39′58″W",
+            },
+            Test {
+                name: "origin belongs to the north and east",
+                latitude: 0.,
+                longitude: 0.,
+                display: "0°0′0″N 0°0′0″E",
+            },
+            Test {
+                name: "rounding seconds must carry into minutes and degrees",
+                latitude: 10. + 59. / 60. + 59.9 / 3_600.,
+                longitude: 179.99999,
+                display: "11°0′0″N 180°0′0″E",
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let latitude = Latitude::from_degrees(test.latitude);
+            let longitude = Longitude::from_degrees(test.longitude);
+
+            let display = format!("{latitude} {longitude}");
+            assert_eq!(display, test.display, "{}", test.name);
+
+            let precise = format!("{latitude:.6}");
+            let parsed: Latitude = precise.parse().unwrap();
+            assert!(
+                approx_eq(parsed.as_degrees(), test.latitude, 1e-9),
+                "{}: got latitude = {}, want {}",
+                test.name,
+                parsed.as_degrees(),
+                test.latitude
+            );
+
+            let precise = format!("{longitude:.6}");
+            let parsed: Longitude = precise.parse().unwrap();
+            assert!(
+                approx_eq(parsed.as_degrees(), test.longitude, 1e-9),
+                "{}: got longitude = {}, want {}",
+                test.name,
+                parsed.as_degrees(),
+                test.longitude
+            );
+        });
+    }
+
+    #[test]
+    fn parse_must_not_fail() {
+        const ABS_ERROR: f64 = 1e-12;
+
+        struct Test {
+            name: &'static str,
+            input: &'static str,
+            latitude: Result<f64, ParseAngleError>,
+            longitude: Result<f64, ParseAngleError>,
+        }
+
+        vec![
+            Test {
+                name: "decimal degrees",
+                input: "-12.5",
+                latitude: Ok(-12.5),
+                longitude: Ok(-12.5),
+            },
+            Test {
+                name: "ascii dms with spaces",
+                input: " 41 22' 30\" ",
+                latitude: Ok(41.375),
+                longitude: Ok(41.375),
+            },
+            Test {
+                name: "negative dms",
+                input: "-41°22′30″",
+                latitude: Ok(-41.375),
+                longitude: Ok(-41.375),
+            },
+            Test {
+                name: "lowercase hemisphere",
+                input: "41°22′30″s",
+                latitude: Ok(-41.375),
+                longitude: Err(ParseAngleError::Hemisphere('s')),
+            },
+            Test {
+                name: "western boundary",
+                input: "180°W",
+                latitude: Err(ParseAngleError::Hemisphere('W')),
+                longitude: Ok(-180.),
+            },
+            Test {
+                name: "longitude beyond the boundary must wrap",
+                input: "190°E",
+                latitude: Err(ParseAngleError::Hemisphere('E')),
+                longitude: Ok(-170.),
+            },
+            Test {
+                name: "latitude beyond the pole must reflect",
+                input: "100N",
+                latitude: Ok(80.),
+                longitude: Err(ParseAngleError::Hemisphere('N')),
+            },
+            Test {
+                name: "empty string",
+                input: "  ",
+                latitude: Err(ParseAngleError::Empty),
+                longitude: Err(ParseAngleError::Empty),
+            },
+            Test {
+                name: "hemisphere alone",
+                input: "N",
+                latitude: Err(ParseAngleError::Empty),
+                longitude: Err(ParseAngleError::Hemisphere('N')),
+            },
+            Test {
+                name: "not a number",
+                input: "41°X′",
+                latitude: Err(ParseAngleError::NotANumber("X".into())),
+                longitude: Err(ParseAngleError::NotANumber("X".into())),
+            },
+            Test {
+                name: "minutes out of range",
+                input: "41°61′",
+                latitude: Err(ParseAngleError::OutOfRange("61".into())),
+                longitude: Err(ParseAngleError::OutOfRange("61".into())),
+            },
+            Test {
+                name: "negative with hemisphere",
+                input: "-41°N",
+                latitude: Err(ParseAngleError::Malformed("-41°".into())),
+                longitude: Err(ParseAngleError::Hemisphere('N')),
+            },
+            Test {
+                name: "too many components",
+                input: "1 2 3 4",
+                latitude: Err(ParseAngleError::Malformed("1 2 3 4".into())),
+                longitude: Err(ParseAngleError::Malformed("1 2 3 4".into())),
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let latitude = test.input.parse::<Latitude>().map(|l| l.as_degrees());
+            match (&latitude, &test.latitude) {
+                (Ok(got), Ok(want)) => assert!(
+                    approx_eq(*got, *want, ABS_ERROR),
+                    "{}: got latitude = {got}, want {want}",
+                    test.name
+                ),
+                (got, want) => assert_eq!(got, want, "{}: latitude", test.name),
+            }
+
+            let longitude = test.input.parse::<Longitude>().map(|l| l.as_degrees());
+            match (&longitude, &test.longitude) {
+                (Ok(got), Ok(want)) => assert!(
+                    approx_eq(*got, *want, ABS_ERROR),
+                    "{}: got longitude = {got}, want {want}",
+                    test.name
+                ),
+                (got, want) => assert_eq!(got, want, "{}: longitude", test.name),
+            }
+        });
+    }
+
+    #[test]
+    fn longitude_boundaries_must_keep_their_hemisphere() {
+        struct Test {
+            name: &'static str,
+            degrees: f64,
+            radians: f64,
+            display: &'static str,
+        }
+
+        vec![
+            Test {
+                name: "eastern boundary",
+                degrees: 180.,
+                radians: PI,
+                display: "180°0′0″E",
+            },
+            Test {
+                name: "western boundary",
+                degrees: -180.,
+                radians: -PI,
+                display: "180°0′0″W",
+            },
+            Test {
+                name: "beyond the eastern boundary",
+                degrees: 181.,
+                radians: -179_f64.to_radians(),
+                display: "179°0′0″W",
+            },
+            Test {
+                name: "beyond the western boundary",
+                degrees: -181.,
+                radians: 179_f64.to_radians(),
+                display: "179°0′0″E",
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let longitude = Longitude::from_degrees(test.degrees);
+            assert!(
+                approx_eq(f64::from(longitude), test.radians, 1e-12),
+                "{}: got longitude = {:?}, want {}",
+                test.name,
+                longitude,
+                test.radians
+            );
+
+            assert_eq!(longitude.to_string(), test.display, "{}", test.name);
+            assert_eq!(
+                test.display.parse::<Longitude>().map(|l| l.to_string()),
+                Ok(test.display.to_string()),
+                "{}",
+                test.name
+            );
+        });
+    }
+
+    #[test]
+    fn deserialize_must_not_fail() {
+        const ABS_ERROR: f64 = 1e-15;
+
+        struct Test {
+            name: &'static str,
+            input: &'static str,
+            output: Option<Coords>,
+        }
+
+        vec![
+            Test {
+                name: "radians",
+                input: r#"{"longitude": 1.5, "latitude": -0.5, "altitude": 2}"#,
+                output: Some(
+                    Coords::default()
+                        .with_longitude(Longitude::from(1.5))
+                        .with_latitude(Latitude::from(-0.5))
+                        .with_altitude(Altitude::from(2.)),
+                ),
+            },
+            Test {
+                name: "tagged degrees",
+                input: r#"{"longitude": "90°W", "latitude": "45°30'N"}"#,
+                output: Some(
+                    Coords::default()
+                        .with_longitude(Longitude::from_degrees(-90.))
+                        .with_latitude(Latitude::from_degrees(45.5)),
+                ),
+            },
+            Test {
+                name: "missing fields",
+                input: r#"{}"#,
+                output: Some(Coords::default()),
+            },
+            Test {
+                name: "malformed degrees",
+                input: r#"{"longitude": "north"}"#,
+                output: None,
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let output = serde_json::from_str::<Coords>(test.input).ok();
+            assert_eq!(
+                output.is_some(),
+                test.output.is_some(),
+                "{}: got coords = {output:?}, want {:?}",
+                test.name,
+                test.output
+            );
+
+            let Some((output, want)) = output.zip(test.output) else {
+                return;
+            };
+
+            assert!(
+                approx_eq(output.longitude.into(), want.longitude.into(), ABS_ERROR)
+                    && approx_eq(output.latitude.into(), want.latitude.into(), ABS_ERROR)
+                    && output.altitude == want.altitude,
+                "{}: got coords = {output:?}, want {want:?}",
+                test.name,
+            );
+        });
+    }
+
+    #[test]
+    fn serde_must_round_trip() {
+        let coords = Coords::default()
+            .with_longitude(Longitude::from(-2.1))
+            .with_latitude(Latitude::from(0.7))
+            .with_altitude(Altitude::from(6.371e6));
+
+        let json = serde_json::to_string(&coords).unwrap();
+        assert_eq!(serde_json::from_str::<Coords>(&json).unwrap(), coords);
+
+        let ron = ron::to_string(&coords).unwrap();
+        assert_eq!(ron::from_str::<Coords>(&ron).unwrap(), coords);
+    }
+}
@@ -0,0 +1,225 @@
+use std::f64::consts::PI;
+
+use crate::Distance;
+
+use super::{Coords, Longitude};
+
+/// Computes the area (in square meters) of the polygon on the surface of a sphere of the given
+/// radius whose vertices are the given points, joined by great-circle arcs.
+///
+/// The polygon is implicitly closed, no matter the winding direction of its vertices, which are
+/// assumed to be on the surface of the sphere (their altitude is ignored). Since any closed line
+/// splits the sphere into two regions, the area is the one of the smallest of them.
+///
+/// Uses the [spherical excess](https://en.wikipedia.org/wiki/Spherical_trigonometry#Area_and_spherical_excess)
+/// of the strips between each edge and the equator, which keeps its precision for both tiny
+/// polygons and polygons around any of the poles.
+pub fn polygon_area(points: &[Coords], radius: Distance) -> f64 {
+    if points.len() < 3 {
+        return 0.;
+    }
+
+    let (excess, winding) = edges(points).fold((0., 0.), |(excess, winding), (from, to)| {
+        let longitude_diff = f64::from(Longitude::from(
+            f64::from(to.longitude) - f64::from(from.longitude),
+        ));
+
+        let from_tan = (f64::from(from.latitude) / 2.).tan();
+        let to_tan = (f64::from(to.latitude) / 2.).tan();
+
+        let strip = 2.
+            * f64::atan2(
+                (longitude_diff / 2.).tan() * (from_tan + to_tan),
+                1. + from_tan * to_tan,
+            );
+
+        (excess + strip, winding + longitude_diff)
+    });
+
+    // a polygon winding around a pole encloses the whole hemisphere but the strips.
+    let excess = if winding.abs() > PI {
+        2. * PI - excess.abs()
+    } else {
+        excess.abs()
+    };
+
+    excess.min(4. * PI - excess) * radius.as_meters().powi(2)
+}
+
+/// Computes the perimeter of the polygon on the surface of a sphere of the given radius whose
+/// vertices are the given points, joined by great-circle arcs.
+///
+/// The polygon is implicitly closed, which means the perimeter includes the arc from the last
+/// point back to the first one.
+pub fn polygon_perimeter(points: &[Coords], radius: Distance) -> Distance {
+    if points.len() < 2 {
+        return Distance::ZERO;
+    }
+
+    radius
+        * edges(points)
+            .map(|(from, to)| from.distance(to))
+            .sum::<f64>()
+}
+
+/// Returns the edges of the polygon with the given vertices, including the one closing it.
+fn edges(points: &[Coords]) -> impl Iterator<Item = (&Coords, &Coords)> {
+    points.iter().zip(points.iter().cycle().skip(1))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::PI;
+
+    use crate::{
+        geographic::{polygon_area, polygon_perimeter, tests::degrees, Coords},
+        tests::approx_eq,
+        Distance,
+    };
+
+    #[test]
+    fn polygon_area_must_not_fail() {
+        const RADIUS_KM: f64 = 6_371.;
+
+        let radius = Distance::km(RADIUS_KM);
+        let sphere = 4. * PI * radius.as_meters().powi(2);
+
+        // triangle from the north pole to two points 120 degrees apart at latitude 60.
+        let polar_sector = polygon_area(
+            &[degrees(0., 90.), degrees(0., 60.), degrees(120., 60.)],
+            radius,
+        );
+
+        // right triangle with legs of about 111 meters each.
+        let (leg, latitude) = (0.001_f64, 45_f64);
+        let planar_triangle = 0.5
+            * (radius.as_meters() * leg.to_radians() * latitude.to_radians().cos())
+            * (radius.as_meters() * leg.to_radians());
+
+        struct Test {
+            name: &'static str,
+            points: Vec<Coords>,
+            area: f64,
+            rel_error: f64,
+        }
+
+        vec![
+            Test {
+                name: "octant of the sphere",
+                points: vec![degrees(0., 0.), degrees(90., 0.), degrees(0., 90.)],
+                area: sphere / 8.,
+                rel_error: 1e-15,
+            },
+            Test {
+                name: "octant of the sphere in the opposite winding",
+                points: vec![degrees(0., 90.), degrees(90., 0.), degrees(0., 0.)],
+                area: sphere / 8.,
+                rel_error: 1e-15,
+            },
+            Test {
+                name: "southern octant across the antimeridian",
+                points: vec![degrees(135., 0.), degrees(-135., 0.), degrees(0., -90.)],
+                area: sphere / 8.,
+                rel_error: 1e-15,
+            },
+            Test {
+                name: "hemisphere",
+                points: vec![
+                    degrees(0., 0.),
+                    degrees(90., 0.),
+                    degrees(180., 0.),
+                    degrees(-90., 0.),
+                ],
+                area: sphere / 2.,
+                rel_error: 1e-15,
+            },
+            Test {
+                name: "triangle around the north pole",
+                points: vec![degrees(0., 60.), degrees(120., 60.), degrees(-120., 60.)],
+                area: 3. * polar_sector,
+                rel_error: 1e-12,
+            },
+            Test {
+                name: "triangle around the north pole in the opposite winding",
+                points: vec![degrees(-120., 60.), degrees(120., 60.), degrees(0., 60.)],
+                area: 3. * polar_sector,
+                rel_error: 1e-12,
+            },
+            Test {
+                name: "quasi-planar triangle",
+                points: vec![
+                    degrees(10., latitude),
+                    degrees(10. + leg, latitude),
+                    degrees(10., latitude + leg),
+                ],
+                area: planar_triangle,
+                rel_error: 1e-4,
+            },
+            Test {
+                name: "quasi-planar triangle across the antimeridian",
+                points: vec![
+                    degrees(180. - leg / 2., latitude),
+                    degrees(-180. + leg / 2., latitude),
+                    degrees(180. - leg / 2., latitude + leg),
+                ],
+                area: planar_triangle,
+                rel_error: 1e-4,
+            },
+            Test {
+                name: "degenerated polygon",
+                points: vec![degrees(0., 0.), degrees(10., 10.)],
+                area: 0.,
+                rel_error: 0.,
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let area = polygon_area(&test.points, radius);
+            assert!(
+                approx_eq(area, test.area, test.area * test.rel_error),
+                "{}: got area = {area}, want {}",
+                test.name,
+                test.area
+            );
+        });
+    }
+
+    #[test]
+    fn polygon_perimeter_must_not_fail() {
+        const ABS_ERROR: f64 = 1e-9;
+
+        struct Test {
+            name: &'static str,
+            points: Vec<Coords>,
+            perimeter: f64,
+        }
+
+        vec![
+            Test {
+                name: "octant of the unit sphere",
+                points: vec![degrees(0., 0.), degrees(90., 0.), degrees(0., 90.)],
+                perimeter: 3. * PI / 2.,
+            },
+            Test {
+                name: "segment across the antimeridian back and forth",
+                points: vec![degrees(170., 0.), degrees(-170., 0.)],
+                perimeter: 40_f64.to_radians(),
+            },
+            Test {
+                name: "single point",
+                points: vec![degrees(0., 0.)],
+                perimeter: 0.,
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let perimeter = polygon_perimeter(&test.points, Distance::meters(1.)).as_meters();
+            assert!(
+                approx_eq(perimeter, test.perimeter, ABS_ERROR),
+                "{}: got perimeter = {perimeter}, want {}",
+                test.name,
+                test.perimeter
+            );
+        });
+    }
+}
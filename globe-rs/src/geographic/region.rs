@@ -0,0 +1,536 @@
+use std::f64::consts::{FRAC_PI_2, PI};
+
+use crate::Radian;
+
+use super::{Coords, Latitude, Longitude};
+
+/// An area on the surface of a sphere bounded by two parallels and two meridians.
+///
+/// ## Definition
+/// The longitude interval of a region goes eastwards from its western boundary to its eastern
+/// one, which means that a western boundary greater than the eastern one stands for a region
+/// crossing the antimeridian. A region from -π to +π covers all longitudes.
+///
+/// Since all meridians meet at the poles, a region reaching any pole contains it no matter its
+/// longitude interval.
+///
+/// ## Example
+/// ```
+/// use globe_rs::geographic::{Coords, Latitude, Longitude, Region};
+///
+/// let region = Region::default()
+///     .with_min_latitude(Latitude::from_degrees(-10.))
+///     .with_max_latitude(Latitude::from_degrees(10.))
+///     .with_west(Longitude::from_degrees(170.))
+///     .with_east(Longitude::from_degrees(-170.));
+///
+/// let antimeridian = Coords::default().with_longitude(Longitude::from_degrees(180.));
+/// assert!(region.contains(&antimeridian));
+/// assert!(!region.contains(&Coords::default()));
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct Region {
+    /// The southern boundary of the region.
+    pub min_latitude: Latitude,
+    /// The northern boundary of the region.
+    pub max_latitude: Latitude,
+    /// The western boundary of the region.
+    pub west: Longitude,
+    /// The eastern boundary of the region.
+    pub east: Longitude,
+}
+
+impl Region {
+    pub fn with_min_latitude(mut self, latitude: Latitude) -> Self {
+        self.min_latitude = latitude;
+        self
+    }
+
+    pub fn with_max_latitude(mut self, latitude: Latitude) -> Self {
+        self.max_latitude = latitude;
+        self
+    }
+
+    pub fn with_west(mut self, longitude: Longitude) -> Self {
+        self.west = longitude;
+        self
+    }
+
+    pub fn with_east(mut self, longitude: Longitude) -> Self {
+        self.east = longitude;
+        self
+    }
+
+    /// Returns the smallest region containing all the given points, if any.
+    ///
+    /// Points at the poles bound the latitude of the region but not its longitude, since any
+    /// meridian goes through them.
+    pub fn from_points(points: impl Iterator<Item = Coords>) -> Option<Self> {
+        let mut latitudes: Option<(f64, f64)> = None;
+        let mut longitudes = Vec::new();
+
+        points.for_each(|point| {
+            let latitude = f64::from(point.latitude);
+            latitudes = Some(match latitudes {
+                Some((min, max)) => (min.min(latitude), max.max(latitude)),
+                None => (latitude, latitude),
+            });
+
+            if !is_pole(point.latitude) {
+                longitudes.push(f64::from(point.longitude));
+            }
+        });
+
+        let (min_latitude, max_latitude) = latitudes?;
+        longitudes.sort_by(f64::total_cmp);
+
+        let (west, east) = match (longitudes.first(), longitudes.last()) {
+            (Some(&first), Some(&last)) => {
+                // the region must leave out the widest gap between consecutive longitudes.
+                let mut bounds = (first, last);
+                let mut widest = first + 2. * PI - last;
+                longitudes.windows(2).for_each(|pair| {
+                    if pair[1] - pair[0] > widest {
+                        widest = pair[1] - pair[0];
+                        bounds = (pair[1], pair[0]);
+                    }
+                });
+
+                bounds
+            }
+            _ => (-PI, PI),
+        };
+
+        Some(Self {
+            min_latitude: Latitude::from(min_latitude),
+            max_latitude: Latitude::from(max_latitude),
+            west: Longitude::from(west),
+            east: Longitude::from(east),
+        })
+    }
+
+    /// Returns true if, and only if, the given point is inside the region, boundaries included.
+    pub fn contains(&self, point: &Coords) -> bool {
+        self.contains_latitude(point.latitude)
+            && (is_pole(point.latitude) || self.contains_longitude(point.longitude))
+    }
+
+    /// Returns true if, and only if, the given latitude is in between the parallels of the region.
+    pub fn contains_latitude(&self, latitude: Latitude) -> bool {
+        (self.min_latitude..=self.max_latitude).contains(&latitude)
+    }
+
+    /// Returns true if, and only if, the given longitude is in between the meridians of the
+    /// region.
+    pub fn contains_longitude(&self, longitude: Longitude) -> bool {
+        let longitude = f64::from(longitude);
+
+        // -π and +π are the same meridian.
+        self.within_longitudes(longitude)
+            || (longitude.abs() == PI && self.within_longitudes(-longitude))
+    }
+
+    /// Returns true if, and only if, self and the given region share any point.
+    pub fn intersects(&self, other: &Region) -> bool {
+        if self.min_latitude > other.max_latitude || other.min_latitude > self.max_latitude {
+            return false;
+        }
+
+        let north_pole = is_pole(self.max_latitude) && is_pole(other.max_latitude);
+        let south_pole = is_pole(self.min_latitude) && is_pole(other.min_latitude);
+
+        north_pole
+            || south_pole
+            || self.contains_longitude(other.west)
+            || other.contains_longitude(self.west)
+    }
+
+    /// Returns the region resulting from moving each boundary of self the given angle away from
+    /// its center, up to the poles and up to covering all longitudes.
+    ///
+    /// The same angle is applied to both parallels and meridians, which means that the actual
+    /// distance added to the east and west of the region shrinks towards the poles.
+    pub fn expanded_by(&self, margin: Radian) -> Self {
+        let margin = margin.as_f64();
+
+        let min_latitude = (f64::from(self.min_latitude) - margin).max(-FRAC_PI_2);
+        let max_latitude = (f64::from(self.max_latitude) + margin).min(FRAC_PI_2);
+
+        let (west, east) = if self.longitude_width() + 2. * margin >= 2. * PI {
+            (-PI, PI)
+        } else {
+            (f64::from(self.west) - margin, f64::from(self.east) + margin)
+        };
+
+        Self {
+            min_latitude: Latitude::from(min_latitude),
+            max_latitude: Latitude::from(max_latitude),
+            west: Longitude::from(west),
+            east: Longitude::from(east),
+        }
+    }
+
+    /// Returns the angle from the western boundary of the region to the eastern one.
+    fn longitude_width(&self) -> f64 {
+        let (west, east) = (f64::from(self.west), f64::from(self.east));
+        if west <= east {
+            east - west
+        } else {
+            east - west + 2. * PI
+        }
+    }
+
+    /// Returns true if, and only if, the given longitude value is in the interval of the region.
+    fn within_longitudes(&self, longitude: f64) -> bool {
+        let (west, east) = (f64::from(self.west), f64::from(self.east));
+        if west <= east {
+            (west..=east).contains(&longitude)
+        } else {
+            longitude >= west || longitude <= east
+        }
+    }
+}
+
+/// Returns true if, and only if, the given latitude is any of the poles.
+fn is_pole(latitude: Latitude) -> bool {
+    f64::from(latitude).abs() >= FRAC_PI_2
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::geographic::{Coords, Latitude, Longitude, Region};
+
+    /// Returns the point at the given longitude and latitude, in degrees.
+    fn point(longitude: f64, latitude: f64) -> Coords {
+        Coords::default()
+            .with_longitude(Longitude::from_degrees(longitude))
+            .with_latitude(Latitude::from_degrees(latitude))
+    }
+
+    /// Returns the region bounded by the given latitudes and longitudes, in degrees.
+    fn region(south: f64, north: f64, west: f64, east: f64) -> Region {
+        Region::default()
+            .with_min_latitude(Latitude::from_degrees(south))
+            .with_max_latitude(Latitude::from_degrees(north))
+            .with_west(Longitude::from_degrees(west))
+            .with_east(Longitude::from_degrees(east))
+    }
+
+    #[test]
+    fn contains_must_not_fail() {
+        struct Test {
+            name: &'static str,
+            region: Region,
+            point: Coords,
+            contains: bool,
+        }
+
+        vec![
+            Test {
+                name: "center of a regular region",
+                region: region(-10., 10., -20., 20.),
+                point: point(0., 0.),
+                contains: true,
+            },
+            Test {
+                name: "corner of a regular region",
+                region: region(-10., 10., -20., 20.),
+                point: point(20., -10.),
+                contains: true,
+            },
+            Test {
+                name: "east of a regular region",
+                region: region(-10., 10., -20., 20.),
+                point: point(30., 0.),
+                contains: false,
+            },
+            Test {
+                name: "north of a regular region",
+                region: region(-10., 10., -20., 20.),
+                point: point(0., 20.),
+                contains: false,
+            },
+            Test {
+                name: "antimeridian inside a region crossing it",
+                region: region(-10., 10., 170., -170.),
+                point: point(180., 0.),
+                contains: true,
+            },
+            Test {
+                name: "western antimeridian inside a region crossing it",
+                region: region(-10., 10., 170., -170.),
+                point: point(-180., 0.),
+                contains: true,
+            },
+            Test {
+                name: "east of the antimeridian inside a region crossing it",
+                region: region(-10., 10., 170., -170.),
+                point: point(-175., 5.),
+                contains: true,
+            },
+            Test {
+                name: "prime meridian outside a region crossing the antimeridian",
+                region: region(-10., 10., 170., -170.),
+                point: point(0., 0.),
+                contains: false,
+            },
+            Test {
+                name: "west of a region crossing the antimeridian",
+                region: region(-10., 10., 170., -170.),
+                point: point(165., 0.),
+                contains: false,
+            },
+            Test {
+                name: "western antimeridian inside a region ending at the eastern one",
+                region: region(-10., 10., 170., 180.),
+                point: point(-180., 0.),
+                contains: true,
+            },
+            Test {
+                name: "any longitude inside a region covering all of them",
+                region: region(-10., 10., -180., 180.),
+                point: point(123., 0.),
+                contains: true,
+            },
+            Test {
+                name: "north pole inside a polar region, whatever its longitude",
+                region: region(80., 90., 0., 10.),
+                point: point(100., 90.),
+                contains: true,
+            },
+            Test {
+                name: "outside the meridians of a polar region",
+                region: region(80., 90., 0., 10.),
+                point: point(100., 85.),
+                contains: false,
+            },
+            Test {
+                name: "south pole outside a northern polar region",
+                region: region(80., 90., 0., 10.),
+                point: point(5., -90.),
+                contains: false,
+            },
+            Test {
+                name: "south pole inside a southern polar region",
+                region: region(-90., -60., -180., 180.),
+                point: point(-45., -90.),
+                contains: true,
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            assert_eq!(
+                test.region.contains(&test.point),
+                test.contains,
+                "{}: region = {:?}, point = {:?}",
+                test.name,
+                test.region,
+                test.point
+            );
+        });
+    }
+
+    #[test]
+    fn intersects_must_not_fail() {
+        struct Test {
+            name: &'static str,
+            left: Region,
+            right: Region,
+            intersects: bool,
+        }
+
+        vec![
+            Test {
+                name: "overlapping regions",
+                left: region(-10., 10., -20., 20.),
+                right: region(0., 30., 10., 40.),
+                intersects: true,
+            },
+            Test {
+                name: "region inside another",
+                left: region(-10., 10., -20., 20.),
+                right: region(-1., 1., -1., 1.),
+                intersects: true,
+            },
+            Test {
+                name: "regions sharing a meridian",
+                left: region(-10., 10., -20., 20.),
+                right: region(-10., 10., 20., 40.),
+                intersects: true,
+            },
+            Test {
+                name: "regions apart in latitude",
+                left: region(-10., 10., -20., 20.),
+                right: region(20., 30., -20., 20.),
+                intersects: false,
+            },
+            Test {
+                name: "regions apart in longitude",
+                left: region(-10., 10., -20., 20.),
+                right: region(-10., 10., 30., 40.),
+                intersects: false,
+            },
+            Test {
+                name: "regions on both sides of the antimeridian",
+                left: region(-10., 10., 170., -170.),
+                right: region(-10., 10., -175., -160.),
+                intersects: true,
+            },
+            Test {
+                name: "region crossing the antimeridian and a region on the prime meridian",
+                left: region(-10., 10., 170., -170.),
+                right: region(-10., 10., -20., 20.),
+                intersects: false,
+            },
+            Test {
+                name: "polar regions apart in longitude",
+                left: region(80., 90., 0., 10.),
+                right: region(85., 90., 100., 110.),
+                intersects: true,
+            },
+            Test {
+                name: "regions apart in longitude near the pole",
+                left: region(80., 89., 0., 10.),
+                right: region(85., 90., 100., 110.),
+                intersects: false,
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            assert_eq!(
+                test.left.intersects(&test.right),
+                test.intersects,
+                "{}: left = {:?}, right = {:?}",
+                test.name,
+                test.left,
+                test.right
+            );
+            assert_eq!(
+                test.right.intersects(&test.left),
+                test.intersects,
+                "{}: intersection must be symmetric",
+                test.name,
+            );
+        });
+    }
+
+    #[test]
+    fn from_points_must_not_fail() {
+        struct Test {
+            name: &'static str,
+            points: Vec<Coords>,
+            output: Option<Region>,
+        }
+
+        vec![
+            Test {
+                name: "no points",
+                points: vec![],
+                output: None,
+            },
+            Test {
+                name: "single point",
+                points: vec![point(10., 20.)],
+                output: Some(region(20., 20., 10., 10.)),
+            },
+            Test {
+                name: "points around the prime meridian",
+                points: vec![point(-10., 5.), point(15., -5.), point(0., 30.)],
+                output: Some(region(-5., 30., -10., 15.)),
+            },
+            Test {
+                name: "points around the antimeridian",
+                points: vec![point(175., 0.), point(-170., 10.), point(160., -10.)],
+                output: Some(region(-10., 10., 160., -170.)),
+            },
+            Test {
+                name: "points at the pole must not bound the longitude",
+                points: vec![point(120., 90.), point(10., 80.), point(20., 85.)],
+                output: Some(region(80., 90., 10., 20.)),
+            },
+            Test {
+                name: "points at the poles only",
+                points: vec![point(0., 90.), point(0., -90.)],
+                output: Some(region(-90., 90., -180., 180.)),
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let points = test.points.clone();
+            let region = Region::from_points(test.points.into_iter());
+            assert_eq!(region, test.output, "{}", test.name);
+
+            if let Some(region) = region {
+                points.iter().for_each(|point| {
+                    assert!(
+                        region.contains(point),
+                        "{}: got region = {region:?} not containing {point:?}",
+                        test.name
+                    );
+                });
+            }
+        });
+    }
+
+    #[test]
+    fn expanded_by_must_not_fail() {
+        struct Test {
+            name: &'static str,
+            region: Region,
+            margin: f64,
+            output: Region,
+        }
+
+        vec![
+            Test {
+                name: "regular region",
+                region: region(-10., 10., -20., 20.),
+                margin: 5.,
+                output: region(-15., 15., -25., 25.),
+            },
+            Test {
+                name: "latitudes must stop at the poles",
+                region: region(-85., 80., -20., 20.),
+                margin: 20.,
+                output: region(-90., 90., -40., 40.),
+            },
+            Test {
+                name: "longitudes must wrap across the antimeridian",
+                region: region(-10., 10., 160., 175.),
+                margin: 10.,
+                output: region(-20., 20., 150., -175.),
+            },
+            Test {
+                name: "longitudes must stop at covering all of them",
+                region: region(-10., 10., 170., 150.),
+                margin: 15.,
+                output: region(-25., 25., -180., 180.),
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let expanded = test.region.expanded_by(test.margin.to_radians().into());
+            [
+                (
+                    expanded.min_latitude.as_degrees(),
+                    test.output.min_latitude.as_degrees(),
+                ),
+                (
+                    expanded.max_latitude.as_degrees(),
+                    test.output.max_latitude.as_degrees(),
+                ),
+                (expanded.west.as_degrees(), test.output.west.as_degrees()),
+                (expanded.east.as_degrees(), test.output.east.as_degrees()),
+            ]
+            .into_iter()
+            .for_each(|(got, want)| {
+                assert!(
+                    (got - want).abs() <= 1e-9,
+                    "{}: got region = {expanded:?}, want {:?}",
+                    test.name,
+                    test.output
+                );
+            });
+        });
+    }
+}
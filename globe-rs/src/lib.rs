@@ -1,63 +1,57 @@
-use std::fmt::Display;
-
 pub mod cartesian;
 pub mod geographic;
+#[cfg(feature = "presets")]
+pub mod presets;
 
 mod orbit;
 pub use orbit::*;
 
+#[cfg(feature = "proptest")]
+mod arbitrary;
+
+#[cfg(any(test, feature = "fixtures"))]
+#[doc(hidden)]
+pub mod fixtures;
+
+mod acceleration;
+pub use acceleration::*;
+
 mod distance;
 pub use distance::*;
 
+mod duration;
+pub use duration::*;
+
+mod epoch;
+pub use epoch::*;
+
+mod frequency;
+pub use frequency::*;
+
 mod luminosity;
 pub use luminosity::*;
 
 mod mass;
 pub use mass::*;
 
+mod positive_float;
+pub use positive_float::*;
+
+mod quantity;
+pub use quantity::ParseQuantityError;
+
 mod radian;
 pub use radian::*;
 
 mod ratio;
 pub use ratio::*;
 
+mod temperature;
+pub use temperature::*;
+
 mod velocity;
 pub use velocity::*;
 
-/// A [f64] that is always positive.
-#[derive(Debug, Default, Clone, Copy, PartialEq, serde::Serialize, serde::Deserialize)]
-struct PositiveFloat(f64);
-
-impl From<f64> for PositiveFloat {
-    fn from(value: f64) -> Self {
-        Self(value.abs())
-    }
-}
-
-impl Eq for PositiveFloat {}
-
-impl Ord for PositiveFloat {
-    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
-        self.0.total_cmp(&other.0)
-    }
-}
-
-impl PartialOrd for PositiveFloat {
-    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
-        Some(self.cmp(other))
-    }
-}
-
-impl Display for PositiveFloat {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}", self.0)
-    }
-}
-
-impl PositiveFloat {
-    pub const ZERO: Self = Self(0.);
-}
-
 #[cfg(test)]
 mod tests {
     use std::ops::Sub;
@@ -1,21 +1,66 @@
 use std::{
+    f64::consts::PI,
     fmt::Debug,
-    ops::{Div, Mul},
+    iter::Sum,
+    ops::{Add, Div, Mul, Sub},
 };
 
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
-use crate::PositiveFloat;
+use crate::{
+    quantity::{self, Quantity},
+    Distance, PositiveFloat, Temperature,
+};
+
+/// The Stefan-Boltzmann constant as W⋅m^−2⋅K^−4.
+pub const STEFAN_BOLTZMANN_CONSTANT: f64 = 5.670374419e-8;
+
+/// The luminosity of a source with an absolute bolometric magnitude of zero, as watts, according
+/// to the [IAU 2015 Resolution B2](https://arxiv.org/abs/1510.06262).
+const ZERO_POINT_WATTS: f64 = 3.0128e28;
+
+/// The difference between the absolute bolometric and visual magnitudes of a Sun-like source,
+/// which radiates most of its energy in the visible spectrum.
+const BOLOMETRIC_CORRECTION: f64 = -0.09;
+
+/// The distance at which the apparent magnitude of a source equals its absolute magnitude, as
+/// parsecs.
+const ABSOLUTE_MAGNITUDE_PARSECS: f64 = 10.;
 
 /// The intensity at which an arbitrary object brights.
-#[derive(Default, Clone, Copy, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[derive(Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
 pub struct Luminosity(PositiveFloat);
 
+impl Add for Luminosity {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self(self.0 + rhs.0)
+    }
+}
+
+impl Sum for Luminosity {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::default(), Add::add)
+    }
+}
+
+impl Sub for Luminosity {
+    type Output = Self;
+
+    /// Returns the difference between both luminosities, saturating at zero.
+    ///
+    /// See [Luminosity::checked_sub] to tell apart reversed operands.
+    fn sub(self, rhs: Self) -> Self::Output {
+        self.saturating_sub(rhs)
+    }
+}
+
 impl Mul<f64> for Luminosity {
     type Output = Self;
 
     fn mul(self, rhs: f64) -> Self::Output {
-        Self((self.0 .0 * rhs).into())
+        Self(PositiveFloat::from_abs(self.0 .0 * rhs))
     }
 }
 
@@ -23,7 +68,7 @@ impl Div<f64> for Luminosity {
     type Output = Self;
 
     fn div(self, rhs: f64) -> Self::Output {
-        Self((self.0 .0 / rhs).into())
+        Self(PositiveFloat::from_abs(self.0 .0 / rhs))
     }
 }
 
@@ -31,7 +76,7 @@ impl Div for Luminosity {
     type Output = Luminosity;
 
     fn div(self, rhs: Self) -> Self::Output {
-        Self((self.0 .0 / rhs.0 .0).into())
+        Self(self.0 / rhs.0)
     }
 }
 
@@ -43,14 +88,29 @@ impl Debug for Luminosity {
     }
 }
 
+impl Serialize for Luminosity {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&quantity::format(self.as_watts(), "W"))
+    }
+}
+
+impl<'de> Deserialize<'de> for Luminosity {
+    /// Deserializes a luminosity from either a number of watts or a number followed by its unit.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Quantity::deserialize(deserializer)?
+            .positive_value(&[("W", 1.)])
+            .map(Self::watts)
+    }
+}
+
 impl Luminosity {
     const LUMENS_PER_WATT: f64 = 93.;
     pub const SUN: Self = Self(PositiveFloat(3.828e26));
     pub const ZERO: Self = Self(PositiveFloat::ZERO);
 
-    /// Returns a new luminosity representing the given watts.
+    /// Returns a new luminosity representing the given watts, saturating at zero.
     pub fn watts(watts: f64) -> Self {
-        Self(watts.into())
+        Self(PositiveFloat::new_saturating(watts))
     }
 
     /// Returns a [f64] representing the luminosity in watts.
@@ -62,4 +122,262 @@ impl Luminosity {
     pub fn as_lm(&self) -> f64 {
         Self::LUMENS_PER_WATT * self.as_watts()
     }
+
+    /// Returns the difference between self and the given luminosity, or [None] if the latter is
+    /// greater.
+    pub fn checked_sub(self, rhs: Self) -> Option<Self> {
+        self.0.checked_sub(rhs.0).map(Self)
+    }
+
+    /// Returns the difference between self and the given luminosity, saturating at zero.
+    pub fn saturating_sub(self, rhs: Self) -> Self {
+        Self(self.0 - rhs.0)
+    }
+
+    /// Returns the luminosity of a black body with the given radius and temperature, according to
+    /// the Stefan-Boltzmann law.
+    pub fn from_black_body(radius: Distance, temperature: Temperature) -> Self {
+        Self::watts(
+            4. * PI
+                * radius.as_meters().powi(2)
+                * STEFAN_BOLTZMANN_CONSTANT
+                * temperature.as_kelvin().powi(4),
+        )
+    }
+
+    /// Returns the luminosity of a Sun-like source with the given absolute visual magnitude.
+    pub fn from_absolute_magnitude(magnitude: f64) -> Self {
+        Self::from_absolute_bolometric_magnitude(magnitude + BOLOMETRIC_CORRECTION)
+    }
+
+    /// Returns the luminosity of a source with the given absolute bolometric magnitude.
+    pub fn from_absolute_bolometric_magnitude(magnitude: f64) -> Self {
+        Self::watts(ZERO_POINT_WATTS * 10_f64.powf(-0.4 * magnitude))
+    }
+
+    /// Returns the absolute visual magnitude of the luminosity, assuming a Sun-like source, which
+    /// is infinite if the luminosity is zero.
+    ///
+    /// E.g. the Sun has an absolute visual magnitude of 4.83.
+    pub fn absolute_magnitude(&self) -> f64 {
+        self.absolute_bolometric_magnitude() - BOLOMETRIC_CORRECTION
+    }
+
+    /// Returns the absolute bolometric magnitude of the luminosity, which accounts for all the
+    /// radiated energy instead of the visible one only, e.g. 4.74 for the Sun.
+    pub fn absolute_bolometric_magnitude(&self) -> f64 {
+        -2.5 * (self.as_watts() / ZERO_POINT_WATTS).log10()
+    }
+
+    /// Returns the visual magnitude of the luminosity as seen from the given distance.
+    pub fn apparent_magnitude_at(&self, distance: Distance) -> f64 {
+        self.absolute_magnitude()
+            + 5. * (distance.as_parsecs() / ABSOLUTE_MAGNITUDE_PARSECS).log10()
+    }
+
+    /// Returns the intensity, as lumens, of a light rendering this luminosity at the given scale,
+    /// saturated to the greatest finite [f32].
+    pub fn as_render_intensity(&self, scale: f64) -> f32 {
+        (self.as_lm() * scale).min(f32::MAX as f64) as f32
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{tests::approx_eq, Distance, Luminosity};
+
+    #[test]
+    fn magnitudes_must_not_fail() {
+        struct Test {
+            name: &'static str,
+            output: f64,
+            want: f64,
+        }
+
+        vec![
+            Test {
+                name: "absolute magnitude of the sun",
+                output: Luminosity::SUN.absolute_magnitude(),
+                want: 4.83,
+            },
+            Test {
+                name: "absolute bolometric magnitude of the sun",
+                output: Luminosity::SUN.absolute_bolometric_magnitude(),
+                want: 4.74,
+            },
+            Test {
+                name: "absolute magnitude of the zero point",
+                output: Luminosity::from_absolute_bolometric_magnitude(0.)
+                    .absolute_bolometric_magnitude(),
+                want: 0.,
+            },
+            Test {
+                name: "visual magnitude round trip",
+                output: Luminosity::from_absolute_magnitude(0.).absolute_magnitude(),
+                want: 0.,
+            },
+            Test {
+                name: "luminosity from absolute magnitude",
+                output: (Luminosity::from_absolute_magnitude(4.83) / Luminosity::SUN).as_watts(),
+                want: 1.,
+            },
+            Test {
+                name: "sun seen from the earth",
+                output: Luminosity::SUN.apparent_magnitude_at(Distance::km(149_597_870.7)),
+                want: -26.74,
+            },
+            Test {
+                name: "sun seen from 10 parsecs",
+                output: Luminosity::SUN.apparent_magnitude_at(Distance::parsecs(10.)),
+                want: 4.83,
+            },
+            Test {
+                name: "binary of equal stars",
+                output: (Luminosity::SUN + Luminosity::SUN).absolute_magnitude(),
+                want: 4.83 - 2.5 * 2_f64.log10(),
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            assert!(
+                approx_eq(test.output, test.want, 0.01),
+                "{}: got {}, want {}",
+                test.name,
+                test.output,
+                test.want
+            );
+        });
+    }
+
+    #[test]
+    fn render_intensity_must_be_finite() {
+        struct Test {
+            name: &'static str,
+            luminosity: Luminosity,
+            scale: f64,
+            output: f32,
+        }
+
+        vec![
+            Test {
+                name: "unscaled",
+                luminosity: Luminosity::watts(100.),
+                scale: 1.,
+                output: 9_300.,
+            },
+            Test {
+                name: "scaled",
+                luminosity: Luminosity::watts(100.),
+                scale: 0.5,
+                output: 4_650.,
+            },
+            Test {
+                name: "saturated",
+                luminosity: Luminosity::SUN * 1e13,
+                scale: 1.,
+                output: f32::MAX,
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let output = test.luminosity.as_render_intensity(test.scale);
+            assert_eq!(
+                output, test.output,
+                "{}: got output = {output}, want {}",
+                test.name, test.output
+            );
+        });
+    }
+
+    #[test]
+    fn deserialize_must_not_fail() {
+        struct Test {
+            name: &'static str,
+            input: &'static str,
+            output: Option<Luminosity>,
+        }
+
+        vec![
+            Test {
+                name: "bare number in watts",
+                input: "100",
+                output: Some(Luminosity::watts(100.)),
+            },
+            Test {
+                name: "watts",
+                input: r#""100 W""#,
+                output: Some(Luminosity::watts(100.)),
+            },
+            Test {
+                name: "no whitespace",
+                input: r#""100W""#,
+                output: Some(Luminosity::watts(100.)),
+            },
+            Test {
+                name: "surrounding whitespace",
+                input: r#""  100 W  ""#,
+                output: Some(Luminosity::watts(100.)),
+            },
+            Test {
+                name: "different case",
+                input: r#""100 w""#,
+                output: Some(Luminosity::watts(100.)),
+            },
+            Test {
+                name: "scientific notation",
+                input: r#""3.828e26 W""#,
+                output: Some(Luminosity::SUN),
+            },
+            Test {
+                name: "invalid suffix",
+                input: r#""100 lm""#,
+                output: None,
+            },
+            Test {
+                name: "missing number",
+                input: r#""W""#,
+                output: None,
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let output = serde_json::from_str::<Luminosity>(test.input).ok();
+            assert_eq!(
+                output, test.output,
+                "{}: got output = {:?}, want {:?}",
+                test.name, output, test.output
+            );
+        });
+    }
+
+    #[test]
+    fn serialize_must_not_fail() {
+        struct Test {
+            name: &'static str,
+            input: Luminosity,
+            output: &'static str,
+        }
+
+        vec![
+            Test {
+                name: "readable luminosity",
+                input: Luminosity::watts(100.),
+                output: "100 W",
+            },
+            Test {
+                name: "big luminosity",
+                input: Luminosity::SUN,
+                output: "3.828e26 W",
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let output = serde_json::to_value(test.input).unwrap();
+            assert_eq!(
+                output, test.output,
+                "{}: got output = {:?}, want {:?}",
+                test.name, output, test.output
+            );
+        });
+    }
 }
@@ -1,13 +1,57 @@
-use std::fmt::Debug;
+use std::{
+    fmt::{Debug, Display},
+    iter::Sum,
+    ops::{Add, Mul, Sub},
+};
 
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
-use crate::PositiveFloat;
+use crate::{
+    quantity::{self, Quantity},
+    PositiveFloat,
+};
+
+/// The symbols of the units a mass may be deserialized from, and how many kilograms each of them
+/// represents.
+const UNITS: &[(&str, f64)] = &[("kg", 1.), ("g", 0.001), ("t", 1_000.)];
 
 /// The mass of an arbitrary object, which is always a positive number.
-#[derive(Default, Clone, Copy, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[derive(Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
 pub struct Mass(PositiveFloat);
 
+impl Add for Mass {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self(self.0 + rhs.0)
+    }
+}
+
+impl Sub for Mass {
+    type Output = Self;
+
+    /// Returns the difference between both masses, saturating at zero.
+    ///
+    /// See [Mass::checked_sub] to tell apart reversed operands.
+    fn sub(self, rhs: Self) -> Self::Output {
+        self.saturating_sub(rhs)
+    }
+}
+
+impl Mul<f64> for Mass {
+    type Output = Self;
+
+    fn mul(self, rhs: f64) -> Self::Output {
+        Self(PositiveFloat::from_abs(self.0 .0 * rhs))
+    }
+}
+
+impl Sum for Mass {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::default(), Add::add)
+    }
+}
+
 impl Debug for Mass {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_tuple("Mass")
@@ -16,14 +60,317 @@ impl Debug for Mass {
     }
 }
 
+impl Display for Mass {
+    /// Displays the mass in the most readable unit among kilograms, Earth masses and solar masses,
+    /// honoring the precision of the formatter, if any.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let earths = self.as_kg() / Self::EARTH.as_kg();
+        let (value, unit) = if self.as_solar_masses() >= 0.01 {
+            (self.as_solar_masses(), "M☉")
+        } else if earths >= 0.01 {
+            (earths, "M⊕")
+        } else {
+            (self.as_kg(), "kg")
+        };
+
+        match f.precision() {
+            Some(precision) => write!(f, "{value:.precision$} {unit}"),
+            None => write!(f, "{value} {unit}"),
+        }
+    }
+}
+
+impl Serialize for Mass {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&quantity::format(self.as_kg(), "kg"))
+    }
+}
+
+impl<'de> Deserialize<'de> for Mass {
+    /// Deserializes a mass from either a number of kilograms or a number followed by its unit.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Quantity::deserialize(deserializer)?
+            .positive_value(UNITS)
+            .map(Self::kg)
+    }
+}
+
 impl Mass {
-    /// Returns a new mass of kg kilograms.
+    pub const SUN: Self = Self(PositiveFloat(1.9891e30));
+    pub const EARTH: Self = Self(PositiveFloat(5.97219e24));
+    pub const JUPITER: Self = Self(PositiveFloat(1.8982e27));
+    pub const MOON: Self = Self(PositiveFloat(7.342e22));
+
+    /// Returns a new mass of kg kilograms, saturating at zero.
     pub fn kg(kg: f64) -> Self {
-        Self((kg).into())
+        Self(PositiveFloat::new_saturating(kg))
+    }
+
+    /// Returns a new mass of the given amount of solar masses.
+    pub fn solar(masses: f64) -> Self {
+        Self::SUN * masses
     }
 
     /// Returns a [f64] representing the mass in kilograms.
     pub fn as_kg(&self) -> f64 {
         self.0 .0
     }
+
+    /// Returns a [f64] representing the mass in solar masses.
+    pub fn as_solar_masses(&self) -> f64 {
+        self.as_kg() / Self::SUN.as_kg()
+    }
+
+    /// Returns the difference between self and the given mass, or [None] if the latter is
+    /// greater.
+    pub fn checked_sub(self, rhs: Self) -> Option<Self> {
+        self.0.checked_sub(rhs.0).map(Self)
+    }
+
+    /// Returns the difference between self and the given mass, saturating at zero.
+    pub fn saturating_sub(self, rhs: Self) -> Self {
+        Self(self.0 - rhs.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{tests::approx_eq, Mass};
+
+    #[test]
+    fn constants_must_not_fail() {
+        struct Test {
+            name: &'static str,
+            mass: Mass,
+            kg: f64,
+        }
+
+        vec![
+            Test {
+                name: "sun",
+                mass: Mass::SUN,
+                kg: 1.9891e30,
+            },
+            Test {
+                name: "earth",
+                mass: Mass::EARTH,
+                kg: 5.97219e24,
+            },
+            Test {
+                name: "jupiter",
+                mass: Mass::JUPITER,
+                kg: 1.8982e27,
+            },
+            Test {
+                name: "moon",
+                mass: Mass::MOON,
+                kg: 7.342e22,
+            },
+            Test {
+                name: "half a solar mass",
+                mass: Mass::solar(0.5),
+                kg: 0.5 * 1.9891e30,
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            assert_eq!(
+                test.mass.as_kg(),
+                test.kg,
+                "{}: got kg = {}, want {}",
+                test.name,
+                test.mass.as_kg(),
+                test.kg
+            );
+        });
+
+        assert!(approx_eq(Mass::SUN.as_solar_masses(), 1., 1e-12));
+    }
+
+    #[test]
+    fn operators_must_not_fail() {
+        struct Test {
+            name: &'static str,
+            output: Mass,
+            want: Mass,
+        }
+
+        vec![
+            Test {
+                name: "addition",
+                output: Mass::kg(2.) + Mass::kg(3.),
+                want: Mass::kg(5.),
+            },
+            Test {
+                name: "subtraction",
+                output: Mass::kg(5.) - Mass::kg(3.),
+                want: Mass::kg(2.),
+            },
+            Test {
+                name: "subtraction saturating at zero",
+                output: Mass::kg(3.) - Mass::kg(5.),
+                want: Mass::kg(0.),
+            },
+            Test {
+                name: "multiplication",
+                output: Mass::kg(3.) * 2.,
+                want: Mass::kg(6.),
+            },
+            Test {
+                name: "sum",
+                output: [Mass::kg(1.), Mass::kg(2.), Mass::kg(3.)].into_iter().sum(),
+                want: Mass::kg(6.),
+            },
+            Test {
+                name: "empty sum",
+                output: std::iter::empty::<Mass>().sum(),
+                want: Mass::kg(0.),
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            assert_eq!(
+                test.output, test.want,
+                "{}: got mass = {:?}, want {:?}",
+                test.name, test.output, test.want
+            );
+        });
+    }
+
+    #[test]
+    fn display_must_not_fail() {
+        struct Test {
+            name: &'static str,
+            input: String,
+            output: &'static str,
+        }
+
+        vec![
+            Test {
+                name: "kilograms",
+                input: Mass::kg(1_000.).to_string(),
+                output: "1000 kg",
+            },
+            Test {
+                name: "earth masses",
+                input: Mass::EARTH.to_string(),
+                output: "1 M⊕",
+            },
+            Test {
+                name: "earth masses with precision",
+                input: format!("{:.1}", Mass::JUPITER),
+                output: "317.8 M⊕",
+            },
+            Test {
+                name: "solar masses",
+                input: Mass::SUN.to_string(),
+                output: "1 M☉",
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            assert_eq!(
+                test.input, test.output,
+                "{}: got output = {:?}, want {:?}",
+                test.name, test.input, test.output
+            );
+        });
+    }
+
+    #[test]
+    fn deserialize_must_not_fail() {
+        struct Test {
+            name: &'static str,
+            input: &'static str,
+            output: Option<Mass>,
+        }
+
+        vec![
+            Test {
+                name: "bare number in kilograms",
+                input: "1000",
+                output: Some(Mass::kg(1_000.)),
+            },
+            Test {
+                name: "kilograms",
+                input: r#""1000 kg""#,
+                output: Some(Mass::kg(1_000.)),
+            },
+            Test {
+                name: "tonnes",
+                input: r#""1 t""#,
+                output: Some(Mass::kg(1_000.)),
+            },
+            Test {
+                name: "no whitespace",
+                input: r#""1000kg""#,
+                output: Some(Mass::kg(1_000.)),
+            },
+            Test {
+                name: "surrounding whitespace",
+                input: r#""  1000 kg  ""#,
+                output: Some(Mass::kg(1_000.)),
+            },
+            Test {
+                name: "different case",
+                input: r#""1000 KG""#,
+                output: Some(Mass::kg(1_000.)),
+            },
+            Test {
+                name: "scientific notation",
+                input: r#""1.9891e30 kg""#,
+                output: Some(Mass::kg(1.9891e30)),
+            },
+            Test {
+                name: "invalid suffix",
+                input: r#""1 lb""#,
+                output: None,
+            },
+            Test {
+                name: "missing number",
+                input: r#""kg""#,
+                output: None,
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let output = serde_json::from_str::<Mass>(test.input).ok();
+            assert_eq!(
+                output, test.output,
+                "{}: got output = {:?}, want {:?}",
+                test.name, output, test.output
+            );
+        });
+    }
+
+    #[test]
+    fn serialize_must_not_fail() {
+        struct Test {
+            name: &'static str,
+            input: Mass,
+            output: &'static str,
+        }
+
+        vec![
+            Test {
+                name: "readable mass",
+                input: Mass::kg(1_000.),
+                output: "1000 kg",
+            },
+            Test {
+                name: "big mass",
+                input: Mass::kg(1.9891e30),
+                output: "1.9891e30 kg",
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let output = serde_json::to_value(test.input).unwrap();
+            assert_eq!(
+                output, test.output,
+                "{}: got output = {:?}, want {:?}",
+                test.name, output, test.output
+            );
+        });
+    }
 }
@@ -1,17 +1,38 @@
-use std::time::Duration;
+use std::{f64::consts::PI, time::Duration};
 
 use alvidir::name::Name;
 use serde::{Deserialize, Serialize};
 
-use crate::{Distance, Luminosity, Mass, GRAVITATIONAL_CONSTANT};
+use crate::{
+    Acceleration, Distance, Frequency, Luminosity, Mass, Temperature, GRAVITATIONAL_CONSTANT,
+    STEFAN_BOLTZMANN_CONSTANT,
+};
 
 /// The period and direction of a rotation.
 #[derive(Debug, Default, Clone, Serialize, Deserialize)]
 pub struct Rotation {
+    /// The time it takes to complete a rotation, if any.
+    #[serde(default, with = "crate::quantity::duration")]
     pub period: Duration,
+    /// The number of rotations per second, taking precedence over the period if set.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub frequency: Option<Frequency>,
     pub clockwise: bool,
 }
 
+impl Rotation {
+    /// Returns the number of rotations per second, being zero if there is no rotation at all.
+    pub fn frequency(&self) -> Frequency {
+        self.frequency
+            .unwrap_or_else(|| Frequency::from(self.period))
+    }
+
+    /// Returns the time it takes to complete a rotation, if any.
+    pub fn period(&self) -> Option<Duration> {
+        self.frequency().period()
+    }
+}
+
 /// An arbitrary spherical body.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Body {
@@ -28,18 +49,142 @@ pub struct Body {
 }
 
 impl Body {
+    /// Returns a new body with the given name and no radius, spin, mass or luminosity.
+    pub fn new(name: Name<Self>) -> Self {
+        Self {
+            name,
+            radius: Distance::default(),
+            spin: Rotation::default(),
+            mass: Mass::default(),
+            luminosity: Luminosity::default(),
+        }
+    }
+
+    pub fn with_radius(mut self, radius: Distance) -> Self {
+        self.radius = radius;
+        self
+    }
+
+    pub fn with_spin(mut self, spin: Rotation) -> Self {
+        self.spin = spin;
+        self
+    }
+
+    pub fn with_mass(mut self, mass: Mass) -> Self {
+        self.mass = mass;
+        self
+    }
+
+    pub fn with_luminosity(mut self, luminosity: Luminosity) -> Self {
+        self.luminosity = luminosity;
+        self
+    }
+
     /// Returns the standard gravitational parameter of the body.
     pub fn gravitational_parameter(&self) -> f64 {
         GRAVITATIONAL_CONSTANT * self.mass.as_kg()
     }
 
+    /// Returns the acceleration of gravity on the surface of the body.
+    pub fn surface_gravity(&self) -> Acceleration {
+        Acceleration::meters_sec2(self.gravitational_parameter() / self.radius.as_meters().powi(2))
+    }
+
+    /// Returns the temperature of a black body with the same radius and luminosity as the body, if
+    /// it has both.
+    pub fn effective_temperature(&self) -> Option<Temperature> {
+        if self.radius == Distance::ZERO || !self.is_luminous() {
+            return None;
+        }
+
+        let surface = 4. * PI * self.radius.as_meters().powi(2);
+        let kelvin =
+            (self.luminosity.as_watts() / (surface * STEFAN_BOLTZMANN_CONSTANT)).powf(0.25);
+        Some(Temperature::kelvin(kelvin))
+    }
+
     /// Returns true if, and only if, the body has a luminousity other than zero.
     pub fn is_luminous(&self) -> bool {
         self.luminosity != Luminosity::ZERO
     }
 
-    /// The time it takes to the body to complete a rotation.
-    pub fn sideral_period(&self) -> Duration {
-        self.spin.period
+    /// The time it takes to the body to complete a rotation, if it spins at all.
+    pub fn sideral_period(&self) -> Option<Duration> {
+        self.spin.period()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use crate::{Frequency, Rotation};
+
+    #[test]
+    fn rotation_period_must_not_fail() {
+        struct Test {
+            name: &'static str,
+            rotation: Rotation,
+            period: Option<Duration>,
+        }
+
+        vec![
+            Test {
+                name: "no rotation",
+                rotation: Rotation::default(),
+                period: None,
+            },
+            Test {
+                name: "period",
+                rotation: Rotation {
+                    period: Duration::from_secs(4),
+                    ..Default::default()
+                },
+                period: Some(Duration::from_secs(4)),
+            },
+            Test {
+                name: "frequency",
+                rotation: Rotation {
+                    frequency: Some(Frequency::hz(0.5)),
+                    ..Default::default()
+                },
+                period: Some(Duration::from_secs(2)),
+            },
+            Test {
+                name: "frequency over period",
+                rotation: Rotation {
+                    period: Duration::from_secs(4),
+                    frequency: Some(Frequency::hz(0.5)),
+                    ..Default::default()
+                },
+                period: Some(Duration::from_secs(2)),
+            },
+            Test {
+                name: "zero frequency",
+                rotation: Rotation {
+                    frequency: Some(Frequency::ZERO),
+                    ..Default::default()
+                },
+                period: None,
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let period = test.rotation.period();
+            assert_eq!(
+                period, test.period,
+                "{}: got period = {period:?}, want {:?}",
+                test.name, test.period
+            );
+        });
+    }
+
+    #[test]
+    fn rotation_must_deserialize_frequency() {
+        let rotation: Rotation =
+            serde_json::from_str(r#"{"frequency": "500 mHz", "clockwise": true}"#).unwrap();
+
+        assert_eq!(rotation.period(), Some(Duration::from_secs(2)));
+        assert!(rotation.clockwise);
     }
 }
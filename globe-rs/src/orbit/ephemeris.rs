@@ -0,0 +1,619 @@
+use std::{
+    io::{self, BufWriter, Write},
+    time::Duration,
+};
+
+use alvidir::name::Name;
+use serde::Serialize;
+
+use crate::{
+    cartesian::{
+        shape::{Sample, Shape},
+        Coords,
+    },
+    Distance, Radian, Ratio, Velocity,
+};
+
+use super::{Body, Orbit, OrbitalSystemState, OrbitalSystemStateGenerator};
+
+/// The format in which an ephemeris is exported.
+///
+/// Every format writes one row per body per step, in depth-first order, with the following columns:
+///
+/// | Column     | Description                                       |
+/// |------------|---------------------------------------------------|
+/// | `time`     | Seconds since the beginning of the simulation.    |
+/// | `body`     | The name of the body.                             |
+/// | `x`        | The x coordinate of the body, in meters.          |
+/// | `y`        | The y coordinate of the body, in meters.          |
+/// | `z`        | The z coordinate of the body, in meters.          |
+/// | `theta`    | The radiant of its orbit at which the body is.    |
+/// | `speed`    | The orbital speed of the body, in meters/second.  |
+/// | `rotation` | How much rotated is the body, in radians.         |
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EphemerisFormat {
+    /// Comma-separated values, preceded by a header with the name of the columns.
+    Csv,
+    /// One JSON object per line, keyed by the name of the columns, behind the `json` feature.
+    #[cfg(feature = "json")]
+    JsonLines,
+}
+
+/// The columns of an ephemeris, in order.
+const COLUMNS: [&str; 8] = ["time", "body", "x", "y", "z", "theta", "speed", "rotation"];
+
+/// A row of an ephemeris.
+#[derive(Serialize)]
+struct EphemerisRow<'a> {
+    time: f64,
+    body: &'a Name<Body>,
+    x: f64,
+    y: f64,
+    z: f64,
+    theta: f64,
+    speed: f64,
+    rotation: f64,
+}
+
+impl<'a> EphemerisRow<'a> {
+    fn new(time: Duration, state: &'a OrbitalSystemState) -> Self {
+        Self {
+            time: time.as_secs_f64(),
+            body: &state.body,
+            x: state.position.x(),
+            y: state.position.y(),
+            z: state.position.z(),
+            theta: state.theta.as_f64(),
+            speed: state.velocity.as_meters_sec(),
+            rotation: state.rotation.as_f64(),
+        }
+    }
+
+    fn write_csv(&self, w: &mut impl Write) -> io::Result<()> {
+        writeln!(
+            w,
+            "{},{},{},{},{},{},{},{}",
+            self.time,
+            escape_csv(self.body.as_ref()),
+            self.x,
+            self.y,
+            self.z,
+            self.theta,
+            self.speed,
+            self.rotation
+        )
+    }
+
+    #[cfg(feature = "json")]
+    fn write_json(&self, w: &mut impl Write) -> io::Result<()> {
+        serde_json::to_writer(&mut *w, self)?;
+        writeln!(w)
+    }
+}
+
+/// Returns the given field quoted if it contains any character with a special meaning in CSV.
+fn escape_csv(field: &str) -> String {
+    if !field.contains([',', '"', '\n', '\r']) {
+        return field.to_string();
+    }
+
+    format!("\"{}\"", field.replace('"', "\"\""))
+}
+
+impl<'a, O: Orbit> OrbitalSystemStateGenerator<'a, O> {
+    /// Writes the state of every body of the system at each step before reaching the given time.
+    ///
+    /// See [EphemerisFormat] for the description of the written columns.
+    pub fn export<W: Write>(
+        self,
+        until: Duration,
+        format: EphemerisFormat,
+        w: W,
+    ) -> io::Result<()> {
+        let mut w = BufWriter::new(w);
+        if format == EphemerisFormat::Csv {
+            writeln!(w, "{}", COLUMNS.join(","))?;
+        }
+
+        for time in self.times_until(until) {
+            let state = self.system.state_at_cached(time, &self.periods);
+            for (state, _) in state.iter() {
+                let row = EphemerisRow::new(time, state);
+                match format {
+                    EphemerisFormat::Csv => row.write_csv(&mut w)?,
+                    #[cfg(feature = "json")]
+                    EphemerisFormat::JsonLines => row.write_json(&mut w)?,
+                }
+            }
+        }
+
+        w.flush()
+    }
+}
+
+/// An orbit given by a sequence of externally computed positions, relative to the body it orbits,
+/// at specific moments in time.
+///
+/// Positions in between are interpolated by cubic Hermite splines. The period of the orbit is the
+/// time span covered by the positions, hence a closed orbit must end with the same position it
+/// starts with.
+#[derive(Debug, Clone, Default)]
+pub struct FixedEphemeris {
+    /// The positions of the orbit, sorted by time.
+    points: Vec<(Duration, Coords)>,
+    /// The initial radiant of the orbit to sample.
+    initial_theta: Radian,
+}
+
+impl From<Vec<(Duration, Coords)>> for FixedEphemeris {
+    fn from(mut points: Vec<(Duration, Coords)>) -> Self {
+        points.sort_by_key(|(time, _)| *time);
+        points.dedup_by_key(|(time, _)| *time);
+
+        Self {
+            points,
+            initial_theta: Default::default(),
+        }
+    }
+}
+
+impl Sample for FixedEphemeris {
+    fn with_initial_theta(mut self, theta: Radian) -> Self {
+        self.initial_theta = theta;
+        self
+    }
+
+    fn sample(&self, segments: usize) -> Shape {
+        Shape {
+            points: (0..segments)
+                .map(|vertex_index| Radian::TWO_PI / segments as f64 * vertex_index as f64)
+                .map(|theta| self.position_at_theta(self.initial_theta + theta))
+                .collect(),
+        }
+    }
+}
+
+impl Orbit for FixedEphemeris {
+    fn min_velocity(&self, _: &Body) -> Velocity {
+        Velocity::meters_sec(self.chord_speeds().reduce(f64::min).unwrap_or_default())
+    }
+
+    fn max_velocity(&self, _: &Body) -> Velocity {
+        Velocity::meters_sec(self.chord_speeds().reduce(f64::max).unwrap_or_default())
+    }
+
+    fn velocity_at_theta(&self, theta: Radian, _: &Body) -> Velocity {
+        let (_, velocity) = self.interpolate(self.time_at_theta(theta));
+        Velocity::meters_sec(velocity.magnitude())
+    }
+
+    fn position_at_theta(&self, theta: Radian) -> Coords {
+        let (position, _) = self.interpolate(self.time_at_theta(theta));
+        position
+    }
+
+    fn theta_in_period(&self, time: Duration, period: Duration) -> Radian {
+        if period.is_zero() {
+            return Radian::default();
+        }
+
+        let elapsed = time.as_secs_f64() - self.start().as_secs_f64();
+        Radian::from(
+            Radian::TWO_PI.as_f64() * elapsed.rem_euclid(period.as_secs_f64())
+                / period.as_secs_f64(),
+        )
+    }
+
+    fn period(&self, _: &Body) -> Duration {
+        self.end().saturating_sub(self.start())
+    }
+
+    fn perimeter(&self) -> Distance {
+        Distance::meters(
+            self.points
+                .windows(2)
+                .map(|points| points[0].1.distance(&points[1].1))
+                .sum(),
+        )
+    }
+
+    fn focus(&self) -> Coords {
+        Coords::default()
+    }
+
+    fn radius(&self) -> Distance {
+        Distance::meters(self.distances().reduce(f64::max).unwrap_or_default())
+    }
+
+    fn is_clockwise(&self) -> bool {
+        let momentum = self
+            .points
+            .windows(2)
+            .map(|points| points[0].1.cross(&points[1].1))
+            .fold(Coords::default(), |momentum, cross| momentum + cross);
+
+        momentum.z() < 0.
+    }
+
+    fn eccentricity(&self) -> Ratio {
+        let periapsis = self.distances().reduce(f64::min).unwrap_or_default();
+        let apoapsis = self.distances().reduce(f64::max).unwrap_or_default();
+        if apoapsis == 0. {
+            return Ratio::default();
+        }
+
+        Ratio::from((apoapsis - periapsis) / (apoapsis + periapsis))
+    }
+
+    fn periapsis(&self) -> Option<Coords> {
+        self.is_closed()
+            .then(|| self.extreme_position(|closest, position| position < closest))
+            .flatten()
+    }
+
+    fn apoapsis(&self) -> Option<Coords> {
+        self.is_closed()
+            .then(|| self.extreme_position(|farthest, position| position > farthest))
+            .flatten()
+    }
+}
+
+impl FixedEphemeris {
+    /// Returns the positions of the orbit, sorted by time.
+    pub fn points(&self) -> &[(Duration, Coords)] {
+        &self.points
+    }
+
+    fn start(&self) -> Duration {
+        self.points
+            .first()
+            .map(|(time, _)| *time)
+            .unwrap_or_default()
+    }
+
+    fn end(&self) -> Duration {
+        self.points
+            .last()
+            .map(|(time, _)| *time)
+            .unwrap_or_default()
+    }
+
+    /// Returns the time, in seconds, at which the orbit is at the given radiant.
+    fn time_at_theta(&self, theta: Radian) -> f64 {
+        let span = self.end().as_secs_f64() - self.start().as_secs_f64();
+        self.start().as_secs_f64() + span * theta.as_f64() / Radian::TWO_PI.as_f64()
+    }
+
+    /// Returns the distances from the orbitee to every position of the orbit.
+    fn distances(&self) -> impl Iterator<Item = f64> + '_ {
+        self.points.iter().map(|(_, position)| position.magnitude())
+    }
+
+    /// Returns the position of the orbit whose distance to the orbitee is preferred over the ones
+    /// of all the others, being the preference given as a predicate on the distance of the
+    /// current choice and the one of the candidate.
+    fn extreme_position(&self, prefer: impl Fn(f64, f64) -> bool) -> Option<Coords> {
+        self.points
+            .iter()
+            .map(|(_, position)| *position)
+            .reduce(|choice, candidate| {
+                if prefer(choice.magnitude(), candidate.magnitude()) {
+                    candidate
+                } else {
+                    choice
+                }
+            })
+    }
+
+    /// Returns the average speed, in meters per second, between every pair of consecutive
+    /// positions.
+    fn chord_speeds(&self) -> impl Iterator<Item = f64> + '_ {
+        self.points.windows(2).map(|points| {
+            let (from, to) = (points[0], points[1]);
+            from.1.distance(&to.1) / (to.0 - from.0).as_secs_f64()
+        })
+    }
+
+    /// Returns the tangent, in meters per second, of the curve at the position with the given
+    /// index, estimated by finite differences.
+    fn tangent(&self, index: usize) -> Coords {
+        let last = self.points.len() - 1;
+        if (index == 0 || index == last) && self.is_closed() {
+            // the ends of a closed orbit are the same point, so the curve continues across them.
+            let (before, first, after) = (self.points[last - 1], self.points[0], self.points[1]);
+            let elapsed = (after.0 - first.0 + self.points[last].0 - before.0).as_secs_f64();
+            return (after.1 - before.1) / elapsed;
+        }
+
+        let before = self.points[index.saturating_sub(1)];
+        let after = self.points[(index + 1).min(last)];
+
+        let elapsed = (after.0 - before.0).as_secs_f64();
+        if elapsed == 0. {
+            return Coords::default();
+        }
+
+        (after.1 - before.1) / elapsed
+    }
+
+    /// Returns true if, and only if, the orbit ends at the same position it starts with.
+    fn is_closed(&self) -> bool {
+        const CLOSURE_TOLERANCE: f64 = 1e-9;
+
+        let (Some(first), Some(last)) = (self.points.first(), self.points.last()) else {
+            return false;
+        };
+
+        self.points.len() > 2
+            && first.1.distance(&last.1) <= CLOSURE_TOLERANCE * self.radius().as_meters()
+    }
+
+    /// Returns the position and velocity (in meters per second) of the orbit at the given time in
+    /// seconds, clamped to the time span of the orbit.
+    fn interpolate(&self, time: f64) -> (Coords, Coords) {
+        let (Some(first), Some(last)) = (self.points.first(), self.points.last()) else {
+            return Default::default();
+        };
+
+        if self.points.len() == 1 || time <= first.0.as_secs_f64() {
+            return (first.1, self.tangent(0));
+        }
+
+        if time >= last.0.as_secs_f64() {
+            return (last.1, self.tangent(self.points.len() - 1));
+        }
+
+        // the index of the first position after the given time.
+        let index = self
+            .points
+            .partition_point(|(point_time, _)| point_time.as_secs_f64() <= time);
+
+        let (t0, p0) = self.points[index - 1];
+        let (t1, p1) = self.points[index];
+        let h = (t1 - t0).as_secs_f64();
+        let m0 = self.tangent(index - 1) * h;
+        let m1 = self.tangent(index) * h;
+
+        let t = (time - t0.as_secs_f64()) / h;
+        let (t2, t3) = (t * t, t * t * t);
+
+        let position = p0 * (2. * t3 - 3. * t2 + 1.)
+            + m0 * (t3 - 2. * t2 + t)
+            + p1 * (-2. * t3 + 3. * t2)
+            + m1 * (t3 - t2);
+
+        let velocity = (p0 * (6. * t2 - 6. * t)
+            + m0 * (3. * t2 - 4. * t + 1.)
+            + p1 * (-6. * t2 + 6. * t)
+            + m1 * (3. * t2 - 2. * t))
+            / h;
+
+        (position, velocity)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{str::FromStr, time::Duration};
+
+    use alvidir::name::Name;
+
+    use super::{escape_csv, EphemerisFormat, FixedEphemeris, COLUMNS};
+    use crate::{
+        cartesian::{shape::Ellipse, Coords},
+        fixtures::body,
+        Distance, Orbit, OrbitalSystem, OrbitalSystemBuilder, OrbitalSystemStateGenerator, Ratio,
+    };
+
+    fn system() -> OrbitalSystem<Ellipse> {
+        OrbitalSystemBuilder::new(body("sun"))
+            .with_satellite(
+                body("planet"),
+                Ellipse::default()
+                    .with_semi_major_axis(Distance::km(100_000.))
+                    .with_eccentricity(Ratio::from(0.3)),
+                |satellites| {
+                    satellites.with_satellite(
+                        body("moon"),
+                        Ellipse::default().with_semi_major_axis(Distance::km(1_000.)),
+                        |satellites| satellites,
+                    )
+                },
+            )
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn export_csv_must_round_trip() {
+        let system = system();
+        let step = Duration::from_secs(600);
+        let until = Duration::from_secs(6 * 3600);
+
+        let mut csv = Vec::new();
+        OrbitalSystemStateGenerator::from(&system)
+            .with_step(step)
+            .export(until, EphemerisFormat::Csv, &mut csv)
+            .unwrap();
+
+        let csv = String::from_utf8(csv).unwrap();
+        let mut lines = csv.lines();
+        assert_eq!(lines.next(), Some(COLUMNS.join(",").as_str()));
+
+        let rows: Vec<Vec<&str>> = lines.map(|line| line.split(',').collect()).collect();
+        assert_eq!(
+            rows.len(),
+            36 * 3,
+            "got {} rows, want {}",
+            rows.len(),
+            36 * 3
+        );
+
+        rows.into_iter().for_each(|row| {
+            let time = Duration::from_secs_f64(row[0].parse().unwrap());
+            let name = Name::from_str(row[1]).unwrap();
+            let position = Coords::default()
+                .with_x(row[2].parse().unwrap())
+                .with_y(row[3].parse().unwrap())
+                .with_z(row[4].parse().unwrap());
+
+            let state = system.state_at(time);
+            let want = state.state(&name).unwrap().position;
+            assert_eq!(position, want, "{name} at {time:?}");
+        });
+    }
+
+    #[test]
+    #[cfg(feature = "json")]
+    fn export_json_lines_must_write_one_object_per_row() {
+        let system = system();
+
+        let mut json = Vec::new();
+        OrbitalSystemStateGenerator::from(&system)
+            .with_step(Duration::from_secs(600))
+            .export(
+                Duration::from_secs(1_200),
+                EphemerisFormat::JsonLines,
+                &mut json,
+            )
+            .unwrap();
+
+        let rows: Vec<serde_json::Value> = String::from_utf8(json)
+            .unwrap()
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+
+        assert_eq!(rows.len(), 2 * 3);
+        rows.iter().for_each(|row| {
+            let keys: Vec<&str> = row
+                .as_object()
+                .unwrap()
+                .keys()
+                .map(String::as_str)
+                .collect();
+            COLUMNS.iter().for_each(|column| {
+                assert!(keys.contains(column), "got keys = {keys:?}, want {column}")
+            });
+        });
+
+        assert_eq!(rows[3]["time"], 600.);
+        assert_eq!(rows[4]["body"], "planet");
+    }
+
+    #[test]
+    fn escape_csv_must_not_fail() {
+        struct Test {
+            name: &'static str,
+            field: &'static str,
+            output: &'static str,
+        }
+
+        vec![
+            Test {
+                name: "plain field",
+                field: "Earth",
+                output: "Earth",
+            },
+            Test {
+                name: "field with comma",
+                field: "Alpha Centauri, A",
+                output: "\"Alpha Centauri, A\"",
+            },
+            Test {
+                name: "field with quotes",
+                field: "The \"Moon\"",
+                output: "\"The \"\"Moon\"\"\"",
+            },
+            Test {
+                name: "field with new line",
+                field: "Io\nII",
+                output: "\"Io\nII\"",
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let output = escape_csv(test.field);
+            assert_eq!(
+                output, test.output,
+                "{}: got output = {:?}, want {:?}",
+                test.name, output, test.output
+            );
+        });
+    }
+
+    #[test]
+    fn fixed_ephemeris_must_reproduce_an_ellipse() {
+        const POINTS: u32 = 256;
+
+        let orbitee = body("sun");
+        let ellipse = Ellipse::default()
+            .with_semi_major_axis(Distance::km(100_000.))
+            .with_eccentricity(Ratio::from(0.3));
+
+        let period = ellipse.period(&orbitee);
+        let position = |time: Duration| ellipse.position_at(time, &orbitee) + ellipse.focus();
+
+        let ephemeris = FixedEphemeris::from(
+            (0..=POINTS)
+                .map(|index| period * index / POINTS)
+                .map(|time| (time, position(time)))
+                .collect::<Vec<_>>(),
+        );
+
+        assert_eq!(ephemeris.period(&orbitee), period);
+
+        let tolerance = ellipse.semi_major_axis.as_meters() * 1e-4;
+        (0..POINTS * 4)
+            .map(|index| period.mul_f64((index as f64 + 0.5) / (POINTS * 4) as f64))
+            .for_each(|time| {
+                let got = ephemeris.position_at(time, &orbitee);
+                let want = position(time);
+                assert!(
+                    got.distance(&want) <= tolerance,
+                    "at {time:?}: got position = {got:?}, want {want:?}"
+                );
+
+                // the speed at which the positions of the ellipse actually change over time.
+                let delta = Duration::from_millis(10);
+                let got = ephemeris.velocity_at(time, &orbitee).as_meters_sec();
+                let want = position(time + delta).distance(&position(time - delta))
+                    / (2. * delta.as_secs_f64());
+                assert!(
+                    (got - want).abs() <= want * 1e-2,
+                    "at {time:?}: got velocity = {got}, want {want}"
+                );
+            });
+
+        let got = ephemeris.eccentricity().as_f64();
+        assert!(
+            (got - 0.3).abs() <= 1e-3,
+            "got eccentricity = {got}, want 0.3"
+        );
+
+        let got = ephemeris.perimeter().as_meters();
+        let want = ellipse.perimeter().as_meters();
+        assert!(
+            (got - want).abs() <= want * 1e-3,
+            "got perimeter = {got}, want {want}"
+        );
+    }
+
+    #[test]
+    fn fixed_ephemeris_must_plug_into_a_system() {
+        let orbit = FixedEphemeris::from(vec![
+            (Duration::from_secs(0), Coords::default().with_x(1e7)),
+            (Duration::from_secs(100), Coords::default().with_y(1e7)),
+            (Duration::from_secs(200), Coords::default().with_x(-1e7)),
+        ]);
+
+        let system: OrbitalSystem<FixedEphemeris> = OrbitalSystemBuilder::new(body("sun"))
+            .with_satellite(body("probe"), orbit, |satellites| satellites)
+            .build()
+            .unwrap();
+
+        let state = system.state_at(Duration::from_secs(100));
+        let probe = state.state(&Name::from_str("probe").unwrap()).unwrap();
+        assert_eq!(probe.position, Coords::default().with_y(1e7));
+    }
+}
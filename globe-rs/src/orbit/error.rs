@@ -0,0 +1,165 @@
+use std::fmt::Display;
+
+use alvidir::name::Name;
+
+use crate::Epoch;
+
+use super::Body;
+
+/// An inconsistency in the configuration of an orbital system.
+#[derive(Debug, Clone, PartialEq)]
+pub enum SystemError {
+    /// Two or more bodies share the same name.
+    DuplicatedName(Name<Body>),
+    /// A field of the body holds an infinite or NaN value.
+    NonFinite {
+        body: Name<Body>,
+        field: &'static str,
+    },
+    /// The body has no mass.
+    ZeroMass(Name<Body>),
+    /// The body is a secondary one but has no orbit.
+    MissingOrbit(Name<Body>),
+    /// The orbit of the body is not closed (eccentricity ≥ 1).
+    UnboundOrbit(Name<Body>),
+    /// The periapsis of the orbit of the body is closer than the sum of its radius and the radius
+    /// of the body it orbits.
+    OrbitTooSmall {
+        body: Name<Body>,
+        orbitee: Name<Body>,
+    },
+}
+
+impl Display for SystemError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SystemError::DuplicatedName(name) => {
+                write!(f, "the name {name} is used by more than one body")
+            }
+            SystemError::NonFinite { body, field } => {
+                write!(f, "the {field} of {body} must be a finite number")
+            }
+            SystemError::ZeroMass(body) => write!(f, "the mass of {body} must not be zero"),
+            SystemError::MissingOrbit(body) => {
+                write!(f, "{body} is a secondary body but has no orbit")
+            }
+            SystemError::UnboundOrbit(body) => {
+                write!(
+                    f,
+                    "the orbit of {body} must have an eccentricity lower than 1"
+                )
+            }
+            SystemError::OrbitTooSmall { body, orbitee } => {
+                write!(f, "the orbit of {body} collides with {orbitee}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for SystemError {}
+
+/// An error while editing an orbital system.
+#[derive(Debug, Clone, PartialEq)]
+pub enum EditError {
+    /// There is no body with the given name in the system.
+    NotFound(Name<Body>),
+    /// The name of the body is already used by another body of the system.
+    DuplicatedName(Name<Body>),
+    /// The body cannot orbit itself or any of its own satellites.
+    CyclicParent {
+        body: Name<Body>,
+        parent: Name<Body>,
+    },
+    /// The edition leaves the affected subsystem inconsistent.
+    Invalid(Vec<SystemError>),
+}
+
+impl Display for EditError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            EditError::NotFound(body) => write!(f, "there is no body named {body}"),
+            EditError::DuplicatedName(body) => {
+                write!(f, "the name {body} is already used by another body")
+            }
+            EditError::CyclicParent { body, parent } => {
+                write!(
+                    f,
+                    "{body} cannot orbit {parent}, which is one of its satellites"
+                )
+            }
+            EditError::Invalid(errors) => {
+                let errors: Vec<String> = errors.iter().map(ToString::to_string).collect();
+                write!(f, "{}", errors.join("; "))
+            }
+        }
+    }
+}
+
+impl std::error::Error for EditError {}
+
+/// An error while computing the state of an orbital system.
+#[derive(Debug, Clone, PartialEq)]
+pub enum StateError {
+    /// The orbital period of the body is undefined, since either the body it orbits has no mass
+    /// or its orbit is too small, or too big, for the period to be represented.
+    UndefinedPeriod {
+        body: Name<Body>,
+        orbitee: Name<Body>,
+    },
+    /// The orbit of the body is not closed (eccentricity ≥ 1).
+    UnboundOrbit(Name<Body>),
+    /// A field of the state of the body resulted in an infinite or NaN value.
+    NonFinite {
+        body: Name<Body>,
+        field: &'static str,
+    },
+    /// The epoch is either NaN or too far from the origin of time to be represented.
+    OutOfRange(Epoch),
+}
+
+impl Display for StateError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            StateError::UndefinedPeriod { body, orbitee } => {
+                write!(
+                    f,
+                    "the orbital period of {body} around {orbitee} is undefined"
+                )
+            }
+            StateError::UnboundOrbit(body) => {
+                write!(f, "the orbit of {body} is not closed")
+            }
+            StateError::NonFinite { body, field } => {
+                write!(f, "the {field} of {body} is not a finite number")
+            }
+            StateError::OutOfRange(epoch) => {
+                write!(
+                    f,
+                    "the epoch {} seconds away from J2000 is out of range",
+                    epoch.as_seconds()
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for StateError {}
+
+/// An error while computing the habitable zone around a body.
+#[derive(Debug, Clone, PartialEq)]
+pub enum HabitableZoneError {
+    /// The body emits no light, hence there is no zone it keeps warm enough.
+    NotLuminous(Name<Body>),
+}
+
+impl Display for HabitableZoneError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            HabitableZoneError::NotLuminous(body) => {
+                write!(f, "{body} is not luminous, hence it has no habitable zone")
+            }
+        }
+    }
+}
+
+impl std::error::Error for HabitableZoneError {}
@@ -0,0 +1,261 @@
+use std::{f64::consts::PI, fmt::Display, time::Duration};
+
+use alvidir::name::Name;
+
+use super::{Body, Orbit, OrbitalSystem};
+
+/// The amount of samples per orbital period in which the time range is divided when searching for
+/// events. It must be greater than one, so no sample travels a whole orbit.
+const SAMPLES_PER_PERIOD: u32 = 8;
+
+/// The kind of a noteworthy moment along the orbit of a body.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OrbitalEventKind {
+    /// The body passes through the point of its orbit closest to its orbitee.
+    Periapsis,
+    /// The body passes through the point of its orbit farthest from its orbitee.
+    Apoapsis,
+}
+
+impl Display for OrbitalEventKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            OrbitalEventKind::Periapsis => write!(f, "periapsis"),
+            OrbitalEventKind::Apoapsis => write!(f, "apoapsis"),
+        }
+    }
+}
+
+/// A noteworthy moment along the orbit of a body.
+#[derive(Debug, Clone, PartialEq)]
+pub struct OrbitalEvent {
+    /// The name of the body the event concerns.
+    pub body: Name<Body>,
+    /// What happens to the body.
+    pub kind: OrbitalEventKind,
+    /// When it happens.
+    pub time: Duration,
+}
+
+impl<O: Orbit> OrbitalSystem<O> {
+    /// Returns all the events happening in the system from the given time (inclusive) to the
+    /// given one (exclusive), sorted by time.
+    ///
+    /// The radiant of an orbit is assumed to be zero at its periapsis, and π at its apoapsis, as
+    /// it is for an [Ellipse](crate::cartesian::shape::Ellipse). Circular and open orbits have no
+    /// apsides, hence they yield no event.
+    pub fn events_between(&self, from: Duration, to: Duration) -> Vec<OrbitalEvent> {
+        let mut events: Vec<_> = self
+            .subsystems()
+            .filter_map(|(system, orbitee)| Some((system, orbitee?, system.orbit.as_ref()?)))
+            .filter(|(_, _, orbit)| orbit.is_closed() && orbit.eccentricity().as_f64() > 0.)
+            .flat_map(|(system, orbitee, orbit)| {
+                apsides_between(orbit, &orbitee.primary, from, to)
+                    .into_iter()
+                    .map(|(kind, time)| OrbitalEvent {
+                        body: system.primary.name.clone(),
+                        kind,
+                        time,
+                    })
+            })
+            .collect();
+
+        events.sort_by_key(|event| event.time);
+        events
+    }
+}
+
+/// Returns the apsides the given orbit passes through from the given time (inclusive) to the given
+/// one (exclusive).
+fn apsides_between<O: Orbit>(
+    orbit: &O,
+    orbitee: &Body,
+    from: Duration,
+    to: Duration,
+) -> Vec<(OrbitalEventKind, Duration)> {
+    let step = orbit.period(orbitee) / SAMPLES_PER_PERIOD;
+    if step.is_zero() {
+        return Vec::default();
+    }
+
+    let phase = |time| phase(orbit, orbitee, time);
+    // the phase relative to the one at a previous time, no matter if it completed the orbit.
+    let unwrap = |phase: f64, since: f64| {
+        if phase < since {
+            phase + 2. * PI
+        } else {
+            phase
+        }
+    };
+
+    let mut apsides = Vec::new();
+    if let Some(kind) = apsis_at(phase(from)).filter(|_| from < to) {
+        apsides.push((kind, from));
+    }
+
+    let mut start = from;
+    while start < to {
+        let end = (start + step).min(to);
+        let since = phase(start);
+        let until = unwrap(phase(end), since);
+
+        [
+            (PI, OrbitalEventKind::Apoapsis),
+            (2. * PI, OrbitalEventKind::Periapsis),
+            (3. * PI, OrbitalEventKind::Apoapsis),
+        ]
+        .into_iter()
+        .filter(|&(target, _)| since < target)
+        // the end of the whole range is exclusive, while the one of each step belongs to it.
+        .filter(|&(target, _)| target < until || (end < to && target == until))
+        .for_each(|(target, kind)| {
+            // the earliest time at which the body reaches the target phase.
+            let (mut before, mut after) = (start, end);
+            while after - before > Duration::from_nanos(1) {
+                let middle = before + (after - before) / 2;
+                if unwrap(phase(middle), since) < target {
+                    before = middle;
+                } else {
+                    after = middle;
+                }
+            }
+
+            apsides.push((kind, after));
+        });
+
+        start = end;
+    }
+
+    apsides
+}
+
+/// Returns the apsis located at the given phase, if any.
+fn apsis_at(phase: f64) -> Option<OrbitalEventKind> {
+    if phase == 0. {
+        Some(OrbitalEventKind::Periapsis)
+    } else if phase == PI {
+        Some(OrbitalEventKind::Apoapsis)
+    } else {
+        None
+    }
+}
+
+/// Returns the radiants the body has travelled along its orbit at the given time since its last
+/// periapsis, no matter the direction of the orbit.
+fn phase<O: Orbit>(orbit: &O, orbitee: &Body, time: Duration) -> f64 {
+    let theta = orbit.theta_at(time, orbitee).as_f64();
+    if orbit.is_clockwise() && theta != 0. {
+        return 2. * PI - theta;
+    }
+
+    theta
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use crate::{
+        cartesian::shape::{Circle, Ellipse},
+        fixtures::system,
+        Distance, Orbit, OrbitKind, OrbitalEventKind, Ratio,
+    };
+
+    fn ellipse(clockwise: bool) -> OrbitKind {
+        Ellipse {
+            clockwise,
+            ..Ellipse::default()
+                .with_semi_major_axis(Distance::km(10_000.))
+                .with_eccentricity(Ratio::try_new(0.6).unwrap())
+        }
+        .into()
+    }
+
+    #[test]
+    fn events_between_must_not_fail() {
+        const ABS_ERROR: Duration = Duration::from_millis(1);
+
+        let period = ellipse(false).period(&system(ellipse(false)).primary);
+
+        struct Test {
+            name: &'static str,
+            orbit: OrbitKind,
+            from: Duration,
+            to: Duration,
+            events: Vec<(OrbitalEventKind, Duration)>,
+        }
+
+        vec![
+            Test {
+                name: "a whole period must pass through both apsides once",
+                orbit: ellipse(false),
+                from: Duration::ZERO,
+                to: period,
+                events: vec![
+                    (OrbitalEventKind::Periapsis, Duration::ZERO),
+                    (OrbitalEventKind::Apoapsis, period / 2),
+                ],
+            },
+            Test {
+                name: "a whole clockwise period must pass through both apsides once",
+                orbit: ellipse(true),
+                from: Duration::ZERO,
+                to: period,
+                events: vec![
+                    (OrbitalEventKind::Periapsis, Duration::ZERO),
+                    (OrbitalEventKind::Apoapsis, period / 2),
+                ],
+            },
+            Test {
+                name: "range across periods",
+                orbit: ellipse(false),
+                from: period / 4,
+                to: period * 2,
+                events: vec![
+                    (OrbitalEventKind::Apoapsis, period / 2),
+                    (OrbitalEventKind::Periapsis, period),
+                    (OrbitalEventKind::Apoapsis, period * 3 / 2),
+                ],
+            },
+            Test {
+                name: "range with no apsis",
+                orbit: ellipse(false),
+                from: period / 8,
+                to: period * 3 / 8,
+                events: vec![],
+            },
+            Test {
+                name: "circular orbits have no apsides",
+                orbit: Circle::default().with_radius(Distance::km(10_000.)).into(),
+                from: Duration::ZERO,
+                to: period * 2,
+                events: vec![],
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let events = system(test.orbit).events_between(test.from, test.to);
+            assert_eq!(
+                events.len(),
+                test.events.len(),
+                "{}: got events = {events:?}, want {:?}",
+                test.name,
+                test.events
+            );
+
+            events
+                .iter()
+                .zip(&test.events)
+                .for_each(|(event, &(kind, time))| {
+                    assert_eq!(event.body.as_ref(), "planet", "{}", test.name);
+                    assert_eq!(event.kind, kind, "{}", test.name);
+                    assert!(
+                        event.time.abs_diff(time) <= ABS_ERROR,
+                        "{}: got {kind:?} at {:?}, want {time:?}",
+                        test.name,
+                        event.time
+                    );
+                });
+        });
+    }
+}
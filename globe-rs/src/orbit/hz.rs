@@ -1,6 +1,6 @@
 use crate::{Distance, Luminosity};
 
-use super::Body;
+use super::{Body, HabitableZoneError};
 
 /// Describes the habitable zone around a body.
 #[derive(Debug, Default)]
@@ -9,13 +9,87 @@ pub struct HabitableZone {
     pub outer_edge: Distance,
 }
 
-impl From<&Body> for HabitableZone {
-    fn from(body: &Body) -> Self {
+impl HabitableZone {
+    /// Returns the distance between the inner and outer edges of the zone.
+    pub fn width(&self) -> Distance {
+        self.outer_edge.saturating_sub(self.inner_edge)
+    }
+}
+
+impl TryFrom<&Body> for HabitableZone {
+    type Error = HabitableZoneError;
+
+    /// Returns the habitable zone around the given body, as long as it is luminous.
+    fn try_from(body: &Body) -> Result<Self, Self::Error> {
+        if !body.is_luminous() {
+            return Err(HabitableZoneError::NotLuminous(body.name.clone()));
+        }
+
         let sun_relative = body.luminosity / Luminosity::SUN;
 
-        Self {
+        Ok(Self {
             inner_edge: Distance::ASTRONOMICAL_UNIT * (sun_relative.as_watts() / 1.1).sqrt(),
             outer_edge: Distance::ASTRONOMICAL_UNIT * (sun_relative.as_watts() / 0.53).sqrt(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use alvidir::name::Name;
+
+    use crate::{tests::approx_eq, Body, Distance, HabitableZone, HabitableZoneError, Luminosity};
+
+    #[test]
+    fn habitable_zone_must_not_fail() {
+        const ABS_ERROR: f64 = 1e-3;
+
+        struct Test {
+            name: &'static str,
+            luminosity: Luminosity,
+            /// The inner and outer edges, in astronomical units.
+            output: Result<(f64, f64), HabitableZoneError>,
         }
+
+        vec![
+            Test {
+                name: "sun-like star",
+                luminosity: Luminosity::SUN,
+                output: Ok((0.953, 1.374)),
+            },
+            Test {
+                name: "star four times brighter than the sun",
+                luminosity: Luminosity::SUN * 4.,
+                output: Ok((1.907, 2.747)),
+            },
+            Test {
+                name: "dark body",
+                luminosity: Luminosity::ZERO,
+                output: Err(HabitableZoneError::NotLuminous(
+                    Name::from_str("body").unwrap(),
+                )),
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let body = Body::new(Name::from_str("body").unwrap()).with_luminosity(test.luminosity);
+            let output = HabitableZone::try_from(&body).map(|hz| {
+                (
+                    hz.inner_edge.as_meters() / Distance::ASTRONOMICAL_UNIT.as_meters(),
+                    hz.outer_edge.as_meters() / Distance::ASTRONOMICAL_UNIT.as_meters(),
+                )
+            });
+
+            match (output, test.output) {
+                (Ok(got), Ok(want)) => assert!(
+                    approx_eq(got.0, want.0, ABS_ERROR) && approx_eq(got.1, want.1, ABS_ERROR),
+                    "{}: got edges = {got:?} AU, want {want:?}",
+                    test.name
+                ),
+                (got, want) => assert_eq!(got, want, "{}", test.name),
+            }
+        });
     }
 }
@@ -0,0 +1,370 @@
+use crate::{
+    cartesian::{shape::Ellipse, Coords},
+    Orbit, Radian,
+};
+
+/// The amount of segments in which an orbit is sampled when searching for intersections.
+const SEGMENTS: usize = 1024;
+/// The amount of iterations performed when refining a sampled solution.
+const ITERATIONS: usize = 64;
+/// The maximum value of the implicit equation of an ellipse for a point to be considered on it.
+const TOLERANCE: f64 = 1e-9;
+
+/// The geometric relation between the paths of two orbits.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Intersections {
+    /// The points at which both paths cross or touch each other.
+    Crossing(Vec<Coords>),
+    /// The paths do not cross each other, being from and to the closest points between them.
+    Disjoint { from: Coords, to: Coords },
+    /// Both paths are the same, hence they overlap all along the way.
+    Coincident,
+}
+
+impl Intersections {
+    /// Returns the points at which both paths cross or touch each other, if any.
+    pub fn points(&self) -> &[Coords] {
+        match self {
+            Intersections::Crossing(points) => points,
+            Intersections::Disjoint { .. } | Intersections::Coincident => &[],
+        }
+    }
+
+    /// Returns the minimum distance between both paths.
+    pub fn separation(&self) -> f64 {
+        match self {
+            Intersections::Crossing(_) | Intersections::Coincident => 0.,
+            Intersections::Disjoint { from, to } => from.distance(to),
+        }
+    }
+}
+
+/// Returns the points (in meters), relative to the focus of a, at which the paths of both orbits
+/// intersect, being focus_offset the position of the focus of b relative to the focus of a.
+pub fn orbit_intersections(a: &Ellipse, b: &Ellipse, focus_offset: Coords) -> Intersections {
+    // the center of b relative to the center of a.
+    let offset = b.focus() + focus_offset + -a.focus();
+
+    if offset.magnitude() == 0. && coincident(a, b) {
+        return Intersections::Coincident;
+    }
+
+    let points = if offset.magnitude() == 0. {
+        concentric_intersections(a, b)
+    } else {
+        sampled_intersections(a, b, offset)
+    };
+
+    if points.is_empty() {
+        let (from, to) = closest_points(a, b, offset);
+        return Intersections::Disjoint {
+            from: from + a.focus(),
+            to: to + a.focus(),
+        };
+    }
+
+    Intersections::Crossing(points.into_iter().map(|point| point + a.focus()).collect())
+}
+
+/// Returns the value of the implicit equation of the ellipse at the given point, relative to its
+/// center, which is zero if, and only if, the point is on the ellipse.
+fn implicit(ellipse: &Ellipse, point: Coords) -> f64 {
+    (point.x() / ellipse.semi_major_axis.as_meters()).powi(2)
+        + (point.y() / ellipse.semi_minor_axis().as_meters()).powi(2)
+        - 1.
+}
+
+/// Returns true if, and only if, both concentric ellipses have the same axes, and therefore the
+/// same path.
+fn coincident(a: &Ellipse, b: &Ellipse) -> bool {
+    let same = |x: f64, y: f64| (x - y).abs() <= x.abs().max(y.abs()) * TOLERANCE;
+
+    same(a.semi_major_axis.as_meters(), b.semi_major_axis.as_meters())
+        && same(
+            a.semi_minor_axis().as_meters(),
+            b.semi_minor_axis().as_meters(),
+        )
+}
+
+/// Solves the intersection of two axis-aligned ellipses sharing the same center, which is linear
+/// on the squares of the coordinates.
+fn concentric_intersections(a: &Ellipse, b: &Ellipse) -> Vec<Coords> {
+    let coefficients = |ellipse: &Ellipse| {
+        (
+            ellipse.semi_major_axis.as_meters().powi(2).recip(),
+            ellipse.semi_minor_axis().as_meters().powi(2).recip(),
+        )
+    };
+
+    let (a1, b1) = coefficients(a);
+    let (a2, b2) = coefficients(b);
+
+    let determinant = a1 * b2 - a2 * b1;
+    if determinant == 0. {
+        // either the ellipses are the same or they never touch each other
+        return Vec::default();
+    }
+
+    let x_squared = (b2 - b1) / determinant;
+    let y_squared = (a1 - a2) / determinant;
+    if x_squared < 0. || y_squared < 0. {
+        return Vec::default();
+    }
+
+    let (x, y) = (x_squared.sqrt(), y_squared.sqrt());
+    let mut points: Vec<Coords> = vec![
+        Coords::from([x, y, 0.]),
+        Coords::from([-x, y, 0.]),
+        Coords::from([-x, -y, 0.]),
+        Coords::from([x, -y, 0.]),
+    ];
+
+    points.dedup();
+    if points.len() > 1 && points.first() == points.last() {
+        points.pop();
+    }
+
+    points
+}
+
+/// Searches the intersections of both ellipses by sampling the path of a, being offset the center
+/// of b relative to the center of a.
+fn sampled_intersections(a: &Ellipse, b: &Ellipse, offset: Coords) -> Vec<Coords> {
+    let value_at = |theta: f64| implicit(b, a.position(theta.into()) + -offset);
+    let step = Radian::TWO_PI.as_f64() / SEGMENTS as f64;
+
+    let values: Vec<f64> = (0..SEGMENTS)
+        .map(|index| value_at(index as f64 * step))
+        .collect();
+
+    let mut roots: Vec<f64> = Vec::new();
+    (0..SEGMENTS).for_each(|index| {
+        let previous = values[(index + SEGMENTS - 1) % SEGMENTS];
+        let current = values[index];
+        let next = values[(index + 1) % SEGMENTS];
+        let from = index as f64 * step;
+
+        if current == 0. {
+            roots.push(from);
+        } else if current * next < 0. {
+            roots.push(bisect(value_at, from, from + step));
+        } else if current.abs() < previous.abs() && current.abs() <= next.abs() {
+            // a local minimum not crossing zero may still be a tangent point
+            let theta = golden_section(|theta| value_at(theta).abs(), from - step, from + step);
+            if value_at(theta).abs() <= TOLERANCE {
+                roots.push(theta);
+            }
+        }
+    });
+
+    let mut points: Vec<Coords> = Vec::new();
+    roots
+        .into_iter()
+        .map(|theta| a.position(theta.into()))
+        .for_each(|point| {
+            let tolerance = a.semi_major_axis.as_meters() * TOLERANCE.sqrt();
+            if points
+                .iter()
+                .all(|other| other.distance(&point) > tolerance)
+            {
+                points.push(point);
+            }
+        });
+
+    points
+}
+
+/// Returns the closest points of both ellipses, relative to the center of a, being offset the
+/// center of b relative to the center of a.
+fn closest_points(a: &Ellipse, b: &Ellipse, offset: Coords) -> (Coords, Coords) {
+    let closest_at = |theta: f64| {
+        let from = a.position(theta.into());
+        // a degenerate ellipse has no closest point, hence its center.
+        let to = b
+            .closest_point(from + -offset)
+            .map(|(_, to)| to)
+            .unwrap_or_default();
+
+        (from, to + offset)
+    };
+
+    let distance_at = |theta: f64| {
+        let (from, to) = closest_at(theta);
+        from.distance(&to)
+    };
+
+    let step = Radian::TWO_PI.as_f64() / SEGMENTS as f64;
+    let nearest = (0..SEGMENTS)
+        .map(|index| index as f64 * step)
+        .min_by(|&x, &y| distance_at(x).total_cmp(&distance_at(y)))
+        .unwrap_or_default();
+
+    closest_at(golden_section(distance_at, nearest - step, nearest + step))
+}
+
+/// Returns the root of f in the range [from, to], given f(from) and f(to) have opposite signs.
+fn bisect(f: impl Fn(f64) -> f64, mut from: f64, mut to: f64) -> f64 {
+    let sign = f(from).signum();
+    for _ in 0..ITERATIONS {
+        let middle = (from + to) / 2.;
+        if f(middle).signum() == sign {
+            from = middle;
+        } else {
+            to = middle;
+        }
+    }
+
+    (from + to) / 2.
+}
+
+/// Returns the minimum of f in the range [from, to], given f is unimodal in that range.
+fn golden_section(f: impl Fn(f64) -> f64, mut from: f64, mut to: f64) -> f64 {
+    let ratio = (5_f64.sqrt() - 1.) / 2.;
+    for _ in 0..ITERATIONS {
+        let lower = to - ratio * (to - from);
+        let upper = from + ratio * (to - from);
+        if f(lower) < f(upper) {
+            to = upper;
+        } else {
+            from = lower;
+        }
+    }
+
+    (from + to) / 2.
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        cartesian::{shape::Ellipse, Coords},
+        orbit::{orbit_intersections, Intersections},
+        Distance, Ratio,
+    };
+
+    const ABS_ERROR: f64 = 0.000001;
+
+    fn ellipse(semi_major_axis: f64, eccentricity: f64) -> Ellipse {
+        Ellipse::default()
+            .with_semi_major_axis(Distance::meters(semi_major_axis))
+            .with_eccentricity(Ratio::from(eccentricity))
+    }
+
+    fn assert_points(name: &str, got: &[Coords], want: &[Coords]) {
+        assert_eq!(
+            got.len(),
+            want.len(),
+            "{name}: got points = {got:?}, want {want:?}"
+        );
+
+        want.iter().for_each(|want| {
+            assert!(
+                got.iter().any(|got| got.distance(want) <= ABS_ERROR),
+                "{name}: got points = {got:?}, want {want:?}"
+            );
+        });
+    }
+
+    #[test]
+    fn orbit_intersections_must_not_fail() {
+        struct Test {
+            name: &'static str,
+            a: Ellipse,
+            b: Ellipse,
+            focus_offset: Coords,
+            points: Vec<Coords>,
+        }
+
+        vec![
+            Test {
+                name: "twice crossing ellipses",
+                a: ellipse(5., 0.),
+                b: ellipse(5., 0.8),
+                focus_offset: Coords::default(),
+                points: vec![Coords::from([-4., 3., 0.]), Coords::from([-4., -3., 0.])],
+            },
+            Test {
+                name: "tangent ellipses",
+                a: ellipse(5., 0.),
+                b: ellipse(1., 0.),
+                focus_offset: Coords::from([4., 0., 0.]),
+                points: vec![Coords::from([5., 0., 0.])],
+            },
+            Test {
+                name: "concentric ellipses crossing four times",
+                a: ellipse(5., 0.8),
+                b: ellipse(4., 0.),
+                focus_offset: Coords::from([-4., 0., 0.]),
+                points: vec![
+                    Coords::from([175_f64.sqrt() / 4. - 4., 81_f64.sqrt() / 4., 0.]),
+                    Coords::from([-175_f64.sqrt() / 4. - 4., 81_f64.sqrt() / 4., 0.]),
+                    Coords::from([-175_f64.sqrt() / 4. - 4., -81_f64.sqrt() / 4., 0.]),
+                    Coords::from([175_f64.sqrt() / 4. - 4., -81_f64.sqrt() / 4., 0.]),
+                ],
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let intersections = orbit_intersections(&test.a, &test.b, test.focus_offset);
+            assert!(
+                matches!(intersections, Intersections::Crossing(_)),
+                "{}: got {:?}, want crossing",
+                test.name,
+                intersections
+            );
+
+            assert_points(test.name, intersections.points(), &test.points);
+        });
+    }
+
+    #[test]
+    fn disjoint_orbits_must_return_the_closest_points() {
+        let intersections = orbit_intersections(
+            &ellipse(5., 0.),
+            &ellipse(1., 0.),
+            Coords::from([10., 0., 0.]),
+        );
+
+        let Intersections::Disjoint { from, to } = &intersections else {
+            panic!("got {intersections:?}, want disjoint");
+        };
+
+        assert_points("disjoint ellipses", &[*from], &[Coords::from([5., 0., 0.])]);
+        assert_points("disjoint ellipses", &[*to], &[Coords::from([9., 0., 0.])]);
+        assert!(
+            (intersections.separation() - 4.).abs() <= ABS_ERROR,
+            "got separation = {}, want 4",
+            intersections.separation()
+        );
+    }
+
+    #[test]
+    fn identical_orbits_must_be_coincident() {
+        struct Test {
+            name: &'static str,
+            a: Ellipse,
+            b: Ellipse,
+            focus_offset: Coords,
+        }
+
+        vec![
+            Test {
+                name: "identical circles",
+                a: ellipse(5., 0.),
+                b: ellipse(5., 0.),
+                focus_offset: Coords::default(),
+            },
+            Test {
+                name: "identical ellipses sharing the focus",
+                a: ellipse(5., 0.8),
+                b: ellipse(5., 0.8),
+                focus_offset: Coords::default(),
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let intersections = orbit_intersections(&test.a, &test.b, test.focus_offset);
+            assert_eq!(intersections, Intersections::Coincident, "{}", test.name);
+            assert_eq!(intersections.separation(), 0., "{}", test.name);
+        });
+    }
+}
@@ -0,0 +1,413 @@
+use std::time::Duration;
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::{
+    cartesian::{
+        shape::{Circle, Ellipse, Sample, Shape, Spiral},
+        Coords,
+    },
+    Distance, Radian, Ratio, Velocity,
+};
+
+use super::{Body, Orbit, OrbitalSystem};
+
+/// Any of the supported orbits, allowing different kinds of orbits in the same system.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case", remote = "Self")]
+pub enum OrbitKind {
+    Circle(Circle),
+    Ellipse(Ellipse),
+    Spiral(Spiral),
+}
+
+impl Serialize for OrbitKind {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        OrbitKind::serialize(self, serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for OrbitKind {
+    /// Deserializes the orbit of the given kind, being an ellipse if the kind is missing, as in
+    /// the files written before orbits were tagged.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        match MaybeTagged::deserialize(deserializer)? {
+            MaybeTagged::Tagged(orbit) => Ok(orbit),
+            MaybeTagged::Untagged(UntaggedEllipse { ellipse, .. }) => Ok(ellipse.into()),
+        }
+    }
+}
+
+/// An [OrbitKind] that may have been written before orbits were tagged.
+#[derive(Deserialize)]
+#[serde(
+    untagged,
+    expecting = "an orbit of a known kind, or an ellipse with no kind at all"
+)]
+enum MaybeTagged {
+    Tagged(#[serde(with = "OrbitKind")] OrbitKind),
+    Untagged(UntaggedEllipse),
+}
+
+/// An [Ellipse] with no kind at all, so orbits of an unknown or invalid kind are not taken as
+/// ellipses.
+#[derive(Deserialize)]
+struct UntaggedEllipse {
+    #[serde(default)]
+    #[allow(dead_code)]
+    kind: Option<NoKind>,
+    #[serde(flatten)]
+    ellipse: Ellipse,
+}
+
+/// A kind that no value deserializes into.
+#[derive(Deserialize)]
+enum NoKind {}
+
+impl Default for OrbitKind {
+    fn default() -> Self {
+        Self::Ellipse(Ellipse::default())
+    }
+}
+
+impl From<Circle> for OrbitKind {
+    fn from(circle: Circle) -> Self {
+        Self::Circle(circle)
+    }
+}
+
+impl From<Ellipse> for OrbitKind {
+    fn from(ellipse: Ellipse) -> Self {
+        Self::Ellipse(ellipse)
+    }
+}
+
+impl From<Spiral> for OrbitKind {
+    fn from(spiral: Spiral) -> Self {
+        Self::Spiral(spiral)
+    }
+}
+
+impl From<OrbitalSystem<Circle>> for OrbitalSystem<OrbitKind> {
+    fn from(system: OrbitalSystem<Circle>) -> Self {
+        system.map_orbits(&OrbitKind::from)
+    }
+}
+
+impl From<OrbitalSystem<Ellipse>> for OrbitalSystem<OrbitKind> {
+    fn from(system: OrbitalSystem<Ellipse>) -> Self {
+        system.map_orbits(&OrbitKind::from)
+    }
+}
+
+impl<O> OrbitalSystem<O> {
+    /// Returns the same system with every orbit transformed by the given function.
+    fn map_orbits<P>(self, f: &impl Fn(O) -> P) -> OrbitalSystem<P> {
+        OrbitalSystem {
+            primary: self.primary,
+            orbit: self.orbit.map(f),
+            secondary: self
+                .secondary
+                .into_iter()
+                .map(|system| system.map_orbits(f))
+                .collect(),
+        }
+    }
+}
+
+impl Sample for OrbitKind {
+    fn with_initial_theta(self, theta: Radian) -> Self {
+        match self {
+            OrbitKind::Circle(circle) => circle.with_initial_theta(theta).into(),
+            OrbitKind::Ellipse(ellipse) => ellipse.with_initial_theta(theta).into(),
+            OrbitKind::Spiral(spiral) => spiral.with_initial_theta(theta).into(),
+        }
+    }
+
+    fn sample(&self, segments: usize) -> Shape {
+        match self {
+            OrbitKind::Circle(circle) => circle.sample(segments),
+            OrbitKind::Ellipse(ellipse) => ellipse.sample(segments),
+            OrbitKind::Spiral(spiral) => spiral.sample(segments),
+        }
+    }
+}
+
+impl Orbit for OrbitKind {
+    fn min_velocity(&self, orbitee: &Body) -> Velocity {
+        match self {
+            OrbitKind::Circle(circle) => circle.min_velocity(orbitee),
+            OrbitKind::Ellipse(ellipse) => ellipse.min_velocity(orbitee),
+            OrbitKind::Spiral(spiral) => spiral.min_velocity(orbitee),
+        }
+    }
+
+    fn max_velocity(&self, orbitee: &Body) -> Velocity {
+        match self {
+            OrbitKind::Circle(circle) => circle.max_velocity(orbitee),
+            OrbitKind::Ellipse(ellipse) => ellipse.max_velocity(orbitee),
+            OrbitKind::Spiral(spiral) => spiral.max_velocity(orbitee),
+        }
+    }
+
+    fn velocity_at_theta(&self, theta: Radian, orbitee: &Body) -> Velocity {
+        match self {
+            OrbitKind::Circle(circle) => circle.velocity_at_theta(theta, orbitee),
+            OrbitKind::Ellipse(ellipse) => ellipse.velocity_at_theta(theta, orbitee),
+            OrbitKind::Spiral(spiral) => spiral.velocity_at_theta(theta, orbitee),
+        }
+    }
+
+    fn position_at_theta(&self, theta: Radian) -> Coords {
+        match self {
+            OrbitKind::Circle(circle) => circle.position_at_theta(theta),
+            OrbitKind::Ellipse(ellipse) => ellipse.position_at_theta(theta),
+            OrbitKind::Spiral(spiral) => spiral.position_at_theta(theta),
+        }
+    }
+
+    fn theta_in_period(&self, time: Duration, period: Duration) -> Radian {
+        match self {
+            OrbitKind::Circle(circle) => circle.theta_in_period(time, period),
+            OrbitKind::Ellipse(ellipse) => ellipse.theta_in_period(time, period),
+            OrbitKind::Spiral(spiral) => spiral.theta_in_period(time, period),
+        }
+    }
+
+    fn period(&self, orbitee: &Body) -> Duration {
+        match self {
+            OrbitKind::Circle(circle) => circle.period(orbitee),
+            OrbitKind::Ellipse(ellipse) => ellipse.period(orbitee),
+            OrbitKind::Spiral(spiral) => spiral.period(orbitee),
+        }
+    }
+
+    fn perimeter(&self) -> Distance {
+        match self {
+            OrbitKind::Circle(circle) => circle.perimeter(),
+            OrbitKind::Ellipse(ellipse) => ellipse.perimeter(),
+            OrbitKind::Spiral(spiral) => spiral.perimeter(),
+        }
+    }
+
+    fn focus(&self) -> Coords {
+        match self {
+            OrbitKind::Circle(circle) => circle.focus(),
+            OrbitKind::Ellipse(ellipse) => ellipse.focus(),
+            OrbitKind::Spiral(spiral) => spiral.focus(),
+        }
+    }
+
+    fn radius(&self) -> Distance {
+        match self {
+            OrbitKind::Circle(circle) => circle.radius(),
+            OrbitKind::Ellipse(ellipse) => ellipse.radius(),
+            OrbitKind::Spiral(spiral) => spiral.radius(),
+        }
+    }
+
+    fn is_clockwise(&self) -> bool {
+        match self {
+            OrbitKind::Circle(circle) => circle.is_clockwise(),
+            OrbitKind::Ellipse(ellipse) => ellipse.is_clockwise(),
+            OrbitKind::Spiral(spiral) => spiral.is_clockwise(),
+        }
+    }
+
+    fn eccentricity(&self) -> Ratio {
+        match self {
+            OrbitKind::Circle(circle) => circle.eccentricity(),
+            OrbitKind::Ellipse(ellipse) => ellipse.eccentricity(),
+            OrbitKind::Spiral(spiral) => spiral.eccentricity(),
+        }
+    }
+
+    fn is_closed(&self) -> bool {
+        match self {
+            OrbitKind::Circle(circle) => circle.is_closed(),
+            OrbitKind::Ellipse(ellipse) => ellipse.is_closed(),
+            OrbitKind::Spiral(spiral) => spiral.is_closed(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{str::FromStr, time::Duration};
+
+    use alvidir::name::Name;
+
+    use crate::{
+        cartesian::shape::{Circle, Ellipse},
+        fixtures::body,
+        orbit::{OrbitKind, OrbitalSystem, OrbitalSystemBuilder},
+        Distance, Luminosity, Orbit, Ratio,
+    };
+
+    #[cfg(feature = "json")]
+    use crate::orbit::ConfigFormat;
+
+    const ABS_ERROR: f64 = 0.000001;
+
+    #[test]
+    fn state_at_must_support_mixed_orbits() {
+        let ellipse = Ellipse::default()
+            .with_semi_major_axis(Distance::km(1_000.))
+            .with_eccentricity(Ratio::from(0.5));
+        let circle = Circle::default().with_radius(Distance::km(10.));
+
+        let system: OrbitalSystem<OrbitKind> =
+            OrbitalSystemBuilder::new(body("sun").with_luminosity(Luminosity::SUN))
+                .with_satellite(body("planet"), ellipse.into(), |satellites| {
+                    satellites.with_satellite(body("moon"), circle.into(), |satellites| satellites)
+                })
+                .build()
+                .unwrap();
+
+        let time = Duration::from_secs(3600);
+        let state = system.state_at(time);
+
+        let sun = body("sun");
+        let planet = state.state(&Name::from_str("planet").unwrap()).unwrap();
+        let want = ellipse.position_at(time, &sun) + ellipse.focus();
+        assert!(
+            planet.position.distance(&want) <= ABS_ERROR,
+            "got planet position = {:?}, want {:?}",
+            planet.position,
+            want
+        );
+
+        let moon = state.state(&Name::from_str("moon").unwrap()).unwrap();
+        let want = circle.position_at(time, &body("planet")) + planet.position;
+        assert!(
+            moon.position.distance(&want) <= ABS_ERROR,
+            "got moon position = {:?}, want {:?}",
+            moon.position,
+            want
+        );
+    }
+
+    #[test]
+    fn orbit_kind_must_be_tagged() {
+        let circle = OrbitKind::from(Circle::default().with_radius(Distance::km(10.)));
+        let json = serde_json::to_value(circle).unwrap();
+        assert_eq!(json["kind"], "circle");
+
+        let orbit: OrbitKind =
+            serde_json::from_str(r#"{"kind": "ellipse", "semi_major_axis": "1 AU"}"#).unwrap();
+        assert!(matches!(orbit, OrbitKind::Ellipse(_)));
+        assert_eq!(orbit.radius(), Distance::ASTRONOMICAL_UNIT);
+    }
+
+    #[test]
+    fn orbit_kind_must_default_to_ellipse() {
+        struct Test {
+            name: &'static str,
+            input: &'static str,
+            output: Option<OrbitKind>,
+        }
+
+        vec![
+            Test {
+                name: "untagged orbit must be an ellipse",
+                input: r#"{"semi_major_axis": "1 AU", "eccentricity": 0.5}"#,
+                output: Some(
+                    Ellipse::default()
+                        .with_semi_major_axis(Distance::ASTRONOMICAL_UNIT)
+                        .with_eccentricity(Ratio::from(0.5))
+                        .into(),
+                ),
+            },
+            Test {
+                name: "unknown kind must fail",
+                input: r#"{"kind": "parabola", "semi_major_axis": "1 AU"}"#,
+                output: None,
+            },
+            Test {
+                name: "invalid field of a tagged orbit must fail",
+                input: r#"{"kind": "circle", "radius": "1 lightsaber"}"#,
+                output: None,
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let output = serde_json::from_str::<OrbitKind>(test.input).ok();
+            assert_eq!(
+                output.map(|orbit| serde_json::to_value(orbit).unwrap()),
+                test.output
+                    .map(|orbit| serde_json::to_value(orbit).unwrap()),
+                "{}",
+                test.name
+            );
+        });
+    }
+
+    #[test]
+    #[cfg(feature = "json")]
+    fn from_reader_must_load_systems_without_orbit_kinds() {
+        // a file written before orbits were tagged with their kind.
+        let source = include_str!("../../tests/legacy/earth_moon.json");
+
+        let system =
+            OrbitalSystem::<OrbitKind>::from_reader(source.as_bytes(), ConfigFormat::Json).unwrap();
+
+        let moon = system.system(&Name::from_str("Moon").unwrap()).unwrap();
+        assert!(
+            matches!(moon.orbit, Some(OrbitKind::Ellipse(_))),
+            "got moon orbit = {:?}, want ellipse",
+            moon.orbit
+        );
+
+        let want = Ellipse::default()
+            .with_semi_major_axis(Distance::km(384_748.))
+            .with_eccentricity(Ratio::from(0.0549006));
+        assert_eq!(moon.orbit.unwrap().radius(), want.radius());
+    }
+
+    #[test]
+    #[cfg(all(feature = "json", feature = "toml", feature = "ron"))]
+    fn orbit_kind_must_round_trip_through_every_format() {
+        struct Test {
+            name: &'static str,
+            format: ConfigFormat,
+            serialize: fn(&OrbitalSystem<OrbitKind>) -> String,
+        }
+
+        let system: OrbitalSystem<OrbitKind> = OrbitalSystemBuilder::new(body("sun"))
+            .with_satellite(
+                body("planet"),
+                Circle::default().with_radius(Distance::km(10.)).into(),
+                |satellites| satellites,
+            )
+            .build()
+            .unwrap();
+
+        vec![
+            Test {
+                name: "json",
+                format: ConfigFormat::Json,
+                serialize: |system| serde_json::to_string(system).unwrap(),
+            },
+            Test {
+                name: "toml",
+                format: ConfigFormat::Toml,
+                serialize: |system| toml::to_string(system).unwrap(),
+            },
+            Test {
+                name: "ron",
+                format: ConfigFormat::Ron,
+                serialize: |system| ron::to_string(system).unwrap(),
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let source = (test.serialize)(&system);
+            let loaded: OrbitalSystem<OrbitKind> = test
+                .format
+                .deserialize(source.as_bytes())
+                .unwrap_or_else(|error| panic!("{}: {error}", test.name));
+
+            assert_eq!((test.serialize)(&loaded), source, "{}", test.name);
+        });
+    }
+}
@@ -0,0 +1,236 @@
+use std::{f64::consts::FRAC_PI_3, fmt::Display};
+
+use crate::{
+    cartesian::{transform::Rotation, Coords},
+    Radian,
+};
+
+use super::BodyPosition;
+
+/// How many iterations of the Newton's method are performed, at most, to locate the collinear
+/// Lagrange points.
+const MAX_ITERATIONS: usize = 32;
+/// The relative error at which the location of a collinear Lagrange point is considered exact.
+const TOLERANCE: f64 = 1e-12;
+
+/// One of the five points at which a body of negligible mass keeps its place relative to two
+/// massive bodies orbiting each other.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LagrangePoint {
+    /// Between both bodies.
+    L1,
+    /// Beyond the secondary body.
+    L2,
+    /// Beyond the primary body, opposite to the secondary one.
+    L3,
+    /// Leading the secondary body by 60 degrees along its orbit.
+    L4,
+    /// Trailing the secondary body by 60 degrees along its orbit.
+    L5,
+}
+
+impl Display for LagrangePoint {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{self:?}")
+    }
+}
+
+impl LagrangePoint {
+    /// All the Lagrange points, in order.
+    pub const ALL: [Self; 5] = [Self::L1, Self::L2, Self::L3, Self::L4, Self::L5];
+}
+
+/// Returns the position of every Lagrange point of the given secondary body orbiting the given
+/// primary one, in the direction given by clockwise.
+///
+/// The secondary body is assumed to be in a circular orbit in the XY plane at its current
+/// distance, which is accurate enough as long as its orbit is close to circular.
+pub fn lagrange_points(
+    primary: BodyPosition,
+    secondary: BodyPosition,
+    clockwise: bool,
+) -> [(LagrangePoint, Coords); 5] {
+    let total_mass = primary.body.mass.as_kg() + secondary.body.mass.as_kg();
+    let mass_ratio = if total_mass > 0. {
+        secondary.body.mass.as_kg() / total_mass
+    } else {
+        0.
+    };
+
+    let separation = secondary.position - primary.position;
+    // positions along the line joining both bodies, in units of their distance from the barycenter.
+    let along = |x: f64| primary.position + separation * (mass_ratio + x);
+
+    let hill = (mass_ratio / 3.).cbrt();
+    let (l1, l2, l3) = (
+        collinear_point(mass_ratio, 1. - mass_ratio - hill),
+        collinear_point(mass_ratio, 1. - mass_ratio + hill),
+        collinear_point(mass_ratio, -1. - 5. * mass_ratio / 12.),
+    );
+
+    // the triangular points make an equilateral triangle with both bodies.
+    let leading = if clockwise { -FRAC_PI_3 } else { FRAC_PI_3 };
+    let triangular = |theta: f64| {
+        primary.position
+            + separation.transform(
+                Rotation::default()
+                    .with_axis(Coords::default().with_z(1.))
+                    .with_theta(Radian::from(theta)),
+            )
+    };
+
+    [
+        (LagrangePoint::L1, along(l1)),
+        (LagrangePoint::L2, along(l2)),
+        (LagrangePoint::L3, along(l3)),
+        (LagrangePoint::L4, triangular(leading)),
+        (LagrangePoint::L5, triangular(-leading)),
+    ]
+}
+
+/// Returns the root of the equation of the collinear Lagrange points closest to the given guess,
+/// being the primary body at -μ and the secondary one at 1 - μ in a rotating frame of reference
+/// centered at the barycenter of both.
+fn collinear_point(mass_ratio: f64, guess: f64) -> f64 {
+    let (primary, secondary) = (-mass_ratio, 1. - mass_ratio);
+
+    // with no mass at all, the secondary body is the point itself.
+    if mass_ratio == 0. && guess > 0. {
+        return secondary;
+    }
+
+    // the gravitational pull of both bodies plus the centrifugal force, and its derivative.
+    let force = |x: f64| {
+        x - (1. - mass_ratio) * (x - primary) / (x - primary).abs().powi(3)
+            - mass_ratio * (x - secondary) / (x - secondary).abs().powi(3)
+    };
+
+    let derivative = |x: f64| {
+        1. + 2. * (1. - mass_ratio) / (x - primary).abs().powi(3)
+            + 2. * mass_ratio / (x - secondary).abs().powi(3)
+    };
+
+    let mut x = guess;
+    for _ in 0..MAX_ITERATIONS {
+        let step = force(x) / derivative(x);
+        if !step.is_finite() {
+            break;
+        }
+
+        x -= step;
+        if step.abs() <= TOLERANCE * x.abs().max(1.) {
+            break;
+        }
+    }
+
+    x
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use alvidir::name::Name;
+
+    use crate::{
+        cartesian::Coords, Body, BodyPosition, Distance, LagrangePoint, Mass,
+        GRAVITATIONAL_CONSTANT,
+    };
+
+    use super::lagrange_points;
+
+    #[test]
+    fn lagrange_points_must_balance_gravity_and_rotation() {
+        const REL_ERROR: f64 = 1e-6;
+
+        struct Test {
+            name: &'static str,
+            primary: f64,
+            secondary: f64,
+            distance: f64,
+            clockwise: bool,
+        }
+
+        vec![
+            Test {
+                name: "sun and earth",
+                primary: 1.989e30,
+                secondary: 5.972e24,
+                distance: Distance::ASTRONOMICAL_UNIT.as_meters(),
+                clockwise: false,
+            },
+            Test {
+                name: "earth and moon",
+                primary: 5.972e24,
+                secondary: 7.342e22,
+                distance: 3.844e8,
+                clockwise: false,
+            },
+            Test {
+                name: "equal masses orbiting clockwise",
+                primary: 1e24,
+                secondary: 1e24,
+                distance: 1e9,
+                clockwise: true,
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let primary =
+                Body::new(Name::from_str("primary").unwrap()).with_mass(Mass::kg(test.primary));
+            let secondary =
+                Body::new(Name::from_str("secondary").unwrap()).with_mass(Mass::kg(test.secondary));
+
+            // off the origin and rotated, so the points do not depend on the frame of reference.
+            let primary_position = Coords::from([3e8, -2e8, 0.]);
+            let secondary_position =
+                primary_position + Coords::from([0.6, 0.8, 0.]) * test.distance;
+
+            let points = lagrange_points(
+                BodyPosition {
+                    body: &primary,
+                    position: primary_position,
+                },
+                BodyPosition {
+                    body: &secondary,
+                    position: secondary_position,
+                },
+                test.clockwise,
+            );
+
+            let total_mass = test.primary + test.secondary;
+            let barycenter = (primary_position * test.primary
+                + secondary_position * test.secondary)
+                / total_mass;
+            let angular_velocity_squared =
+                GRAVITATIONAL_CONSTANT * total_mass / test.distance.powi(3);
+            let characteristic_acceleration = angular_velocity_squared * test.distance;
+
+            let pull = |mass: f64, from: Coords, at: Coords| {
+                let offset = from - at;
+                offset * (GRAVITATIONAL_CONSTANT * mass / offset.magnitude().powi(3))
+            };
+
+            points.into_iter().for_each(|(point, position)| {
+                let gravity = pull(test.primary, primary_position, position)
+                    + pull(test.secondary, secondary_position, position);
+                let centrifugal = (position - barycenter) * angular_velocity_squared;
+
+                // forces may cancel each other, as at the L1 point of equal masses.
+                let residual = (gravity + centrifugal).magnitude() / characteristic_acceleration;
+                assert!(
+                    residual <= REL_ERROR,
+                    "{}: {point} got a residual of {residual}",
+                    test.name,
+                );
+            });
+
+            // the leading point is ahead of the secondary body in the direction it orbits.
+            let (_, l4) = points[LagrangePoint::L4 as usize];
+            let ahead = (secondary_position - primary_position)
+                .cross(&(l4 - primary_position))
+                .z();
+            assert_eq!(ahead < 0., test.clockwise, "{}: L4 must lead", test.name);
+        });
+    }
+}
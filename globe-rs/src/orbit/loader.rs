@@ -0,0 +1,253 @@
+use std::{fmt::Display, io::Read, path::Path};
+
+use serde::de::DeserializeOwned;
+
+use crate::Orbit;
+
+use super::{OrbitalSystem, SystemError};
+
+/// The format in which an orbital system is described, each behind the feature of the same name.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConfigFormat {
+    #[cfg(feature = "json")]
+    Json,
+    #[cfg(feature = "toml")]
+    Toml,
+    #[cfg(feature = "ron")]
+    Ron,
+}
+
+impl ConfigFormat {
+    /// Returns the format corresponding to the extension of the given path, if any.
+    pub fn from_path(path: impl AsRef<Path>) -> Option<Self> {
+        match path.as_ref().extension()?.to_str()? {
+            #[cfg(feature = "json")]
+            "json" => Some(Self::Json),
+            #[cfg(feature = "toml")]
+            "toml" => Some(Self::Toml),
+            #[cfg(feature = "ron")]
+            "ron" => Some(Self::Ron),
+            _ => None,
+        }
+    }
+
+    /// Reads any deserializable value from the given source in self format, so other sections of
+    /// a configuration file can be read the same way the orbital system is.
+    #[cfg_attr(
+        not(any(feature = "json", feature = "toml", feature = "ron")),
+        allow(unused_variables)
+    )]
+    pub fn deserialize<T: DeserializeOwned>(&self, reader: impl Read) -> Result<T, LoadError> {
+        match *self {
+            #[cfg(feature = "json")]
+            ConfigFormat::Json => serde_json::from_reader(reader).map_err(LoadError::Json),
+            #[cfg(feature = "toml")]
+            ConfigFormat::Toml => {
+                let source = std::io::read_to_string(reader)?;
+                toml::from_str(&source).map_err(LoadError::Toml)
+            }
+            #[cfg(feature = "ron")]
+            ConfigFormat::Ron => ron::de::from_reader(reader).map_err(LoadError::Ron),
+        }
+    }
+}
+
+/// An error while loading an orbital system.
+#[derive(Debug)]
+pub enum LoadError {
+    /// The source could not be read.
+    Io(std::io::Error),
+    /// The source is not a valid JSON description of a system.
+    #[cfg(feature = "json")]
+    Json(serde_json::Error),
+    /// The source is not a valid TOML description of a system.
+    #[cfg(feature = "toml")]
+    Toml(toml::de::Error),
+    /// The source is not a valid RON description of a system.
+    #[cfg(feature = "ron")]
+    Ron(ron::error::SpannedError),
+    /// The described system is inconsistent.
+    Invalid(Vec<SystemError>),
+}
+
+impl Display for LoadError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            LoadError::Io(error) => write!(f, "{error}"),
+            #[cfg(feature = "json")]
+            LoadError::Json(error) => write!(f, "{error}"),
+            #[cfg(feature = "toml")]
+            LoadError::Toml(error) => write!(f, "{error}"),
+            #[cfg(feature = "ron")]
+            LoadError::Ron(error) => write!(f, "{error}"),
+            LoadError::Invalid(errors) => {
+                let errors: Vec<String> = errors.iter().map(ToString::to_string).collect();
+                write!(f, "{}", errors.join("; "))
+            }
+        }
+    }
+}
+
+impl std::error::Error for LoadError {}
+
+impl From<std::io::Error> for LoadError {
+    fn from(error: std::io::Error) -> Self {
+        Self::Io(error)
+    }
+}
+
+impl<O> OrbitalSystem<O>
+where
+    O: Orbit + Default + DeserializeOwned,
+{
+    /// Reads an orbital system from the given source in the given format, validating it.
+    ///
+    /// Physical quantities are given either as bare numbers, in kilometers, kilograms, watts or
+    /// seconds, or as strings followed by their unit (e.g. "1.5 AU", "1.9891e30 kg" or "27 d").
+    ///
+    /// ```
+    /// # #[cfg(feature = "toml")]
+    /// # {
+    /// use globe_rs::{cartesian::shape::Ellipse, ConfigFormat, OrbitalSystem};
+    ///
+    /// let toml = r#"
+    ///     [primary]
+    ///     name = "Sun"
+    ///     radius = "696340 km"
+    ///     spin = { period = "27 d", clockwise = false }
+    ///     mass = 1.9891e30
+    ///     luminosity = 3.828e26
+    ///
+    ///     [[secondary]]
+    ///     primary = { name = "Earth", radius = "6371 km", spin = { period = "24 h", clockwise = false }, mass = 5.97219e24, luminosity = 0 }
+    ///     orbit = { semi_major_axis = "1 AU", eccentricity = 0.017, initial_theta = 0, clockwise = false }
+    /// "#;
+    ///
+    /// let system = OrbitalSystem::<Ellipse>::from_reader(toml.as_bytes(), ConfigFormat::Toml);
+    /// assert!(system.is_ok());
+    /// # }
+    /// ```
+    pub fn from_reader(reader: impl Read, format: ConfigFormat) -> Result<Self, LoadError> {
+        let system: Self = format.deserialize(reader)?;
+
+        system.validate().map_err(LoadError::Invalid)?;
+        Ok(system)
+    }
+}
+
+#[cfg(all(test, feature = "json"))]
+mod tests {
+    use std::time::Duration;
+
+    use crate::{
+        cartesian::shape::Ellipse,
+        fixtures::body,
+        orbit::{ConfigFormat, LoadError, OrbitalSystem, OrbitalSystemBuilder, Rotation},
+        Distance, Luminosity, Mass, Ratio,
+    };
+
+    fn system() -> OrbitalSystem<Ellipse> {
+        let spin = Rotation {
+            period: Duration::from_secs(24 * 3600),
+            ..Default::default()
+        };
+
+        OrbitalSystemBuilder::new(
+            body("Sun")
+                .with_radius(Distance::km(696_340.))
+                .with_spin(spin.clone())
+                .with_mass(Mass::kg(1.9891e30))
+                .with_luminosity(Luminosity::SUN),
+        )
+        .with_satellite(
+            body("Earth")
+                .with_radius(Distance::km(6_371.))
+                .with_spin(spin.clone())
+                .with_mass(Mass::kg(5.97219e24)),
+            Ellipse::default()
+                .with_semi_major_axis(Distance::ASTRONOMICAL_UNIT)
+                .with_eccentricity(Ratio::from(0.017)),
+            |satellites| {
+                satellites.with_satellite(
+                    body("Moon")
+                        .with_radius(Distance::km(1_737.4))
+                        .with_spin(spin.clone())
+                        .with_mass(Mass::kg(7.34767309e22)),
+                    Ellipse::default()
+                        .with_semi_major_axis(Distance::km(384_748.))
+                        .with_eccentricity(Ratio::from(0.0549006)),
+                    |satellites| satellites,
+                )
+            },
+        )
+        .build()
+        .unwrap()
+    }
+
+    #[test]
+    #[cfg(all(feature = "toml", feature = "ron"))]
+    fn from_reader_must_round_trip() {
+        struct Test {
+            name: &'static str,
+            format: ConfigFormat,
+            serialize: fn(&OrbitalSystem<Ellipse>) -> String,
+        }
+
+        vec![
+            Test {
+                name: "json",
+                format: ConfigFormat::Json,
+                serialize: |system| serde_json::to_string(system).unwrap(),
+            },
+            Test {
+                name: "toml",
+                format: ConfigFormat::Toml,
+                serialize: |system| toml::to_string(system).unwrap(),
+            },
+            Test {
+                name: "ron",
+                format: ConfigFormat::Ron,
+                serialize: |system| ron::to_string(system).unwrap(),
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            // a semi-major axis with no exact representation in astronomical units.
+            let mut want = system();
+            want.secondary[0].orbit = want.secondary[0]
+                .orbit
+                .map(|orbit| orbit.with_semi_major_axis(Distance::km(120_000_000.)));
+
+            let source = (test.serialize)(&want);
+            let got = OrbitalSystem::<Ellipse>::from_reader(source.as_bytes(), test.format)
+                .unwrap_or_else(|error| panic!("{}: {error}", test.name));
+
+            let (got, want) = (format!("{got:?}"), format!("{want:?}"));
+            assert_eq!(got, want, "{}: got = {got}, want {want}", test.name);
+        });
+    }
+
+    #[test]
+    fn from_reader_must_load_bare_distances_in_kilometers() {
+        // a file written before quantities could be given with their units.
+        let source = include_str!("../../tests/legacy/earth_moon.json");
+
+        let loaded =
+            OrbitalSystem::<Ellipse>::from_reader(source.as_bytes(), ConfigFormat::Json).unwrap();
+
+        assert_eq!(format!("{loaded:?}"), format!("{:?}", system()));
+    }
+
+    #[test]
+    fn from_reader_must_validate_the_system() {
+        let mut system = system();
+        system.secondary[0].primary.name = system.primary.name.clone();
+
+        let source = serde_json::to_string(&system).unwrap();
+        let result = OrbitalSystem::<Ellipse>::from_reader(source.as_bytes(), ConfigFormat::Json);
+        assert!(
+            matches!(result, Err(LoadError::Invalid(_))),
+            "got {result:?}, want invalid"
+        );
+    }
+}
@@ -1,16 +1,40 @@
 use std::time::Duration;
 
 use crate::{
-    cartesian::{shape::Sample, Coords},
-    Distance, Radian, Velocity,
+    cartesian::{shape::Shape, Coords},
+    Distance, Radian, Ratio, Velocity,
 };
 
 mod body;
 pub use body::*;
 
+mod ephemeris;
+pub use ephemeris::*;
+
+mod error;
+pub use error::*;
+
+mod event;
+pub use event::*;
+
 mod hz;
 pub use hz::*;
 
+mod intersection;
+pub use intersection::*;
+
+mod kind;
+pub use kind::*;
+
+mod lagrange;
+pub use lagrange::*;
+
+mod loader;
+pub use loader::*;
+
+mod path;
+pub use path::*;
+
 mod state;
 pub use state::*;
 
@@ -24,7 +48,10 @@ pub use system::*;
 pub const GRAVITATIONAL_CONSTANT: f64 = 6.674010551359e-11;
 
 /// The orbit of an object around a central body.
-pub trait Orbit: Copy + Sample {
+///
+/// The trait is object safe, so systems whose orbits are of different types, or own heap data,
+/// can be described as an `OrbitalSystem<Box<dyn Orbit + Send + Sync>>`.
+pub trait Orbit {
     /// The minimum velocity of the object across the orbit.
     fn min_velocity(&self, orbitee: &Body) -> Velocity;
 
@@ -32,13 +59,29 @@ pub trait Orbit: Copy + Sample {
     fn max_velocity(&self, orbitee: &Body) -> Velocity;
 
     /// The orbital velocity of the object at ha given time.
-    fn velocity_at(&self, time: Duration, orbitee: &Body) -> Velocity;
+    fn velocity_at(&self, time: Duration, orbitee: &Body) -> Velocity {
+        self.velocity_at_theta(self.theta_at(time, orbitee), orbitee)
+    }
 
     /// Returns the position of the object at the given time.
-    fn position_at(&self, time: Duration, orbitee: &Body) -> Coords;
+    fn position_at(&self, time: Duration, orbitee: &Body) -> Coords {
+        self.position_at_theta(self.theta_at(time, orbitee))
+    }
 
     /// Returns the radiant of the orbit at which is located the object.
-    fn theta_at(&self, time: Duration, orbitee: &Body) -> Radian;
+    fn theta_at(&self, time: Duration, orbitee: &Body) -> Radian {
+        self.theta_in_period(time, self.period(orbitee))
+    }
+
+    /// The orbital velocity of the object when located at the given radiant of the orbit.
+    fn velocity_at_theta(&self, theta: Radian, orbitee: &Body) -> Velocity;
+
+    /// Returns the position of the object when located at the given radiant of the orbit.
+    fn position_at_theta(&self, theta: Radian) -> Coords;
+
+    /// Returns the radiant of the orbit at which is located the object at the given time, being
+    /// period the time it takes for the object to complete the orbit.
+    fn theta_in_period(&self, time: Duration, period: Duration) -> Radian;
 
     /// The orbit's period.
     fn period(&self, orbitee: &Body) -> Duration;
@@ -56,4 +99,115 @@ pub trait Orbit: Copy + Sample {
     /// Returns true if, and only if, the object is orbiting clockwise. Otheriwise
     /// returns false.
     fn is_clockwise(&self) -> bool;
+
+    /// Returns the eccentricity of the orbit, being zero for a perfect circle.
+    fn eccentricity(&self) -> Ratio;
+
+    /// Returns the position, relative to the orbit's center, of the point of the orbit closest to
+    /// the orbitee, if the orbit is closed.
+    fn periapsis(&self) -> Option<Coords> {
+        self.is_closed()
+            .then(|| self.position_at_theta(Radian::default()))
+    }
+
+    /// Returns the position, relative to the orbit's center, of the point of the orbit farthest
+    /// from the orbitee, if the orbit is closed.
+    fn apoapsis(&self) -> Option<Coords> {
+        self.is_closed()
+            .then(|| self.position_at_theta(Radian::TWO_PI / 2.))
+    }
+
+    /// Returns true if, and only if, the object gets back to where it started once the period is
+    /// completed, which is the case of any periodic orbit.
+    fn is_closed(&self) -> bool {
+        true
+    }
+
+    /// Samples the orbit as a discrete set of [Coords] by dividing its period into segments of
+    /// equal duration, so the distance between consecutive points is proportional to the velocity
+    /// of the object at each section of the orbit.
+    fn sample_by_time(&self, segments: usize, orbitee: &Body) -> Shape {
+        let period = self.period(orbitee);
+
+        Shape {
+            points: (0..segments)
+                .map(|vertex_index| period.mul_f64(vertex_index as f64 / segments as f64))
+                .map(|time| self.position_at(time, orbitee))
+                .collect(),
+        }
+    }
+}
+
+impl<T: Orbit + ?Sized> Orbit for Box<T> {
+    fn min_velocity(&self, orbitee: &Body) -> Velocity {
+        (**self).min_velocity(orbitee)
+    }
+
+    fn max_velocity(&self, orbitee: &Body) -> Velocity {
+        (**self).max_velocity(orbitee)
+    }
+
+    fn velocity_at(&self, time: Duration, orbitee: &Body) -> Velocity {
+        (**self).velocity_at(time, orbitee)
+    }
+
+    fn position_at(&self, time: Duration, orbitee: &Body) -> Coords {
+        (**self).position_at(time, orbitee)
+    }
+
+    fn theta_at(&self, time: Duration, orbitee: &Body) -> Radian {
+        (**self).theta_at(time, orbitee)
+    }
+
+    fn velocity_at_theta(&self, theta: Radian, orbitee: &Body) -> Velocity {
+        (**self).velocity_at_theta(theta, orbitee)
+    }
+
+    fn position_at_theta(&self, theta: Radian) -> Coords {
+        (**self).position_at_theta(theta)
+    }
+
+    fn theta_in_period(&self, time: Duration, period: Duration) -> Radian {
+        (**self).theta_in_period(time, period)
+    }
+
+    fn period(&self, orbitee: &Body) -> Duration {
+        (**self).period(orbitee)
+    }
+
+    fn perimeter(&self) -> Distance {
+        (**self).perimeter()
+    }
+
+    fn focus(&self) -> Coords {
+        (**self).focus()
+    }
+
+    fn radius(&self) -> Distance {
+        (**self).radius()
+    }
+
+    fn is_clockwise(&self) -> bool {
+        (**self).is_clockwise()
+    }
+
+    fn eccentricity(&self) -> Ratio {
+        (**self).eccentricity()
+    }
+
+    fn periapsis(&self) -> Option<Coords> {
+        (**self).periapsis()
+    }
+
+    fn apoapsis(&self) -> Option<Coords> {
+        (**self).apoapsis()
+    }
+
+    fn is_closed(&self) -> bool {
+        (**self).is_closed()
+    }
+
+    fn sample_by_time(&self, segments: usize, orbitee: &Body) -> Shape {
+        (**self).sample_by_time(segments, orbitee)
+    }
 }
@@ -0,0 +1,55 @@
+use std::{fmt::Display, ops::Deref};
+
+use alvidir::name::Name;
+use serde::{Deserialize, Serialize};
+
+use super::Body;
+
+/// The location of a body in an orbital system, given by the names of all the bodies from the
+/// primary one of the system down to the body itself.
+///
+/// Unlike a single name, a path tells apart bodies sharing the same name in different branches of
+/// the system.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct NamePath(Vec<Name<Body>>);
+
+impl From<Vec<Name<Body>>> for NamePath {
+    fn from(names: Vec<Name<Body>>) -> Self {
+        Self(names)
+    }
+}
+
+impl FromIterator<Name<Body>> for NamePath {
+    fn from_iter<T: IntoIterator<Item = Name<Body>>>(iter: T) -> Self {
+        Self(iter.into_iter().collect())
+    }
+}
+
+impl Deref for NamePath {
+    type Target = [Name<Body>];
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl Display for NamePath {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let names: Vec<String> = self.0.iter().map(ToString::to_string).collect();
+        write!(f, "{}", names.join("/"))
+    }
+}
+
+impl NamePath {
+    /// Returns the name of the body the path points to, if any.
+    pub fn body(&self) -> Option<&Name<Body>> {
+        self.0.last()
+    }
+
+    /// Returns the same path extended with the given name.
+    pub fn with(mut self, name: Name<Body>) -> Self {
+        self.0.push(name);
+        self
+    }
+}
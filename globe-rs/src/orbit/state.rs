@@ -1,13 +1,13 @@
-use std::time::Duration;
+use std::{collections::HashMap, time::Duration};
 
 use alvidir::name::Name;
 
 use crate::{
     cartesian::{transform::Translation, Coords},
-    Radian, Velocity,
+    Distance, Epoch, Radian, Velocity,
 };
 
-use super::{Body, Orbit, OrbitalSystem};
+use super::{Body, Orbit, OrbitalSystem, StateError};
 
 /// An union of the [Body] type and its [Cartesian] position.
 #[derive(Debug, Clone, Copy)]
@@ -18,8 +18,101 @@ pub struct BodyPosition<'a> {
     pub position: Coords,
 }
 
+/// The orbital periods of the bodies of a system, so they do not have to be computed every time the
+/// state of the system is requested.
+///
+/// Periods are stored in the same depth-first order in which the state of the system is computed,
+/// so no lookup is required to retrieve them.
+#[derive(Debug, Clone, Default)]
+pub struct PeriodCache(Vec<(Name<Body>, Option<Duration>)>);
+
+impl<O: Orbit> From<&OrbitalSystem<O>> for PeriodCache {
+    fn from(system: &OrbitalSystem<O>) -> Self {
+        Self(
+            system
+                .subsystems()
+                .map(|(system, parent)| {
+                    let period = parent
+                        .zip(system.orbit.as_ref())
+                        .map(|(parent, orbit)| orbit.period(&parent.primary));
+
+                    (system.primary.name.clone(), period)
+                })
+                .collect(),
+        )
+    }
+}
+
+impl PeriodCache {
+    /// Returns the orbital period of the body with the given name, if any.
+    pub fn period(&self, name: &Name<Body>) -> Option<Duration> {
+        self.0
+            .iter()
+            .find(|(body, _)| body == name)
+            .and_then(|(_, period)| *period)
+    }
+
+    /// Returns the orbital period at the given depth-first position, as long as it belongs to
+    /// the body with the given name.
+    fn period_at(&self, index: usize, name: &Name<Body>) -> Option<Duration> {
+        self.0
+            .get(index)
+            .filter(|(body, _)| body == name)
+            .and_then(|(_, period)| *period)
+    }
+}
+
+/// A moment in time relative to the origin of a system, which may be earlier than it.
+#[derive(Debug, Clone, Copy)]
+enum Moment {
+    After(Duration),
+    Before(Duration),
+}
+
+impl TryFrom<Epoch> for Moment {
+    type Error = StateError;
+
+    fn try_from(epoch: Epoch) -> Result<Self, Self::Error> {
+        let seconds = epoch.as_seconds();
+        if seconds < 0. {
+            return Duration::try_from_secs_f64(-seconds)
+                .map(Self::Before)
+                .map_err(|_| StateError::OutOfRange(epoch));
+        }
+
+        Duration::try_from_secs_f64(seconds)
+            .map(Self::After)
+            .map_err(|_| StateError::OutOfRange(epoch))
+    }
+}
+
+impl Moment {
+    /// Returns the non-negative time at which anything repeating with the given period is in the
+    /// same state as at self. Anything not repeating at all is assumed to remain in its initial
+    /// state before the origin.
+    fn in_cycle(self, period: Option<Duration>) -> Duration {
+        let time = match self {
+            Self::After(time) => return time,
+            Self::Before(time) => time,
+        };
+
+        let Some(period) = period.filter(|period| !period.is_zero()) else {
+            return Duration::ZERO;
+        };
+
+        // the remainder is computed in nanoseconds so no precision is lost for long times.
+        let remainder = time.as_nanos() % period.as_nanos();
+        let nanos_per_sec = Duration::from_secs(1).as_nanos();
+        period
+            - Duration::new(
+                (remainder / nanos_per_sec) as u64,
+                (remainder % nanos_per_sec) as u32,
+            )
+    }
+}
+
 /// The configuration of a [System] in a specific moment in time.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct OrbitalSystemState {
     /// The name of the ruling body.
     pub body: Name<Body>,
@@ -36,74 +129,192 @@ pub struct OrbitalSystemState {
 }
 
 impl OrbitalSystemState {
-    fn spin_at(mut time: Duration, body: &Body) -> Radian {
-        time = Duration::from_secs_f64(time.as_secs_f64() % body.spin.period.as_secs_f64());
+    fn spin_at(time: Duration, body: &Body) -> Radian {
+        // a body with no period of rotation does not spin at all, since its frequency is zero
+        let rotation = body.spin.frequency() * time;
+        if body.spin.clockwise {
+            return -rotation;
+        }
 
-        (Radian::from(body.spin.period).as_f64() * time.as_secs() as f64).into()
+        rotation
     }
 
-    fn position_at<O: Orbit>(
+    pub fn at<O: Orbit>(
         time: Duration,
         system: &OrbitalSystem<O>,
         parent: Option<BodyPosition>,
-    ) -> Coords {
-        let (Some(parent), Some(orbit)) = (parent, system.orbit) else {
-            return Default::default();
-        };
+    ) -> Self {
+        Self::at_with::<O>(Moment::After(time), system, parent, None, &mut 0)
+    }
 
-        orbit
-            .position_at(time, parent.body)
-            .transform(Translation::default().with_vector(parent.position))
-            .transform(Translation::default().with_vector(orbit.focus()))
+    /// Returns the state of the system at the given epoch, being [Epoch::J2000] the origin of
+    /// time.
+    ///
+    /// Epochs earlier than the origin are resolved by winding each orbit and spin back as many
+    /// periods as required, while the bodies in open orbits remain at the beginning of them.
+    ///
+    /// ## Panics
+    ///
+    /// If the epoch is NaN or too far from the origin of time to be represented as a [Duration].
+    /// See [OrbitalSystemState::try_at_epoch] for a non-panicking version of this method.
+    pub fn at_epoch<O: Orbit>(
+        epoch: Epoch,
+        system: &OrbitalSystem<O>,
+        parent: Option<BodyPosition>,
+    ) -> Self {
+        let time = Moment::try_from(epoch).expect("the epoch must be representable");
+        Self::at_with::<O>(time, system, parent, None, &mut 0)
     }
 
-    fn theta_at<O: Orbit>(
+    /// Returns the state of the system at the given time, taking the orbital periods from the
+    /// given cache instead of computing them again.
+    pub fn at_cached<O: Orbit>(
         time: Duration,
         system: &OrbitalSystem<O>,
         parent: Option<BodyPosition>,
-    ) -> Radian {
-        let (Some(parent), Some(orbit)) = (parent, system.orbit) else {
-            return Default::default();
-        };
-
-        orbit.theta_at(time, parent.body)
+        periods: &PeriodCache,
+    ) -> Self {
+        Self::at_with::<O>(Moment::After(time), system, parent, Some(periods), &mut 0)
     }
 
-    fn velocity_at<O: Orbit>(
+    /// Returns the state of the system at the given time, or the error of the first body for which
+    /// the state cannot be computed.
+    pub fn try_at<O: Orbit>(
         time: Duration,
         system: &OrbitalSystem<O>,
         parent: Option<BodyPosition>,
-    ) -> Velocity {
-        let (Some(parent), Some(orbit)) = (parent, system.orbit) else {
-            return Default::default();
+        periods: Option<&PeriodCache>,
+    ) -> Result<Self, StateError> {
+        Self::try_at_moment::<O>(Moment::After(time), system, parent, periods)
+    }
+
+    /// Same as [OrbitalSystemState::try_at], but at the given epoch as described in
+    /// [OrbitalSystemState::at_epoch].
+    pub fn try_at_epoch<O: Orbit>(
+        epoch: Epoch,
+        system: &OrbitalSystem<O>,
+        parent: Option<BodyPosition>,
+        periods: Option<&PeriodCache>,
+    ) -> Result<Self, StateError> {
+        Self::try_at_moment::<O>(epoch.try_into()?, system, parent, periods)
+    }
+
+    fn try_at_moment<O: Orbit>(
+        time: Moment,
+        system: &OrbitalSystem<O>,
+        parent: Option<BodyPosition>,
+        periods: Option<&PeriodCache>,
+    ) -> Result<Self, StateError> {
+        if let Some(parent) = parent {
+            Self::check_orbit(system, parent.body)?;
+        }
+
+        system
+            .subsystems()
+            .filter_map(|(system, parent)| parent.map(|parent| (system, &parent.primary)))
+            .try_for_each(|(system, orbitee)| Self::check_orbit(system, orbitee))?;
+
+        let state = Self::at_with::<O>(time, system, parent, periods, &mut 0);
+        state
+            .iter()
+            .try_for_each(|(state, _)| match state.non_finite_field() {
+                Some(field) => Err(StateError::NonFinite {
+                    body: state.body.clone(),
+                    field,
+                }),
+                None => Ok(()),
+            })?;
+
+        Ok(state)
+    }
+
+    /// Fails if the orbit of the given system around the given body is either unbound or has an
+    /// undefined period.
+    fn check_orbit<O: Orbit>(system: &OrbitalSystem<O>, orbitee: &Body) -> Result<(), StateError> {
+        let Some(orbit) = &system.orbit else {
+            return Ok(());
         };
 
-        orbit.velocity_at(time, parent.body)
+        if orbit.eccentricity().as_f64() >= 1. {
+            return Err(StateError::UnboundOrbit(system.primary.name.clone()));
+        }
+
+        // the period grows with the cube of the orbit, which must neither overflow nor vanish.
+        let parameter = orbitee.gravitational_parameter();
+        let radius = orbit.radius().as_meters();
+        let cube = radius.powi(3) / parameter;
+        if parameter.is_finite() && parameter > 0. && cube.is_finite() && cube > 0. {
+            return Ok(());
+        }
+
+        Err(StateError::UndefinedPeriod {
+            body: system.primary.name.clone(),
+            orbitee: orbitee.name.clone(),
+        })
     }
 
-    pub fn at<O: Orbit>(
-        time: Duration,
+    /// Returns the name of the first field of the state holding an infinite or NaN value, if any.
+    fn non_finite_field(&self) -> Option<&'static str> {
+        [
+            ("rotation", self.rotation.as_f64()),
+            ("position", self.position.x()),
+            ("position", self.position.y()),
+            ("position", self.position.z()),
+            ("theta", self.theta.as_f64()),
+            ("velocity", self.velocity.as_meters_sec()),
+        ]
+        .into_iter()
+        .find(|(_, value)| !value.is_finite())
+        .map(|(field, _)| field)
+    }
+
+    /// Returns true if, and only if, every field of the state and its secondary states is finite.
+    pub(crate) fn is_finite(&self) -> bool {
+        self.iter()
+            .all(|(state, _)| state.non_finite_field().is_none())
+    }
+
+    fn at_with<O: Orbit>(
+        time: Moment,
         system: &OrbitalSystem<O>,
         parent: Option<BodyPosition>,
+        periods: Option<&PeriodCache>,
+        index: &mut usize,
     ) -> Self {
         let mut state = OrbitalSystemState {
             body: system.primary.name.clone(),
-            rotation: Self::spin_at(time, &system.primary),
-            position: Self::position_at::<O>(time, system, parent),
-            theta: Self::theta_at::<O>(time, system, parent),
-            velocity: Self::velocity_at::<O>(time, system, parent),
+            rotation: Self::spin_at(time.in_cycle(system.primary.spin.period()), &system.primary),
+            position: Default::default(),
+            theta: Default::default(),
+            velocity: Default::default(),
             secondary: Default::default(),
         };
 
+        if let (Some(parent), Some(orbit)) = (parent, &system.orbit) {
+            let period = periods
+                .and_then(|periods| periods.period_at(*index, &system.primary.name))
+                .unwrap_or_else(|| orbit.period(parent.body));
+
+            // the theta is computed once, since both the position and velocity depend on it.
+            let time = time.in_cycle(orbit.is_closed().then_some(period));
+            state.theta = orbit.theta_in_period(time, period);
+            state.velocity = orbit.velocity_at_theta(state.theta, parent.body);
+            state.position = orbit
+                .position_at_theta(state.theta)
+                .transform(Translation::default().with_vector(parent.position))
+                .transform(Translation::default().with_vector(orbit.focus()));
+        }
+
         let parent = BodyPosition {
             body: &system.primary,
             position: state.position,
         };
 
+        *index += 1;
         state.secondary = system
             .secondary
             .iter()
-            .map(|system| Self::at::<O>(time, system, Some(parent)))
+            .map(|system| Self::at_with::<O>(time, system, Some(parent), periods, index))
             .collect();
 
         state
@@ -111,13 +322,181 @@ impl OrbitalSystemState {
 
     /// Returns the state of the system for which the primary body has the given name.
     pub fn state<'a>(&'a self, name: &Name<Body>) -> Option<&'a OrbitalSystemState> {
-        if &self.body == name {
-            return Some(self);
+        self.iter()
+            .map(|(state, _)| state)
+            .find(|state| &state.body == name)
+    }
+
+    /// Returns the position of the body with the given name, if any.
+    pub fn position_of(&self, name: &Name<Body>) -> Option<Coords> {
+        self.state(name).map(|state| state.position)
+    }
+
+    /// Returns the position of every body in the system, including the primary one, indexed by
+    /// name.
+    ///
+    /// Since positions are absolute, the map saves walking the tree on every lookup. If the same
+    /// name is used by more than one body, the last of them in depth-first order prevails.
+    pub fn positions(&self) -> HashMap<Name<Body>, Coords> {
+        self.iter()
+            .map(|(state, _)| (state.body.clone(), state.position))
+            .collect()
+    }
+
+    /// Returns the state of the system which primary body is located at the given path, starting
+    /// from the primary body of self.
+    pub fn state_by_path<'a>(&'a self, path: &[Name<Body>]) -> Option<&'a OrbitalSystemState> {
+        let (name, path) = path.split_first()?;
+        if &self.body != name {
+            return None;
         }
 
+        path.iter().try_fold(self, |state, name| {
+            state.secondary.iter().find(|state| &state.body == name)
+        })
+    }
+
+    /// Returns the state of the system around which orbits the body with the given name, if any.
+    pub fn parent_state<'a>(&'a self, name: &Name<Body>) -> Option<&'a OrbitalSystemState> {
+        self.iter()
+            .map(|(state, _)| state)
+            .find(|state| state.secondary.iter().any(|state| &state.body == name))
+    }
+
+    /// Returns a depth-first iterator over the states of the system, including itself, yielding
+    /// each of them along with its depth, being zero the depth of self.
+    pub fn iter(&self) -> impl Iterator<Item = (&OrbitalSystemState, usize)> {
+        let mut stack = vec![(self, 0)];
+        std::iter::from_fn(move || {
+            let (state, depth) = stack.pop()?;
+            stack.extend(
+                state
+                    .secondary
+                    .iter()
+                    .rev()
+                    .map(|subsystem| (subsystem, depth + 1)),
+            );
+
+            Some((state, depth))
+        })
+    }
+
+    /// Returns a copy of self in which every position is relative to the body with the given
+    /// name, or an exact copy of self if there is no such body.
+    ///
+    /// Positions far from the origin of coordinates lose most of their precision once converted
+    /// into single precision floats, as renderers do. Rebasing the state onto the body of interest
+    /// (also known as floating origin) keeps the precision where it matters.
+    pub fn positions_relative_to(&self, origin: &Name<Body>) -> OrbitalSystemState {
+        let mut state = self.clone();
+        if let Some(offset) = self.state(origin).map(|state| state.position) {
+            state.rebase(offset);
+        }
+
+        state
+    }
+
+    /// Moves the positions of self and all its secondary states by the opposite of the given
+    /// offset.
+    fn rebase(&mut self, offset: Coords) {
+        self.position = self.position - offset;
         self.secondary
+            .iter_mut()
+            .for_each(|state| state.rebase(offset));
+    }
+}
+
+/// The differences between two states of the same orbital system, matching bodies by name.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct StateDiff {
+    /// The bodies in the latest state that are not in the previous one.
+    pub created: Vec<Name<Body>>,
+    /// The bodies in the previous state that are not in the latest one.
+    pub removed: Vec<Name<Body>>,
+    /// The bodies that moved further than the threshold, along with their displacement.
+    pub moved: Vec<(Name<Body>, Coords)>,
+}
+
+impl StateDiff {
+    /// Returns true if, and only if, there are no differences between the states.
+    pub fn is_empty(&self) -> bool {
+        self.created.is_empty() && self.removed.is_empty() && self.moved.is_empty()
+    }
+}
+
+impl OrbitalSystemState {
+    /// Returns the differences between the given previous state and self, considering a body as
+    /// moved only if its displacement is greater than the given epsilon.
+    pub fn diff(&self, previous: &Self, position_epsilon: Distance) -> StateDiff {
+        let mut previous_by_name: HashMap<&Name<Body>, &OrbitalSystemState> = previous
+            .iter()
+            .map(|(state, _)| (&state.body, state))
+            .collect();
+
+        let mut diff = StateDiff::default();
+        self.iter()
+            .for_each(|(state, _)| match previous_by_name.remove(&state.body) {
+                Some(previous) => {
+                    let displacement = state.position - previous.position;
+                    if displacement.magnitude() > position_epsilon.as_meters() {
+                        diff.moved.push((state.body.clone(), displacement));
+                    }
+                }
+                None => diff.created.push(state.body.clone()),
+            });
+
+        // iterating the previous state again keeps the removed bodies in depth-first order.
+        diff.removed = previous
+            .iter()
+            .filter(|(state, _)| previous_by_name.contains_key(&state.body))
+            .map(|(state, _)| state.body.clone())
+            .collect();
+
+        diff
+    }
+
+    /// Returns the state at the given fraction of the way from self to the given next state,
+    /// matching bodies by name.
+    ///
+    /// Positions and velocities are interpolated linearly, while angles follow the shortest
+    /// rotation between them. Bodies missing in the next state remain as they are in self.
+    pub fn interpolate(&self, next: &Self, t: f64) -> Self {
+        let next_by_name: HashMap<&Name<Body>, &OrbitalSystemState> =
+            next.iter().map(|(state, _)| (&state.body, state)).collect();
+
+        self.interpolate_with(&next_by_name, t)
+    }
+
+    fn interpolate_with(
+        &self,
+        next_by_name: &HashMap<&Name<Body>, &OrbitalSystemState>,
+        t: f64,
+    ) -> Self {
+        let secondary = self
+            .secondary
             .iter()
-            .find_map(|state: &OrbitalSystemState| state.state(name))
+            .map(|state| state.interpolate_with(next_by_name, t))
+            .collect();
+
+        let Some(next) = next_by_name.get(&self.body) else {
+            return OrbitalSystemState {
+                body: self.body.clone(),
+                secondary,
+                ..*self
+            };
+        };
+
+        OrbitalSystemState {
+            body: self.body.clone(),
+            rotation: self.rotation.lerp_angle(next.rotation, t),
+            position: self.position.lerp(next.position, t),
+            theta: self.theta.lerp_angle(next.theta, t),
+            velocity: Velocity::meters_sec(
+                self.velocity.as_meters_sec()
+                    + (next.velocity.as_meters_sec() - self.velocity.as_meters_sec()) * t,
+            ),
+            secondary,
+        }
     }
 }
 
@@ -127,8 +506,14 @@ pub struct OrbitalSystemStateGenerator<'a, O: Orbit> {
     pub system: &'a OrbitalSystem<O>,
     /// The time-step between generations.
     pub step: Duration,
-    /// The latest generation time.
+    /// The time at which the generation begins, and to which it rewinds.
+    pub start: Duration,
+    /// The time of the next generation.
     pub time: Duration,
+    /// The orbital periods of the system being iterated.
+    pub periods: PeriodCache,
+    /// The time of the latest generation, if any.
+    last: Option<Duration>,
 }
 
 impl<'a, O: Orbit> From<&'a OrbitalSystem<O>> for OrbitalSystemStateGenerator<'a, O> {
@@ -136,7 +521,10 @@ impl<'a, O: Orbit> From<&'a OrbitalSystem<O>> for OrbitalSystemStateGenerator<'a
         Self {
             system,
             step: Duration::from_secs(1),
+            start: Duration::ZERO,
             time: Duration::ZERO,
+            periods: PeriodCache::from(system),
+            last: None,
         }
     }
 }
@@ -145,9 +533,7 @@ impl<'a, O: Orbit> Iterator for OrbitalSystemStateGenerator<'a, O> {
     type Item = OrbitalSystemState;
 
     fn next(&mut self) -> Option<Self::Item> {
-        let state = self.system.state_at(self.time);
-        self.time += self.step;
-        Some(state)
+        Some(self.generate())
     }
 }
 
@@ -156,4 +542,96 @@ impl<'a, O: Orbit> OrbitalSystemStateGenerator<'a, O> {
         self.step = step;
         self
     }
+
+    /// Makes the generation begin at the given time.
+    pub fn with_start(mut self, start: Duration) -> Self {
+        self.start = start;
+        self.time = start;
+        self
+    }
+
+    /// Makes the next generation to be the state of the system at the given time.
+    pub fn seek(&mut self, to: Duration) {
+        self.time = to;
+    }
+
+    /// Makes the next generation to be the state of the system at the start time.
+    pub fn rewind(&mut self) {
+        self.seek(self.start);
+    }
+
+    /// Returns the time of the latest yielded state, if any.
+    pub fn last_time(&self) -> Option<Duration> {
+        self.last
+    }
+
+    /// Returns an iterator over the states the generator would yield before reaching the given
+    /// time.
+    pub fn take_until(
+        mut self,
+        until: Duration,
+    ) -> impl ExactSizeIterator<Item = OrbitalSystemState> + 'a {
+        (0..self.count_until(until)).map(move |_| self.generate())
+    }
+
+    /// Returns the state of the system at the time of the next generation, and advances it by one
+    /// step.
+    fn generate(&mut self) -> OrbitalSystemState {
+        let state = self.system.state_at_cached(self.time, &self.periods);
+        self.last = Some(self.time);
+        self.time += self.step;
+        state
+    }
+
+    /// Returns the state of the system at each of the given times, in the same order.
+    pub fn batch(&self, times: &[Duration]) -> Vec<OrbitalSystemState> {
+        times
+            .iter()
+            .map(|time| self.system.state_at_cached(*time, &self.periods))
+            .collect()
+    }
+
+    /// Returns the amount of states the generator would yield before reaching the given time.
+    fn count_until(&self, until: Duration) -> usize {
+        let Some(span) = until.checked_sub(self.time).filter(|span| !span.is_zero()) else {
+            return 0;
+        };
+
+        if self.step.is_zero() {
+            // a generator with no step would yield the same state forever
+            return 1;
+        }
+
+        span.as_nanos().div_ceil(self.step.as_nanos()) as usize
+    }
+
+    /// Returns the times the generator would yield a state for before reaching the given one.
+    pub(crate) fn times_until(&self, until: Duration) -> Vec<Duration> {
+        let times = std::iter::successors(Some(self.time), |time| time.checked_add(self.step))
+            .take_while(|time| time < &until);
+
+        if self.step.is_zero() {
+            // a generator with no step would yield the same state forever
+            return times.take(1).collect();
+        }
+
+        times.collect()
+    }
+}
+
+#[cfg(feature = "rayon")]
+impl<'a, O: Orbit + Sync> OrbitalSystemStateGenerator<'a, O> {
+    /// Returns the same states the generator would yield before reaching the given time, splitting
+    /// the time range across threads.
+    pub fn par_collect(self, until: Duration) -> Vec<OrbitalSystemState> {
+        use rayon::prelude::*;
+
+        let times = self.times_until(until);
+        let chunk_size = times.len().div_ceil(rayon::current_num_threads()).max(1);
+
+        times
+            .par_chunks(chunk_size)
+            .flat_map_iter(|times| self.batch(times))
+            .collect()
+    }
 }
@@ -1,8 +1,8 @@
-use std::time::Duration;
+use std::{collections::HashMap, time::Duration};
 
 use alvidir::name::Name;
 
-use crate::{Distance, Velocity};
+use crate::{cartesian::Coords, Distance, Velocity};
 
 use super::{Body, HabitableZone, Orbit, OrbitalSystem};
 
@@ -22,54 +22,84 @@ pub struct SystemStats {
     pub radius: Distance,
     /// The perimeter of the orbit.
     pub perimeter: Distance,
-    /// The time it takes for the system to complete its orbit.
-    pub orbital_period: Duration,
+    /// The time it takes for the system to complete its orbit, if it orbits any other.
+    pub orbital_period: Option<Duration>,
     /// The synodic periods of the system relative to its major systems.
     pub synodic_periods: Vec<SynodicPeriod>,
-    /// The minimum velocity at which the system orbits.
-    pub min_velocity: Velocity,
-    /// The maximum velocity at which the system orbits.
-    pub max_velocity: Velocity,
-    /// The habitable zone of the system, if any.
-    pub habitable_zone: HabitableZone,
+    /// The minimum velocity at which the system orbits, if it orbits any other.
+    pub min_velocity: Option<Velocity>,
+    /// The maximum velocity at which the system orbits, if it orbits any other.
+    pub max_velocity: Option<Velocity>,
+    /// The distance from the orbitee to the closest point of the orbit, if it is closed.
+    pub periapsis: Option<Distance>,
+    /// The distance from the orbitee to the farthest point of the orbit, if it is closed.
+    pub apoapsis: Option<Distance>,
+    /// The habitable zone of the system, if its ruling body is luminous.
+    pub habitable_zone: Option<HabitableZone>,
     /// The descriptor of the systems orbiting in this one.
     pub secondary: Vec<SystemStats>,
 }
 
 impl<O: Orbit> From<&OrbitalSystem<O>> for SystemStats {
     fn from(system: &OrbitalSystem<O>) -> Self {
-        SystemStats::new(system, None)
+        // secondaries are keyed by the address of the system they orbit, since names are not
+        // guaranteed to be unique until the system is validated.
+        let mut secondaries: HashMap<*const OrbitalSystem<O>, Vec<SystemStats>> = HashMap::new();
+        let subsystems: Vec<_> = system.subsystems().collect();
+
+        // visiting the subsystems in reverse order guarantees the stats of all the secondary
+        // systems are computed before the ones of the system they orbit.
+        let mut root = None;
+        subsystems
+            .into_iter()
+            .rev()
+            .for_each(|(subsystem, orbitee)| {
+                let mut stats = SystemStats::new(subsystem, orbitee);
+                stats.secondary = secondaries
+                    .remove(&std::ptr::from_ref(subsystem))
+                    .unwrap_or_default();
+                stats.secondary.reverse();
+
+                match orbitee {
+                    Some(orbitee) => secondaries
+                        .entry(std::ptr::from_ref(orbitee))
+                        .or_default()
+                        .push(stats),
+                    None => root = Some(stats),
+                }
+            });
+
+        root.expect("the system itself must always be visited")
     }
 }
 
 impl SystemStats {
+    /// Returns the stats of the given system, being orbitee the system it orbits, if any.
     fn new<O: Orbit>(system: &OrbitalSystem<O>, orbitee: Option<&OrbitalSystem<O>>) -> Self {
+        let orbiting = orbitee.zip(system.orbit.as_ref());
+
         Self {
             body: system.primary.name.clone(),
-            radius: system.orbit.map(|orbit| orbit.radius()).unwrap_or_default(),
+            radius: system
+                .orbit
+                .as_ref()
+                .map(|orbit| orbit.radius())
+                .unwrap_or_default(),
             perimeter: system
                 .orbit
+                .as_ref()
                 .map(|orbit| orbit.perimeter())
                 .unwrap_or_default(),
-            orbital_period: orbitee
-                .zip(system.orbit)
-                .map(|(orbitee, orbit)| orbit.period(&orbitee.primary))
-                .unwrap_or_default(),
+            orbital_period: orbiting.map(|(orbitee, orbit)| orbit.period(&orbitee.primary)),
             synodic_periods: Default::default(),
-            min_velocity: orbitee
-                .zip(system.orbit)
-                .map(|(orbitee, orbit)| orbit.min_velocity(&orbitee.primary))
-                .unwrap_or_default(),
-            max_velocity: orbitee
-                .zip(system.orbit)
-                .map(|(orbitee, orbit)| orbit.max_velocity(&orbitee.primary))
-                .unwrap_or_default(),
-            habitable_zone: HabitableZone::from(&system.primary),
-            secondary: system
-                .secondary
-                .iter()
-                .map(|subsystem| SystemStats::new(subsystem, Some(system)))
-                .collect(),
+            min_velocity: orbiting.map(|(orbitee, orbit)| orbit.min_velocity(&orbitee.primary)),
+            max_velocity: orbiting.map(|(orbitee, orbit)| orbit.max_velocity(&orbitee.primary)),
+            periapsis: orbiting
+                .and_then(|(_, orbit)| orbit.periapsis().map(|apsis| apsis_distance(orbit, apsis))),
+            apoapsis: orbiting
+                .and_then(|(_, orbit)| orbit.apoapsis().map(|apsis| apsis_distance(orbit, apsis))),
+            habitable_zone: HabitableZone::try_from(&system.primary).ok(),
+            secondary: Default::default(),
         }
     }
 
@@ -81,4 +111,152 @@ impl SystemStats {
 
         self.secondary.iter().find_map(|system| system.stats(name))
     }
+
+    /// Returns the stats of the body located at the given path, starting from the body of self.
+    pub fn stats_by_path<'a>(&'a self, path: &[Name<Body>]) -> Option<&'a SystemStats> {
+        let (name, path) = path.split_first()?;
+        if &self.body != name {
+            return None;
+        }
+
+        path.iter().try_fold(self, |stats, name| {
+            stats.secondary.iter().find(|stats| &stats.body == name)
+        })
+    }
+}
+
+/// Returns the distance from the orbitee to the given apsis of the given orbit.
+fn apsis_distance<O: Orbit>(orbit: &O, apsis: Coords) -> Distance {
+    // positions relative to the orbitee are the ones relative to the center translated by the focus.
+    Distance::meters((apsis + orbit.focus()).magnitude())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use alvidir::name::Name;
+
+    use crate::{
+        cartesian::shape::Circle, fixtures::body, tests::approx_eq, Distance, Luminosity, Mass,
+        OrbitalSystem, OrbitalSystemBuilder, SystemStats,
+    };
+
+    fn circle(radius: f64) -> Circle {
+        Circle::default().with_radius(Distance::meters(radius))
+    }
+
+    /// A station orbiting a moon orbiting a planet orbiting a star.
+    fn system() -> OrbitalSystem<Circle> {
+        OrbitalSystemBuilder::new(
+            body("star")
+                .with_mass(Mass::kg(2e30))
+                .with_luminosity(Luminosity::SUN),
+        )
+        .with_satellite(
+            body("planet").with_mass(Mass::kg(6e24)),
+            circle(1.5e11),
+            |planet| {
+                planet.with_satellite(
+                    body("moon").with_mass(Mass::kg(7e22)),
+                    circle(4e8),
+                    |moon| {
+                        moon.with_satellite(
+                            body("station").with_mass(Mass::kg(1e3)),
+                            circle(2e6),
+                            |station| station,
+                        )
+                    },
+                )
+            },
+        )
+        .build()
+        .unwrap()
+    }
+
+    #[test]
+    fn stats_must_be_relative_to_the_orbitee() {
+        const REL_ERROR: f64 = 1e-9;
+
+        struct Test {
+            name: &'static str,
+            body: &'static str,
+            /// 2π√(r³/GM), in seconds.
+            orbital_period: Option<f64>,
+            /// √(GM/r), in meters per second.
+            velocity: Option<f64>,
+        }
+
+        let stats = SystemStats::from(&system());
+
+        vec![
+            Test {
+                name: "the root must not orbit anything",
+                body: "star",
+                orbital_period: None,
+                velocity: None,
+            },
+            Test {
+                name: "planet orbiting the star",
+                body: "planet",
+                orbital_period: Some(31_594_269.229_177_97),
+                velocity: Some(29_830.656_605_487_81),
+            },
+            Test {
+                name: "moon orbiting the planet",
+                body: "moon",
+                orbital_period: Some(2_511_890.975_853_22),
+                velocity: Some(1_000.550_639_749_858),
+            },
+            Test {
+                name: "station orbiting the moon",
+                body: "station",
+                orbital_period: Some(8_222.093_238_802_767),
+                velocity: Some(1_528.366_347_763_405_4),
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let stats = stats.stats(&Name::from_str(test.body).unwrap()).unwrap();
+
+            let orbital_period = stats.orbital_period.map(|period| period.as_secs_f64());
+            match (orbital_period, test.orbital_period) {
+                (Some(got), Some(want)) => assert!(
+                    approx_eq(got, want, want * REL_ERROR),
+                    "{}: got orbital period = {got}, want {want}",
+                    test.name
+                ),
+                (got, want) => assert_eq!(got, want, "{}: orbital period", test.name),
+            }
+
+            [stats.min_velocity, stats.max_velocity]
+                .into_iter()
+                .map(|velocity| velocity.map(|velocity| velocity.as_meters_sec()))
+                .for_each(|velocity| match (velocity, test.velocity) {
+                    (Some(got), Some(want)) => assert!(
+                        approx_eq(got, want, want * REL_ERROR),
+                        "{}: got velocity = {got}, want {want}",
+                        test.name
+                    ),
+                    (got, want) => assert_eq!(got, want, "{}: velocity", test.name),
+                });
+        });
+    }
+
+    #[test]
+    fn habitable_zone_must_surround_luminous_bodies_only() {
+        let stats = SystemStats::from(&system());
+
+        ["star", "planet", "moon", "station"]
+            .into_iter()
+            .for_each(|name| {
+                let stats = stats.stats(&Name::from_str(name).unwrap()).unwrap();
+                assert_eq!(
+                    stats.habitable_zone.is_some(),
+                    name == "star",
+                    "{name}: got habitable zone = {:?}",
+                    stats.habitable_zone
+                );
+            });
+    }
 }
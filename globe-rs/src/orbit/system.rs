@@ -1,11 +1,18 @@
-use std::time::Duration;
+use std::{collections::HashSet, ops::Range, time::Duration};
 
 use alvidir::name::Name;
 use serde::{Deserialize, Serialize};
 
-use crate::{Distance, Orbit};
+use crate::{
+    cartesian::{
+        shape::Shape,
+        transform::{Rotation, Scaling},
+        Coords,
+    },
+    geographic, Distance, Epoch, Mass, Orbit, Radian,
+};
 
-use super::{Body, OrbitalSystemState};
+use super::{Body, EditError, NamePath, OrbitalSystemState, PeriodCache, StateError, SystemError};
 
 /// An orbital system.
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -16,33 +23,2443 @@ pub struct OrbitalSystem<O> {
     #[serde(default)]
     pub orbit: Option<O>,
     /// The systems orbiting the primary body.
+    #[serde(default)]
     pub secondary: Vec<OrbitalSystem<O>>,
 }
 
 impl<O: Orbit> OrbitalSystem<O> {
     /// Returns the state of the system in a given moment in time.
+    ///
+    /// See [OrbitalSystem::try_state_at] for a version of this method detecting states holding
+    /// infinite or NaN values.
     pub fn state_at(&self, time: Duration) -> OrbitalSystemState {
-        OrbitalSystemState::at::<O>(time, self, None)
+        let state = OrbitalSystemState::at::<O>(time, self, None);
+        debug_assert!(state.is_finite(), "the state of the system must be finite");
+        state
+    }
+
+    /// Returns the state of the system at the given epoch, being [Epoch::J2000] the moment in time
+    /// every system starts from.
+    ///
+    /// See [OrbitalSystemState::at_epoch] for how epochs earlier than J2000 are resolved.
+    pub fn state_at_epoch(&self, epoch: Epoch) -> OrbitalSystemState {
+        let state = OrbitalSystemState::at_epoch::<O>(epoch, self, None);
+        debug_assert!(state.is_finite(), "the state of the system must be finite");
+        state
+    }
+
+    /// Returns the state of the system in a given moment in time, taking the orbital periods from
+    /// the given cache.
+    pub fn state_at_cached(&self, time: Duration, periods: &PeriodCache) -> OrbitalSystemState {
+        let state = OrbitalSystemState::at_cached::<O>(time, self, None, periods);
+        debug_assert!(state.is_finite(), "the state of the system must be finite");
+        state
+    }
+
+    /// Returns the state of the system in a given moment in time, or the error of the first body
+    /// for which the state cannot be computed.
+    pub fn try_state_at(&self, time: Duration) -> Result<OrbitalSystemState, StateError> {
+        OrbitalSystemState::try_at::<O>(time, self, None, None)
+    }
+
+    /// Same as [OrbitalSystem::try_state_at], but taking the orbital periods from the given cache.
+    pub fn try_state_at_cached(
+        &self,
+        time: Duration,
+        periods: &PeriodCache,
+    ) -> Result<OrbitalSystemState, StateError> {
+        OrbitalSystemState::try_at::<O>(time, self, None, Some(periods))
     }
 
-    /// Returns the radius of the system.
+    /// Same as [OrbitalSystem::try_state_at_cached], but at the given epoch as described in
+    /// [OrbitalSystem::state_at_epoch].
+    pub fn try_state_at_epoch_cached(
+        &self,
+        epoch: Epoch,
+        periods: &PeriodCache,
+    ) -> Result<OrbitalSystemState, StateError> {
+        OrbitalSystemState::try_at_epoch::<O>(epoch, self, None, Some(periods))
+    }
+
+    /// Returns the radius of the system, which is the distance from the center of the primary body
+    /// to the farthest point any of the bodies in the system may reach.
+    ///
+    /// The [Orbit::radius] of each satellite already stands for the farthest distance from its
+    /// orbitee, which is the apoapsis of elliptic orbits.
     pub fn radius(&self) -> Distance {
-        let radius =
-            self.primary.radius + self.orbit.map(|orbit| orbit.radius()).unwrap_or_default();
+        self.secondary
+            .iter()
+            .map(|system| {
+                system
+                    .orbit
+                    .as_ref()
+                    .map(|orbit| orbit.radius())
+                    .unwrap_or_default()
+                    + system.radius()
+            })
+            .fold(self.primary.radius, Ord::max)
+    }
+
+    /// Returns the sum of the masses of all the bodies in the system.
+    pub fn total_mass(&self) -> Mass {
+        self.iter().map(|(body, _, _)| body.mass).sum()
+    }
+
+    /// Returns the center of mass of all the bodies in the system at the given time.
+    pub fn barycenter_at(&self, time: Duration) -> Coords {
+        self.barycenter_of(&self.state_at(time))
+    }
+
+    /// Returns the center of mass of all the bodies in the system in the given state, which must be
+    /// one of the system itself.
+    pub fn barycenter_of(&self, state: &OrbitalSystemState) -> Coords {
+        barycenter(
+            self.iter()
+                .zip(state.iter())
+                .map(|((body, _, _), (state, _))| (body.mass, state.position)),
+        )
+    }
+
+    /// Returns a depth-first iterator over the bodies of the system, yielding each of them along
+    /// with the name of the body it orbits, if any, and its orbit.
+    pub fn iter(&self) -> impl Iterator<Item = (&Body, Option<&Name<Body>>, &Option<O>)> {
+        self.subsystems().map(|(system, parent)| {
+            (
+                &system.primary,
+                parent.map(|parent| &parent.primary.name),
+                &system.orbit,
+            )
+        })
+    }
+
+    /// Returns a depth-first iterator over the mutable bodies of the system, yielding each of them
+    /// along with the name of the body it orbits, if any, and its orbit.
+    pub fn iter_mut(
+        &mut self,
+    ) -> impl Iterator<Item = (&mut Body, Option<Name<Body>>, &mut Option<O>)> {
+        let mut stack = vec![(self, None)];
+        std::iter::from_fn(move || {
+            let (system, parent) = stack.pop()?;
+            let OrbitalSystem {
+                primary,
+                orbit,
+                secondary,
+            } = system;
+
+            stack.extend(
+                secondary
+                    .iter_mut()
+                    .rev()
+                    .map(|subsystem| (subsystem, Some(primary.name.clone()))),
+            );
+
+            Some((primary, parent, orbit))
+        })
+    }
+
+    /// Returns a depth-first iterator over the subsystems of the system, including itself, yielding
+    /// each of them along with the system it orbits, if any.
+    pub(crate) fn subsystems(
+        &self,
+    ) -> impl Iterator<Item = (&OrbitalSystem<O>, Option<&OrbitalSystem<O>>)> {
+        let mut stack = vec![(self, None)];
+        std::iter::from_fn(move || {
+            let (system, parent) = stack.pop()?;
+            stack.extend(
+                system
+                    .secondary
+                    .iter()
+                    .rev()
+                    .map(|subsystem| (subsystem, Some(system))),
+            );
+
+            Some((system, parent))
+        })
+    }
+
+    /// Checks the consistency of the system, returning all the errors found, if any.
+    pub fn validate(&self) -> Result<(), Vec<SystemError>> {
+        let mut errors = Vec::new();
+        self.collect_errors(None, &mut HashSet::new(), &mut errors);
+
+        if errors.is_empty() {
+            return Ok(());
+        }
+
+        Err(errors)
+    }
+
+    fn collect_errors(
+        &self,
+        orbitee: Option<&Body>,
+        names: &mut HashSet<Name<Body>>,
+        errors: &mut Vec<SystemError>,
+    ) {
+        let body = &self.primary;
+
+        if !names.insert(body.name.clone()) {
+            errors.push(SystemError::DuplicatedName(body.name.clone()));
+        }
+
+        [
+            ("radius", body.radius.as_meters()),
+            ("mass", body.mass.as_kg()),
+            ("luminosity", body.luminosity.as_watts()),
+        ]
+        .into_iter()
+        .filter(|(_, value)| !value.is_finite())
+        .for_each(|(field, _)| {
+            errors.push(SystemError::NonFinite {
+                body: body.name.clone(),
+                field,
+            })
+        });
+
+        if body.mass.as_kg() == 0. {
+            errors.push(SystemError::ZeroMass(body.name.clone()));
+        }
+
+        match (orbitee, &self.orbit) {
+            (Some(_), None) => errors.push(SystemError::MissingOrbit(body.name.clone())),
+            (Some(orbitee), Some(orbit)) => {
+                if !orbit.radius().as_meters().is_finite() {
+                    errors.push(SystemError::NonFinite {
+                        body: body.name.clone(),
+                        field: "orbit",
+                    });
+                } else if periapsis(orbit) < orbitee.radius + body.radius {
+                    errors.push(SystemError::OrbitTooSmall {
+                        body: body.name.clone(),
+                        orbitee: orbitee.name.clone(),
+                    });
+                }
+
+                if orbit.eccentricity().as_f64() >= 1. {
+                    errors.push(SystemError::UnboundOrbit(body.name.clone()));
+                }
+            }
+            _ => {}
+        }
 
         self.secondary
             .iter()
-            .map(|system| system.radius() + radius)
-            .max()
-            .unwrap_or(radius)
+            .for_each(|system| system.collect_errors(Some(body), names, errors));
     }
 
     /// Returns the system in the system which primary body has the given name.
     pub fn system<'a>(&'a self, name: &Name<Body>) -> Option<&'a OrbitalSystem<O>> {
+        self.subsystems()
+            .find(|(system, _)| &system.primary.name == name)
+            .map(|(system, _)| system)
+    }
+
+    /// Returns the system which primary body is located at the given path, starting from the
+    /// primary body of self.
+    pub fn system_by_path<'a>(&'a self, path: &[Name<Body>]) -> Option<&'a OrbitalSystem<O>> {
+        let (name, path) = path.split_first()?;
+        if &self.primary.name != name {
+            return None;
+        }
+
+        path.iter().try_fold(self, |system, name| {
+            system
+                .secondary
+                .iter()
+                .find(|system| &system.primary.name == name)
+        })
+    }
+
+    /// Returns the path of the body with the given name, if any.
+    pub fn path(&self, name: &Name<Body>) -> Option<NamePath> {
+        let system = self.system(name)?;
+        Some(
+            self.ancestors(name)
+                .into_iter()
+                .cloned()
+                .collect::<NamePath>()
+                .with(system.primary.name.clone()),
+        )
+    }
+
+    /// Returns the mutable system in the system which primary body has the given name.
+    fn system_mut<'a>(&'a mut self, name: &Name<Body>) -> Option<&'a mut OrbitalSystem<O>> {
         if &self.primary.name == name {
             return Some(self);
         }
 
-        self.secondary.iter().find_map(|system| system.system(name))
+        self.secondary
+            .iter_mut()
+            .find_map(|system| system.system_mut(name))
+    }
+
+    /// Adds the given system as a satellite of the body with the given name, as long as the names
+    /// of its bodies are not used yet and the resulting subsystem is consistent.
+    pub fn insert(
+        &mut self,
+        parent: &Name<Body>,
+        system: OrbitalSystem<O>,
+    ) -> Result<(), EditError> {
+        self.insert_at(parent, usize::MAX, system)
+            .map_err(|(error, _)| error)
+    }
+
+    /// Removes the body with the given name from the system, returning it along with all its
+    /// satellites. The primary body of the system cannot be removed.
+    pub fn remove(&mut self, name: &Name<Body>) -> Option<OrbitalSystem<O>> {
+        self.detach(name).map(|(_, _, system)| system)
+    }
+
+    /// Moves the body with the given name, along with all its satellites, to orbit the new parent
+    /// through the given orbit. On failure, the system is left untouched.
+    pub fn reparent(
+        &mut self,
+        name: &Name<Body>,
+        new_parent: &Name<Body>,
+        orbit: O,
+    ) -> Result<(), EditError> {
+        let subsystem = self
+            .system(name)
+            .ok_or_else(|| EditError::NotFound(name.clone()))?;
+
+        if subsystem.system(new_parent).is_some() {
+            return Err(EditError::CyclicParent {
+                body: name.clone(),
+                parent: new_parent.clone(),
+            });
+        }
+
+        if self.system(new_parent).is_none() {
+            return Err(EditError::NotFound(new_parent.clone()));
+        }
+
+        let (parent, index, mut system) = self
+            .detach(name)
+            .ok_or_else(|| EditError::NotFound(name.clone()))?;
+
+        let previous = system.orbit.replace(orbit);
+        if let Err((error, mut system)) = self.insert_at(new_parent, usize::MAX, system) {
+            system.orbit = previous;
+            if let Some(parent) = self.system_mut(&parent) {
+                parent.secondary.insert(index, *system);
+            }
+
+            return Err(error);
+        }
+
+        Ok(())
+    }
+
+    /// Inserts the given system as the satellite at the given index (or the last one, if out of
+    /// bounds) of the body with the given name, giving the system back on failure.
+    fn insert_at(
+        &mut self,
+        parent: &Name<Body>,
+        index: usize,
+        system: OrbitalSystem<O>,
+    ) -> Result<(), (EditError, Box<OrbitalSystem<O>>)> {
+        let duplicated = {
+            let names: HashSet<&Name<Body>> = self.iter().map(|(body, _, _)| &body.name).collect();
+            system
+                .iter()
+                .map(|(body, _, _)| &body.name)
+                .find(|name| names.contains(name))
+                .cloned()
+        };
+
+        if let Some(name) = duplicated {
+            return Err((EditError::DuplicatedName(name), Box::new(system)));
+        }
+
+        let Some(parent) = self.system_mut(parent) else {
+            return Err((EditError::NotFound(parent.clone()), Box::new(system)));
+        };
+
+        let index = index.min(parent.secondary.len());
+        parent.secondary.insert(index, system);
+
+        if let Err(errors) = parent.validate() {
+            let system = parent.secondary.remove(index);
+            return Err((EditError::Invalid(errors), Box::new(system)));
+        }
+
+        Ok(())
+    }
+
+    /// Removes the body with the given name from the system, returning it along with the name of
+    /// the body it was orbiting and its index among the satellites of the latter.
+    fn detach(&mut self, name: &Name<Body>) -> Option<(Name<Body>, usize, OrbitalSystem<O>)> {
+        let parent = self.parent(name)?.name.clone();
+        let system = self.system_mut(&parent)?;
+        let index = system
+            .secondary
+            .iter()
+            .position(|system| &system.primary.name == name)?;
+
+        Some((parent, index, system.secondary.remove(index)))
+    }
+
+    /// Returns the body around which orbits the body with the given name, if any.
+    pub fn parent<'a>(&'a self, name: &Name<Body>) -> Option<&'a Body> {
+        self.orbitee(name).map(|system| &system.primary)
+    }
+
+    /// Returns the names of all the bodies the body with the given name orbits, directly or
+    /// indirectly, starting from the primary body of the system.
+    pub fn ancestors<'a>(&'a self, name: &Name<Body>) -> Vec<&'a Name<Body>> {
+        let mut ancestors: Vec<&Name<Body>> =
+            std::iter::successors(self.parent(name), |body| self.parent(&body.name))
+                .map(|body| &body.name)
+                .collect();
+
+        ancestors.reverse();
+        ancestors
+    }
+
+    /// Returns the system around which orbits the body with the given name, if any.
+    fn orbitee<'a>(&'a self, name: &Name<Body>) -> Option<&'a OrbitalSystem<O>> {
+        self.subsystems()
+            .find(|(system, _)| &system.primary.name == name)
+            .and_then(|(_, parent)| parent)
+    }
+
+    /// Returns the sequence of geographic coordinates, relative to the rotating surface of the
+    /// body it orbits, over which the satellite with the given name passes during the given range
+    /// of time.
+    pub fn ground_track(
+        &self,
+        satellite: &Name<Body>,
+        over: Range<Duration>,
+        step: Duration,
+    ) -> Vec<geographic::Coords> {
+        let Some(orbitee) = self.orbitee(satellite) else {
+            return Vec::default();
+        };
+
+        if step.is_zero() {
+            return Vec::default();
+        }
+
+        std::iter::successors(Some(over.start), |time| Some(*time + step))
+            .take_while(|time| over.contains(time))
+            .filter_map(|time| {
+                let state = OrbitalSystemState::at(time, orbitee, None);
+                state.state(satellite).map(|satellite| {
+                    satellite.position.transform(
+                        Rotation::default()
+                            .with_axis(Coords::default().with_z(1.))
+                            .with_theta(-state.rotation),
+                    )
+                })
+            })
+            .map(geographic::Coords::from)
+            .collect()
+    }
+
+    /// Returns the first luminous body in the system, if any.
+    fn luminous(&self) -> Option<&Body> {
+        self.iter()
+            .map(|(body, _, _)| body)
+            .find(|body| body.is_luminous())
+    }
+
+    /// Returns the direction, in the rotating frame of the body with the given name, towards the
+    /// luminous body of the system at the given time.
+    fn sunward(&self, body: &Name<Body>, at: Duration) -> Option<Coords> {
+        let luminous = self.luminous().filter(|luminous| &luminous.name != body)?;
+        let state = self.state_at(at);
+
+        let body_state = state.state(body)?;
+        let luminous_state = state.state(&luminous.name)?;
+
+        let direction = luminous_state.position + -body_state.position;
+        if direction.magnitude() == 0. {
+            return None;
+        }
+
+        Some(
+            direction.unit().transform(
+                Rotation::default()
+                    .with_axis(Coords::default().with_z(1.))
+                    .with_theta(-body_state.rotation),
+            ),
+        )
+    }
+
+    /// Returns the point on the surface of the body with the given name at which the light of the
+    /// luminous body of the system hits perpendicularly at the given time.
+    pub fn subsolar_point(&self, body: &Name<Body>, at: Duration) -> Option<geographic::Coords> {
+        let radius = self.system(body)?.primary.radius;
+        let sunward = self.sunward(body, at)?;
+
+        Some(
+            geographic::Coords::from(sunward)
+                .with_altitude(geographic::Altitude::from(radius.as_meters())),
+        )
+    }
+
+    /// Returns the boundary between the day and night sides of the body with the given name at the
+    /// given time, sampled as a great circle of its surface in the rotating frame of the body.
+    pub fn terminator(&self, body: &Name<Body>, at: Duration, segments: usize) -> Shape {
+        let Some((radius, sunward)) = self
+            .system(body)
+            .map(|system| system.primary.radius)
+            .zip(self.sunward(body, at))
+        else {
+            return Shape::default();
+        };
+
+        let reference = if sunward.cross(&Coords::default().with_z(1.)).magnitude() == 0. {
+            Coords::default().with_x(1.)
+        } else {
+            Coords::default().with_z(1.)
+        };
+
+        let start = sunward
+            .cross(&reference)
+            .unit()
+            .transform(Scaling::default().with_factor(radius.as_meters()));
+
+        Shape {
+            points: (0..segments)
+                .map(|vertex_index| Radian::TWO_PI / segments as f64 * vertex_index as f64)
+                .map(|theta| {
+                    start.transform(Rotation::default().with_axis(sunward).with_theta(theta))
+                })
+                .collect(),
+        }
+    }
+
+    /// Returns the time it takes to the luminous body of the system to return to the same position
+    /// in the sky of the body with the given name, if ever.
+    ///
+    /// Unlike the [Body::sideral_period], the solar day accounts for the motion of the body (or the
+    /// ancestor of it orbiting the luminous body) along its orbit. A body whose spin matches its
+    /// orbit, as a tidally locked one, has no solar day at all.
+    pub fn solar_day(&self, body: &Name<Body>) -> Option<Duration> {
+        let frequency = self
+            .solar_frequency(body)
+            .filter(|frequency| *frequency != 0.)?;
+        Some(Duration::from_secs_f64(frequency.abs().recip()))
+    }
+
+    /// Returns the local apparent solar time at the given longitude of the body with the given name
+    /// at the given time, being zero the midnight and half of the [OrbitalSystem::solar_day] the
+    /// noon.
+    pub fn local_solar_time(
+        &self,
+        body: &Name<Body>,
+        at: Duration,
+        longitude: geographic::Longitude,
+    ) -> Option<Duration> {
+        let frequency = self
+            .solar_frequency(body)
+            .filter(|frequency| *frequency != 0.)?;
+        let subsolar = geographic::Longitude::from(self.sunward(body, at)?);
+
+        // the luminous body moves westwards in the sky of a body spinning faster than it orbits,
+        // which means that the eastern longitudes are ahead in time.
+        let hour_angle = (f64::from(longitude) - f64::from(subsolar)) * frequency.signum();
+        let day_fraction = (hour_angle / Radian::TWO_PI.as_f64() + 0.5).rem_euclid(1.);
+
+        Some(Duration::from_secs_f64(day_fraction / frequency.abs()))
+    }
+
+    /// Returns the frequency (in hertz) at which the luminous body of the system goes around the
+    /// sky of the body with the given name, being negative if it moves eastwards.
+    fn solar_frequency(&self, body: &Name<Body>) -> Option<f64> {
+        let luminous = self.luminous().filter(|luminous| &luminous.name != body)?;
+        let path = self.path(body)?;
+
+        // the body orbiting the luminous one is the body itself or any of its ancestors.
+        let orbiting = path
+            .iter()
+            .position(|name| name == &luminous.name)
+            .and_then(|index| path.get(index + 1))
+            .and_then(|name| self.system(name))?;
+
+        let orbit = orbiting.orbit.as_ref()?;
+        let orbit_frequency = match orbit.period(luminous) {
+            period if period.is_zero() => 0.,
+            period if orbit.is_clockwise() => -period.as_secs_f64().recip(),
+            period => period.as_secs_f64().recip(),
+        };
+
+        let spin = &self.system(body)?.primary.spin;
+        let spin_frequency = match spin.frequency().as_hz() {
+            frequency if spin.clockwise => -frequency,
+            frequency => frequency,
+        };
+
+        Some(spin_frequency - orbit_frequency)
+    }
+}
+
+/// Returns the mass-weighted mean of the given positions, or the origin if there is no mass at all.
+fn barycenter(bodies: impl IntoIterator<Item = (Mass, Coords)>) -> Coords {
+    let (mass, moment) = bodies.into_iter().fold(
+        (Mass::default(), Coords::default()),
+        |(mass, moment), (body_mass, position)| {
+            (mass + body_mass, moment + position * body_mass.as_kg())
+        },
+    );
+
+    if mass.as_kg() == 0. {
+        return Coords::default();
+    }
+
+    moment / mass.as_kg()
+}
+
+/// Returns the distance between the focus of the given orbit and its closest point, being the
+/// radius of the orbit the farthest one.
+fn periapsis<O: Orbit>(orbit: &O) -> Distance {
+    let eccentricity = orbit.eccentricity().as_f64();
+    orbit.radius() * ((1. - eccentricity) / (1. + eccentricity))
+}
+
+/// Builds an [OrbitalSystem] satellite by satellite.
+pub struct OrbitalSystemBuilder<O> {
+    system: OrbitalSystem<O>,
+}
+
+impl<O: Orbit> OrbitalSystemBuilder<O> {
+    /// Returns a new builder for a system ruled by the given body.
+    pub fn new(primary: Body) -> Self {
+        Self {
+            system: OrbitalSystem {
+                primary,
+                orbit: None,
+                secondary: Vec::default(),
+            },
+        }
+    }
+
+    /// Adds a satellite orbiting the primary body of the system, whose own satellites are
+    /// defined by the given closure.
+    pub fn with_satellite<F>(mut self, body: Body, orbit: O, satellites: F) -> Self
+    where
+        F: FnOnce(OrbitalSystemBuilder<O>) -> OrbitalSystemBuilder<O>,
+    {
+        let mut builder = OrbitalSystemBuilder::new(body);
+        builder.system.orbit = Some(orbit);
+
+        self.system.secondary.push(satellites(builder).system);
+        self
+    }
+
+    /// Returns the resulting system, as long as it is consistent.
+    pub fn build(self) -> Result<OrbitalSystem<O>, Vec<SystemError>> {
+        self.system.validate()?;
+        Ok(self.system)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{collections::HashMap, f64::consts::PI, str::FromStr, time::Duration};
+
+    use alvidir::name::Name;
+
+    use super::barycenter;
+    use crate::{
+        cartesian::{
+            shape::{Circle, Ellipse},
+            transform::Translation,
+            Coords,
+        },
+        fixtures::{self, body},
+        tests::approx_eq,
+        Body, Distance, EditError, Epoch, FixedEphemeris, Frequency, Luminosity, Mass, NamePath,
+        Orbit, OrbitKind, OrbitalSystem, OrbitalSystemBuilder, OrbitalSystemState,
+        OrbitalSystemStateGenerator, PeriodCache, Ratio, Rotation, StateDiff, StateError,
+        SystemError, SystemStats,
+    };
+
+    /// A planet orbiting a luminous sun, both weighing a kilogram and spinning as given.
+    fn system(spin: Rotation) -> OrbitalSystem<Circle> {
+        let mut system = fixtures::system(Circle::default().with_radius(Distance::km(1_000.)));
+        system.primary = system
+            .primary
+            .with_mass(Mass::kg(1.))
+            .with_spin(spin.clone())
+            .with_luminosity(Luminosity::SUN);
+
+        system.secondary[0].primary = system.secondary[0]
+            .primary
+            .clone()
+            .with_mass(Mass::kg(1.))
+            .with_spin(spin);
+
+        system
+    }
+
+    #[test]
+    fn validate_must_not_fail() {
+        struct Test {
+            name: &'static str,
+            system: OrbitalSystem<Circle>,
+            errors: Vec<SystemError>,
+        }
+
+        let spin = Rotation {
+            period: Duration::from_secs(3600),
+            ..Default::default()
+        };
+
+        let valid = system(spin.clone());
+        let planet = &valid.secondary[0];
+
+        vec![
+            Test {
+                name: "consistent system must be valid",
+                system: valid.clone(),
+                errors: vec![],
+            },
+            Test {
+                name: "duplicated names must fail",
+                system: OrbitalSystem {
+                    secondary: vec![planet.clone(), planet.clone()],
+                    ..valid.clone()
+                },
+                errors: vec![SystemError::DuplicatedName(planet.primary.name.clone())],
+            },
+            Test {
+                name: "orbit smaller than the bodies must fail",
+                system: OrbitalSystem {
+                    secondary: vec![OrbitalSystem {
+                        orbit: Some(Circle::default().with_radius(Distance::meters(1_500.))),
+                        ..planet.clone()
+                    }],
+                    ..valid.clone()
+                },
+                errors: vec![SystemError::OrbitTooSmall {
+                    body: planet.primary.name.clone(),
+                    orbitee: valid.primary.name.clone(),
+                }],
+            },
+            Test {
+                name: "secondary body without orbit must fail",
+                system: OrbitalSystem {
+                    secondary: vec![OrbitalSystem {
+                        orbit: None,
+                        ..planet.clone()
+                    }],
+                    ..valid.clone()
+                },
+                errors: vec![SystemError::MissingOrbit(planet.primary.name.clone())],
+            },
+            Test {
+                name: "body fields must be consistent",
+                system: OrbitalSystem {
+                    primary: Body {
+                        radius: Distance::km(f64::INFINITY),
+                        mass: Mass::kg(0.),
+                        spin: Rotation::default(),
+                        ..valid.primary.clone()
+                    },
+                    secondary: Default::default(),
+                    ..valid.clone()
+                },
+                errors: vec![
+                    SystemError::NonFinite {
+                        body: valid.primary.name.clone(),
+                        field: "radius",
+                    },
+                    SystemError::ZeroMass(valid.primary.name.clone()),
+                ],
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let errors = test.system.validate().err().unwrap_or_default();
+            assert_eq!(
+                errors, test.errors,
+                "{}: got errors = {:?}, want {:?}",
+                test.name, errors, test.errors
+            );
+        });
+    }
+
+    #[test]
+    fn validate_must_check_the_periapsis() {
+        struct Test {
+            name: &'static str,
+            eccentricity: f64,
+            errors: Vec<SystemError>,
+        }
+
+        vec![
+            Test {
+                name: "periapsis clear of the bodies must be valid",
+                eccentricity: 0.5,
+                errors: vec![],
+            },
+            Test {
+                name: "periapsis within the bodies must fail",
+                eccentricity: 0.9,
+                errors: vec![SystemError::OrbitTooSmall {
+                    body: Name::from_str("planet").unwrap(),
+                    orbitee: Name::from_str("sun").unwrap(),
+                }],
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            // the apoapsis is far beyond the radii of both bodies, unlike the periapsis.
+            let system = OrbitalSystem {
+                primary: body("sun"),
+                orbit: None,
+                secondary: vec![OrbitalSystem {
+                    primary: body("planet"),
+                    orbit: Some(
+                        Ellipse::default()
+                            .with_semi_major_axis(Distance::km(10.))
+                            .with_eccentricity(Ratio::from(test.eccentricity)),
+                    ),
+                    secondary: Default::default(),
+                }],
+            };
+
+            let errors = system.validate().err().unwrap_or_default();
+            assert_eq!(errors, test.errors, "{}", test.name);
+        });
+    }
+
+    fn nested_system() -> OrbitalSystem<Circle> {
+        // bodies weighing a kilogram barely pull each other, so their orbits last long.
+        let body = |name: &str| body(name).with_mass(Mass::kg(1.));
+        let satellite = |name: &str, radius: f64, secondary| OrbitalSystem {
+            primary: body(name),
+            orbit: Some(Circle::default().with_radius(Distance::km(radius))),
+            secondary,
+        };
+
+        OrbitalSystem {
+            primary: body("sun"),
+            orbit: None,
+            secondary: vec![
+                satellite("planet", 1_000., vec![satellite("moon", 10., vec![])]),
+                satellite("dwarf", 2_000., vec![]),
+            ],
+        }
+    }
+
+    #[test]
+    fn iter_must_traverse_depth_first() {
+        let system = nested_system();
+        let got: Vec<(String, Option<String>)> = system
+            .iter()
+            .map(|(body, parent, _)| (body.name.to_string(), parent.map(ToString::to_string)))
+            .collect();
+
+        let want: Vec<(String, Option<String>)> = [
+            ("sun", None),
+            ("planet", Some("sun")),
+            ("moon", Some("planet")),
+            ("dwarf", Some("sun")),
+        ]
+        .into_iter()
+        .map(|(body, parent)| (body.to_string(), parent.map(ToString::to_string)))
+        .collect();
+
+        assert_eq!(got, want);
+        assert_eq!(system.radius(), Distance::km(2_001.));
+    }
+
+    #[test]
+    fn iter_mut_must_traverse_depth_first() {
+        let mut system = nested_system();
+        let parents: Vec<Option<String>> = system
+            .iter_mut()
+            .map(|(body, parent, orbit)| {
+                body.radius = Distance::km(2.);
+                *orbit = None;
+                parent.map(|parent| parent.to_string())
+            })
+            .collect();
+
+        assert_eq!(
+            parents,
+            vec![
+                None,
+                Some("sun".to_string()),
+                Some("planet".to_string()),
+                Some("sun".to_string())
+            ]
+        );
+
+        system.iter().for_each(|(body, _, orbit)| {
+            assert_eq!(body.radius, Distance::km(2.));
+            assert!(orbit.is_none());
+        });
+    }
+
+    #[test]
+    fn state_iter_must_traverse_depth_first() {
+        let state = nested_system().state_at(Duration::ZERO);
+        let got: Vec<(String, usize)> = state
+            .iter()
+            .map(|(state, depth)| (state.body.to_string(), depth))
+            .collect();
+
+        assert_eq!(
+            got,
+            vec![
+                ("sun".to_string(), 0),
+                ("planet".to_string(), 1),
+                ("moon".to_string(), 2),
+                ("dwarf".to_string(), 1)
+            ]
+        );
+    }
+
+    #[test]
+    fn diff_must_not_fail() {
+        struct Test {
+            name: &'static str,
+            current: OrbitalSystemState,
+            output: StateDiff,
+        }
+
+        let state = |name: &str, x: f64, secondary| OrbitalSystemState {
+            body: Name::from_str(name).unwrap(),
+            rotation: Default::default(),
+            position: Coords::default().with_x(x),
+            theta: Default::default(),
+            velocity: Default::default(),
+            secondary,
+        };
+
+        let name = |name: &str| Name::from_str(name).unwrap();
+        let epsilon = Distance::meters(10.);
+        let previous = state(
+            "sun",
+            0.,
+            vec![state("planet", 1_000., vec![state("moon", 1_100., vec![])])],
+        );
+
+        vec![
+            Test {
+                name: "same state",
+                current: previous.clone(),
+                output: StateDiff::default(),
+            },
+            Test {
+                name: "body added",
+                current: state(
+                    "sun",
+                    0.,
+                    vec![
+                        state("planet", 1_000., vec![state("moon", 1_100., vec![])]),
+                        state("dwarf", 2_000., vec![]),
+                    ],
+                ),
+                output: StateDiff {
+                    created: vec![name("dwarf")],
+                    ..Default::default()
+                },
+            },
+            Test {
+                name: "body removed",
+                current: state("sun", 0., vec![state("planet", 1_000., vec![])]),
+                output: StateDiff {
+                    removed: vec![name("moon")],
+                    ..Default::default()
+                },
+            },
+            Test {
+                name: "body moved below the epsilon",
+                current: state(
+                    "sun",
+                    0.,
+                    vec![state("planet", 1_000., vec![state("moon", 1_105., vec![])])],
+                ),
+                output: StateDiff::default(),
+            },
+            Test {
+                name: "body moved above the epsilon",
+                current: state(
+                    "sun",
+                    0.,
+                    vec![state("planet", 1_000., vec![state("moon", 1_150., vec![])])],
+                ),
+                output: StateDiff {
+                    moved: vec![(name("moon"), Coords::default().with_x(50.))],
+                    ..Default::default()
+                },
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let output = test.current.diff(&previous, epsilon);
+            assert_eq!(
+                output, test.output,
+                "{}: got diff = {:?}, want {:?}",
+                test.name, output, test.output
+            );
+        });
+    }
+
+    #[test]
+    fn positions_must_accumulate_the_translations_of_the_parents() {
+        let system = nested_system();
+        let time = Duration::from_secs(5_000);
+        let state = system.state_at(time);
+
+        // parents are always visited before their satellites.
+        let mut want = HashMap::new();
+        system.subsystems().for_each(|(subsystem, orbitee)| {
+            let position = match (orbitee, &subsystem.orbit) {
+                (Some(orbitee), Some(orbit)) => orbit
+                    .position_at(time, &orbitee.primary)
+                    .transform(Translation::default().with_vector(want[&orbitee.primary.name]))
+                    .transform(Translation::default().with_vector(orbit.focus())),
+                _ => Coords::default(),
+            };
+
+            want.insert(subsystem.primary.name.clone(), position);
+        });
+
+        assert_eq!(state.positions(), want);
+
+        want.iter().for_each(|(name, position)| {
+            assert_eq!(state.position_of(name), Some(*position), "{name}");
+        });
+
+        assert_eq!(state.position_of(&Name::from_str("pluto").unwrap()), None);
+    }
+
+    #[test]
+    fn interpolate_must_not_fail() {
+        const ABS_ERROR: f64 = 1e-3;
+
+        let system = nested_system();
+        let step = Duration::from_millis(100);
+        let previous = system.state_at(Duration::from_secs(3_600));
+        let next = system.state_at(Duration::from_secs(3_600) + step);
+
+        assert_eq!(previous.interpolate(&next, 0.), previous);
+        assert_eq!(previous.interpolate(&next, 1.), next);
+
+        let want = system.state_at(Duration::from_secs(3_600) + step / 2);
+        let got = previous.interpolate(&next, 0.5);
+        got.iter()
+            .zip(want.iter())
+            .for_each(|((got, _), (want, _))| {
+                assert_eq!(got.body, want.body);
+                assert!(
+                    got.position.distance(&want.position) <= ABS_ERROR,
+                    "{}: got position = {:?}, want {:?}",
+                    got.body,
+                    got.position,
+                    want.position
+                );
+                assert!(
+                    approx_eq(got.theta.as_f64(), want.theta.as_f64(), ABS_ERROR),
+                    "{}: got theta = {:?}, want {:?}",
+                    got.body,
+                    got.theta,
+                    want.theta
+                );
+            });
+
+        // bodies missing in the next state remain where they were.
+        let mut partial = next.clone();
+        partial.secondary.pop();
+        let got = previous.interpolate(&partial, 0.5);
+        let dwarf = Name::from_str("dwarf").unwrap();
+        assert_eq!(got.state(&dwarf), previous.state(&dwarf));
+    }
+
+    #[test]
+    fn positions_relative_to_must_preserve_distances() {
+        let state = |name: &str, position: [f64; 3], secondary| OrbitalSystemState {
+            body: Name::from_str(name).unwrap(),
+            rotation: Default::default(),
+            position: Coords::from(position),
+            theta: Default::default(),
+            velocity: Default::default(),
+            secondary,
+        };
+
+        let sun = state(
+            "sun",
+            [0., 0., 0.],
+            vec![
+                state("earth", [1.496e11, 0., 0.], vec![]),
+                state(
+                    "neptune",
+                    [-3.1e12, 5.0e12, 1.2e9],
+                    vec![state(
+                        "triton",
+                        [-3.1e12 + 3.3e8, 5.0e12 - 1.7e8, 1.2e9],
+                        vec![],
+                    )],
+                ),
+            ],
+        );
+
+        let names = ["sun", "earth", "neptune", "triton"].map(|name| Name::from_str(name).unwrap());
+
+        names.iter().for_each(|origin| {
+            let rebased = sun.positions_relative_to(origin);
+
+            let position = rebased.state(origin).unwrap().position;
+            assert_eq!(
+                position,
+                Coords::default(),
+                "{origin}: got origin position = {position:?}, want the origin of coordinates"
+            );
+
+            names.iter().for_each(|from| {
+                names.iter().for_each(|to| {
+                    let want = sun
+                        .state(from)
+                        .unwrap()
+                        .position
+                        .distance(&sun.state(to).unwrap().position);
+                    let got = rebased
+                        .state(from)
+                        .unwrap()
+                        .position
+                        .distance(&rebased.state(to).unwrap().position);
+
+                    // subtracting the offset rounds each component of every position once.
+                    let tolerance = 6e12 * f64::EPSILON;
+                    assert!(
+                        approx_eq(got, want, tolerance),
+                        "{origin}: got distance from {from} to {to} = {got}, want {want}"
+                    );
+                });
+            });
+        });
+
+        let root = sun.positions_relative_to(&names[0]);
+        assert_eq!(
+            root, sun,
+            "rebasing onto the root must not change the state"
+        );
+
+        let unknown = sun.positions_relative_to(&Name::from_str("pluto").unwrap());
+        assert_eq!(
+            unknown, sun,
+            "rebasing onto an unknown body must not change the state"
+        );
+    }
+
+    #[test]
+    fn radius_must_not_fail() {
+        struct Test {
+            name: &'static str,
+            system: OrbitalSystem<OrbitKind>,
+            radius: Distance,
+        }
+
+        let with_radius = |name: &str, radius: f64| body(name).with_radius(Distance::km(radius));
+        let circle = |radius: f64| Circle::default().with_radius(Distance::km(radius));
+
+        vec![
+            Test {
+                name: "single body",
+                system: OrbitalSystemBuilder::new(with_radius("sun", 10.)).system,
+                radius: Distance::km(10.),
+            },
+            Test {
+                name: "satellite of a satellite must not count the radius of its ancestors",
+                system: OrbitalSystemBuilder::new(with_radius("sun", 10.))
+                    .with_satellite(with_radius("planet", 1.), circle(100.).into(), |planet| {
+                        planet.with_satellite(with_radius("moon", 0.5), circle(5.).into(), |moon| {
+                            moon
+                        })
+                    })
+                    .system,
+                radius: Distance::km(105.5),
+            },
+            Test {
+                name: "elliptic orbit must count its apoapsis",
+                system: OrbitalSystemBuilder::new(with_radius("sun", 10.))
+                    .with_satellite(with_radius("planet", 1.), circle(100.).into(), |planet| {
+                        planet.with_satellite(
+                            with_radius("moon", 0.5),
+                            Ellipse::default()
+                                .with_semi_major_axis(Distance::km(4.))
+                                .with_eccentricity(Ratio::try_new(0.5).unwrap())
+                                .into(),
+                            |moon| moon,
+                        )
+                    })
+                    .system,
+                radius: Distance::km(106.5),
+            },
+            Test {
+                name: "primary body larger than the orbits of its satellites",
+                system: OrbitalSystemBuilder::new(with_radius("sun", 1_000.))
+                    .with_satellite(with_radius("planet", 1.), circle(100.).into(), |planet| {
+                        planet
+                    })
+                    .system,
+                radius: Distance::km(1_000.),
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let radius = test.system.radius();
+            assert!(
+                approx_eq(radius.as_meters(), test.radius.as_meters(), 1e-6),
+                "{}: got radius = {radius:?}, want {:?}",
+                test.name,
+                test.radius
+            );
+        });
+    }
+
+    #[test]
+    fn boxed_orbits_must_behave_as_the_unboxed_ones() {
+        type DynOrbit = Box<dyn Orbit + Send + Sync>;
+
+        let circle = Circle::default().with_radius(Distance::km(1_000.));
+        let ephemeris = FixedEphemeris::from(vec![
+            (Duration::from_secs(0), Coords::default().with_x(1e4)),
+            (Duration::from_secs(100), Coords::default().with_y(1e4)),
+            (Duration::from_secs(200), Coords::default().with_x(-1e4)),
+        ]);
+
+        let system = OrbitalSystemBuilder::<DynOrbit>::new(body("sun").with_mass(Mass::kg(1e20)))
+            .with_satellite(body("planet"), Box::new(circle), |planet| {
+                planet.with_satellite(body("probe"), Box::new(ephemeris.clone()), |probe| probe)
+            })
+            .build()
+            .unwrap();
+
+        let time = Duration::from_secs(100);
+        let state = system.state_at(time);
+        let sun = system.system(&Name::from_str("sun").unwrap()).unwrap();
+
+        let planet = state.state(&Name::from_str("planet").unwrap()).unwrap();
+        assert_eq!(planet.position, circle.position_at(time, &sun.primary));
+
+        let probe = state.state(&Name::from_str("probe").unwrap()).unwrap();
+        assert_eq!(
+            probe.position,
+            planet.position + Coords::default().with_y(1e4)
+        );
+
+        let stats = SystemStats::from(&system);
+        assert_eq!(stats.secondary[0].perimeter, circle.perimeter());
+        assert_eq!(
+            stats.secondary[0].secondary[0].perimeter,
+            ephemeris.perimeter()
+        );
+    }
+
+    #[test]
+    fn parent_must_not_fail() {
+        struct Test {
+            name: &'static str,
+            body: &'static str,
+            parent: Option<&'static str>,
+            ancestors: Vec<&'static str>,
+        }
+
+        vec![
+            Test {
+                name: "primary body",
+                body: "sun",
+                parent: None,
+                ancestors: vec![],
+            },
+            Test {
+                name: "satellite of the primary body",
+                body: "planet",
+                parent: Some("sun"),
+                ancestors: vec!["sun"],
+            },
+            Test {
+                name: "satellite of a satellite",
+                body: "moon",
+                parent: Some("planet"),
+                ancestors: vec!["sun", "planet"],
+            },
+            Test {
+                name: "unknown body",
+                body: "comet",
+                parent: None,
+                ancestors: vec![],
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let system = nested_system();
+            let body = Name::from_str(test.body).unwrap();
+
+            let parent = system.parent(&body).map(|parent| parent.name.to_string());
+            assert_eq!(
+                parent.as_deref(),
+                test.parent,
+                "{}: got parent = {:?}, want {:?}",
+                test.name,
+                parent,
+                test.parent
+            );
+
+            let parent_state = system
+                .state_at(Duration::ZERO)
+                .parent_state(&body)
+                .map(|state| state.body.to_string());
+            assert_eq!(
+                parent_state.as_deref(),
+                test.parent,
+                "{}: got parent state = {:?}, want {:?}",
+                test.name,
+                parent_state,
+                test.parent
+            );
+
+            let ancestors: Vec<String> = system
+                .ancestors(&body)
+                .into_iter()
+                .map(ToString::to_string)
+                .collect();
+            assert_eq!(
+                ancestors, test.ancestors,
+                "{}: got ancestors = {:?}, want {:?}",
+                test.name, ancestors, test.ancestors
+            );
+        });
+    }
+
+    fn names(system: &OrbitalSystem<Circle>) -> Vec<String> {
+        system
+            .iter()
+            .map(|(body, _, _)| body.name.to_string())
+            .collect()
+    }
+
+    #[test]
+    fn remove_must_take_the_satellites_along() {
+        let mut system = nested_system();
+
+        let removed = system.remove(&Name::from_str("planet").unwrap()).unwrap();
+        assert_eq!(names(&removed), vec!["planet", "moon"]);
+        assert_eq!(names(&system), vec!["sun", "dwarf"]);
+
+        assert!(system.remove(&Name::from_str("sun").unwrap()).is_none());
+        assert!(system.remove(&Name::from_str("moon").unwrap()).is_none());
+    }
+
+    #[test]
+    fn insert_must_not_fail() {
+        struct Test {
+            name: &'static str,
+            parent: &'static str,
+            body: &'static str,
+            radius: f64,
+            error: Option<fn(&EditError) -> bool>,
+        }
+
+        vec![
+            Test {
+                name: "new satellite",
+                parent: "dwarf",
+                body: "comet",
+                radius: 10.,
+                error: None,
+            },
+            Test {
+                name: "unknown parent",
+                parent: "comet",
+                body: "asteroid",
+                radius: 10.,
+                error: Some(|error| matches!(error, EditError::NotFound(_))),
+            },
+            Test {
+                name: "duplicated name",
+                parent: "dwarf",
+                body: "moon",
+                radius: 10.,
+                error: Some(|error| matches!(error, EditError::DuplicatedName(_))),
+            },
+            Test {
+                name: "inconsistent orbit",
+                parent: "dwarf",
+                body: "comet",
+                radius: 1.,
+                error: Some(|error| matches!(error, EditError::Invalid(_))),
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let mut system = nested_system();
+            let satellite = OrbitalSystem {
+                primary: body(test.body),
+                orbit: Some(Circle::default().with_radius(Distance::km(test.radius))),
+                secondary: Default::default(),
+            };
+
+            let result = system.insert(&Name::from_str(test.parent).unwrap(), satellite);
+            match test.error {
+                Some(is_expected) => {
+                    assert!(
+                        result.as_ref().is_err_and(is_expected),
+                        "{}: got result = {:?}",
+                        test.name,
+                        result
+                    );
+                    assert_eq!(names(&system), names(&nested_system()), "{}", test.name);
+                }
+                None => {
+                    assert!(result.is_ok(), "{}: got result = {:?}", test.name, result);
+                    assert_eq!(
+                        system
+                            .parent(&Name::from_str(test.body).unwrap())
+                            .map(|parent| parent.name.to_string()),
+                        Some(test.parent.to_string()),
+                        "{}",
+                        test.name
+                    );
+                }
+            }
+        });
+    }
+
+    #[test]
+    fn reparent_must_not_fail() {
+        let moon = Name::from_str("moon").unwrap();
+        let planet = Name::from_str("planet").unwrap();
+        let dwarf = Name::from_str("dwarf").unwrap();
+
+        let mut system = nested_system();
+        let result = system.reparent(
+            &moon,
+            &dwarf,
+            Circle::default().with_radius(Distance::km(20.)),
+        );
+        assert!(result.is_ok(), "got result = {result:?}");
+        assert_eq!(names(&system), vec!["sun", "planet", "dwarf", "moon"]);
+        assert_eq!(
+            system
+                .system(&moon)
+                .and_then(|moon| moon.orbit)
+                .map(|orbit| orbit.radius()),
+            Some(Distance::km(20.))
+        );
+
+        let mut system = nested_system();
+        let result = system.reparent(
+            &planet,
+            &moon,
+            Circle::default().with_radius(Distance::km(20.)),
+        );
+        assert!(
+            matches!(result, Err(EditError::CyclicParent { .. })),
+            "got result = {result:?}, want cyclic parent"
+        );
+
+        let mut system = nested_system();
+        let result = system.reparent(
+            &moon,
+            &dwarf,
+            Circle::default().with_radius(Distance::km(1.)),
+        );
+        assert!(
+            matches!(result, Err(EditError::Invalid(_))),
+            "got result = {result:?}, want invalid"
+        );
+        assert_eq!(names(&system), names(&nested_system()));
+        assert_eq!(
+            system
+                .system(&moon)
+                .and_then(|moon| moon.orbit)
+                .map(|orbit| orbit.radius()),
+            Some(Distance::km(10.))
+        );
+    }
+
+    #[test]
+    fn barycenter_must_not_fail() {
+        const ABS_ERROR: f64 = 0.000001;
+
+        struct Test {
+            name: &'static str,
+            bodies: Vec<(Mass, Coords)>,
+            barycenter: Coords,
+        }
+
+        vec![
+            Test {
+                name: "no bodies",
+                bodies: vec![],
+                barycenter: Coords::default(),
+            },
+            Test {
+                name: "two equal masses at opposite sides",
+                bodies: vec![
+                    (Mass::kg(1e20), Coords::default().with_x(1_000.)),
+                    (Mass::kg(1e20), Coords::default().with_x(-1_000.)),
+                ],
+                barycenter: Coords::default(),
+            },
+            Test {
+                name: "unequal masses",
+                bodies: vec![
+                    (Mass::kg(3.), Coords::default()),
+                    (Mass::kg(1.), Coords::default().with_y(4.)),
+                ],
+                barycenter: Coords::default().with_y(1.),
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let barycenter = barycenter(test.bodies);
+            assert!(
+                barycenter.distance(&test.barycenter) <= ABS_ERROR,
+                "{}: got barycenter = {:?}, want {:?}",
+                test.name,
+                barycenter,
+                test.barycenter
+            );
+        });
+    }
+
+    #[test]
+    fn barycenter_at_must_weight_positions_by_mass() {
+        const ABS_ERROR: f64 = 0.000001;
+
+        let system = nested_system();
+        assert_eq!(system.total_mass(), Mass::kg(4.));
+
+        let state = system.state_at(Duration::ZERO);
+        let want = state
+            .iter()
+            .map(|(state, _)| state.position)
+            .fold(Coords::default(), |sum, position| sum + position)
+            / 4.;
+
+        let got = system.barycenter_at(Duration::ZERO);
+        assert!(
+            got.distance(&want) <= ABS_ERROR,
+            "got barycenter = {got:?}, want {want:?}"
+        );
+    }
+
+    #[test]
+    fn state_at_cached_must_match_state_at() {
+        let system = nested_system();
+        let periods = PeriodCache::from(&system);
+
+        [0, 1_000, 100_000, 10_000_000]
+            .into_iter()
+            .map(Duration::from_secs)
+            .for_each(|time| {
+                let want = system.state_at(time);
+                let got = system.state_at_cached(time, &periods);
+
+                got.iter()
+                    .zip(want.iter())
+                    .for_each(|((got, _), (want, _))| {
+                        assert_eq!(got.body, want.body);
+                        assert_eq!(got.position, want.position, "{} at {time:?}", got.body);
+                        assert_eq!(got.theta, want.theta, "{} at {time:?}", got.body);
+                        assert_eq!(got.velocity, want.velocity, "{} at {time:?}", got.body);
+                    });
+            });
+    }
+
+    /// Returns a system with two bodies named "io", orbiting different planets.
+    fn duplicated_system() -> OrbitalSystem<Circle> {
+        let satellite = |name: &str, radius: f64, secondary| OrbitalSystem {
+            primary: body(name),
+            orbit: Some(Circle::default().with_radius(Distance::km(radius))),
+            secondary,
+        };
+
+        OrbitalSystem {
+            primary: body("sun"),
+            orbit: None,
+            secondary: vec![
+                satellite("jupiter", 1_000., vec![satellite("io", 10., vec![])]),
+                satellite("saturn", 2_000., vec![satellite("io", 20., vec![])]),
+            ],
+        }
+    }
+
+    #[test]
+    fn validate_must_reject_duplicated_names() {
+        let errors = duplicated_system().validate().unwrap_err();
+        assert_eq!(
+            errors,
+            vec![SystemError::DuplicatedName(Name::from_str("io").unwrap())]
+        );
+    }
+
+    #[test]
+    fn path_lookups_must_disambiguate_duplicated_names() {
+        struct Test {
+            name: &'static str,
+            path: &'static [&'static str],
+            radius: Option<Distance>,
+        }
+
+        let system = duplicated_system();
+        let state = system.state_at(Duration::from_secs(1_000));
+        let stats = SystemStats::from(&system);
+
+        vec![
+            Test {
+                name: "satellite of the first planet",
+                path: &["sun", "jupiter", "io"],
+                radius: Some(Distance::km(10.)),
+            },
+            Test {
+                name: "satellite of the second planet",
+                path: &["sun", "saturn", "io"],
+                radius: Some(Distance::km(20.)),
+            },
+            Test {
+                name: "primary body",
+                path: &["sun"],
+                radius: Some(Distance::ZERO),
+            },
+            Test {
+                name: "path not starting at the primary body",
+                path: &["jupiter", "io"],
+                radius: None,
+            },
+            Test {
+                name: "unknown body",
+                path: &["sun", "jupiter", "europa"],
+                radius: None,
+            },
+            Test {
+                name: "empty path",
+                path: &[],
+                radius: None,
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let path: NamePath = test
+                .path
+                .iter()
+                .map(|name| Name::from_str(name).unwrap())
+                .collect();
+
+            let radius = system
+                .system_by_path(&path)
+                .map(|system| system.orbit.map(|orbit| orbit.radius()).unwrap_or_default());
+            assert_eq!(
+                radius, test.radius,
+                "{}: got radius = {:?}, want {:?}",
+                test.name, radius, test.radius
+            );
+
+            let stats = stats.stats_by_path(&path).map(|stats| stats.radius);
+            assert_eq!(
+                stats, test.radius,
+                "{}: got stats radius = {:?}, want {:?}",
+                test.name, stats, test.radius
+            );
+
+            let got = state.state_by_path(&path).map(|state| &state.body);
+            let want = test.radius.and(path.body());
+            assert_eq!(
+                got, want,
+                "{}: got state = {:?}, want {:?}",
+                test.name, got, want
+            );
+        });
+
+        let jupiter_io = state
+            .state_by_path(&["sun", "jupiter", "io"].map(|name| Name::from_str(name).unwrap()))
+            .unwrap();
+        let saturn_io = state
+            .state_by_path(&["sun", "saturn", "io"].map(|name| Name::from_str(name).unwrap()))
+            .unwrap();
+        assert_ne!(jupiter_io.position, saturn_io.position);
+    }
+
+    #[test]
+    fn name_path_must_serialize_as_a_sequence() {
+        let system = nested_system();
+        let path = system.path(&Name::from_str("moon").unwrap()).unwrap();
+        assert_eq!(path.to_string(), "sun/planet/moon");
+
+        let json = serde_json::to_string(&path).unwrap();
+        assert_eq!(json, r#"["sun","planet","moon"]"#);
+        assert_eq!(serde_json::from_str::<NamePath>(&json).unwrap(), path);
+    }
+
+    #[test]
+    fn try_state_at_must_detect_undefined_states() {
+        struct Test {
+            name: &'static str,
+            system: OrbitalSystem<Ellipse>,
+            output: Result<(), StateError>,
+        }
+
+        let system = |orbitee: Body, orbit: Ellipse| OrbitalSystem {
+            primary: orbitee,
+            orbit: None,
+            secondary: vec![OrbitalSystem {
+                primary: body("planet"),
+                orbit: Some(orbit),
+                secondary: Default::default(),
+            }],
+        };
+
+        let orbit = Ellipse::default().with_semi_major_axis(Distance::km(1_000.));
+
+        vec![
+            Test {
+                name: "consistent system",
+                system: system(body("sun"), orbit),
+                output: Ok(()),
+            },
+            Test {
+                name: "massless orbitee",
+                system: system(body("sun").with_mass(Mass::kg(0.)), orbit),
+                output: Err(StateError::UndefinedPeriod {
+                    body: Name::from_str("planet").unwrap(),
+                    orbitee: Name::from_str("sun").unwrap(),
+                }),
+            },
+            Test {
+                name: "orbit with no size",
+                system: system(body("sun"), orbit.with_semi_major_axis(Distance::ZERO)),
+                output: Err(StateError::UndefinedPeriod {
+                    body: Name::from_str("planet").unwrap(),
+                    orbitee: Name::from_str("sun").unwrap(),
+                }),
+            },
+            Test {
+                name: "unbound orbit",
+                system: system(
+                    body("sun"),
+                    // ratios are clamped to 1 unless deserialized
+                    serde_json::from_str(r#"{"semi_major_axis": "1000 km", "eccentricity": 1.5}"#)
+                        .unwrap(),
+                ),
+                output: Err(StateError::UnboundOrbit(Name::from_str("planet").unwrap())),
+            },
+            Test {
+                name: "parabolic orbit",
+                system: system(body("sun"), orbit.with_eccentricity(Ratio::new_clamped(1.))),
+                output: Err(StateError::UnboundOrbit(Name::from_str("planet").unwrap())),
+            },
+            Test {
+                name: "orbit too small for its period to be represented",
+                system: system(
+                    body("sun"),
+                    orbit.with_semi_major_axis(Distance::meters(1e-110)),
+                ),
+                output: Err(StateError::UndefinedPeriod {
+                    body: Name::from_str("planet").unwrap(),
+                    orbitee: Name::from_str("sun").unwrap(),
+                }),
+            },
+            Test {
+                name: "orbit too big for its period to be represented",
+                system: system(
+                    body("sun"),
+                    orbit.with_semi_major_axis(Distance::meters(1e110)),
+                ),
+                output: Err(StateError::UndefinedPeriod {
+                    body: Name::from_str("planet").unwrap(),
+                    orbitee: Name::from_str("sun").unwrap(),
+                }),
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let output = test
+                .system
+                .try_state_at(Duration::from_secs(1_000))
+                .map(|_| ());
+
+            assert_eq!(
+                output, test.output,
+                "{}: got output = {:?}, want {:?}",
+                test.name, output, test.output
+            );
+        });
+
+        let ephemeris = FixedEphemeris::from(vec![
+            (Duration::from_secs(0), Coords::default().with_x(1e4)),
+            (Duration::from_secs(100), Coords::default().with_y(f64::NAN)),
+        ]);
+
+        let output = OrbitalSystemBuilder::new(body("sun"))
+            .with_satellite(body("probe"), ephemeris, |probe| probe)
+            .build()
+            .unwrap()
+            .try_state_at(Duration::from_secs(50))
+            .map(|_| ());
+
+        let want = Err(StateError::NonFinite {
+            body: Name::from_str("probe").unwrap(),
+            field: "position",
+        });
+
+        assert_eq!(output, want, "got output = {output:?}, want {want:?}");
+    }
+
+    #[test]
+    fn batch_must_match_generator() {
+        let system = nested_system();
+        let step = Duration::from_secs(600);
+
+        let want: Vec<_> = OrbitalSystemStateGenerator::from(&system)
+            .with_step(step)
+            .take(10)
+            .collect();
+
+        let times: Vec<_> = (0..10).map(|index| step * index).collect();
+        let got = OrbitalSystemStateGenerator::from(&system).batch(&times);
+
+        assert_eq!(got, want);
+    }
+
+    #[test]
+    fn seek_must_match_state_at() {
+        let system = nested_system();
+        let start = Duration::from_secs(3_600);
+        let to = Duration::from_secs(12_345);
+
+        let mut generator = OrbitalSystemStateGenerator::from(&system)
+            .with_step(Duration::from_secs(600))
+            .with_start(start);
+
+        assert_eq!(generator.last_time(), None);
+        assert_eq!(generator.next(), Some(system.state_at(start)));
+        assert_eq!(generator.last_time(), Some(start));
+
+        generator.seek(to);
+        assert_eq!(generator.next(), Some(system.state_at(to)));
+        assert_eq!(generator.last_time(), Some(to));
+
+        generator.rewind();
+        assert_eq!(generator.next(), Some(system.state_at(start)));
+        assert_eq!(generator.last_time(), Some(start));
+    }
+
+    #[test]
+    fn state_at_epoch_must_not_fail() {
+        let system = nested_system();
+        let periods = PeriodCache::from(&system);
+        let time = Duration::from_secs(12_345);
+
+        assert_eq!(
+            system.state_at_epoch(Epoch::J2000 + time),
+            system.state_at(time)
+        );
+        assert_eq!(
+            system.state_at_epoch(Epoch::J2000),
+            system.state_at(Duration::ZERO)
+        );
+        assert_eq!(
+            system.try_state_at_epoch_cached(Epoch::J2000 + time, &periods),
+            Ok(system.state_at(time))
+        );
+    }
+
+    #[test]
+    fn try_state_at_epoch_must_reject_out_of_range_epochs() {
+        let system = nested_system();
+        let periods = PeriodCache::from(&system);
+
+        [f64::NAN, 1e30, -1e30]
+            .into_iter()
+            .map(Epoch::from_seconds)
+            .for_each(|epoch| {
+                // NaN epochs are not equal to themselves, hence the pattern matching.
+                let output = system.try_state_at_epoch_cached(epoch, &periods);
+                assert!(
+                    matches!(output, Err(StateError::OutOfRange(_))),
+                    "{epoch:?}: got output = {output:?}"
+                );
+            });
+    }
+
+    #[test]
+    fn state_before_epoch_must_mirror_the_one_after() {
+        const ABS_ERROR: f64 = 1e-6;
+
+        let star = body("star").with_mass(Mass::kg(2e30));
+        let planet = body("planet");
+        let orbit = Ellipse::default()
+            .with_semi_major_axis(Distance::km(1.5e8))
+            .with_eccentricity(Ratio::try_new(0.5).unwrap());
+
+        let period = orbit.period(&star);
+        let system = OrbitalSystem {
+            primary: star,
+            orbit: None,
+            secondary: vec![OrbitalSystem {
+                primary: planet,
+                orbit: Some(OrbitKind::from(orbit)),
+                secondary: Default::default(),
+            }],
+        };
+
+        struct Test {
+            name: &'static str,
+            before: Duration,
+            after: Duration,
+        }
+
+        vec![
+            Test {
+                name: "half a period",
+                before: period / 2,
+                after: period / 2,
+            },
+            Test {
+                name: "a quarter of a period",
+                before: period / 4,
+                after: period * 3 / 4,
+            },
+            Test {
+                name: "several periods and a quarter",
+                before: period * 5 + period / 4,
+                after: period * 3 / 4,
+            },
+            Test {
+                name: "whole periods",
+                before: period * 3,
+                after: Duration::ZERO,
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let before = system.state_at_epoch(Epoch::J2000 - test.before);
+            let after = system.state_at(test.after);
+
+            let (before, after) = (&before.secondary[0], &after.secondary[0]);
+            assert!(
+                before.position.distance(&after.position) <= ABS_ERROR * after.position.magnitude(),
+                "{}: got position = {:?}, want {:?}",
+                test.name,
+                before.position,
+                after.position
+            );
+        });
+    }
+
+    #[test]
+    fn take_until_must_not_fail() {
+        let system = nested_system();
+
+        struct Test {
+            name: &'static str,
+            start: Duration,
+            step: Duration,
+            until: Duration,
+            len: usize,
+        }
+
+        vec![
+            Test {
+                name: "span multiple of the step must exclude the end",
+                start: Duration::ZERO,
+                step: Duration::from_secs(600),
+                until: Duration::from_secs(6_000),
+                len: 10,
+            },
+            Test {
+                name: "span not multiple of the step must round up",
+                start: Duration::ZERO,
+                step: Duration::from_secs(600),
+                until: Duration::from_secs(6_001),
+                len: 11,
+            },
+            Test {
+                name: "span from a custom start",
+                start: Duration::from_secs(1_000),
+                step: Duration::from_secs(600),
+                until: Duration::from_secs(7_000),
+                len: 10,
+            },
+            Test {
+                name: "end before the start",
+                start: Duration::from_secs(1_000),
+                step: Duration::from_secs(600),
+                until: Duration::from_secs(500),
+                len: 0,
+            },
+            Test {
+                name: "zero step must yield a single state",
+                start: Duration::ZERO,
+                step: Duration::ZERO,
+                until: Duration::from_secs(6_000),
+                len: 1,
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let states = OrbitalSystemStateGenerator::from(&system)
+                .with_step(test.step)
+                .with_start(test.start)
+                .take_until(test.until);
+
+            assert_eq!(states.len(), test.len, "{}", test.name);
+
+            let states: Vec<_> = states.collect();
+            assert_eq!(states.len(), test.len, "{}", test.name);
+
+            states.iter().enumerate().for_each(|(index, state)| {
+                let time = test.start + test.step * index as u32;
+                assert_eq!(
+                    state,
+                    &system.state_at(time),
+                    "{}: state #{index}",
+                    test.name
+                );
+            });
+        });
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn par_collect_must_match_generator() {
+        let system = nested_system();
+        let step = Duration::from_secs(600);
+        let until = Duration::from_secs(24 * 3600);
+
+        let want: Vec<_> = OrbitalSystemStateGenerator::from(&system)
+            .with_step(step)
+            .take((until.as_secs() / step.as_secs()) as usize)
+            .collect();
+
+        let got = OrbitalSystemStateGenerator::from(&system)
+            .with_step(step)
+            .par_collect(until);
+
+        assert_eq!(got.len(), want.len());
+        got.iter()
+            .zip(want.iter())
+            .enumerate()
+            .for_each(|(index, (got, want))| assert_eq!(got, want, "state #{index}"));
+    }
+
+    #[test]
+    fn builder_must_nest_satellites() {
+        let spin = Rotation {
+            period: Duration::from_secs(3600),
+            ..Default::default()
+        };
+
+        let system = OrbitalSystemBuilder::new(
+            body("sun")
+                .with_radius(Distance::km(1.))
+                .with_mass(Mass::kg(1.))
+                .with_spin(spin.clone())
+                .with_luminosity(Luminosity::SUN),
+        )
+        .with_satellite(
+            body("planet")
+                .with_radius(Distance::km(1.))
+                .with_mass(Mass::kg(1.))
+                .with_spin(spin.clone()),
+            Circle::default().with_radius(Distance::km(1_000.)),
+            |satellites| {
+                satellites.with_satellite(
+                    body("moon")
+                        .with_radius(Distance::meters(1.))
+                        .with_mass(Mass::kg(1.))
+                        .with_spin(spin.clone()),
+                    Circle::default().with_radius(Distance::km(10.)),
+                    |satellites| satellites,
+                )
+            },
+        )
+        .build()
+        .unwrap();
+
+        assert_eq!(system.secondary.len(), 1);
+        assert_eq!(system.secondary[0].secondary.len(), 1);
+        assert!(system.system(&Name::from_str("moon").unwrap()).is_some());
+    }
+
+    #[test]
+    fn builder_must_validate_the_system() {
+        let errors = OrbitalSystemBuilder::<Circle>::new(body("sun").with_mass(Mass::kg(0.)))
+            .build()
+            .unwrap_err();
+
+        assert_eq!(
+            errors,
+            vec![SystemError::ZeroMass(Name::from_str("sun").unwrap())]
+        );
+    }
+
+    #[test]
+    fn subsolar_point_must_not_fail() {
+        const ABS_ERROR: f64 = 0.000001;
+
+        struct Test {
+            name: &'static str,
+            spin: Rotation,
+            at: Duration,
+            longitude: f64,
+        }
+
+        vec![
+            Test {
+                name: "non rotating body must face the sun from the back",
+                spin: Rotation::default(),
+                at: Duration::from_secs(3600),
+                longitude: PI,
+            },
+            Test {
+                name: "a quarter of rotation must move the subsolar point a quarter back",
+                spin: Rotation {
+                    period: Duration::from_secs(4 * 3600),
+                    ..Default::default()
+                },
+                at: Duration::from_secs(3600),
+                longitude: PI / 2.,
+            },
+            Test {
+                name: "three quarters of rotation must move the subsolar point to the other side",
+                spin: Rotation {
+                    period: Duration::from_secs(4 * 3600),
+                    ..Default::default()
+                },
+                at: Duration::from_secs(3 * 3600),
+                longitude: -PI / 2.,
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let point = system(test.spin)
+                .subsolar_point(&Name::from_str("planet").unwrap(), test.at)
+                .unwrap();
+
+            let longitude = f64::from(point.longitude);
+            let latitude = f64::from(point.latitude);
+
+            // the antimeridian is both PI and -PI, hence the signed difference between both.
+            let diff = (longitude - test.longitude + PI).rem_euclid(2. * PI) - PI;
+
+            assert!(
+                approx_eq(diff, 0., ABS_ERROR),
+                "{}: got longitude = {}, want {}",
+                test.name,
+                longitude,
+                test.longitude
+            );
+
+            assert!(
+                approx_eq(latitude, 0., ABS_ERROR),
+                "{}: got latitude = {}, want 0",
+                test.name,
+                latitude,
+            );
+        });
+    }
+
+    #[test]
+    fn subsolar_point_of_luminous_body_must_be_none() {
+        let point = system(Rotation::default())
+            .subsolar_point(&Name::from_str("sun").unwrap(), Duration::ZERO);
+
+        assert!(point.is_none(), "got subsolar point = {point:?}, want None");
+    }
+
+    #[test]
+    fn solar_day_must_not_fail() {
+        const REL_ERROR: f64 = 1e-9;
+
+        let sun = system(Rotation::default()).primary;
+        let year = Circle::default()
+            .with_radius(Distance::km(1_000.))
+            .period(&sun)
+            .as_secs_f64();
+
+        struct Test {
+            name: &'static str,
+            spin: Rotation,
+            clockwise_orbit: bool,
+            solar_day: Option<f64>,
+        }
+
+        vec![
+            Test {
+                name: "non rotating body must see a single day per year",
+                spin: Rotation::default(),
+                clockwise_orbit: false,
+                solar_day: Some(year),
+            },
+            Test {
+                name: "prograde spin must lose a day per year",
+                spin: Rotation {
+                    period: Duration::from_secs_f64(year / 4.),
+                    ..Default::default()
+                },
+                clockwise_orbit: false,
+                solar_day: Some(year / 3.),
+            },
+            Test {
+                name: "retrograde spin must gain a day per year",
+                spin: Rotation {
+                    period: Duration::from_secs_f64(year / 4.),
+                    clockwise: true,
+                    ..Default::default()
+                },
+                clockwise_orbit: false,
+                solar_day: Some(year / 5.),
+            },
+            Test {
+                name: "spin against a clockwise orbit must gain a day per year",
+                spin: Rotation {
+                    period: Duration::from_secs_f64(year / 4.),
+                    ..Default::default()
+                },
+                clockwise_orbit: true,
+                solar_day: Some(year / 5.),
+            },
+            Test {
+                name: "tidally locked body must have no solar day",
+                spin: Rotation {
+                    frequency: Some(Frequency::hz(year.recip())),
+                    ..Default::default()
+                },
+                clockwise_orbit: false,
+                solar_day: None,
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let mut system = system(test.spin);
+            if let Some(orbit) = system.secondary[0].orbit.as_mut() {
+                orbit.clockwise = test.clockwise_orbit;
+            }
+
+            let solar_day = system
+                .solar_day(&Name::from_str("planet").unwrap())
+                .map(|day| day.as_secs_f64());
+
+            match (solar_day, test.solar_day) {
+                (Some(got), Some(want)) => assert!(
+                    approx_eq(got, want, want * REL_ERROR),
+                    "{}: got solar day = {got}, want {want}",
+                    test.name
+                ),
+                (got, want) => assert_eq!(got, want, "{}", test.name),
+            }
+        });
+
+        let sun = system(Rotation::default()).solar_day(&Name::from_str("sun").unwrap());
+        assert!(sun.is_none(), "got solar day = {sun:?}, want None");
+    }
+
+    #[test]
+    fn local_solar_time_must_not_fail() {
+        const REL_ERROR: f64 = 1e-6;
+
+        let year = Circle::default()
+            .with_radius(Distance::km(1_000.))
+            .period(&system(Rotation::default()).primary)
+            .as_secs_f64();
+
+        let system = system(Rotation {
+            period: Duration::from_secs_f64(year / 4.),
+            ..Default::default()
+        });
+
+        let planet = Name::from_str("planet").unwrap();
+        let day = system.solar_day(&planet).unwrap().as_secs_f64();
+
+        struct Test {
+            name: &'static str,
+            at: f64,
+            longitude: f64,
+            time: f64,
+        }
+
+        vec![
+            Test {
+                name: "the side facing the sun must be at noon",
+                at: 0.,
+                longitude: PI,
+                time: 0.5,
+            },
+            Test {
+                name: "the side facing away from the sun must be at midnight",
+                at: 0.,
+                longitude: 0.,
+                time: 0.,
+            },
+            Test {
+                name: "the eastern longitudes must be in the morning before noon",
+                at: 0.,
+                longitude: PI / 2.,
+                time: 0.25,
+            },
+            Test {
+                name: "the western longitudes must be in the evening after noon",
+                at: 0.,
+                longitude: -PI / 2.,
+                time: 0.75,
+            },
+            Test {
+                name: "a quarter of a solar day must move the clock a quarter forward",
+                at: 0.25,
+                longitude: 0.,
+                time: 0.25,
+            },
+            Test {
+                name: "a whole solar day must move the clock back to the same time",
+                at: 1.,
+                longitude: PI / 2.,
+                time: 0.25,
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let time = system
+                .local_solar_time(
+                    &planet,
+                    Duration::from_secs_f64(test.at * day),
+                    test.longitude.into(),
+                )
+                .unwrap()
+                .as_secs_f64();
+
+            let want = test.time * day;
+            assert!(
+                approx_eq(time, want, day * REL_ERROR),
+                "{}: got local solar time = {time}, want {want}",
+                test.name
+            );
+        });
+    }
+
+    #[test]
+    fn ground_track_must_not_fail() {
+        const ABS_ERROR: f64 = 0.000001;
+
+        let orbit = Circle::default().with_radius(Distance::km(42_164.));
+        let period = orbit.period(&Body {
+            mass: Mass::kg(5.97219e24),
+            ..body("planet")
+        });
+
+        struct Test {
+            name: &'static str,
+            spin: Rotation,
+            longitude: f64,
+        }
+
+        vec![
+            Test {
+                name: "non rotating body must follow the orbit",
+                spin: Rotation::default(),
+                longitude: PI / 4.,
+            },
+            Test {
+                name: "synchronous rotation must remain over the same longitude",
+                spin: Rotation {
+                    period,
+                    ..Default::default()
+                },
+                longitude: 0.,
+            },
+            Test {
+                name: "retrograde rotation must double the longitude",
+                spin: Rotation {
+                    period,
+                    clockwise: true,
+                    ..Default::default()
+                },
+                longitude: PI / 2.,
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let system = OrbitalSystem {
+                primary: Body {
+                    mass: Mass::kg(5.97219e24),
+                    spin: test.spin,
+                    ..body("planet")
+                },
+                orbit: None,
+                secondary: vec![OrbitalSystem {
+                    primary: body("satellite"),
+                    orbit: Some(orbit),
+                    secondary: Default::default(),
+                }],
+            };
+
+            let step = period / 8;
+            let track = system.ground_track(
+                &Name::from_str("satellite").unwrap(),
+                Duration::ZERO..step * 8,
+                step,
+            );
+
+            assert_eq!(
+                track.len(),
+                8,
+                "{}: got {} points, want 8",
+                test.name,
+                track.len()
+            );
+
+            let longitude = f64::from(track[1].longitude);
+            assert!(
+                approx_eq(longitude, test.longitude, ABS_ERROR),
+                "{}: got longitude = {}, want {}",
+                test.name,
+                longitude,
+                test.longitude
+            );
+        });
+    }
+
+    #[test]
+    fn ground_track_of_unknown_satellite_must_be_empty() {
+        let track = system(Rotation::default()).ground_track(
+            &Name::from_str("sun").unwrap(),
+            Duration::ZERO..Duration::from_secs(60),
+            Duration::from_secs(1),
+        );
+
+        assert!(track.is_empty(), "got track = {track:?}, want empty");
+    }
+
+    #[test]
+    fn terminator_must_be_perpendicular_to_the_subsolar_point() {
+        const ABS_ERROR: f64 = 0.000001;
+
+        let system = system(Rotation {
+            period: Duration::from_secs(4 * 3600),
+            ..Default::default()
+        });
+
+        let name = Name::from_str("planet").unwrap();
+        let at = Duration::from_secs(3600);
+
+        let subsolar = Coords::from(system.subsolar_point(&name, at).unwrap());
+
+        let terminator = system.terminator(&name, at, 16);
+        assert_eq!(terminator.points.len(), 16);
+
+        terminator.points.iter().for_each(|point| {
+            let radius = point.magnitude();
+            assert!(
+                approx_eq(radius, Distance::km(1.).as_meters(), ABS_ERROR),
+                "got radius = {radius}, want 1000",
+            );
+
+            let cos = point.unit().x() * subsolar.unit().x()
+                + point.unit().y() * subsolar.unit().y()
+                + point.unit().z() * subsolar.unit().z();
+
+            assert!(
+                approx_eq(cos, 0., ABS_ERROR),
+                "got cos = {cos}, want 0 for point {point:?}",
+            );
+        });
     }
 }
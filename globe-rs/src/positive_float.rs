@@ -0,0 +1,563 @@
+use std::{
+    fmt::Display,
+    ops::{Add, Div, Mul, Sub},
+};
+
+use serde::{Deserialize, Serialize};
+
+/// An error while building a [PositiveFloat] from an arbitrary value.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum NegativeValueError {
+    /// The value is lower than zero.
+    Negative(f64),
+    /// The value is not a number.
+    NotANumber,
+    /// The value is infinite.
+    Infinite,
+}
+
+impl Display for NegativeValueError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            NegativeValueError::Negative(value) => {
+                write!(f, "the value must not be negative, got {value}")
+            }
+            NegativeValueError::NotANumber => write!(f, "the value must be a number"),
+            NegativeValueError::Infinite => write!(f, "the value must be finite"),
+        }
+    }
+}
+
+impl std::error::Error for NegativeValueError {}
+
+/// A [f64] that is always positive, which includes zero and infinity but never NaN.
+///
+/// Since NaN is not representable, positive floats are totally ordered, and so are all the units
+/// built on top of them. Checked conversions only accept finite values, while saturating ones take
+/// NaN as infinity, so an undefined value is never mistaken for a finite one.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(try_from = "f64")]
+pub struct PositiveFloat(pub(crate) f64);
+
+impl TryFrom<f64> for PositiveFloat {
+    type Error = NegativeValueError;
+
+    fn try_from(value: f64) -> Result<Self, Self::Error> {
+        if value.is_nan() {
+            return Err(NegativeValueError::NotANumber);
+        }
+
+        if value < 0. {
+            return Err(NegativeValueError::Negative(value));
+        }
+
+        if value.is_infinite() {
+            return Err(NegativeValueError::Infinite);
+        }
+
+        Ok(Self::new_saturating(value))
+    }
+}
+
+impl From<PositiveFloat> for f64 {
+    fn from(value: PositiveFloat) -> Self {
+        value.0
+    }
+}
+
+impl Eq for PositiveFloat {}
+
+impl Ord for PositiveFloat {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.0.total_cmp(&other.0)
+    }
+}
+
+impl PartialOrd for PositiveFloat {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Display for PositiveFloat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl Add for PositiveFloat {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self::new_saturating(self.0 + rhs.0)
+    }
+}
+
+impl Sub for PositiveFloat {
+    type Output = Self;
+
+    /// Returns the difference between self and rhs, saturating at zero.
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self::new_saturating(self.0 - rhs.0)
+    }
+}
+
+impl Mul for PositiveFloat {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        Self::new_saturating(self.0 * rhs.0)
+    }
+}
+
+impl Div for PositiveFloat {
+    type Output = Self;
+
+    /// Returns the quotient of self and rhs, being infinite if rhs is zero.
+    fn div(self, rhs: Self) -> Self::Output {
+        Self::new_saturating(self.0 / rhs.0)
+    }
+}
+
+impl PositiveFloat {
+    pub const ZERO: Self = Self(0.);
+
+    /// Returns the positive float closest to the given value, taking NaN as infinity.
+    pub fn new_saturating(value: f64) -> Self {
+        if value.is_nan() {
+            Self(f64::INFINITY)
+        } else if value > 0. {
+            Self(value)
+        } else {
+            Self::ZERO
+        }
+    }
+
+    /// Returns the positive float for the absolute of the given value, taking NaN as infinity.
+    pub fn from_abs(value: f64) -> Self {
+        Self::new_saturating(value.abs())
+    }
+
+    /// Returns the positive float as a [f64].
+    pub fn get(&self) -> f64 {
+        self.0
+    }
+
+    /// Returns the difference between self and rhs, or [None] if rhs is greater than self.
+    pub fn checked_sub(self, rhs: Self) -> Option<Self> {
+        (rhs <= self).then(|| self - rhs)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cmp::Ordering;
+
+    use crate::{
+        geographic::Altitude, tests::approx_eq, Distance, Luminosity, Mass, NegativeValueError,
+        PositiveFloat, Radian, Ratio, Velocity,
+    };
+
+    #[test]
+    fn positive_float_must_not_be_negative_nor_nan() {
+        struct Test {
+            name: &'static str,
+            input: f64,
+            checked: Result<f64, NegativeValueError>,
+            saturating: f64,
+            abs: f64,
+        }
+
+        vec![
+            Test {
+                name: "positive",
+                input: 1.5,
+                checked: Ok(1.5),
+                saturating: 1.5,
+                abs: 1.5,
+            },
+            Test {
+                name: "zero",
+                input: 0.,
+                checked: Ok(0.),
+                saturating: 0.,
+                abs: 0.,
+            },
+            Test {
+                name: "negative zero",
+                input: -0.,
+                checked: Ok(0.),
+                saturating: 0.,
+                abs: 0.,
+            },
+            Test {
+                name: "negative",
+                input: -1.5,
+                checked: Err(NegativeValueError::Negative(-1.5)),
+                saturating: 0.,
+                abs: 1.5,
+            },
+            Test {
+                name: "infinity",
+                input: f64::INFINITY,
+                checked: Err(NegativeValueError::Infinite),
+                saturating: f64::INFINITY,
+                abs: f64::INFINITY,
+            },
+            Test {
+                name: "negative infinity",
+                input: f64::NEG_INFINITY,
+                checked: Err(NegativeValueError::Negative(f64::NEG_INFINITY)),
+                saturating: 0.,
+                abs: f64::INFINITY,
+            },
+            Test {
+                name: "not a number",
+                input: f64::NAN,
+                checked: Err(NegativeValueError::NotANumber),
+                saturating: f64::INFINITY,
+                abs: f64::INFINITY,
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let checked = PositiveFloat::try_from(test.input).map(|value| value.get());
+            assert_eq!(
+                checked, test.checked,
+                "{}: got checked = {checked:?}, want {:?}",
+                test.name, test.checked
+            );
+
+            let saturating = PositiveFloat::new_saturating(test.input);
+            assert_eq!(
+                saturating,
+                PositiveFloat(test.saturating),
+                "{}: got saturating = {saturating:?}, want {:?}",
+                test.name,
+                test.saturating
+            );
+            assert!(
+                saturating.get().is_sign_positive(),
+                "{}: got a negative zero",
+                test.name
+            );
+
+            let abs = PositiveFloat::from_abs(test.input);
+            assert_eq!(
+                abs,
+                PositiveFloat(test.abs),
+                "{}: got abs = {abs:?}, want {:?}",
+                test.name,
+                test.abs
+            );
+        });
+    }
+
+    #[test]
+    fn arithmetic_must_preserve_invariant() {
+        struct Test {
+            name: &'static str,
+            output: PositiveFloat,
+            want: f64,
+        }
+
+        let zero = PositiveFloat::ZERO;
+        let one = PositiveFloat(1.);
+        let two = PositiveFloat(2.);
+        let infinity = PositiveFloat(f64::INFINITY);
+
+        vec![
+            Test {
+                name: "addition",
+                output: one + two,
+                want: 3.,
+            },
+            Test {
+                name: "subtraction",
+                output: two - one,
+                want: 1.,
+            },
+            Test {
+                name: "subtraction below zero",
+                output: one - two,
+                want: 0.,
+            },
+            Test {
+                name: "infinity minus infinity",
+                output: infinity - infinity,
+                want: f64::INFINITY,
+            },
+            Test {
+                name: "multiplication",
+                output: two * two,
+                want: 4.,
+            },
+            Test {
+                name: "zero times infinity",
+                output: zero * infinity,
+                want: f64::INFINITY,
+            },
+            Test {
+                name: "division",
+                output: one / two,
+                want: 0.5,
+            },
+            Test {
+                name: "division by zero",
+                output: one / zero,
+                want: f64::INFINITY,
+            },
+            Test {
+                name: "zero divided by zero",
+                output: zero / zero,
+                want: f64::INFINITY,
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            assert_eq!(
+                test.output,
+                PositiveFloat(test.want),
+                "{}: got {:?}, want {}",
+                test.name,
+                test.output,
+                test.want
+            );
+        });
+    }
+
+    #[test]
+    fn units_must_not_hold_nan_nor_negatives() {
+        struct Test {
+            name: &'static str,
+            nan: f64,
+            negative: f64,
+            want_negative: f64,
+        }
+
+        vec![
+            Test {
+                name: "distance",
+                nan: Distance::meters(f64::NAN).as_meters(),
+                negative: Distance::meters(-1.).as_meters(),
+                want_negative: 0.,
+            },
+            Test {
+                name: "mass",
+                nan: Mass::kg(f64::NAN).as_kg(),
+                negative: Mass::kg(-1.).as_kg(),
+                want_negative: 0.,
+            },
+            Test {
+                name: "velocity",
+                nan: Velocity::meters_sec(f64::NAN).as_meters_sec(),
+                negative: Velocity::meters_sec(-1.).as_meters_sec(),
+                want_negative: 0.,
+            },
+            Test {
+                name: "luminosity",
+                nan: Luminosity::watts(f64::NAN).as_watts(),
+                negative: Luminosity::watts(-1.).as_watts(),
+                want_negative: 0.,
+            },
+            Test {
+                name: "radian",
+                nan: Radian::from(f64::NAN).as_f64(),
+                negative: Radian::from(-1.).as_f64(),
+                want_negative: Radian::TWO_PI.as_f64() - 1.,
+            },
+            Test {
+                name: "altitude",
+                nan: Altitude::from(f64::NAN).into(),
+                negative: Altitude::from(-1.).into(),
+                want_negative: 1.,
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            assert_eq!(
+                test.nan,
+                f64::INFINITY,
+                "{}: got nan = {}, want infinity",
+                test.name,
+                test.nan
+            );
+            assert_eq!(
+                test.negative, test.want_negative,
+                "{}: got negative = {}, want {}",
+                test.name, test.negative, test.want_negative
+            );
+        });
+    }
+
+    #[test]
+    fn units_must_be_totally_ordered() {
+        struct Test {
+            name: &'static str,
+            ordering: Ordering,
+            want: Ordering,
+        }
+
+        vec![
+            Test {
+                name: "lower distance",
+                ordering: Distance::km(1.).cmp(&Distance::km(2.)),
+                want: Ordering::Less,
+            },
+            Test {
+                name: "equal distances",
+                ordering: Distance::meters(1_000.).cmp(&Distance::km(1.)),
+                want: Ordering::Equal,
+            },
+            Test {
+                name: "greater velocity",
+                ordering: Velocity::meters_sec(2.).cmp(&Velocity::meters_sec(1.)),
+                want: Ordering::Greater,
+            },
+            Test {
+                name: "equal velocities",
+                ordering: Velocity::meters_sec(1.).cmp(&Velocity::meters_sec(1.)),
+                want: Ordering::Equal,
+            },
+            Test {
+                name: "equal masses",
+                ordering: Mass::kg(1.).cmp(&Mass::kg(1.)),
+                want: Ordering::Equal,
+            },
+            Test {
+                name: "lower luminosity",
+                ordering: Luminosity::ZERO.cmp(&Luminosity::SUN),
+                want: Ordering::Less,
+            },
+            Test {
+                name: "equal radians",
+                ordering: Radian::from(-1.).cmp(&Radian::from(Radian::TWO_PI.as_f64() - 1.)),
+                want: Ordering::Equal,
+            },
+            Test {
+                name: "not a number is infinite",
+                ordering: Mass::kg(f64::NAN).cmp(&Mass::kg(f64::INFINITY)),
+                want: Ordering::Equal,
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            assert_eq!(
+                test.ordering, test.want,
+                "{}: got ordering = {:?}, want {:?}",
+                test.name, test.ordering, test.want
+            );
+        });
+
+        let velocities = [1., 3., 3., 2.].map(Velocity::meters_sec);
+        assert_eq!(velocities.iter().max(), Some(&Velocity::meters_sec(3.)));
+        assert_eq!(velocities.iter().min(), Some(&Velocity::meters_sec(1.)));
+    }
+
+    #[test]
+    fn units_must_be_summable() {
+        let distance: Distance = [1., 2., 3.].map(Distance::km).into_iter().sum();
+        assert_eq!(distance, Distance::km(6.));
+
+        let luminosity: Luminosity = [Luminosity::SUN; 2].into_iter().sum();
+        assert_eq!(luminosity, Luminosity::SUN * 2.);
+
+        let radian: Radian = [Radian::from(3.), Radian::from(4.)].into_iter().sum();
+        assert!(
+            approx_eq(radian.as_f64(), 7. - Radian::TWO_PI.as_f64(), 1e-12),
+            "got radian = {radian:?}, want the sum wrapped"
+        );
+
+        let ratio: Ratio = [0.5, 0.5, 0.5].map(Ratio::from).into_iter().product();
+        assert_eq!(ratio, Ratio::from(0.125));
+
+        let empty: Ratio = std::iter::empty().product();
+        assert_eq!(empty, Ratio::from(1.));
+    }
+
+    #[test]
+    fn deserialize_must_reject_negatives() {
+        struct Test {
+            name: &'static str,
+            input: &'static str,
+            output: Option<PositiveFloat>,
+        }
+
+        vec![
+            Test {
+                name: "positive",
+                input: "1.5",
+                output: Some(PositiveFloat(1.5)),
+            },
+            Test {
+                name: "negative",
+                input: "-1.5",
+                output: None,
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let output = serde_json::from_str::<PositiveFloat>(test.input).ok();
+            assert_eq!(
+                output, test.output,
+                "{}: got output = {:?}, want {:?}",
+                test.name, output, test.output
+            );
+        });
+    }
+
+    #[test]
+    fn units_must_reject_negatives_and_non_finite_values_on_deserialize() {
+        struct Test {
+            name: &'static str,
+            output: Option<f64>,
+            want: Option<f64>,
+        }
+
+        vec![
+            Test {
+                name: "positive distance",
+                output: serde_json::from_str::<Distance>(r#""1.5 km""#)
+                    .map(|distance| distance.as_km())
+                    .ok(),
+                want: Some(1.5),
+            },
+            Test {
+                name: "negative distance",
+                output: serde_json::from_str::<Distance>(r#""-1.5 km""#)
+                    .map(|distance| distance.as_km())
+                    .ok(),
+                want: None,
+            },
+            Test {
+                name: "infinite mass",
+                output: serde_json::from_str::<Mass>(r#""inf kg""#)
+                    .map(|mass| mass.as_kg())
+                    .ok(),
+                want: None,
+            },
+            Test {
+                name: "not a number velocity",
+                output: serde_json::from_str::<Velocity>(r#""NaN m/s""#)
+                    .map(|velocity| velocity.as_meters_sec())
+                    .ok(),
+                want: None,
+            },
+            Test {
+                name: "negative bare luminosity",
+                output: serde_json::from_str::<Luminosity>("-1")
+                    .map(|luminosity| luminosity.as_watts())
+                    .ok(),
+                want: None,
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            assert_eq!(
+                test.output, test.want,
+                "{}: got output = {:?}, want {:?}",
+                test.name, test.output, test.want
+            );
+        });
+    }
+}
@@ -0,0 +1,154 @@
+//! Ready to use orbital systems with published values of real bodies.
+
+use std::{str::FromStr, time::Duration};
+
+use alvidir::name::Name;
+
+use crate::{
+    cartesian::shape::Ellipse, Body, Distance, Luminosity, Mass, OrbitalSystem,
+    OrbitalSystemBuilder, Ratio, Rotation,
+};
+
+/// Returns the solar system, including the Sun, the eight planets, the Moon and Pluto.
+pub fn solar_system() -> OrbitalSystem<Ellipse> {
+    OrbitalSystemBuilder::new(sun())
+        .with_satellite(
+            body("Mercury", 2_439.7, 3.3011e23, 1_407.6, false),
+            orbit(57_909_050., 0.2056),
+            |satellites| satellites,
+        )
+        .with_satellite(
+            body("Venus", 6_051.8, 4.8675e24, 5_832.5, true),
+            orbit(108_208_000., 0.0068),
+            |satellites| satellites,
+        )
+        .with_satellite(earth(), earth_orbit(), |satellites| {
+            satellites.with_satellite(moon(), moon_orbit(), |satellites| satellites)
+        })
+        .with_satellite(
+            body("Mars", 3_389.5, 6.4171e23, 24.6229, false),
+            orbit(227_939_366., 0.0934),
+            |satellites| satellites,
+        )
+        .with_satellite(
+            body("Jupiter", 69_911., 1.8982e27, 9.925, false),
+            orbit(778_479_000., 0.0489),
+            |satellites| satellites,
+        )
+        .with_satellite(
+            body("Saturn", 58_232., 5.6834e26, 10.656, false),
+            orbit(1_433_530_000., 0.0565),
+            |satellites| satellites,
+        )
+        .with_satellite(
+            body("Uranus", 25_362., 8.681e25, 17.24, true),
+            orbit(2_870_972_000., 0.0457),
+            |satellites| satellites,
+        )
+        .with_satellite(
+            body("Neptune", 24_622., 1.02413e26, 16.11, false),
+            orbit(4_500_000_000., 0.0113),
+            |satellites| satellites,
+        )
+        .with_satellite(
+            body("Pluto", 1_188.3, 1.303e22, 153.2928, true),
+            orbit(5_906_380_000., 0.2488),
+            |satellites| satellites,
+        )
+        .build()
+        .expect("the solar system should be consistent")
+}
+
+/// Returns the system made of the Earth and the Moon.
+pub fn earth_moon() -> OrbitalSystem<Ellipse> {
+    OrbitalSystemBuilder::new(earth())
+        .with_satellite(moon(), moon_orbit(), |satellites| satellites)
+        .build()
+        .expect("the earth-moon system should be consistent")
+}
+
+/// Returns the system made of the Sun and the Earth.
+pub fn sun_earth() -> OrbitalSystem<Ellipse> {
+    OrbitalSystemBuilder::new(sun())
+        .with_satellite(earth(), earth_orbit(), |satellites| satellites)
+        .build()
+        .expect("the sun-earth system should be consistent")
+}
+
+fn sun() -> Body {
+    body("Sun", 696_340., 1.9891e30, 609.12, false).with_luminosity(Luminosity::SUN)
+}
+
+fn earth() -> Body {
+    body("Earth", 6_371., 5.97219e24, 23.9345, false)
+}
+
+fn earth_orbit() -> Ellipse {
+    orbit(149_598_023., 0.0167)
+}
+
+fn moon() -> Body {
+    body("Moon", 1_737.4, 7.342e22, 655.72, false)
+}
+
+fn moon_orbit() -> Ellipse {
+    orbit(384_399., 0.0549)
+}
+
+/// Returns a body with the given radius in kilometers, mass in kilograms and spin period in
+/// hours.
+fn body(name: &str, radius: f64, mass: f64, spin: f64, clockwise: bool) -> Body {
+    Body::new(Name::from_str(name).expect("body names should not be empty"))
+        .with_radius(Distance::km(radius))
+        .with_mass(Mass::kg(mass))
+        .with_spin(Rotation {
+            period: Duration::from_secs_f64(spin * 3_600.),
+            clockwise,
+            ..Default::default()
+        })
+}
+
+/// Returns an orbit with the given semi-major axis in kilometers and eccentricity.
+fn orbit(semi_major_axis: f64, eccentricity: f64) -> Ellipse {
+    Ellipse::default()
+        .with_semi_major_axis(Distance::km(semi_major_axis))
+        .with_eccentricity(Ratio::from(eccentricity))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use alvidir::name::Name;
+
+    use crate::{
+        presets::{earth_moon, solar_system, sun_earth},
+        SystemStats,
+    };
+
+    const SECONDS_PER_DAY: f64 = 86_400.;
+
+    #[test]
+    fn earth_orbital_period_must_be_a_year() {
+        let earth = Name::from_str("Earth").unwrap();
+        vec![solar_system(), sun_earth()]
+            .into_iter()
+            .for_each(|system| {
+                let stats = SystemStats::from(&system);
+                let period = stats.stats(&earth).unwrap().orbital_period.unwrap();
+                let days = period.as_secs_f64() / SECONDS_PER_DAY;
+
+                assert!(
+                    (days - 365.25).abs() <= 365.25 * 0.01,
+                    "got orbital period = {days} days, want 365.25"
+                );
+            });
+    }
+
+    #[test]
+    fn presets_must_be_consistent() {
+        assert_eq!(solar_system().secondary.len(), 9);
+        assert_eq!(earth_moon().secondary.len(), 1);
+        assert_eq!(sun_earth().secondary.len(), 1);
+    }
+}
@@ -0,0 +1,234 @@
+use std::fmt::Display;
+
+use serde::{de::Error, Deserialize};
+
+use crate::PositiveFloat;
+
+/// A physical quantity as found in a serialized document: either a bare number, in the default
+/// unit, or a number followed by the symbol of its unit.
+#[derive(Deserialize)]
+#[serde(untagged)]
+pub(crate) enum Quantity {
+    Number(f64),
+    Text(String),
+}
+
+/// An error while parsing a quantity from a string.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseQuantityError(String);
+
+impl Display for ParseQuantityError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl std::error::Error for ParseQuantityError {}
+
+impl Quantity {
+    /// Returns the value of the quantity given the symbols of the supported units and the factor
+    /// converting each of them into the returned value, being the first one the default unit.
+    pub(crate) fn value<E: Error>(self, units: &[(&str, f64)]) -> Result<f64, E> {
+        match self {
+            Quantity::Number(value) => Ok(value * default_factor(units)),
+            Quantity::Text(text) => parse(&text, units).map_err(E::custom),
+        }
+    }
+
+    /// Same as [Quantity::value], but failing if the value is either negative or non-finite.
+    pub(crate) fn positive_value<E: Error>(self, units: &[(&str, f64)]) -> Result<f64, E> {
+        let value = self.value(units)?;
+        PositiveFloat::try_from(value)
+            .map(|value| value.get())
+            .map_err(E::custom)
+    }
+}
+
+/// Returns the factor of the default unit, which is the first one.
+fn default_factor(units: &[(&str, f64)]) -> f64 {
+    units.first().map(|(_, factor)| *factor).unwrap_or(1.)
+}
+
+/// Parses a number followed by an optional unit, matched case-insensitively.
+pub(crate) fn parse(text: &str, units: &[(&str, f64)]) -> Result<f64, ParseQuantityError> {
+    let text = text.trim().to_lowercase();
+    if let Ok(value) = text.parse::<f64>() {
+        return Ok(value * default_factor(units));
+    }
+
+    let mut units = units.to_vec();
+    units.sort_by_key(|(symbol, _)| std::cmp::Reverse(symbol.len()));
+
+    let (value, factor) = units
+        .into_iter()
+        .find_map(|(symbol, factor)| {
+            text.strip_suffix(&symbol.to_lowercase())
+                .map(|value| (value, factor))
+        })
+        .ok_or_else(|| ParseQuantityError(format!("unknown unit in quantity {text:?}")))?;
+
+    value
+        .trim()
+        .parse::<f64>()
+        .map(|value| value * factor)
+        .map_err(|_| ParseQuantityError(format!("invalid number in quantity {text:?}")))
+}
+
+/// Returns the given value followed by the symbol of its unit, in scientific notation if the
+/// value is too big or too small to be read comfortably.
+pub(crate) fn format(value: f64, unit: &str) -> String {
+    if value != 0. && value.is_finite() && !(1e-3..1e6).contains(&value.abs()) {
+        format!("{value:e} {unit}")
+    } else {
+        format!("{value} {unit}")
+    }
+}
+
+/// Serializes a [Duration](std::time::Duration) as a number of seconds and deserializes it from
+/// either a number of seconds or a number followed by its time unit.
+pub(crate) mod duration {
+    use std::time::Duration;
+
+    use serde::{de::Error, Deserialize, Deserializer, Serializer};
+
+    use super::{ParseQuantityError, Quantity};
+    use crate::duration::{
+        SECONDS_PER_DAY, SECONDS_PER_HOUR, SECONDS_PER_MINUTE, SECONDS_PER_YEAR,
+    };
+
+    const UNITS: &[(&str, f64)] = &[
+        ("s", 1.),
+        ("ms", 0.001),
+        ("min", SECONDS_PER_MINUTE as f64),
+        ("h", SECONDS_PER_HOUR as f64),
+        ("d", SECONDS_PER_DAY as f64),
+        ("days", SECONDS_PER_DAY as f64),
+        ("y", SECONDS_PER_YEAR as f64),
+        ("years", SECONDS_PER_YEAR as f64),
+    ];
+
+    /// Parses a duration from either a number of seconds or a number followed by its time unit.
+    pub(crate) fn parse(text: &str) -> Result<Duration, ParseQuantityError> {
+        let seconds = super::parse(text, UNITS)?;
+        Duration::try_from_secs_f64(seconds)
+            .map_err(|_| ParseQuantityError(format!("{text:?} is not a valid duration")))
+    }
+
+    pub(crate) fn serialize<S: Serializer>(
+        duration: &Duration,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.serialize_f64(duration.as_secs_f64())
+    }
+
+    pub(crate) fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Duration, D::Error> {
+        let seconds = Quantity::deserialize(deserializer)?.value(UNITS)?;
+        Duration::try_from_secs_f64(seconds).map_err(D::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{format, parse};
+
+    #[test]
+    fn parse_must_not_fail() {
+        struct Test {
+            name: &'static str,
+            text: &'static str,
+            output: Result<f64, ()>,
+        }
+
+        let units = &[("m", 0.001), ("km", 1.), ("AU", 10.)];
+
+        vec![
+            Test {
+                name: "bare number in the default unit",
+                text: "12.5",
+                output: Ok(0.0125),
+            },
+            Test {
+                name: "number with unit",
+                text: "1500 m",
+                output: Ok(1.5),
+            },
+            Test {
+                name: "number with longest matching unit",
+                text: "3km",
+                output: Ok(3.),
+            },
+            Test {
+                name: "unit in different case",
+                text: " 2 au ",
+                output: Ok(20.),
+            },
+            Test {
+                name: "scientific notation",
+                text: "1e3 m",
+                output: Ok(1.),
+            },
+            Test {
+                name: "unknown unit",
+                text: "2 pc",
+                output: Err(()),
+            },
+            Test {
+                name: "missing number",
+                text: "km",
+                output: Err(()),
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let output = parse(test.text, units).map_err(|_| ());
+            assert_eq!(
+                output, test.output,
+                "{}: got output = {:?}, want {:?}",
+                test.name, output, test.output
+            );
+        });
+    }
+
+    #[test]
+    fn format_must_not_fail() {
+        struct Test {
+            name: &'static str,
+            value: f64,
+            output: &'static str,
+        }
+
+        vec![
+            Test {
+                name: "zero",
+                value: 0.,
+                output: "0 km",
+            },
+            Test {
+                name: "readable value",
+                value: 696_340.,
+                output: "696340 km",
+            },
+            Test {
+                name: "big value",
+                value: 1.9891e30,
+                output: "1.9891e30 km",
+            },
+            Test {
+                name: "small value",
+                value: 0.0001,
+                output: "1e-4 km",
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let output = format(test.value, "km");
+            assert_eq!(
+                output, test.output,
+                "{}: got output = {:?}, want {:?}",
+                test.name, output, test.output
+            );
+        });
+    }
+}
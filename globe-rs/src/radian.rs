@@ -1,39 +1,83 @@
 use std::{
     f64::consts::PI,
-    fmt::Debug,
+    fmt::{Debug, Display},
+    iter::Sum,
     ops::{Add, Div, Mul, Neg, Sub},
+    str::FromStr,
     time::Duration,
 };
 
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
-use crate::PositiveFloat;
+use crate::{
+    quantity::{self, Quantity},
+    Frequency, ParseQuantityError, PositiveFloat,
+};
+
+/// The symbols of the units an angle may be deserialized from, and how many radians each of them
+/// represents.
+const UNITS: &[(&str, f64)] = &[
+    ("rad", 1.),
+    ("deg", PI / 180.),
+    ("°", PI / 180.),
+    ("pi", PI),
+    ("π", PI),
+];
+
+/// Same as [UNITS], but telling how many degrees each of them represents.
+const DEGREE_UNITS: &[(&str, f64)] = &[
+    ("deg", 1.),
+    ("°", 1.),
+    ("rad", 180. / PI),
+    ("pi", 180.),
+    ("π", 180.),
+];
+
+/// Returns the given angle wrapped into the range of [0, full].
+fn wrap(value: f64, full: f64) -> f64 {
+    if (0. ..=full).contains(&value) {
+        return value;
+    }
+
+    let mut modulus = value % full;
+    if value.is_sign_negative() {
+        modulus = (modulus + full) % full;
+    }
+
+    modulus
+}
 
 /// The [radian](https://en.wikipedia.org/wiki/Radian) unit, which is always a positive number
-/// within the range of [0, 2π].
-#[derive(Default, Clone, Copy, PartialEq, PartialOrd, Serialize, Deserialize)]
+/// within the range of [0, 2π], unless built from a non-finite value, which is kept as infinity.
+#[derive(Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
 pub struct Radian(PositiveFloat);
 
 impl From<f64> for Radian {
     fn from(value: f64) -> Self {
-        if (0. ..=Self::TWO_PI.as_f64()).contains(&value) {
-            return Self(value.into());
-        }
-
-        let mut modulus = value % Self::TWO_PI.as_f64();
-        if value.is_sign_negative() {
-            modulus = (modulus + Self::TWO_PI.as_f64()) % Self::TWO_PI.as_f64();
-        }
+        Self(PositiveFloat::new_saturating(wrap(
+            value,
+            Self::TWO_PI.as_f64(),
+        )))
+    }
+}
 
-        Self(modulus.into())
+impl From<Degree> for Radian {
+    fn from(degree: Degree) -> Self {
+        Self::from_degrees(degree.as_f64())
     }
 }
 
 impl From<Duration> for Radian {
-    /// The radiants per seconds the period represents.
+    /// The radiants per seconds the period represents, being zero if so is the period.
     fn from(period: Duration) -> Self {
-        let hz = 1. / period.as_secs_f64();
-        (hz * Self::TWO_PI.as_f64()).into()
+        Frequency::from(period).into()
+    }
+}
+
+impl From<Frequency> for Radian {
+    /// The radiants per seconds the frequency represents.
+    fn from(frequency: Frequency) -> Self {
+        (frequency.as_hz() * Self::TWO_PI.as_f64()).into()
     }
 }
 
@@ -53,6 +97,13 @@ impl Add for Radian {
     }
 }
 
+impl Sum for Radian {
+    /// Returns the rotation resulting from applying all the given ones, one after the other.
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::default(), Add::add)
+    }
+}
+
 impl Sub for Radian {
     type Output = Self;
 
@@ -85,6 +136,46 @@ impl Debug for Radian {
     }
 }
 
+impl Display for Radian {
+    /// Displays the angle in radians, or in degrees if the alternate flag (`{:#}`) is set,
+    /// honoring the precision of the formatter, if any.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if f.alternate() {
+            return Display::fmt(&Degree::from(*self), f);
+        }
+
+        match f.precision() {
+            Some(precision) => write!(f, "{:.precision$} rad", self.as_f64()),
+            None => write!(f, "{} rad", self.as_f64()),
+        }
+    }
+}
+
+impl FromStr for Radian {
+    type Err = ParseQuantityError;
+
+    /// Parses an angle from either a number of radians or a number followed by its unit, being
+    /// any of `rad`, `deg`, `°`, `pi` or `π`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        quantity::parse(s, UNITS).map(Self::from)
+    }
+}
+
+impl Serialize for Radian {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_f64(self.as_f64())
+    }
+}
+
+impl<'de> Deserialize<'de> for Radian {
+    /// Deserializes an angle from either a number of radians or a number followed by its unit.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Quantity::deserialize(deserializer)?
+            .value(UNITS)
+            .map(Self::from)
+    }
+}
+
 impl Radian {
     pub const TWO_PI: Self = Self(PositiveFloat(2. * PI));
 
@@ -93,22 +184,546 @@ impl Radian {
         self.0 == Self::TWO_PI.0
     }
 
+    /// Returns the radiant corresponding to the given amount of degrees.
+    pub fn from_degrees(degrees: f64) -> Self {
+        Self::from(degrees.to_radians())
+    }
+
     /// Returns the amount of radiants as a [f64].
     pub fn as_f64(&self) -> f64 {
         self.0 .0
     }
 
+    /// Returns the amount of degrees as a [f64].
+    pub fn as_degrees(&self) -> f64 {
+        self.as_f64().to_degrees()
+    }
+
+    /// Returns the sine of the angle.
+    pub fn sin(&self) -> f64 {
+        self.as_f64().sin()
+    }
+
+    /// Returns the cosine of the angle.
+    pub fn cos(&self) -> f64 {
+        self.as_f64().cos()
+    }
+
+    /// Returns the tangent of the angle.
+    pub fn tan(&self) -> f64 {
+        self.as_f64().tan()
+    }
+
+    /// Returns the angle of the point (x, y) from the positive X axis, as the four quadrant
+    /// [f64::atan2] does, but wrapped as any other radiant.
+    pub fn atan2(y: f64, x: f64) -> Self {
+        Self::from(y.atan2(x))
+    }
+
     /// Returns the absolute difference between self and the given radiant.
     pub fn abs_diff(self, rhs: Self) -> Self {
-        Self((self.0 .0 - rhs.0 .0).abs().into())
+        Self(PositiveFloat::from_abs(self.0 .0 - rhs.0 .0))
+    }
+
+    /// Returns the shortest rotation from the given radiant to self, being positive if it goes
+    /// counterclockwise. The returned value is always in the range of (-π, π].
+    pub fn signed_diff(self, rhs: Self) -> f64 {
+        let diff = (self.0 .0 - rhs.0 .0).rem_euclid(Self::TWO_PI.as_f64());
+        if diff > PI {
+            diff - Self::TWO_PI.as_f64()
+        } else {
+            diff
+        }
+    }
+
+    /// Returns the radiant at the given fraction of the shortest rotation from self to rhs.
+    pub fn lerp_angle(self, rhs: Self, t: f64) -> Self {
+        (self.0 .0 + rhs.signed_diff(self) * t).into()
+    }
+
+    /// Returns the radiant representing the same rotation as self, but in the opposite direction.
+    pub fn reversed(self) -> Self {
+        -self
+    }
+}
+
+/// An angle in degrees, which is always a positive number within the range of [0, 360].
+#[derive(Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+pub struct Degree(PositiveFloat);
+
+impl From<f64> for Degree {
+    fn from(value: f64) -> Self {
+        Self(PositiveFloat::new_saturating(wrap(value, 360.)))
+    }
+}
+
+impl From<Radian> for Degree {
+    fn from(radian: Radian) -> Self {
+        Self(PositiveFloat::new_saturating(radian.as_degrees()))
+    }
+}
+
+impl Debug for Degree {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("Degree")
+            .field(&format!("{}°", self.0))
+            .finish()
+    }
+}
+
+impl Display for Degree {
+    /// Displays the angle in degrees, honoring the precision of the formatter, if any.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match f.precision() {
+            Some(precision) => write!(f, "{:.precision$}°", self.as_f64()),
+            None => write!(f, "{}°", self.as_f64()),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Degree {
+    /// Deserializes an angle from either a number of degrees or a number followed by its unit.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Quantity::deserialize(deserializer)?
+            .value(DEGREE_UNITS)
+            .map(Self::from)
+    }
+}
+
+impl Degree {
+    /// Returns the amount of degrees as a [f64].
+    pub fn as_f64(&self) -> f64 {
+        self.0 .0
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use std::f64::consts::{FRAC_PI_2, PI};
+    use std::f64::consts::{FRAC_PI_2, FRAC_PI_4, PI};
 
-    use crate::Radian;
+    use crate::{tests::approx_eq, Degree, Radian};
+
+    #[test]
+    fn degrees_must_wrap_like_radians() {
+        struct Test {
+            name: &'static str,
+            degrees: f64,
+            radians: f64,
+        }
+
+        vec![
+            Test {
+                name: "degrees within range",
+                degrees: 90.,
+                radians: FRAC_PI_2,
+            },
+            Test {
+                name: "full rotation",
+                degrees: 360.,
+                radians: Radian::TWO_PI.as_f64(),
+            },
+            Test {
+                name: "negative degrees",
+                degrees: -90.,
+                radians: -FRAC_PI_2,
+            },
+            Test {
+                name: "overflowing degrees",
+                degrees: 450.,
+                radians: Radian::TWO_PI.as_f64() + FRAC_PI_2,
+            },
+            Test {
+                name: "several negative rotations",
+                degrees: -765.,
+                radians: -765_f64.to_radians(),
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let want = Radian::from(test.radians);
+
+            let got = Radian::from_degrees(test.degrees);
+            assert!(
+                approx_eq(got.as_f64(), want.as_f64(), 1e-12),
+                "{}: got radian = {got:?}, want {want:?}",
+                test.name
+            );
+
+            let got = Radian::from(Degree::from(test.degrees));
+            assert!(
+                approx_eq(got.as_f64(), want.as_f64(), 1e-12),
+                "{}: got radian from degree = {got:?}, want {want:?}",
+                test.name
+            );
+
+            let got = Degree::from(want).as_f64();
+            assert!(
+                approx_eq(got, want.as_degrees(), 1e-12),
+                "{}: got degree = {got}, want {}",
+                test.name,
+                want.as_degrees()
+            );
+        });
+    }
+
+    #[test]
+    fn signed_diff_must_take_the_shortest_rotation() {
+        struct Test {
+            name: &'static str,
+            from: Radian,
+            to: Radian,
+            diff: f64,
+            half_way: Radian,
+        }
+
+        vec![
+            Test {
+                name: "counterclockwise",
+                from: Radian::from(0.5),
+                to: Radian::from(1.5),
+                diff: 1.,
+                half_way: Radian::from(1.),
+            },
+            Test {
+                name: "clockwise",
+                from: Radian::from(1.5),
+                to: Radian::from(0.5),
+                diff: -1.,
+                half_way: Radian::from(1.),
+            },
+            Test {
+                name: "counterclockwise across the seam",
+                from: Radian::from(-0.5),
+                to: Radian::from(0.5),
+                diff: 1.,
+                half_way: Radian::from(0.),
+            },
+            Test {
+                name: "clockwise across the seam",
+                from: Radian::from(0.25),
+                to: Radian::from(-0.25),
+                diff: -0.5,
+                half_way: Radian::from(0.),
+            },
+            Test {
+                name: "half a rotation",
+                from: Radian::from(0.),
+                to: Radian::from(PI),
+                diff: PI,
+                half_way: Radian::from(FRAC_PI_2),
+            },
+            Test {
+                name: "full rotation",
+                from: Radian::from(0.),
+                to: Radian::TWO_PI,
+                diff: 0.,
+                half_way: Radian::from(0.),
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let diff = test.to.signed_diff(test.from);
+            assert!(
+                approx_eq(diff, test.diff, 1e-12),
+                "{}: got diff = {diff}, want {}",
+                test.name,
+                test.diff
+            );
+
+            let half_way = test.from.lerp_angle(test.to, 0.5);
+            assert!(
+                half_way.signed_diff(test.half_way).abs() < 1e-12,
+                "{}: got half way = {half_way:?}, want {:?}",
+                test.name,
+                test.half_way
+            );
+        });
+    }
+
+    #[test]
+    fn signed_diff_must_be_inverse_of_add() {
+        // A xorshift generator, so the pairs are arbitrary but the test deterministic.
+        let mut seed = 0x2545_f491_4f6c_dd1d_u64;
+        let mut next = || {
+            seed ^= seed << 13;
+            seed ^= seed >> 7;
+            seed ^= seed << 17;
+            (seed as f64 / u64::MAX as f64 - 0.5) * 8. * PI
+        };
+
+        (0..1_000).for_each(|_| {
+            let (a, b) = (Radian::from(next()), Radian::from(next()));
+
+            let diff = b.signed_diff(a);
+            assert!(
+                -PI < diff && diff <= PI,
+                "a = {a:?}, b = {b:?}: got diff = {diff}, want within (-π, π]"
+            );
+
+            let got = a + Radian::from(diff);
+            assert!(
+                got.signed_diff(b).abs() < 1e-9,
+                "a = {a:?}, b = {b:?}: got a + diff = {got:?}, want {b:?}"
+            );
+
+            assert!(
+                approx_eq(a.lerp_angle(b, 1.).signed_diff(b), 0., 1e-9),
+                "a = {a:?}, b = {b:?}: lerp must end at b"
+            );
+        });
+    }
+
+    #[test]
+    fn reversed_must_rotate_the_other_way() {
+        struct Test {
+            name: &'static str,
+            input: Radian,
+            output: Radian,
+        }
+
+        vec![
+            Test {
+                name: "zero",
+                input: Radian::from(0.),
+                output: Radian::from(0.),
+            },
+            Test {
+                name: "quarter of rotation",
+                input: Radian::from(FRAC_PI_2),
+                output: Radian::from(3. * FRAC_PI_2),
+            },
+            Test {
+                name: "half rotation",
+                input: Radian::from(PI),
+                output: Radian::from(PI),
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let output = test.input.reversed();
+            assert!(
+                approx_eq(output.as_f64(), test.output.as_f64(), 1e-12),
+                "{}: got output = {output:?}, want {:?}",
+                test.name,
+                test.output
+            );
+        });
+    }
+
+    #[test]
+    fn deserialize_must_not_fail() {
+        struct Test {
+            name: &'static str,
+            input: &'static str,
+            output: Option<f64>,
+        }
+
+        vec![
+            Test {
+                name: "bare number in radians",
+                input: "1.5",
+                output: Some(1.5),
+            },
+            Test {
+                name: "radians",
+                input: r#""1.5 rad""#,
+                output: Some(1.5),
+            },
+            Test {
+                name: "degrees",
+                input: r#""5.14 deg""#,
+                output: Some(5.14_f64.to_radians()),
+            },
+            Test {
+                name: "degrees symbol",
+                input: r#""-90°""#,
+                output: Some(Radian::TWO_PI.as_f64() - FRAC_PI_2),
+            },
+            Test {
+                name: "invalid suffix",
+                input: r#""90 grad""#,
+                output: None,
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let output = serde_json::from_str::<Radian>(test.input)
+                .ok()
+                .map(|radian| radian.as_f64());
+
+            assert!(
+                match (output, test.output) {
+                    (Some(output), Some(want)) => approx_eq(output, want, 1e-12),
+                    (output, want) => output == want,
+                },
+                "{}: got output = {:?}, want {:?}",
+                test.name,
+                output,
+                test.output
+            );
+        });
+    }
+
+    #[test]
+    fn degree_deserialize_must_not_fail() {
+        struct Test {
+            name: &'static str,
+            input: &'static str,
+            output: Option<f64>,
+        }
+
+        vec![
+            Test {
+                name: "bare number in degrees",
+                input: "90",
+                output: Some(90.),
+            },
+            Test {
+                name: "more than a full rotation",
+                input: "720",
+                output: Some(0.),
+            },
+            Test {
+                name: "negative degrees",
+                input: "-90",
+                output: Some(270.),
+            },
+            Test {
+                name: "degrees",
+                input: r#""5.14 deg""#,
+                output: Some(5.14),
+            },
+            Test {
+                name: "radians",
+                input: r#""1.5 rad""#,
+                output: Some(1.5_f64.to_degrees()),
+            },
+            Test {
+                name: "invalid suffix",
+                input: r#""90 grad""#,
+                output: None,
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let output = serde_json::from_str::<Degree>(test.input)
+                .ok()
+                .map(|degree| degree.as_f64());
+
+            assert!(
+                match (output, test.output) {
+                    (Some(output), Some(want)) => approx_eq(output, want, 1e-12),
+                    (output, want) => output == want,
+                },
+                "{}: got output = {:?}, want {:?}",
+                test.name,
+                output,
+                test.output
+            );
+        });
+    }
+
+    #[test]
+    fn display_must_not_fail() {
+        assert_eq!(Radian::from(1.5).to_string(), "1.5 rad");
+        assert_eq!(format!("{:.2}", Radian::from(PI)), "3.14 rad");
+        assert_eq!(format!("{:#}", Radian::from(PI)), "180°");
+        assert_eq!(format!("{:#.1}", Radian::from(-FRAC_PI_2)), "270.0°");
+        assert_eq!(Degree::from(-90.).to_string(), "270°");
+        assert_eq!(format!("{:.1}", Degree::from(5.14)), "5.1°");
+    }
+
+    #[test]
+    fn from_str_must_not_fail() {
+        struct Test {
+            name: &'static str,
+            input: &'static str,
+            output: Result<f64, ()>,
+        }
+
+        vec![
+            Test {
+                name: "bare number of radians",
+                input: "1.57",
+                output: Ok(1.57),
+            },
+            Test {
+                name: "radians",
+                input: "2 rad",
+                output: Ok(2.),
+            },
+            Test {
+                name: "degrees",
+                input: "90deg",
+                output: Ok(FRAC_PI_2),
+            },
+            Test {
+                name: "degrees symbol",
+                input: "-90°",
+                output: Ok(3. * FRAC_PI_2),
+            },
+            Test {
+                name: "fraction of pi",
+                input: "0.5pi",
+                output: Ok(FRAC_PI_2),
+            },
+            Test {
+                name: "fraction of pi symbol",
+                input: "1.5 π",
+                output: Ok(3. * FRAC_PI_2),
+            },
+            Test {
+                name: "more than a full rotation",
+                input: "3PI",
+                output: Ok(PI),
+            },
+            Test {
+                name: "empty string",
+                input: "",
+                output: Err(()),
+            },
+            Test {
+                name: "unknown unit",
+                input: "1.5 grad",
+                output: Err(()),
+            },
+            Test {
+                name: "unit without number",
+                input: "pi",
+                output: Err(()),
+            },
+            Test {
+                name: "not a number",
+                input: "half deg",
+                output: Err(()),
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let output = test.input.parse::<Radian>().map(|radian| radian.as_f64());
+            assert!(
+                match (output.clone(), test.output) {
+                    (Ok(output), Ok(want)) => approx_eq(output, want, 1e-12),
+                    (output, want) => output.map_err(|_| ()) == want,
+                },
+                "{}: got output = {:?}, want {:?}",
+                test.name,
+                output,
+                test.output
+            );
+        });
+    }
+
+    #[test]
+    fn trigonometry_must_match_f64() {
+        let radian = Radian::from(-FRAC_PI_4);
+
+        assert_eq!(radian.sin(), (2. * PI - FRAC_PI_4).sin());
+        assert_eq!(radian.cos(), (2. * PI - FRAC_PI_4).cos());
+        assert_eq!(radian.tan(), (2. * PI - FRAC_PI_4).tan());
+        assert_eq!(Radian::atan2(-1., 1.), radian);
+        assert_eq!(Radian::atan2(0., -1.), Radian::from(PI));
+    }
 
     #[test]
     fn radiant_must_not_exceed_boundaries() {
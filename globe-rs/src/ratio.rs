@@ -1,25 +1,75 @@
-use std::fmt::Debug;
+use std::{
+    fmt::{Debug, Display},
+    iter::Product,
+    ops::Mul,
+};
 
-use serde::{Deserialize, Serialize};
+use serde::{de::Error as _, Deserialize, Deserializer, Serialize, Serializer};
 
 use crate::PositiveFloat;
 
+/// An error while building a [Ratio] from an arbitrary value.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum RatioError {
+    /// The value is not a number.
+    NotANumber,
+    /// The value is lower than 0.
+    Negative(f64),
+    /// The value is equal to or greater than 1.
+    NotLowerThanOne(f64),
+}
+
+impl Display for RatioError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RatioError::NotANumber => write!(f, "a ratio must be a number"),
+            RatioError::Negative(value) => write!(f, "a ratio must not be negative, got {value}"),
+            RatioError::NotLowerThanOne(value) => {
+                write!(f, "a ratio must be lower than 1, got {value}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for RatioError {}
+
 /// A value that must be in the range of [[0, 1]].
-#[derive(Default, Clone, Copy, PartialEq, PartialOrd, Serialize, Deserialize)]
+///
+/// Since the eccentricity of an orbit is a ratio, orbits are closed by construction as long as
+/// they are built through [Ratio::try_new]. Deserialized ratios are only checked to be positive
+/// numbers, leaving values greater than or equal to 1 to be reported by
+/// [OrbitalSystem::validate](crate::OrbitalSystem::validate) as
+/// [SystemError::UnboundOrbit](crate::SystemError::UnboundOrbit), next to the body they belong to.
+#[derive(Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
 pub struct Ratio(PositiveFloat);
 
 impl From<f64> for Ratio {
     fn from(value: f64) -> Self {
         if value > 1. {
-            Self(PositiveFloat::from(1.))
-        } else if value < 0. {
-            Self(PositiveFloat::from(0.))
+            Self(PositiveFloat(1.))
+        } else if value.is_nan() {
+            Self::default()
         } else {
-            Self(value.into())
+            Self(PositiveFloat::new_saturating(value))
         }
     }
 }
 
+impl Mul for Ratio {
+    type Output = Self;
+
+    /// Returns the ratio of a ratio, which is always within the range of [[0, 1]] as well.
+    fn mul(self, rhs: Self) -> Self::Output {
+        Self(self.0 * rhs.0)
+    }
+}
+
+impl Product for Ratio {
+    fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self(PositiveFloat(1.)), Mul::mul)
+    }
+}
+
 impl Debug for Ratio {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_tuple("Ratio")
@@ -28,9 +78,214 @@ impl Debug for Ratio {
     }
 }
 
+impl Display for Ratio {
+    /// Displays the ratio as a percentage, honoring the precision of the formatter, if any.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match f.precision() {
+            Some(precision) => write!(f, "{:.precision$}%", self.as_percent()),
+            None => write!(f, "{}%", self.as_percent()),
+        }
+    }
+}
+
+impl Serialize for Ratio {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_f64(self.as_f64())
+    }
+}
+
+impl<'de> Deserialize<'de> for Ratio {
+    /// Deserializes a ratio from any positive number, including those greater than 1.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = f64::deserialize(deserializer)?;
+        if value.is_nan() {
+            return Err(D::Error::custom(RatioError::NotANumber));
+        }
+
+        if value < 0. {
+            return Err(D::Error::custom(RatioError::Negative(value)));
+        }
+
+        Ok(Self(PositiveFloat(value)))
+    }
+}
+
 impl Ratio {
+    /// Returns the ratio closest to the given value within the range of [[0, 1]], taking NaN as
+    /// zero.
+    pub fn new_clamped(value: f64) -> Self {
+        Self::from(value)
+    }
+
+    /// Returns the ratio for the given value if, and only if, it is within the range of [0, 1).
+    pub fn try_new(value: f64) -> Result<Self, RatioError> {
+        if value.is_nan() {
+            Err(RatioError::NotANumber)
+        } else if value < 0. {
+            Err(RatioError::Negative(value))
+        } else if value >= 1. {
+            Err(RatioError::NotLowerThanOne(value))
+        } else {
+            Ok(Self(PositiveFloat(value)))
+        }
+    }
+
+    /// Returns the ratio closest to the given percentage within the range of [[0, 1]].
+    pub fn percent(value: f64) -> Self {
+        Self::from(value / 100.)
+    }
+
     /// Returns the ratio as a [f64].
     pub fn as_f64(&self) -> f64 {
         self.0 .0
     }
+
+    /// Returns the ratio as a percentage.
+    pub fn as_percent(&self) -> f64 {
+        self.as_f64() * 100.
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{tests::approx_eq, Ratio, RatioError};
+
+    #[test]
+    fn ratio_must_be_within_bounds() {
+        struct Test {
+            name: &'static str,
+            input: f64,
+            clamped: f64,
+            output: Result<f64, RatioError>,
+        }
+
+        vec![
+            Test {
+                name: "zero",
+                input: 0.,
+                clamped: 0.,
+                output: Ok(0.),
+            },
+            Test {
+                name: "within range",
+                input: 0.206,
+                clamped: 0.206,
+                output: Ok(0.206),
+            },
+            Test {
+                name: "closest to one",
+                input: 1. - f64::EPSILON,
+                clamped: 1. - f64::EPSILON,
+                output: Ok(1. - f64::EPSILON),
+            },
+            Test {
+                name: "one",
+                input: 1.,
+                clamped: 1.,
+                output: Err(RatioError::NotLowerThanOne(1.)),
+            },
+            Test {
+                name: "greater than one",
+                input: 1.7,
+                clamped: 1.,
+                output: Err(RatioError::NotLowerThanOne(1.7)),
+            },
+            Test {
+                name: "negative",
+                input: -0.1,
+                clamped: 0.,
+                output: Err(RatioError::Negative(-0.1)),
+            },
+            Test {
+                name: "negative zero",
+                input: -0.,
+                clamped: 0.,
+                output: Ok(0.),
+            },
+            Test {
+                name: "not a number",
+                input: f64::NAN,
+                clamped: 0.,
+                output: Err(RatioError::NotANumber),
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let clamped = Ratio::new_clamped(test.input).as_f64();
+            assert_eq!(
+                clamped, test.clamped,
+                "{}: got clamped = {clamped}, want {}",
+                test.name, test.clamped
+            );
+
+            let output = Ratio::try_new(test.input).map(|ratio| ratio.as_f64());
+            assert_eq!(
+                output, test.output,
+                "{}: got output = {:?}, want {:?}",
+                test.name, output, test.output
+            );
+        });
+    }
+
+    #[test]
+    fn percent_must_not_fail() {
+        let ratio = Ratio::percent(20.6);
+        assert!(
+            approx_eq(ratio.as_f64(), 0.206, 1e-12),
+            "got ratio = {ratio:?}, want 0.206"
+        );
+        assert!(
+            approx_eq(ratio.as_percent(), 20.6, 1e-12),
+            "got percent = {}, want 20.6",
+            ratio.as_percent()
+        );
+
+        assert_eq!(Ratio::percent(150.), Ratio::from(1.));
+        assert_eq!(Ratio::percent(-5.), Ratio::from(0.));
+    }
+
+    #[test]
+    fn display_must_not_fail() {
+        assert_eq!(Ratio::from(0.5).to_string(), "50%");
+        assert_eq!(format!("{:.1}", Ratio::from(0.0167)), "1.7%");
+    }
+
+    #[test]
+    fn deserialize_must_not_fail() {
+        struct Test {
+            name: &'static str,
+            input: &'static str,
+            output: Option<f64>,
+        }
+
+        vec![
+            Test {
+                name: "within range",
+                input: "0.206",
+                output: Some(0.206),
+            },
+            Test {
+                name: "greater than one",
+                input: "1.5",
+                output: Some(1.5),
+            },
+            Test {
+                name: "negative",
+                input: "-0.5",
+                output: None,
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let output = serde_json::from_str::<Ratio>(test.input)
+                .ok()
+                .map(|ratio| ratio.as_f64());
+
+            assert_eq!(
+                output, test.output,
+                "{}: got output = {:?}, want {:?}",
+                test.name, output, test.output
+            );
+        });
+    }
 }
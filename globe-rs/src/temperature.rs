@@ -0,0 +1,186 @@
+use std::fmt::{Debug, Display};
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::{
+    quantity::{self, Quantity},
+    PositiveFloat,
+};
+
+/// The temperature of the freezing point of water, as kelvins.
+const ZERO_CELSIUS: f64 = 273.15;
+
+/// The symbols of the units a temperature may be deserialized from, and how many kelvins each of
+/// them represents.
+const UNITS: &[(&str, f64)] = &[("K", 1.)];
+
+/// The absolute temperature of an arbitrary object, which is always a positive number.
+#[derive(Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Temperature(PositiveFloat);
+
+impl Debug for Temperature {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("Temperature")
+            .field(&format!("{} K", self.0))
+            .finish()
+    }
+}
+
+impl Display for Temperature {
+    /// Displays the temperature in kelvins, honoring the precision of the formatter, if any.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match f.precision() {
+            Some(precision) => write!(f, "{:.precision$} K", self.as_kelvin()),
+            None => write!(f, "{} K", self.as_kelvin()),
+        }
+    }
+}
+
+impl Serialize for Temperature {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&quantity::format(self.as_kelvin(), "K"))
+    }
+}
+
+impl<'de> Deserialize<'de> for Temperature {
+    /// Deserializes a temperature from either a number of kelvins or a number followed by its
+    /// unit.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Quantity::deserialize(deserializer)?
+            .positive_value(UNITS)
+            .map(Self::kelvin)
+    }
+}
+
+impl Temperature {
+    pub const ZERO: Self = Self(PositiveFloat::ZERO);
+
+    /// Returns a new temperature of the given kelvins, saturating at the absolute zero.
+    pub fn kelvin(kelvin: f64) -> Self {
+        Self(PositiveFloat::new_saturating(kelvin))
+    }
+
+    /// Returns a new temperature of the given degrees Celsius, saturating at the absolute zero.
+    pub fn celsius(celsius: f64) -> Self {
+        Self::kelvin(celsius + ZERO_CELSIUS)
+    }
+
+    /// Returns a [f64] representing the temperature in kelvins.
+    pub fn as_kelvin(&self) -> f64 {
+        self.0 .0
+    }
+
+    /// Returns a [f64] representing the temperature in degrees Celsius.
+    pub fn as_celsius(&self) -> f64 {
+        self.as_kelvin() - ZERO_CELSIUS
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use alvidir::name::Name;
+
+    use crate::{tests::approx_eq, Body, Distance, Luminosity, Temperature};
+
+    #[test]
+    fn conversions_must_not_fail() {
+        struct Test {
+            name: &'static str,
+            output: f64,
+            want: f64,
+        }
+
+        vec![
+            Test {
+                name: "kelvin as celsius",
+                output: Temperature::kelvin(300.).as_celsius(),
+                want: 26.85,
+            },
+            Test {
+                name: "celsius as kelvin",
+                output: Temperature::celsius(-40.).as_kelvin(),
+                want: 233.15,
+            },
+            Test {
+                name: "below the absolute zero",
+                output: Temperature::celsius(-300.).as_kelvin(),
+                want: 0.,
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            assert!(
+                approx_eq(test.output, test.want, 1e-9),
+                "{}: got {}, want {}",
+                test.name,
+                test.output,
+                test.want
+            );
+        });
+
+        assert!(Temperature::kelvin(5_772.) > Temperature::celsius(5_000.));
+    }
+
+    #[test]
+    fn black_body_must_match_the_sun() {
+        let radius = Distance::km(695_700.);
+        let luminosity = Luminosity::from_black_body(radius, Temperature::kelvin(5_772.));
+        let ratio = luminosity.as_watts() / Luminosity::SUN.as_watts();
+        assert!(
+            approx_eq(ratio, 1., 0.01),
+            "got luminosity = {luminosity:?}, want {:?}",
+            Luminosity::SUN
+        );
+
+        let sun = Body::new(Name::from_str("Sun").unwrap())
+            .with_radius(radius)
+            .with_luminosity(Luminosity::SUN);
+
+        let temperature = sun.effective_temperature().unwrap();
+        assert!(
+            approx_eq(temperature.as_kelvin(), 5_772., 5_772. * 0.01),
+            "got temperature = {temperature:?}, want 5772 K"
+        );
+
+        let planet = Body::new(Name::from_str("Earth").unwrap()).with_radius(radius);
+        assert_eq!(planet.effective_temperature(), None);
+    }
+
+    #[test]
+    fn deserialize_must_not_fail() {
+        struct Test {
+            name: &'static str,
+            input: &'static str,
+            output: Option<Temperature>,
+        }
+
+        vec![
+            Test {
+                name: "bare number in kelvins",
+                input: "5772",
+                output: Some(Temperature::kelvin(5_772.)),
+            },
+            Test {
+                name: "kelvins",
+                input: r#""5772 K""#,
+                output: Some(Temperature::kelvin(5_772.)),
+            },
+            Test {
+                name: "invalid suffix",
+                input: r#""5772 F""#,
+                output: None,
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let output = serde_json::from_str::<Temperature>(test.input).ok();
+            assert_eq!(
+                output, test.output,
+                "{}: got output = {:?}, want {:?}",
+                test.name, output, test.output
+            );
+        });
+    }
+}
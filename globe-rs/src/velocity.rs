@@ -1,12 +1,51 @@
-use std::fmt::Debug;
+use std::{
+    fmt::{Debug, Display},
+    ops::{Mul, Sub},
+    time::Duration,
+};
 
-use crate::PositiveFloat;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::{
+    quantity::{self, Quantity},
+    Distance, PositiveFloat,
+};
+
+const METERS_PER_KM: f64 = 1000.;
 
 /// The velocity at which an aritrary object moves throught space, which is always a positive
 /// number.
-#[derive(Default, Clone, Copy, PartialEq, PartialOrd)]
+#[derive(Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
 pub struct Velocity(PositiveFloat);
 
+impl Mul<Duration> for Velocity {
+    type Output = Distance;
+
+    /// Returns the distance covered at this velocity during the given time.
+    fn mul(self, rhs: Duration) -> Self::Output {
+        Distance::meters(self.as_meters_sec() * rhs.as_secs_f64())
+    }
+}
+
+impl Mul<Velocity> for Duration {
+    type Output = Distance;
+
+    fn mul(self, rhs: Velocity) -> Self::Output {
+        rhs * self
+    }
+}
+
+impl Sub for Velocity {
+    type Output = Self;
+
+    /// Returns the difference between both velocities, saturating at zero.
+    ///
+    /// See [Velocity::checked_sub] to tell apart reversed operands.
+    fn sub(self, rhs: Self) -> Self::Output {
+        self.saturating_sub(rhs)
+    }
+}
+
 impl Debug for Velocity {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_tuple("Velocity")
@@ -15,14 +54,202 @@ impl Debug for Velocity {
     }
 }
 
+impl Display for Velocity {
+    /// Displays the velocity in the most readable unit among meters and kilometers per second,
+    /// honoring the precision of the formatter, if any.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let (value, unit) = if self.as_meters_sec() >= METERS_PER_KM {
+            (self.as_meters_sec() / METERS_PER_KM, "km/s")
+        } else {
+            (self.as_meters_sec(), "m/s")
+        };
+
+        match f.precision() {
+            Some(precision) => write!(f, "{value:.precision$} {unit}"),
+            None => write!(f, "{value} {unit}"),
+        }
+    }
+}
+
+impl Serialize for Velocity {
+    /// Serializes the velocity in the most readable unit among meters and kilometers per second.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let velocity = if self.as_meters_sec() >= METERS_PER_KM {
+            quantity::format(self.as_meters_sec() / METERS_PER_KM, "km/s")
+        } else {
+            quantity::format(self.as_meters_sec(), "m/s")
+        };
+
+        serializer.serialize_str(&velocity)
+    }
+}
+
+impl<'de> Deserialize<'de> for Velocity {
+    /// Deserializes a velocity from either a number of meters per second or a number followed by
+    /// its unit.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Quantity::deserialize(deserializer)?
+            .positive_value(&[("m/s", 1.), ("km/s", METERS_PER_KM)])
+            .map(Self::meters_sec)
+    }
+}
+
 impl Velocity {
-    /// Returns a new velocity of v meters per second.
+    /// Returns a new velocity of v meters per second, saturating at zero.
     pub fn meters_sec(v: f64) -> Self {
-        Self(v.into())
+        Self(PositiveFloat::new_saturating(v))
     }
 
     /// Returns a [f64] representing the velocity in meters per second.
     pub fn as_meters_sec(&self) -> f64 {
         self.0 .0
     }
+
+    /// Returns the difference between self and the given velocity, or [None] if the latter is
+    /// greater.
+    pub fn checked_sub(self, rhs: Self) -> Option<Self> {
+        self.0.checked_sub(rhs.0).map(Self)
+    }
+
+    /// Returns the difference between self and the given velocity, saturating at zero.
+    pub fn saturating_sub(self, rhs: Self) -> Self {
+        Self(self.0 - rhs.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Velocity;
+
+    #[test]
+    fn deserialize_must_not_fail() {
+        struct Test {
+            name: &'static str,
+            input: &'static str,
+            output: Option<Velocity>,
+        }
+
+        vec![
+            Test {
+                name: "bare number in meters per second",
+                input: "500",
+                output: Some(Velocity::meters_sec(500.)),
+            },
+            Test {
+                name: "meters per second",
+                input: r#""500 m/s""#,
+                output: Some(Velocity::meters_sec(500.)),
+            },
+            Test {
+                name: "kilometers per second",
+                input: r#""29.78 km/s""#,
+                output: Some(Velocity::meters_sec(29_780.)),
+            },
+            Test {
+                name: "no whitespace",
+                input: r#""500m/s""#,
+                output: Some(Velocity::meters_sec(500.)),
+            },
+            Test {
+                name: "surrounding whitespace",
+                input: r#""  500 m/s  ""#,
+                output: Some(Velocity::meters_sec(500.)),
+            },
+            Test {
+                name: "different case",
+                input: r#""500 M/S""#,
+                output: Some(Velocity::meters_sec(500.)),
+            },
+            Test {
+                name: "scientific notation",
+                input: r#""5e2 m/s""#,
+                output: Some(Velocity::meters_sec(500.)),
+            },
+            Test {
+                name: "invalid suffix",
+                input: r#""500 m/h""#,
+                output: None,
+            },
+            Test {
+                name: "missing number",
+                input: r#""km/s""#,
+                output: None,
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let output = serde_json::from_str::<Velocity>(test.input).ok();
+            assert_eq!(
+                output, test.output,
+                "{}: got output = {:?}, want {:?}",
+                test.name, output, test.output
+            );
+        });
+    }
+
+    #[test]
+    fn serialize_must_not_fail() {
+        struct Test {
+            name: &'static str,
+            input: Velocity,
+            output: &'static str,
+        }
+
+        vec![
+            Test {
+                name: "meters per second",
+                input: Velocity::meters_sec(500.),
+                output: "500 m/s",
+            },
+            Test {
+                name: "kilometers per second",
+                input: Velocity::meters_sec(29_780.),
+                output: "29.78 km/s",
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let output = serde_json::to_value(test.input).unwrap();
+            assert_eq!(
+                output, test.output,
+                "{}: got output = {:?}, want {:?}",
+                test.name, output, test.output
+            );
+        });
+    }
+
+    #[test]
+    fn display_must_not_fail() {
+        struct Test {
+            name: &'static str,
+            input: String,
+            output: &'static str,
+        }
+
+        vec![
+            Test {
+                name: "meters per second",
+                input: Velocity::meters_sec(500.).to_string(),
+                output: "500 m/s",
+            },
+            Test {
+                name: "kilometers per second",
+                input: Velocity::meters_sec(29_780.).to_string(),
+                output: "29.78 km/s",
+            },
+            Test {
+                name: "with precision",
+                input: format!("{:.1}", Velocity::meters_sec(7_660.)),
+                output: "7.7 km/s",
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            assert_eq!(
+                test.input, test.output,
+                "{}: got output = {}, want {}",
+                test.name, test.input, test.output
+            );
+        });
+    }
 }